@@ -950,7 +950,20 @@ fn default_field_catalog() -> DebugFieldCatalog {
                         20.0,
                         0.05,
                     ),
-                    float_field("ball.wall_bounce_factor", "壁バウンド減衰", 0.0, 2.0, 0.01),
+                    float_field(
+                        "wall_material.glass.restitution",
+                        "ガラス壁の反発係数",
+                        0.0,
+                        2.0,
+                        0.01,
+                    ),
+                    float_field(
+                        "wall_material.mesh.restitution",
+                        "金網の反発係数",
+                        0.0,
+                        2.0,
+                        0.01,
+                    ),
                 ],
             },
             DebugFieldSection {
@@ -12,6 +12,12 @@
 //! # 設定名を指定
 //! cargo run --bin headless_sim -- -c debug
 //! # → assets/config/simulation_debug.ron を読み込み
+//!
+//! # マッチ形式プリセットを指定（game_config.ron の match_format.presets から選択）
+//! cargo run --bin headless_sim -- -c stress -f fast4
+//!
+//! # 環境条件（標高・気温・ボール内圧）をスイープ（environment_sweep を定義した設定）
+//! cargo run --bin headless_sim -- -c environment
 //! ```
 
 use clap::Parser;
@@ -27,6 +33,11 @@ struct Args {
     /// If omitted, uses simulation_config.ron
     #[arg(short, long)]
     config: Option<String>,
+
+    /// Match format preset name (e.g., "fast4", "pro_set", "timed")
+    /// Overrides execution.match_format in the simulation config
+    #[arg(short, long)]
+    format: Option<String>,
 }
 
 /// 設定ファイルパスを解決
@@ -45,7 +56,7 @@ fn main() {
     println!("=== Padel Game Headless Simulator ===\n");
 
     // GameConfig をロード
-    let game_config = match load_game_config("assets/config/game_config.ron") {
+    let mut game_config = match load_game_config("assets/config/game_config.ron") {
         Ok(c) => c,
         Err(e) => {
             eprintln!("Failed to load game config: {}", e);
@@ -66,12 +77,29 @@ fn main() {
         }
     };
 
+    // マッチ形式プリセットを適用（CLI > シミュレーション設定 > game_config.ron）
+    // @spec 30703_set_spec.md#req-30703-056
+    let match_format = args
+        .format
+        .clone()
+        .or_else(|| sim_file_config.execution.match_format.clone());
+    if let Some(name) = match_format {
+        if let Err(e) = game_config.select_match_format(&name) {
+            eprintln!("Failed to select match format: {}", e);
+            std::process::exit(1);
+        }
+    }
+
     if sim_file_config.execution.verbose {
         println!("\nExecution settings:");
         println!("  match_count: {}", sim_file_config.execution.match_count);
         println!("  timeout_secs: {}", sim_file_config.execution.timeout_secs);
         println!("  seed: {:?}", sim_file_config.execution.seed);
         println!("  verbose: {}", sim_file_config.execution.verbose);
+        println!(
+            "  match_format: {:?}",
+            game_config.match_format.selected.as_deref()
+        );
 
         println!("\nOutput settings:");
         println!("  result_file: {:?}", sim_file_config.output.result_file);
@@ -111,30 +139,94 @@ fn main() {
         println!();
     }
 
-    // SimulationConfig を設定ファイルから構築
-    let sim_config = SimulationConfig {
-        match_count: sim_file_config.execution.match_count,
-        timeout_secs: sim_file_config.execution.timeout_secs,
-        seed: sim_file_config.execution.seed,
-        verbose: sim_file_config.execution.verbose,
-        output_path: sim_file_config.output.result_file.clone(),
+    // 環境条件スイープ（空なら game_config.ron の条件で1回だけ実行）
+    // @spec 77100_headless_sim.md#req-77100-003
+    let cases = if sim_file_config.environment_sweep.is_empty() {
+        vec![None]
+    } else {
+        sim_file_config
+            .environment_sweep
+            .iter()
+            .cloned()
+            .map(Some)
+            .collect()
     };
 
-    // シミュレーション実行
-    let mut runner = SimulationRunner::new(sim_config).with_file_config(sim_file_config);
-    let report = runner.run(&game_config);
+    let mut summaries = Vec::new();
+    let mut total_anomalies = 0;
+    for case in cases {
+        let mut case_config = game_config.clone();
+        let mut result_file = sim_file_config.output.result_file.clone();
+        if let Some(case) = &case {
+            println!("\n=== Environment: {} ===", case.name);
+            case.apply_to(&mut case_config);
+            result_file = result_file.map(|path| result_file_for_case(&path, &case.name));
+        }
+
+        // SimulationConfig を設定ファイルから構築
+        let sim_config = SimulationConfig {
+            match_count: sim_file_config.execution.match_count,
+            timeout_secs: sim_file_config.execution.timeout_secs,
+            seed: sim_file_config.execution.seed,
+            verbose: sim_file_config.execution.verbose,
+            output_path: result_file,
+        };
+
+        // シミュレーション実行
+        let mut runner =
+            SimulationRunner::new(sim_config).with_file_config(sim_file_config.clone());
+        let report = runner.run(&case_config);
+
+        // サマリー出力
+        padel_game::simulation::SimulationReporter::new().print_summary(&report);
+
+        total_anomalies += report.total_anomalies;
+        if let Some(case) = case {
+            summaries.push((case.name, report));
+        }
+    }
 
-    // サマリー出力
-    padel_game::simulation::SimulationReporter::new().print_summary(&report);
+    // 条件ごとの比較
+    if !summaries.is_empty() {
+        println!("=== Environment Sweep ===");
+        println!(
+            "{:<16} {:<28} {:>10} {:>10} {:>10}",
+            "case", "environment", "rallies", "duration", "anomalies"
+        );
+        for (name, report) in &summaries {
+            println!(
+                "{:<16} {:<28} {:>10.1} {:>9.1}s {:>10}",
+                name,
+                report.environment,
+                report.avg_rally_count,
+                report.avg_duration_secs,
+                report.total_anomalies
+            );
+        }
+    }
 
     // 異常があった場合は終了コード1
-    if report.total_anomalies > 0 {
-        eprintln!(
-            "\nSimulation completed with {} anomalies.",
-            report.total_anomalies
-        );
+    if total_anomalies > 0 {
+        eprintln!("\nSimulation completed with {} anomalies.", total_anomalies);
         std::process::exit(1);
     }
 
     println!("\nSimulation completed successfully.");
 }
+
+/// ケースごとの結果ファイルパス（拡張子の前にケース名を付ける）
+/// - ("results.json", "altitude") -> "results_altitude.json"
+fn result_file_for_case(path: &str, case_name: &str) -> String {
+    let path = std::path::Path::new(path);
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(ext) => format!("{}_{}.{}", stem, case_name, ext.to_string_lossy()),
+        None => format!("{}_{}", stem, case_name),
+    };
+    path.with_file_name(file_name)
+        .to_string_lossy()
+        .into_owned()
+}
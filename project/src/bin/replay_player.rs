@@ -15,29 +15,33 @@
 
 use std::sync::Arc;
 
+use bevy::ecs::system::SystemParam;
 use bevy::{app::ScheduleRunnerPlugin, asset::AssetPlugin, prelude::*, state::app::StatesPlugin};
 use clap::Parser;
 
 use padel_game::character::{spawn_articulated_player, CharacterPlugin};
 use padel_game::components::AiController;
 use padel_game::core::{
-    BallHitEvent, PlayerJumpEvent, PlayerKnockbackEvent, PlayerLandEvent, PlayerMoveEvent,
-    RacketContactEvent, ShotEvent, ShotExecutedEvent, SwingIntentEvent,
+    BallHitEvent, CourtSide, PlayerJumpEvent, PlayerKnockbackEvent, PlayerLandEvent,
+    PlayerMoveEvent, RacketContactEvent, ServeLetEvent, ServeNetFaultEvent, ShotEvent,
+    ShotExecutedEvent, SwingIntentEvent,
 };
 use padel_game::replay::loader::load_replay;
-use padel_game::replay::player::{replay_input_system, ReplayPlayer};
+use padel_game::replay::player::{
+    replay_input_system, verify_state_checksum_system, ReplayPlayer, ReplayVerifier,
+};
 use padel_game::replay::ControlType;
 use padel_game::resource::config::{load_game_config, GameConfig};
 use padel_game::resource::debug::LastShotDebugInfo;
 use padel_game::resource::{FixedDeltaTime, GameRng, MatchFlowState};
 use padel_game::simulation::AnomalyDetectorPlugin;
 use padel_game::systems::{
-    ceiling_collision_system, gravity_system, jump_system, knockback_movement_system,
-    knockback_start_system, knockback_timer_system, landing_system, movement_system,
-    plan_racket_swing_system, shot_cooldown_system, shot_direction_system, shot_input_system,
-    update_racket_swing_system, vertical_movement_system, AiServePlugin, BallCollisionPlugin,
-    BallTrajectoryPlugin, BoundaryPlugin, FaultJudgmentPlugin, GameSystemSet, MatchFlowPlugin,
-    PointJudgmentPlugin, ScoringPlugin,
+    ceiling_collision_system, gravity_system, initial_player_position, jump_system,
+    knockback_movement_system, knockback_start_system, knockback_timer_system, landing_system,
+    movement_system, plan_racket_swing_system, shot_cooldown_system, shot_direction_system,
+    shot_input_system, update_racket_swing_system, vertical_movement_system, AiServePlugin,
+    BallCollisionPlugin, BallTrajectoryPlugin, BoundaryPlugin, FaultJudgmentPlugin,
+    FixedTickPlugin, GameSystemSet, MatchFlowPlugin, PointJudgmentPlugin, ScoringPlugin,
 };
 
 /// Replay Player for Padel Game
@@ -91,17 +95,20 @@ fn main() {
         "  Initial serve side: {:?}",
         replay_data.metadata.initial_serve_side
     );
+    println!("  Players: {}", replay_data.metadata.player_count());
     println!("  Total frames: {}", replay_data.frames.len());
     println!();
 
     // GameConfig をロード
-    let game_config = match load_game_config("assets/config/game_config.ron") {
+    let mut game_config = match load_game_config("assets/config/game_config.ron") {
         Ok(c) => c,
         Err(e) => {
             eprintln!("Failed to load game config: {}", e);
             std::process::exit(1);
         }
     };
+    // チーム人数はリプレイ記録時の構成に合わせる
+    game_config.team.players_per_team = replay_data.metadata.players_per_team();
 
     // ReplayPlayer リソースを初期化（Arc で共有してcloneコストを削減）
     let replay_data = Arc::new(replay_data);
@@ -114,9 +121,10 @@ fn main() {
     // Bevy アプリを構築して実行
     let mut app = App::new();
 
-    // MinimalPlugins（60FPS固定タイムステップ）
+    // MinimalPlugins（リプレイ記録時のティックレートで固定タイムステップ）
+    let tick_rate = replay_data.metadata.tick_rate;
     app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(
-        std::time::Duration::from_secs_f64(1.0 / 60.0),
+        FixedDeltaTime::from_tick_rate(tick_rate).timestep(),
     )));
 
     // StatesPlugin（State管理用、MinimalPluginsに含まれない）
@@ -125,6 +133,10 @@ fn main() {
     // AssetPlugin（CharacterPluginがアセットローダーを使用）
     app.add_plugins(AssetPlugin::default());
 
+    // 固定ティック: app.update() 1回で記録時の1ティックを再生する
+    // @spec 77100_headless_sim.md#req-77100-001
+    app.add_plugins(FixedTickPlugin::stepped(tick_rate));
+
     // ゲームロジックプラグイン
     app.add_plugins(ReplayPlaybackPlugins);
 
@@ -132,7 +144,6 @@ fn main() {
     app.insert_resource(game_config)
         .insert_resource(game_rng)
         .insert_resource(replay_player)
-        .init_resource::<FixedDeltaTime>() // 物理計算用
         // ヘッドレス環境用のアセットリソース
         // サーブシステム（serve_toss_input_system）がMeshとColorMaterialを必要とする
         .init_resource::<Assets<Mesh>>()
@@ -145,8 +156,20 @@ fn main() {
     // セットアップシステム（プレイヤーのスポーン）
     app.add_systems(Startup, replay_setup_system);
 
+    // 状態チェックサム検証（ティック終了時）
+    // @spec REQ-77103-011
+    app.init_resource::<ReplayVerifier>();
+    app.add_systems(FixedPostUpdate, verify_state_checksum_system);
+
+    // レット・ネットフォールトの集計
+    app.init_resource::<ServeNetCallStats>();
+    app.add_systems(Update, count_serve_net_calls_system);
+
     // 完了検出用の状態
-    app.add_systems(Update, check_replay_finished);
+    app.add_systems(
+        Update,
+        check_replay_finished.after(count_serve_net_calls_system),
+    );
 
     println!("Starting replay playback...\n");
 
@@ -162,6 +185,42 @@ struct ReplayPlaybackConfig {
     verify: bool,
 }
 
+/// サーブのレット・ネットフォールト集計
+/// @spec 30902_fault_spec.md#req-30902-055
+/// @spec 30902_fault_spec.md#req-30902-057
+#[derive(Resource, Default)]
+struct ServeNetCallStats {
+    lets: u32,
+    net_faults: u32,
+}
+
+/// レット・ネットフォールトイベントを集計するシステム
+fn count_serve_net_calls_system(
+    mut stats: ResMut<ServeNetCallStats>,
+    mut let_events: MessageReader<ServeLetEvent>,
+    mut net_fault_events: MessageReader<ServeNetFaultEvent>,
+    config: Res<ReplayPlaybackConfig>,
+) {
+    for event in let_events.read() {
+        stats.lets += 1;
+        if config.verbose {
+            println!(
+                "Let: server={:?}, consecutive={}",
+                event.server, event.consecutive_lets
+            );
+        }
+    }
+    for event in net_fault_events.read() {
+        stats.net_faults += 1;
+        if config.verbose {
+            println!(
+                "Net fault: server={:?}, fault_count={}",
+                event.server, event.fault_count
+            );
+        }
+    }
+}
+
 /// リプレイ再生用プラグインセット
 struct ReplayPlaybackPlugins;
 
@@ -193,18 +252,15 @@ impl Plugin for ReplayPlaybackPlugins {
             .add_message::<RacketContactEvent>()
             .add_message::<ShotExecutedEvent>();
 
-        // SystemSet の順序を設定
-        app.configure_sets(
-            Update,
-            GameSystemSet::Input.before(GameSystemSet::GameLogic),
+        // 入力システム（リプレイから入力を注入、1ティックにつき1フレーム分）
+        app.add_systems(
+            FixedUpdate,
+            replay_input_system.in_set(GameSystemSet::Input),
         );
 
-        // 入力システム（リプレイから入力を注入）
-        app.add_systems(Update, replay_input_system.in_set(GameSystemSet::Input));
-
         // ゲームロジックシステム
         app.add_systems(
-            Update,
+            FixedUpdate,
             (
                 // ふっとばし開始
                 knockback_start_system,
@@ -229,15 +285,30 @@ impl Plugin for ReplayPlaybackPlugins {
     }
 }
 
+/// 再生完了時に出力する再生結果
+#[derive(SystemParam)]
+struct ReplayResults<'w> {
+    replay_player: Res<'w, ReplayPlayer>,
+    match_score: Res<'w, padel_game::resource::MatchScore>,
+    serve_net_stats: Res<'w, ServeNetCallStats>,
+    verifier: Res<'w, ReplayVerifier>,
+}
+
 /// リプレイ再生完了チェック
 fn check_replay_finished(
-    replay_player: Res<ReplayPlayer>,
+    results: ReplayResults,
     match_state: Res<State<MatchFlowState>>,
-    match_score: Res<padel_game::resource::MatchScore>,
     config: Res<ReplayPlaybackConfig>,
     mut app_exit: MessageWriter<AppExit>,
     mut frame_count: Local<u32>,
 ) {
+    let ReplayResults {
+        replay_player,
+        match_score,
+        serve_net_stats,
+        verifier,
+    } = results;
+
     *frame_count += 1;
 
     // 詳細出力
@@ -262,27 +333,73 @@ fn check_replay_finished(
         // @spec REQ-77103-007
         if config.verify {
             println!("\n=== Verification Results ===");
+            // エンドチェンジ後もチーム（開始時のサイド）順で表示
+            let p1 = match_score.side_of_team(CourtSide::Left);
+            let p2 = match_score.side_of_team(CourtSide::Right);
             println!(
                 "Points: P1[{}] vs P2[{}]",
-                match_score.points[0].index, match_score.points[1].index
+                match_score.get_point_index(p1),
+                match_score.get_point_index(p2)
             );
             println!(
                 "Games: P1 {} : {} P2",
-                match_score.scores[0].games, match_score.scores[1].games
+                match_score.get_score(p1).games,
+                match_score.get_score(p2).games
             );
             println!(
                 "Sets: P1 {} : {} P2",
-                match_score.scores[0].sets, match_score.scores[1].sets
+                match_score.get_score(p1).sets,
+                match_score.get_score(p2).sets
             );
+            println!("Ends swapped: {}", match_score.ends_swapped);
             println!("Server: {:?}", match_score.server);
             println!("Game State: {:?}", match_score.game_state);
             println!(
-                "Determinism check: Run this replay multiple times to verify identical results."
+                "Serve lets: {}, Net faults: {}",
+                serve_net_stats.lets, serve_net_stats.net_faults
+            );
+        }
+
+        // 状態チェックサムの検証結果
+        // @spec REQ-77103-011
+        let checksum_count = replay_player.data().map_or(0, |data| data.checksums.len());
+        println!("\n=== Determinism Check ===");
+        if checksum_count == 0 {
+            println!("No state checksums recorded in this replay");
+        } else if let Some(desync) = verifier.desync() {
+            println!(
+                "DESYNC at frame {} (recorded={:016x}, actual={:016x})",
+                desync.frame, desync.recorded_hash, desync.actual_hash
+            );
+            println!("Checksums verified before desync: {}", verifier.verified());
+            match desync.keyframe {
+                Some(keyframe) if verifier.is_awaiting_keyframe() => {
+                    println!("Field diff unavailable: playback ended before keyframe {keyframe}")
+                }
+                Some(keyframe) => {
+                    println!("Field diff at keyframe {}:", keyframe);
+                    if desync.diffs.is_empty() {
+                        println!("  (no difference in keyframe fields)");
+                    }
+                    for diff in &desync.diffs {
+                        println!("  {}", diff);
+                    }
+                }
+                None => println!("Field diff unavailable: no keyframe at or after the desync"),
+            }
+        } else {
+            println!(
+                "OK: {} / {} checksums matched",
+                verifier.verified(),
+                checksum_count
             );
-            println!("(Future: EventTracer will enable frame-by-frame comparison)");
         }
 
-        app_exit.write(AppExit::Success);
+        if verifier.desync().is_some() {
+            app_exit.write(AppExit::error());
+        } else {
+            app_exit.write(AppExit::Success);
+        }
     }
 }
 
@@ -295,46 +412,28 @@ fn replay_setup_system(
 ) {
     let metadata = replay_player.metadata().expect("Replay not loaded");
 
-    info!(
-        "Setting up replay: Left={:?}, Right={:?}",
-        metadata.left_control, metadata.right_control
-    );
-
-    // Player 1 (Left側)
-    let player1_pos = Vec3::new(config.player.x_min + 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player1_color;
-    let player1_color = Color::srgb(r, g, b);
-    let player1_entity = spawn_articulated_player(&mut commands, 1, player1_pos, player1_color);
-
-    // ControlType::Ai の場合のみ AiController を追加
-    // ControlType::Human の場合は replay_input_system が入力を注入
-    if metadata.left_control == ControlType::Ai {
-        commands.entity(player1_entity).insert(AiController {
-            home_position: player1_pos,
-            target_position: player1_pos,
-            ..Default::default()
-        });
-        info!("Player 1 (Left): AI controller added");
-    } else {
-        info!("Player 1 (Left): Human (replay input injection)");
-    }
-
-    // Player 2 (Right側)
-    let player2_pos = Vec3::new(config.player.x_max - 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player2_color;
-    let player2_color = Color::srgb(r, g, b);
-    let player2_entity = spawn_articulated_player(&mut commands, 2, player2_pos, player2_color);
-
-    if metadata.right_control == ControlType::Ai {
-        commands.entity(player2_entity).insert(AiController {
-            home_position: player2_pos,
-            target_position: player2_pos,
-            ..Default::default()
-        });
-        info!("Player 2 (Right): AI controller added");
-    } else {
-        info!("Player 2 (Right): Human (replay input injection)");
+    info!("Setting up replay: controls={:?}", metadata.controls);
+
+    let player_count = metadata.player_count();
+    for player_id in 1..=player_count as u8 {
+        let position = initial_player_position(player_id, &config);
+        let (r, g, b) = config.player_visual.color_for(player_id);
+        let color = Color::srgb(r, g, b);
+        let entity = spawn_articulated_player(&mut commands, player_id, position, color);
+
+        // ControlType::Ai の場合のみ AiController を追加
+        // ControlType::Human の場合は replay_input_system が入力を注入
+        if metadata.control_for(player_id) == ControlType::Ai {
+            commands.entity(entity).insert(AiController {
+                home_position: position,
+                target_position: position,
+                ..Default::default()
+            });
+            info!("Player {}: AI controller added", player_id);
+        } else {
+            info!("Player {}: Human (replay input injection)", player_id);
+        }
     }
 
-    info!("Replay setup complete: 2 players spawned");
+    info!("Replay setup complete: {} players spawned", player_count);
 }
@@ -36,28 +36,30 @@ impl Deref for SharedReplayData {
     }
 }
 use padel_game::core::{
-    BallHitEvent, PlayerJumpEvent, PlayerKnockbackEvent, PlayerLandEvent, PlayerMoveEvent,
-    RacketContactEvent, ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent,
-    SwingIntentEvent,
+    BallHitEvent, CourtSide, PlayerJumpEvent, PlayerKnockbackEvent, PlayerLandEvent,
+    PlayerMoveEvent, RacketContactEvent, ShotAttributesCalculatedEvent, ShotEvent,
+    ShotExecutedEvent, SwingIntentEvent,
 };
 use padel_game::presentation::{
     ball_spin_color_system, despawn_ball_shadow_system, player_hold_visual_system,
-    save_player_original_color_system, spawn_ball_shadow_system, spawn_player_shadow_system,
-    sync_shadow_system, sync_transform_system, DebugUiPlugin, WORLD_SCALE,
+    save_player_original_color_system, spawn_ball_shadow_system, spawn_court_walls,
+    spawn_player_shadow_system, sync_shadow_system, sync_transform_system, DebugUiPlugin,
+    WORLD_SCALE,
 };
 use padel_game::replay::loader::load_replay;
 use padel_game::replay::player::{replay_input_system, ReplayPlayer};
 use padel_game::resource::config::{load_game_config, GameConfig};
 use padel_game::resource::debug::LastShotDebugInfo;
-use padel_game::resource::{FixedDeltaTime, GameRng, MatchFlowState};
+use padel_game::resource::{GameRng, MatchFlowState};
 use padel_game::simulation::AnomalyDetectorPlugin;
 use padel_game::systems::{
-    ai_movement_system, ai_shot_system, ceiling_collision_system, gravity_system, jump_system,
-    knockback_movement_system, knockback_start_system, knockback_timer_system, landing_system,
-    movement_system, plan_racket_swing_system, shot_cooldown_system, shot_direction_system,
-    shot_input_system, update_racket_swing_system, vertical_movement_system, AiServePlugin,
-    BallCollisionPlugin, BallTrajectoryPlugin, BoundaryPlugin, FaultJudgmentPlugin, GameSystemSet,
-    MatchFlowPlugin, PointJudgmentPlugin, ScoringPlugin,
+    ai_movement_system, ai_shot_system, ceiling_collision_system, gravity_system,
+    initial_player_position, jump_system, knockback_movement_system, knockback_start_system,
+    knockback_timer_system, landing_system, movement_system, plan_racket_swing_system,
+    shot_cooldown_system, shot_direction_system, shot_input_system, update_racket_swing_system,
+    vertical_movement_system, AiServePlugin, BallCollisionPlugin, BallTrajectoryPlugin,
+    BoundaryPlugin, FaultJudgmentPlugin, FixedTickPlugin, GameSystemSet, MatchFlowPlugin,
+    PointJudgmentPlugin, ScoringPlugin,
 };
 
 /// Replay Viewer for Padel Game
@@ -110,17 +112,20 @@ fn main() {
         "  Initial serve side: {:?}",
         replay_data.metadata.initial_serve_side
     );
+    println!("  Players: {}", replay_data.metadata.player_count());
     println!("  Total frames: {}", replay_data.frames.len());
     println!();
 
     // GameConfig をロード
-    let game_config = match load_game_config("assets/config/game_config.ron") {
+    let mut game_config = match load_game_config("assets/config/game_config.ron") {
         Ok(c) => c,
         Err(e) => {
             eprintln!("Failed to load game config: {}", e);
             std::process::exit(1);
         }
     };
+    // チーム人数はリプレイ記録時の構成に合わせる
+    game_config.team.players_per_team = replay_data.metadata.players_per_team();
 
     // リプレイのシード値でGameRngを初期化（AI動作の再現性確保）
     // 同一シードにより、元の試合と同じ結果を再シミュレーション
@@ -142,6 +147,10 @@ fn main() {
     // StatesPlugin（State管理用、DefaultPluginsに含まれている可能性があるがredundantでも問題なし）
     // 念のため追加しない（DefaultPluginsに含まれている）
 
+    // 固定ティック: 記録時のティックレートで実時間に合わせて再生し、表示は補間する
+    // @spec 77100_headless_sim.md#req-77100-001
+    app.add_plugins(FixedTickPlugin::realtime(replay_data.metadata.tick_rate));
+
     // ゲームロジックプラグイン
     app.add_plugins(ReplayViewerPlugins);
 
@@ -149,7 +158,6 @@ fn main() {
     let shared_replay_data = SharedReplayData(Arc::new(replay_data));
     app.insert_resource(game_config)
         .insert_resource(game_rng)
-        .init_resource::<FixedDeltaTime>()
         .insert_resource(ReplayViewerConfig {
             verbose: args.verbose,
         })
@@ -205,21 +213,18 @@ impl Plugin for ReplayViewerPlugins {
             .add_message::<ShotExecutedEvent>()
             .add_message::<ShotAttributesCalculatedEvent>();
 
-        // SystemSet の順序を設定
-        app.configure_sets(
-            Update,
-            GameSystemSet::Input.before(GameSystemSet::GameLogic),
-        );
-
         // セットアップシステム
         app.add_systems(Startup, setup_replay_viewer);
 
-        // 入力システム（Human プレイヤーの入力注入）
-        app.add_systems(Update, replay_input_system.in_set(GameSystemSet::Input));
+        // 入力システム（Human プレイヤーの入力注入、1ティックにつき1フレーム分）
+        app.add_systems(
+            FixedUpdate,
+            replay_input_system.in_set(GameSystemSet::Input),
+        );
 
         // ゲームロジックシステム（AI による再シミュレーション）
         app.add_systems(
-            Update,
+            FixedUpdate,
             (
                 // ふっとばし開始
                 knockback_start_system,
@@ -241,7 +246,17 @@ impl Plugin for ReplayViewerPlugins {
                 (knockback_movement_system, knockback_timer_system),
                 // 境界チェック
                 (ceiling_collision_system, landing_system),
-                // 座標変換（論理座標→表示座標）
+            )
+                .chain()
+                .in_set(GameSystemSet::GameLogic),
+        );
+
+        // 表示システム（描画フレームごと）
+        app.add_systems(
+            Update,
+            (
+                // 座標変換（論理座標→表示座標、ティック間を補間）
+                // @spec 77100_headless_sim.md#req-77100-002
                 sync_transform_system,
                 // 影システム
                 (
@@ -257,8 +272,7 @@ impl Plugin for ReplayViewerPlugins {
                     ball_spin_color_system,
                 ),
             )
-                .chain()
-                .in_set(GameSystemSet::GameLogic),
+                .chain(),
         );
     }
 }
@@ -276,16 +290,12 @@ fn setup_replay_viewer(
     // Camera2d をスポーン
     commands.spawn(Camera2d);
 
-    let left_control = replay_data.metadata.left_control;
-    let right_control = replay_data.metadata.right_control;
+    let controls = &replay_data.metadata.controls;
 
-    info!(
-        "Replay Viewer: Setting up with left={:?}, right={:?}",
-        left_control, right_control
-    );
+    info!("Replay Viewer: Setting up with controls={:?}", controls);
 
     // Human プレイヤーがいる場合はリプレイ再生を開始
-    let has_human = left_control == ControlType::Human || right_control == ControlType::Human;
+    let has_human = controls.contains(&ControlType::Human);
     if has_human {
         // Arc::clone で参照カウントのみ増加（データはコピーしない）
         replay_player.start_playback(Arc::clone(&replay_data.0));
@@ -295,17 +305,14 @@ fn setup_replay_viewer(
     // コート境界を描画
     spawn_court(&mut commands, &config);
 
-    // Player 1 (Left側) をスポーン（1Pコート側: 画面左側）
-    let player1_pos = Vec3::new(config.player.x_min + 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player1_color;
-    let player1_color = Color::srgb(r, g, b);
-    spawn_player_for_replay(&mut commands, 1, player1_pos, player1_color, left_control);
-
-    // Player 2 (Right側) をスポーン（2Pコート側: 画面右側）
-    let player2_pos = Vec3::new(config.player.x_max - 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player2_color;
-    let player2_color = Color::srgb(r, g, b);
-    spawn_player_for_replay(&mut commands, 2, player2_pos, player2_color, right_control);
+    // 全プレイヤーをスポーン（奇数ID: 画面左側、偶数ID: 画面右側）
+    for player_id in 1..=replay_data.metadata.player_count() as u8 {
+        let position = initial_player_position(player_id, &config);
+        let (r, g, b) = config.player_visual.color_for(player_id);
+        let color = Color::srgb(r, g, b);
+        let control = replay_data.metadata.control_for(player_id);
+        spawn_player_for_replay(&mut commands, player_id, position, color, control);
+    }
 }
 
 /// リプレイビューア用プレイヤースポーンヘルパー
@@ -428,6 +435,8 @@ fn spawn_court(commands: &mut Commands, config: &GameConfig) {
     // センターサービスライン
     spawn_rect(commands, service_x, line, -service_x / 2.0, 0.0, 0.0, white);
     spawn_rect(commands, service_x, line, service_x / 2.0, 0.0, 0.0, white);
+    // 壁セグメント
+    spawn_court_walls(commands, config);
 
     info!("Court spawned for replay viewer");
 }
@@ -454,7 +463,12 @@ fn check_replay_finished(
         println!("Total frames: {}", *frame_count);
         println!(
             "Final Score: P1 {} : {} P2 (games)",
-            match_score.scores[0].games, match_score.scores[1].games
+            match_score
+                .get_score(match_score.side_of_team(CourtSide::Left))
+                .games,
+            match_score
+                .get_score(match_score.side_of_team(CourtSide::Right))
+                .games
         );
 
         app_exit.write(AppExit::Success);
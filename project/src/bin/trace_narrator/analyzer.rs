@@ -1,9 +1,10 @@
 //! Rally Analyzer
 //! @spec 77201_narrative_spec.md REQ-77201-003, REQ-77201-004, REQ-77201-005, REQ-77201-006
+//! @spec 77201_narrative_spec.md REQ-77201-010, REQ-77201-011, REQ-77201-012
 //!
 //! ラリー境界検出、統計計算、異常検出を行う。
 
-use super::types::{FrameTrace, GameEvent};
+use super::types::{FrameTrace, GameEvent, Vec3};
 
 /// 異常の重大度
 /// @spec REQ-77201-005
@@ -93,14 +94,79 @@ pub struct Rally {
     pub end_reason: String,
     /// ショット情報一覧
     pub shots: Vec<ShotInfo>,
+    /// 打たれたショット種類（サーブを含む、打球順）
+    /// @spec REQ-77201-009
+    pub shot_types: Vec<String>,
     /// バウンス数
     pub bounce_count: u32,
     /// 壁反射数
     pub wall_reflect_count: u32,
+    /// うち金網での反射数
+    pub mesh_reflect_count: u32,
     /// 異常一覧
     pub anomalies: Vec<Anomaly>,
     /// 統計
     pub stats: RallyStats,
+    /// ポイント後のスコア状況（デュース・アドバンテージ・ゴールデンポイント等）
+    /// @spec REQ-77201-010
+    pub score_situation: Option<ScoreSituation>,
+    /// このポイントで決まったゲーム
+    /// @spec REQ-77201-010
+    pub game_won: Option<GameResult>,
+    /// サーブのネット接触（レット・ネットフォールト、発生順）
+    /// @spec REQ-77201-011
+    pub serve_net_contacts: Vec<ServeNetContact>,
+    /// このラリーの前に行われたエンドチェンジ
+    /// @spec REQ-77201-012
+    pub end_change: Option<EndChange>,
+}
+
+/// ポイント後のスコア状況
+/// @spec REQ-77201-010
+#[derive(Debug, Clone)]
+pub struct ScoreSituation {
+    /// ポイントを取ったプレイヤー（1 or 2）
+    pub scorer: u8,
+    /// 状況（"Regular", "Deuce", "Advantage(Left)", "GoldenPoint", "Tiebreak"）
+    pub situation: String,
+}
+
+/// ゲーム獲得情報
+/// @spec REQ-77201-010
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    /// ゲームを取ったプレイヤー（1 or 2）
+    pub winner: u8,
+    /// 決着したポイントの直前のスコア状況
+    pub deciding_situation: String,
+}
+
+/// サーブのネット接触
+/// @spec REQ-77201-011
+#[derive(Debug, Clone)]
+pub struct ServeNetContact {
+    /// 発生フレーム
+    pub frame: u64,
+    /// サーバー（1 or 2）
+    pub server: u8,
+    /// 着地位置
+    pub position: Vec3,
+    /// レットなら連続回数、ネットフォールトなら None
+    pub consecutive_lets: Option<u32>,
+}
+
+/// エンドチェンジ
+/// @spec REQ-77201-012
+#[derive(Debug, Clone)]
+pub struct EndChange {
+    /// 発生フレーム
+    pub frame: u64,
+    /// 開始時から入れ替わっているか（false なら開始時のサイドに戻った）
+    pub ends_swapped: bool,
+    /// それまでに行われたゲーム数
+    pub games_played: u32,
+    /// タイブレーク中のエンドチェンジか
+    pub in_tiebreak: bool,
 }
 
 /// 解析結果
@@ -120,9 +186,13 @@ struct CurrentRallyState {
     start_frame: u64,
     start_time: f32,
     shots: Vec<ShotInfo>,
+    shot_types: Vec<String>,
     bounces: u32,
     wall_reflects: u32,
+    mesh_reflects: u32,
     anomalies: Vec<Anomaly>,
+    serve_net_contacts: Vec<ServeNetContact>,
+    end_change: Option<EndChange>,
     rally_number: u32,
 }
 
@@ -133,14 +203,27 @@ impl CurrentRallyState {
         shot
     }
 
+    /// 打球イベントを処理（ショット種類を記録）
+    fn handle_ball_hit(&mut self, shot_type: &str) {
+        self.shot_types.push(shot_type.to_string());
+    }
+
     /// バウンスイベントを処理
     fn handle_bounce(&mut self) {
         self.bounces += 1;
     }
 
     /// 壁反射イベントを処理
-    fn handle_wall_reflect(&mut self) {
+    fn handle_wall_reflect(&mut self, material: &str) {
         self.wall_reflects += 1;
+        if material == "Mesh" {
+            self.mesh_reflects += 1;
+        }
+    }
+
+    /// サーブのネット接触（レット・ネットフォールト）を処理
+    fn handle_serve_net_contact(&mut self, contact: ServeNetContact) {
+        self.serve_net_contacts.push(contact);
     }
 
     /// 物理異常イベントを処理
@@ -168,10 +251,16 @@ impl CurrentRallyState {
             winner,
             end_reason: reason,
             shots: std::mem::take(&mut self.shots),
+            shot_types: std::mem::take(&mut self.shot_types),
             bounce_count: self.bounces,
             wall_reflect_count: self.wall_reflects,
+            mesh_reflect_count: self.mesh_reflects,
             anomalies: std::mem::take(&mut self.anomalies),
             stats,
+            score_situation: None,
+            game_won: None,
+            serve_net_contacts: std::mem::take(&mut self.serve_net_contacts),
+            end_change: self.end_change.take(),
         };
 
         // 次のラリーの開始点をリセット
@@ -179,6 +268,7 @@ impl CurrentRallyState {
         self.start_time = end_time;
         self.bounces = 0;
         self.wall_reflects = 0;
+        self.mesh_reflects = 0;
 
         rally
     }
@@ -251,8 +341,40 @@ pub fn analyze_rallies(frames: &[FrameTrace], anomaly_threshold: f32) -> Analysi
                         all_shots.push(state.handle_shot(shot));
                     }
                 }
+                GameEvent::BallHit { shot_type, .. } => state.handle_ball_hit(shot_type),
                 GameEvent::Bounce { .. } => state.handle_bounce(),
-                GameEvent::WallReflect { .. } => state.handle_wall_reflect(),
+                GameEvent::WallReflect { material, .. } => state.handle_wall_reflect(material),
+                GameEvent::ServeLet {
+                    server,
+                    position,
+                    consecutive_lets,
+                } => state.handle_serve_net_contact(ServeNetContact {
+                    frame: frame.frame,
+                    server: *server,
+                    position: *position,
+                    consecutive_lets: Some(*consecutive_lets),
+                }),
+                GameEvent::ServeNetFault { server, position } => {
+                    state.handle_serve_net_contact(ServeNetContact {
+                        frame: frame.frame,
+                        server: *server,
+                        position: *position,
+                        consecutive_lets: None,
+                    })
+                }
+                // エンドチェンジはポイント間に記録されるため、次のラリーに付ける
+                GameEvent::ChangeEnds {
+                    ends_swapped,
+                    games_played,
+                    in_tiebreak,
+                } => {
+                    state.end_change = Some(EndChange {
+                        frame: frame.frame,
+                        ends_swapped: *ends_swapped,
+                        games_played: *games_played,
+                        in_tiebreak: *in_tiebreak,
+                    });
+                }
                 GameEvent::PhysicsAnomaly { .. } => {
                     if let Some(anomaly) = create_anomaly_from_event(frame.frame, event) {
                         all_anomalies.push(state.handle_anomaly(anomaly));
@@ -266,6 +388,26 @@ pub fn analyze_rallies(frames: &[FrameTrace], anomaly_threshold: f32) -> Analysi
                         reason.clone(),
                     ));
                 }
+                // スコア状況・ゲーム獲得は Point の直後に記録されるため、直前のラリーに付ける
+                GameEvent::ScoreSituation { scorer, situation } => {
+                    if let Some(rally) = rallies.last_mut() {
+                        rally.score_situation = Some(ScoreSituation {
+                            scorer: *scorer,
+                            situation: situation.clone(),
+                        });
+                    }
+                }
+                GameEvent::GameWon {
+                    winner,
+                    deciding_situation,
+                } => {
+                    if let Some(rally) = rallies.last_mut() {
+                        rally.game_won = Some(GameResult {
+                            winner: *winner,
+                            deciding_situation: deciding_situation.clone(),
+                        });
+                    }
+                }
                 _ => {}
             }
         }
@@ -382,6 +524,7 @@ fn detect_spin_anomalies(shots: &[ShotInfo], threshold: f32, anomalies: &mut Vec
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Vec3;
 
     fn make_shot(frame: u64, player: u8, power: f32, spin: f32) -> ShotInfo {
         ShotInfo {
@@ -434,6 +577,220 @@ mod tests {
         assert_eq!(anomalies[0].frame, 6);
     }
 
+    #[test]
+    fn test_wall_reflect_material_count() {
+        let wall = |material: &str| GameEvent::WallReflect {
+            position: Vec3 {
+                x: 3.0,
+                y: 1.0,
+                z: 10.0,
+            },
+            wall_type: "RightWall".to_string(),
+            material: material.to_string(),
+        };
+        let frames = vec![
+            FrameTrace {
+                frame: 10,
+                timestamp: 0.2,
+                entities: Vec::new(),
+                events: vec![wall("Mesh"), wall("Glass")],
+            },
+            FrameTrace {
+                frame: 20,
+                timestamp: 0.4,
+                entities: Vec::new(),
+                events: vec![GameEvent::Point {
+                    winner: 1,
+                    reason: "DoubleBounce".to_string(),
+                }],
+            },
+        ];
+
+        let result = analyze_rallies(&frames, 2.0);
+
+        assert_eq!(result.rallies[0].wall_reflect_count, 2);
+        assert_eq!(result.rallies[0].mesh_reflect_count, 1);
+    }
+
+    #[test]
+    fn test_ball_hit_shot_types() {
+        let hit = |player: u8, shot_type: &str| GameEvent::BallHit {
+            player,
+            shot_type: shot_type.to_string(),
+        };
+        let frames = vec![
+            FrameTrace {
+                frame: 10,
+                timestamp: 0.2,
+                entities: Vec::new(),
+                events: vec![hit(1, "Serve"), hit(2, "Lob")],
+            },
+            FrameTrace {
+                frame: 20,
+                timestamp: 0.4,
+                entities: Vec::new(),
+                events: vec![
+                    hit(1, "Smash"),
+                    GameEvent::Point {
+                        winner: 1,
+                        reason: "DoubleBounce".to_string(),
+                    },
+                ],
+            },
+        ];
+
+        let result = analyze_rallies(&frames, 2.0);
+
+        assert_eq!(result.rallies[0].shot_types, vec!["Serve", "Lob", "Smash"]);
+    }
+
+    #[test]
+    fn test_score_situation_attached_to_rally() {
+        let frames = vec![
+            FrameTrace {
+                frame: 10,
+                timestamp: 0.2,
+                entities: Vec::new(),
+                events: vec![
+                    GameEvent::Point {
+                        winner: 2,
+                        reason: "DoubleBounce".to_string(),
+                    },
+                    GameEvent::ScoreSituation {
+                        scorer: 2,
+                        situation: "Advantage(Right)".to_string(),
+                    },
+                ],
+            },
+            FrameTrace {
+                frame: 20,
+                timestamp: 0.4,
+                entities: Vec::new(),
+                events: vec![
+                    GameEvent::Point {
+                        winner: 2,
+                        reason: "Out".to_string(),
+                    },
+                    GameEvent::ScoreSituation {
+                        scorer: 2,
+                        situation: "Regular".to_string(),
+                    },
+                    GameEvent::GameWon {
+                        winner: 2,
+                        deciding_situation: "Advantage(Right)".to_string(),
+                    },
+                ],
+            },
+        ];
+
+        let result = analyze_rallies(&frames, 2.0);
+
+        let first = &result.rallies[0];
+        assert_eq!(
+            first.score_situation.as_ref().unwrap().situation,
+            "Advantage(Right)"
+        );
+        assert!(first.game_won.is_none());
+        let game = result.rallies[1].game_won.as_ref().unwrap();
+        assert_eq!(game.winner, 2);
+        assert_eq!(game.deciding_situation, "Advantage(Right)");
+    }
+
+    #[test]
+    fn test_serve_net_contacts_recorded() {
+        let position = Vec3 {
+            x: 3.0,
+            y: 0.0,
+            z: -1.5,
+        };
+        let frames = vec![
+            FrameTrace {
+                frame: 10,
+                timestamp: 0.2,
+                entities: Vec::new(),
+                events: vec![GameEvent::ServeLet {
+                    server: 1,
+                    position,
+                    consecutive_lets: 1,
+                }],
+            },
+            FrameTrace {
+                frame: 30,
+                timestamp: 0.6,
+                entities: Vec::new(),
+                events: vec![GameEvent::ServeNetFault {
+                    server: 1,
+                    position,
+                }],
+            },
+            FrameTrace {
+                frame: 50,
+                timestamp: 1.0,
+                entities: Vec::new(),
+                events: vec![GameEvent::Point {
+                    winner: 2,
+                    reason: "DoubleFault".to_string(),
+                }],
+            },
+        ];
+
+        let result = analyze_rallies(&frames, 2.0);
+
+        let contacts = &result.rallies[0].serve_net_contacts;
+        assert_eq!(contacts.len(), 2);
+        assert_eq!(contacts[0].frame, 10);
+        assert_eq!(contacts[0].consecutive_lets, Some(1));
+        assert_eq!(contacts[1].frame, 30);
+        assert_eq!(contacts[1].consecutive_lets, None);
+    }
+
+    #[test]
+    fn test_change_ends_attached_to_next_rally() {
+        let point = |winner: u8| GameEvent::Point {
+            winner,
+            reason: "DoubleBounce".to_string(),
+        };
+        let frames = vec![
+            FrameTrace {
+                frame: 10,
+                timestamp: 0.2,
+                entities: Vec::new(),
+                events: vec![point(1)],
+            },
+            FrameTrace {
+                frame: 15,
+                timestamp: 0.3,
+                entities: Vec::new(),
+                events: vec![GameEvent::ChangeEnds {
+                    ends_swapped: true,
+                    games_played: 1,
+                    in_tiebreak: false,
+                }],
+            },
+            FrameTrace {
+                frame: 20,
+                timestamp: 0.4,
+                entities: Vec::new(),
+                events: vec![point(2)],
+            },
+            FrameTrace {
+                frame: 30,
+                timestamp: 0.6,
+                entities: Vec::new(),
+                events: vec![point(2)],
+            },
+        ];
+
+        let result = analyze_rallies(&frames, 2.0);
+
+        assert!(result.rallies[0].end_change.is_none());
+        let change = result.rallies[1].end_change.as_ref().unwrap();
+        assert_eq!(change.frame, 15);
+        assert!(change.ends_swapped);
+        assert_eq!(change.games_played, 1);
+        assert!(result.rallies[2].end_change.is_none());
+    }
+
     #[test]
     fn test_anomaly_severity_emoji() {
         assert_eq!(AnomalySeverity::Warning.emoji(), "⚠️");
@@ -1,9 +1,10 @@
 //! Markdown Formatter
-//! @spec 77201_narrative_spec.md REQ-77201-007, REQ-77201-008
+//! @spec 77201_narrative_spec.md REQ-77201-007, REQ-77201-008, REQ-77201-010, REQ-77201-011
+//! @spec 77201_narrative_spec.md REQ-77201-012
 //!
 //! ラリー解析結果をマークダウン形式で出力する。
 
-use super::analyzer::{AnalysisResult, Anomaly, Rally};
+use super::analyzer::{AnalysisResult, Anomaly, EndChange, Rally, ServeNetContact};
 use super::types::FrameTrace;
 
 /// 詳細度レベル
@@ -198,11 +199,33 @@ fn format_rally(rally: &Rally, options: &FormatOptions) -> String {
         rally.number, rally.start_frame, rally.end_frame
     );
 
+    // @spec REQ-77201-012: ラリー前のエンドチェンジ
+    if let Some(change) = &rally.end_change {
+        output.push_str(&format_end_change(change));
+    }
+
     // 基本情報
     output.push_str(&format!(
         "**Result**: P{} wins ({})\n",
         rally.winner, rally.end_reason
     ));
+    // @spec REQ-77201-010: スコア状況とゲーム獲得
+    if let Some(label) = rally
+        .score_situation
+        .as_ref()
+        .and_then(|score| situation_label(&score.situation, score.scorer))
+    {
+        output.push_str(&format!("**Score**: {}\n", label));
+    }
+    if let Some(game) = &rally.game_won {
+        match situation_label(&game.deciding_situation, game.winner) {
+            Some(label) => output.push_str(&format!(
+                "**Game**: P{} (won from {})\n",
+                game.winner, label
+            )),
+            None => output.push_str(&format!("**Game**: P{}\n", game.winner)),
+        }
+    }
     output.push_str(&format!("**Duration**: {:.2}s\n", rally.duration_secs));
     output.push_str(&format!("**Shots**: {}\n\n", rally.stats.shot_count));
 
@@ -262,14 +285,34 @@ fn format_rally(rally: &Rally, options: &FormatOptions) -> String {
         }
     }
 
-    // バウンス・壁反射情報
+    // @spec REQ-77201-009: ショット種類の内訳
+    if options.detail_level != DetailLevel::Summary && !rally.shot_types.is_empty() {
+        output.push_str(&format!(
+            "- Shot types: {}\n",
+            format_shot_types(&rally.shot_types)
+        ));
+    }
+
+    // バウンス・壁反射情報（壁反射は材質別）
     if options.detail_level != DetailLevel::Summary {
         output.push_str(&format!(
-            "- Bounces: {}, Wall reflects: {}\n\n",
-            rally.bounce_count, rally.wall_reflect_count
+            "- Bounces: {}, Wall reflects: {} (glass: {}, mesh: {})\n\n",
+            rally.bounce_count,
+            rally.wall_reflect_count,
+            rally.wall_reflect_count - rally.mesh_reflect_count,
+            rally.mesh_reflect_count
         ));
     }
 
+    // @spec REQ-77201-011: サーブのレット・ネットフォールト
+    if options.detail_level != DetailLevel::Summary && !rally.serve_net_contacts.is_empty() {
+        output.push_str("#### Serve Net Contacts\n\n");
+        for contact in &rally.serve_net_contacts {
+            output.push_str(&format_serve_net_contact(contact));
+        }
+        output.push('\n');
+    }
+
     // @spec REQ-77201-005: 異常ハイライト
     if !rally.anomalies.is_empty() {
         output.push_str("#### Anomalies\n\n");
@@ -287,6 +330,73 @@ fn format_rally(rally: &Rally, options: &FormatOptions) -> String {
     output
 }
 
+/// スコア状況の表示文字列（通常進行は表示しない）
+/// @spec REQ-77201-010
+///
+/// アドバンテージはポイントを取った側（ゲームを取った側）に付くため、
+/// コートサイドではなくそのプレイヤーで表示する。
+fn situation_label(situation: &str, player: u8) -> Option<String> {
+    match situation {
+        "Regular" => None,
+        s if s.starts_with("Advantage") => Some(format!("Advantage P{}", player)),
+        s => Some(s.to_string()),
+    }
+}
+
+/// エンドチェンジを1行で出力
+/// @spec REQ-77201-012
+///
+/// P1 は Left 側から開始するため、入れ替わっていれば Right 側にいる。
+fn format_end_change(change: &EndChange) -> String {
+    let timing = if change.in_tiebreak {
+        "during tiebreak".to_string()
+    } else {
+        format!("after {} games", change.games_played)
+    };
+    let sides = if change.ends_swapped {
+        "P1 now on the Right side"
+    } else {
+        "P1 back on the Left side"
+    };
+    format!(
+        "**Change of ends** (Frame {}, {}): {}\n",
+        change.frame, timing, sides
+    )
+}
+
+/// サーブのネット接触を1行で出力
+/// @spec REQ-77201-011
+fn format_serve_net_contact(contact: &ServeNetContact) -> String {
+    let kind = match contact.consecutive_lets {
+        Some(count) => format!("Let #{}", count),
+        None => "Net fault".to_string(),
+    };
+    format!(
+        "- **Frame {}**: {} (P{} serve, landed at x={:.2}, z={:.2})\n",
+        contact.frame, kind, contact.server, contact.position.x, contact.position.z
+    )
+}
+
+/// ショット種類を出現順に集計（例: "Serve x1, Lob x2"）
+/// @spec REQ-77201-009
+fn format_shot_types(shot_types: &[String]) -> String {
+    let mut counts: Vec<(&str, u32)> = Vec::new();
+    for shot_type in shot_types {
+        match counts
+            .iter_mut()
+            .find(|(name, _)| *name == shot_type.as_str())
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((shot_type, 1)),
+        }
+    }
+    counts
+        .iter()
+        .map(|(name, count)| format!("{} x{}", name, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// 統計的異常を生成
 fn format_statistical_anomalies(anomalies: &[Anomaly]) -> String {
     let mut output = String::from("## Statistical Anomalies\n\n");
@@ -307,7 +417,8 @@ fn format_statistical_anomalies(anomalies: &[Anomaly]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::analyzer::{AnomalySeverity, RallyStats};
+    use crate::analyzer::{AnomalySeverity, GameResult, RallyStats, ScoreSituation};
+    use crate::types::Vec3;
 
     fn make_rally(number: u32, winner: u8, shots: u32) -> Rally {
         Rally {
@@ -318,9 +429,15 @@ mod tests {
             winner,
             end_reason: "DoubleBounce".to_string(),
             shots: Vec::new(),
+            shot_types: Vec::new(),
             bounce_count: 2,
             wall_reflect_count: 0,
+            mesh_reflect_count: 0,
             anomalies: Vec::new(),
+            score_situation: None,
+            game_won: None,
+            serve_net_contacts: Vec::new(),
+            end_change: None,
             stats: RallyStats {
                 shot_count: shots,
                 p1_shot_count: shots / 2,
@@ -375,6 +492,105 @@ mod tests {
         assert!(!output.contains("Play-by-Play"));
     }
 
+    #[test]
+    fn test_format_rally_shot_types() {
+        let mut rally = make_rally(1, 1, 3);
+        rally.shot_types = ["Serve", "Lob", "Bandeja", "Lob"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(output.contains("- Shot types: Serve x1, Lob x2, Bandeja x1"));
+
+        let summary = FormatOptions {
+            detail_level: DetailLevel::Summary,
+            ..Default::default()
+        };
+        assert!(!format_rally(&rally, &summary).contains("Shot types"));
+    }
+
+    #[test]
+    fn test_format_rally_score_situation() {
+        let mut rally = make_rally(1, 2, 2);
+        rally.score_situation = Some(ScoreSituation {
+            scorer: 2,
+            situation: "Advantage(Left)".to_string(),
+        });
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(output.contains("**Score**: Advantage P2"));
+
+        rally.score_situation = Some(ScoreSituation {
+            scorer: 2,
+            situation: "Regular".to_string(),
+        });
+        rally.game_won = Some(GameResult {
+            winner: 2,
+            deciding_situation: "GoldenPoint".to_string(),
+        });
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(!output.contains("**Score**"));
+        assert!(output.contains("**Game**: P2 (won from GoldenPoint)"));
+    }
+
+    #[test]
+    fn test_format_rally_serve_net_contacts() {
+        let position = Vec3 {
+            x: 3.0,
+            y: 0.0,
+            z: -1.5,
+        };
+        let mut rally = make_rally(1, 2, 0);
+        rally.serve_net_contacts = vec![
+            ServeNetContact {
+                frame: 10,
+                server: 1,
+                position,
+                consecutive_lets: Some(1),
+            },
+            ServeNetContact {
+                frame: 30,
+                server: 1,
+                position,
+                consecutive_lets: None,
+            },
+        ];
+
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(output.contains("- **Frame 10**: Let #1 (P1 serve, landed at x=3.00, z=-1.50)"));
+        assert!(output.contains("- **Frame 30**: Net fault (P1 serve, landed at x=3.00, z=-1.50)"));
+
+        let summary = FormatOptions {
+            detail_level: DetailLevel::Summary,
+            ..Default::default()
+        };
+        assert!(!format_rally(&rally, &summary).contains("Serve Net Contacts"));
+    }
+
+    #[test]
+    fn test_format_rally_end_change() {
+        let mut rally = make_rally(2, 1, 2);
+        rally.end_change = Some(EndChange {
+            frame: 15,
+            ends_swapped: true,
+            games_played: 1,
+            in_tiebreak: false,
+        });
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(output
+            .contains("**Change of ends** (Frame 15, after 1 games): P1 now on the Right side"));
+
+        rally.end_change = Some(EndChange {
+            frame: 40,
+            ends_swapped: false,
+            games_played: 12,
+            in_tiebreak: true,
+        });
+        let output = format_rally(&rally, &FormatOptions::default());
+        assert!(output
+            .contains("**Change of ends** (Frame 40, during tiebreak): P1 back on the Left side"));
+    }
+
     #[test]
     fn test_format_rally_with_anomalies() {
         let mut rally = make_rally(1, 1, 2);
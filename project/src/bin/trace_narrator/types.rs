@@ -11,6 +11,8 @@ use serde::Deserialize;
 pub enum EntityType {
     Player1,
     Player2,
+    Player3,
+    Player4,
     Ball,
 }
 
@@ -61,12 +63,38 @@ pub enum GameEvent {
         position: Vec3,
         court_side: CourtSide,
     },
-    /// 壁反射
-    WallReflect { position: Vec3, wall_type: String },
+    /// 壁反射（material は材質対応前のログでは空）
+    WallReflect {
+        position: Vec3,
+        wall_type: String,
+        #[serde(default)]
+        material: String,
+    },
     /// ポイント獲得
     Point { winner: u8, reason: String },
+    /// スコア状況（デュース／アドバンテージ等）
+    ScoreSituation { scorer: u8, situation: String },
+    /// ゲーム獲得
+    GameWon {
+        winner: u8,
+        deciding_situation: String,
+    },
     /// フォールト
     Fault { fault_type: String },
+    /// サーブレット（ネットイン後の打ち直し）
+    ServeLet {
+        server: u8,
+        position: Vec3,
+        consecutive_lets: u32,
+    },
+    /// サーブネットフォールト
+    ServeNetFault { server: u8, position: Vec3 },
+    /// エンドチェンジ
+    ChangeEnds {
+        ends_swapped: bool,
+        games_played: u32,
+        in_tiebreak: bool,
+    },
     /// 状態遷移
     StateChange { from: String, to: String },
     /// ショット属性計算詳細
@@ -118,7 +146,12 @@ impl GameEvent {
             GameEvent::Bounce { .. } => "Bounce",
             GameEvent::WallReflect { .. } => "WallReflect",
             GameEvent::Point { .. } => "Point",
+            GameEvent::ScoreSituation { .. } => "ScoreSituation",
+            GameEvent::GameWon { .. } => "GameWon",
             GameEvent::Fault { .. } => "Fault",
+            GameEvent::ServeLet { .. } => "ServeLet",
+            GameEvent::ServeNetFault { .. } => "ServeNetFault",
+            GameEvent::ChangeEnds { .. } => "ChangeEnds",
             GameEvent::StateChange { .. } => "StateChange",
             GameEvent::ShotAttributesCalculated { .. } => "ShotAttributesCalculated",
             GameEvent::AiMovementDecision { .. } => "AiMovementDecision",
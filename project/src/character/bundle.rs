@@ -10,7 +10,7 @@ use super::components::{
 use crate::components::{
     GroundedState, InputState, KnockbackState, LogicalPosition, Player, ShotState, Velocity,
 };
-use crate::core::CourtSide;
+use crate::core::player_team_side;
 
 /// パーツ分離キャラクターのパーツ設定
 /// @spec 31001_parts_spec.md
@@ -102,11 +102,8 @@ pub fn spawn_articulated_player(
     position: Vec3,
     base_color: Color,
 ) -> Entity {
-    let court_side = if player_id == 1 {
-        CourtSide::Left
-    } else {
-        CourtSide::Right
-    };
+    // @spec 30105_doubles_spec.md#req-30105-001
+    let court_side = player_team_side(player_id);
 
     // 親エンティティ（キャラクター本体）をスポーン
     // @spec 31001_parts_spec.md#req-31001-002
@@ -43,8 +43,10 @@ impl Plugin for CharacterPlugin {
         // 起動時にアニメーションデータをロード
         app.add_systems(Startup, load_character_animations_system);
 
+        // アニメーション状態はゲームロジックと同じ固定ティックで進める
+        // @spec 77100_headless_sim.md#req-77100-001
         app.add_systems(
-            Update,
+            FixedUpdate,
             (
                 // ShotEventでショットアニメーションをトリガー
                 // @spec 31002_animation_spec.md#req-31002-052
@@ -65,11 +67,12 @@ impl Plugin for CharacterPlugin {
                 update_part_states_system,
                 // ラケットスイング中は専用軌道でラケットパーツを上書き
                 override_racket_swing_pose_system,
-                // パーツTransform同期は最後に実行
-                // @spec 31001_parts_spec.md#req-31001-006
-                sync_part_transforms_system,
             )
                 .chain(),
         );
+
+        // パーツTransform同期は描画フレームごとに実行
+        // @spec 31001_parts_spec.md#req-31001-006
+        app.add_systems(Update, sync_part_transforms_system);
     }
 }
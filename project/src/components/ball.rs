@@ -4,7 +4,7 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
+use crate::core::{topspin_component, CourtSide};
 
 use super::physics::{LogicalPosition, Velocity};
 
@@ -68,18 +68,23 @@ impl BounceCount {
 pub struct LastShooter {
     /// 最後にショットを打ったプレイヤー側
     pub side: Option<CourtSide>,
+    /// 最後にショットを打ったプレイヤーID（ボディヒット判定で打った本人を除外する）
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    pub player_id: Option<u8>,
 }
 
 impl LastShooter {
     /// ショット元を記録
-    pub fn record(&mut self, shooter: CourtSide) {
+    pub fn record(&mut self, shooter: CourtSide, player_id: u8) {
         self.side = Some(shooter);
+        self.player_id = Some(player_id);
     }
 
     /// リセット
     #[allow(dead_code)]
     pub fn reset(&mut self) {
         self.side = None;
+        self.player_id = None;
     }
 }
 
@@ -94,23 +99,40 @@ pub struct BounceState {
 
 /// ボールのスピン状態コンポーネント
 /// @spec 30802_visual_feedback_spec.md#req-30802-004
+/// @spec 30401_trajectory_spec.md#req-30401-103
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct BallSpin {
-    /// スピン値（-1.0〜+1.0）
-    /// 正: トップスピン、負: スライス、0: ニュートラル
-    pub value: f32,
+    /// 角速度ベクトル（ワールド座標、大きさ 1.0 = 最大スピン）
+    /// トップスピン・スライスは進行方向に対する水平軸成分、サイドスピンはY軸成分
+    pub angular_velocity: Vec3,
 }
 
-/// `Option<&BallSpin>` からスピン値を取得するための拡張トレイト
+impl BallSpin {
+    /// 進行方向に対するトップスピン量（正: トップスピン、負: スライス）
+    #[inline]
+    pub fn topspin(&self, velocity: Vec3) -> f32 {
+        topspin_component(self.angular_velocity, velocity)
+    }
+}
+
+/// `Option<&BallSpin>` からスピンを取得するための拡張トレイト
 pub trait BallSpinExt {
-    /// スピン値を取得（Noneまたはデフォルトの場合は0.0を返す）
-    fn value_or_default(&self) -> f32;
+    /// 角速度を取得（Noneの場合はゼロベクトルを返す）
+    fn angular_or_default(&self) -> Vec3;
+
+    /// 進行方向に対するトップスピン量を取得（Noneの場合は0.0を返す）
+    fn topspin_or_default(&self, velocity: Vec3) -> f32;
 }
 
 impl BallSpinExt for Option<&BallSpin> {
     #[inline]
-    fn value_or_default(&self) -> f32 {
-        self.map_or(0.0, |s| s.value)
+    fn angular_or_default(&self) -> Vec3 {
+        self.map_or(Vec3::ZERO, |s| s.angular_velocity)
+    }
+
+    #[inline]
+    fn topspin_or_default(&self, velocity: Vec3) -> f32 {
+        self.map_or(0.0, |s| s.topspin(velocity))
     }
 }
 
@@ -164,6 +186,7 @@ impl BallBundle {
         position: Vec3,
         velocity: Vec3,
         shooter: CourtSide,
+        shooter_id: u8,
         meshes: &mut Assets<Mesh>,
         materials: &mut Assets<ColorMaterial>,
     ) -> Self {
@@ -175,6 +198,7 @@ impl BallBundle {
             bounce_state: BounceState::default(),
             last_shooter: LastShooter {
                 side: Some(shooter),
+                player_id: Some(shooter_id),
             },
             ball_spin: BallSpin::default(),
             mesh: Mesh2d(meshes.add(Circle::new(BALL_DISPLAY_RADIUS))),
@@ -191,6 +215,7 @@ impl BallBundle {
         position: Vec3,
         velocity: Vec3,
         shooter: CourtSide,
+        shooter_id: u8,
     ) -> (
         Ball,
         LogicalPosition,
@@ -209,6 +234,7 @@ impl BallBundle {
             BounceState::default(),
             LastShooter {
                 side: Some(shooter),
+                player_id: Some(shooter_id),
             },
             BallSpin::default(),
             Transform::default(),
@@ -3,6 +3,8 @@
 
 use bevy::prelude::*;
 
+use crate::core::ShotModifier;
+
 /// 入力状態コンポーネント
 /// 各プレイヤーエンティティに付与される入力状態
 /// @spec 20006_input_system.md
@@ -18,6 +20,26 @@ pub struct InputState {
     pub holding: bool,
     /// ホールド継続時間（秒）
     pub hold_time: f32,
+    /// ロブボタンを押しているか
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub lob_held: bool,
+    /// ドロップショットボタンを押しているか
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub drop_shot_held: bool,
+}
+
+impl InputState {
+    /// 押しているショット種類ボタン（両方押している場合はロブ）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub fn shot_modifier(&self) -> ShotModifier {
+        if self.lob_held {
+            ShotModifier::Lob
+        } else if self.drop_shot_held {
+            ShotModifier::DropShot
+        } else {
+            ShotModifier::None
+        }
+    }
 }
 
 /// 移動入力を量子化する（i8精度に丸める）
@@ -19,6 +19,25 @@ impl LogicalPosition {
     }
 }
 
+/// 直前のティック開始時点の論理座標（表示補間用）
+/// @spec 77100_headless_sim.md#req-77100-002
+///
+/// LogicalPosition を持つエンティティに snapshot_previous_position_system が付与・更新する。
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PreviousLogicalPosition {
+    pub value: Vec3,
+}
+
+impl PreviousLogicalPosition {
+    /// 直前ティックと現在ティックの間を補間した位置
+    ///
+    /// `alpha` は前ティックから次のティックまでの経過割合（0.0〜1.0）。
+    #[inline]
+    pub fn interpolate(&self, current: Vec3, alpha: f32) -> Vec3 {
+        self.value.lerp(current, alpha.clamp(0.0, 1.0))
+    }
+}
+
 /// 速度コンポーネント
 /// @spec 30201_movement_spec.md
 #[derive(Component, Debug, Clone, Copy, Default)]
@@ -3,7 +3,7 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
+use crate::core::{player_team_side, player_team_slot, CourtLane, CourtSide};
 use crate::resource::config::PlayerVisualConfig;
 
 use super::input::InputState;
@@ -14,12 +14,21 @@ use super::shot::ShotState;
 /// @spec 30200_overview.md
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Player {
-    /// プレイヤーID（1 or 2）
+    /// プレイヤーID（シングルス: 1 or 2、ダブルス: 1〜4）
     pub id: u8,
     /// プレイヤーがどちら側のコートにいるか
     pub court_side: CourtSide,
 }
 
+impl Player {
+    /// ダブルスでの担当サイド（ドライブ/レベス）
+    /// @spec 30105_doubles_spec.md#req-30105-003
+    #[inline]
+    pub fn lane(&self) -> CourtLane {
+        CourtLane::from_slot(player_team_slot(self.id))
+    }
+}
+
 /// 人間操作プレイヤーマーカーコンポーネント
 /// @spec 20006_input_system.md
 #[derive(Component, Debug, Clone, Copy, Default)]
@@ -102,17 +111,10 @@ pub struct PlayerBundle {
 #[allow(dead_code)]
 impl PlayerBundle {
     pub fn new(id: u8, position: Vec3, visual_config: &PlayerVisualConfig) -> Self {
-        let court_side = if id == 1 {
-            CourtSide::Left
-        } else {
-            CourtSide::Right
-        };
+        // @spec 30105_doubles_spec.md#req-30105-001
+        let court_side = player_team_side(id);
         // @data 80101_game_constants.md#player-visual-config
-        let (r, g, b) = if id == 1 {
-            visual_config.player1_color
-        } else {
-            visual_config.player2_color
-        };
+        let (r, g, b) = visual_config.color_for(id);
         let color = Color::srgb(r, g, b);
         let (width, height) = visual_config.size;
         Self {
@@ -4,6 +4,8 @@
 
 use bevy::prelude::*;
 
+use crate::core::ShotModifier;
+
 /// ラケットスイング状態
 /// @spec 30606_racket_contact_spec.md
 #[derive(Debug, Clone, Copy)]
@@ -22,6 +24,8 @@ pub struct RacketSwingState {
     pub input_direction: Vec2,
     /// ホールド時間（ミリ秒）
     pub hold_time_ms: f32,
+    /// ショット種類の指定（スイング開始時のボタン）
+    pub modifier: ShotModifier,
     /// 予測接触点（論理座標）
     pub planned_hit_position: Vec3,
     /// スイング軌道制御点（論理座標）
@@ -45,6 +49,7 @@ impl Default for RacketSwingState {
             contact_time_seconds: 0.0,
             input_direction: Vec2::ZERO,
             hold_time_ms: 0.0,
+            modifier: ShotModifier::None,
             planned_hit_position: Vec3::ZERO,
             start_position: Vec3::ZERO,
             pre_contact_position: Vec3::ZERO,
@@ -162,6 +167,9 @@ pub struct ShotAttributes {
     /// スピン量（正: トップスピン、負: スライス）、-1.0〜+1.0
     /// @spec 30604_shot_attributes_spec.md#req-30604-066
     pub spin: f32,
+    /// サイドスピン量（コース方向へ曲げる量）、0.0〜1.0
+    /// @spec 30604_shot_attributes_spec.md#req-30604-071
+    pub sidespin: f32,
     /// 精度（コースブレに影響）、0.0〜2.0
     /// @spec 30604_shot_attributes_spec.md#req-30604-067
     pub accuracy: f32,
@@ -174,6 +182,7 @@ impl Default for ShotAttributes {
             stability: 1.0,
             angle: 15.0,
             spin: 0.0,
+            sidespin: 0.0,
             accuracy: 1.0,
         }
     }
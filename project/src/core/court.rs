@@ -38,7 +38,7 @@ impl CourtSide {
 /// @spec 30501_court_spec.md#req-30501-002
 /// @spec 30501_court_spec.md#req-30501-003
 /// @spec 30501_court_spec.md#req-30501-004
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct CourtBounds {
     /// サイドウォール左端Z座標（コート幅方向）(REQ-30501-002)
     pub left: f32,
@@ -129,30 +129,90 @@ impl CourtBounds {
     }
 }
 
+/// ネットの接触部位
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetZone {
+    /// ネット本体（白帯より下）
+    Body,
+    /// 白帯（ネット上端のコード）
+    Cord,
+    /// ネット両端のポスト
+    Post,
+}
+
 /// ネット情報
 /// @spec 30501_court_spec.md#req-30501-005
+///
+/// ネット上端は中央で最も低く、ポストに向かって放物線状に高くなる（たるみ）。
 #[derive(Debug, Clone, Copy)]
 pub struct NetInfo {
     /// ネットのX座標（コート中央、Left/Rightの境界、打ち合い方向）
     pub x: f32,
-    /// ネットの高さ
-    #[allow(dead_code)] // is_collision で使用、将来のネット衝突判定で使用予定
+    /// ネット中央の高さ（最も低い位置）
     pub height: f32,
+    /// ポスト位置でのネットの高さ
+    pub post_height: f32,
+    /// ポスト外側端のZ座標（ネットは -post_z 〜 +post_z に張られる）
+    pub post_z: f32,
+    /// ポストの幅（Z方向）
+    pub post_width: f32,
+    /// 白帯の幅（ネット上端からの深さ）
+    pub cord_band: f32,
 }
 
 impl NetInfo {
-    /// ネット情報を生成
+    /// ネット情報を生成（たるみ・ポスト・白帯のない平らなネット）
     /// @spec 30501_court_spec.md#req-30501-005
     pub fn new(x: f32, height: f32) -> Self {
-        Self { x, height }
+        Self {
+            x,
+            height,
+            post_height: height,
+            post_z: f32::INFINITY,
+            post_width: 0.0,
+            cord_band: 0.0,
+        }
+    }
+
+    /// 指定Z座標でのネット上端の高さ
+    /// @spec 30501_court_spec.md#req-30501-005
+    ///
+    /// 中央（Z = 0）で height、ポスト位置（|Z| = post_z）で post_height。
+    #[inline]
+    pub fn height_at(&self, z: f32) -> f32 {
+        let t = (z / self.post_z).clamp(-1.0, 1.0);
+        self.height + (self.post_height - self.height) * t * t
+    }
+
+    /// ネット面上の点（高さ y、Z座標 z）が接触する部位
+    /// @spec 30503_boundary_behavior.md#beh-30503-005
+    ///
+    /// ネットの上・ポストの外側は None。
+    pub fn zone_at(&self, y: f32, z: f32) -> Option<NetZone> {
+        let side = z.abs();
+        if side > self.post_z {
+            return None;
+        }
+        if side >= self.post_z - self.post_width {
+            return (y < self.post_height).then_some(NetZone::Post);
+        }
+        let top = self.height_at(z);
+        if y >= top {
+            None
+        } else if y >= top - self.cord_band {
+            Some(NetZone::Cord)
+        } else {
+            Some(NetZone::Body)
+        }
     }
 
     /// 指定位置がネットに衝突するかチェック
-    /// ネット位置（X座標）にいて、ネット高さ未満の場合に衝突
-    #[allow(dead_code)] // テストで使用、将来のネット衝突判定で使用予定
+    /// ネット位置（X座標）にいて、その位置のネット高さ未満の場合に衝突
+    #[allow(dead_code)] // テストで使用
     #[inline]
-    pub fn is_collision(&self, y: f32, x: f32, tolerance: f32) -> bool {
-        (x - self.x).abs() < tolerance && y < self.height
+    pub fn is_collision(&self, y: f32, x: f32, z: f32, tolerance: f32) -> bool {
+        (x - self.x).abs() < tolerance && self.zone_at(y, z).is_some()
     }
 }
 
@@ -257,10 +317,55 @@ mod tests {
         assert_eq!(net.x, 0.0);
         assert_eq!(net.height, 1.0);
 
-        // ネット衝突判定 (y, x, tolerance)
-        assert!(net.is_collision(0.5, 0.0, 0.1)); // ネット位置、高さ未満
-        assert!(!net.is_collision(1.5, 0.0, 0.1)); // ネット位置、高さ超過
-        assert!(!net.is_collision(0.5, 1.0, 0.1)); // ネット位置外
+        // ネット衝突判定 (y, x, z, tolerance)
+        assert!(net.is_collision(0.5, 0.0, 0.0, 0.1)); // ネット位置、高さ未満
+        assert!(!net.is_collision(1.5, 0.0, 0.0, 0.1)); // ネット位置、高さ超過
+        assert!(!net.is_collision(0.5, 1.0, 0.0, 0.1)); // ネット位置外
+    }
+
+    /// 実寸のネット（中央0.88m、ポスト0.92m、ポスト幅0.1m、白帯0.05m、ポストは Z = ±5）
+    fn sagging_net() -> NetInfo {
+        NetInfo {
+            post_height: 0.92,
+            post_z: 5.0,
+            post_width: 0.1,
+            cord_band: 0.05,
+            ..NetInfo::new(0.0, 0.88)
+        }
+    }
+
+    /// TST-30504-020: ネットは中央が低く、ポストに向かって高くなる
+    #[test]
+    fn test_req_30501_005_net_sag() {
+        let net = sagging_net();
+
+        assert!((net.height_at(0.0) - 0.88).abs() < 1e-6);
+        assert!((net.height_at(5.0) - 0.92).abs() < 1e-6);
+        assert!((net.height_at(-5.0) - 0.92).abs() < 1e-6);
+        assert!((net.height_at(2.5) - 0.89).abs() < 1e-6);
+        // ポストの外側はポストの高さのまま
+        assert!((net.height_at(8.0) - 0.92).abs() < 1e-6);
+
+        // 平らなネットはどこでも同じ高さ
+        assert_eq!(test_net().height_at(3.0), 1.0);
+    }
+
+    /// TST-30504-021: ネットの接触部位（本体・白帯・ポスト）
+    #[test]
+    fn test_beh_30503_005_net_zones() {
+        let net = sagging_net();
+
+        assert_eq!(net.zone_at(0.5, 0.0), Some(NetZone::Body));
+        assert_eq!(net.zone_at(0.85, 0.0), Some(NetZone::Cord));
+        assert_eq!(net.zone_at(0.89, 0.0), None);
+        // 中央では越える高さでも、ポスト寄りではネットに当たる
+        assert_eq!(net.zone_at(0.89, 4.5), Some(NetZone::Cord));
+        // ポスト
+        assert_eq!(net.zone_at(0.5, 4.95), Some(NetZone::Post));
+        assert_eq!(net.zone_at(0.91, -4.95), Some(NetZone::Post));
+        assert_eq!(net.zone_at(0.95, 4.95), None);
+        // ポストの外側
+        assert_eq!(net.zone_at(0.5, 5.5), None);
     }
 
     /// TST-30504-006: コート区分（Left/Right）
@@ -0,0 +1,314 @@
+//! コートの囲い（壁セグメント）
+//! @spec 30503_boundary_behavior.md#beh-30503-009
+//!
+//! 外壁は CourtBounds の4平面（左右壁・前後壁）上に並ぶ壁セグメントで構成する。
+//! セグメントごとに区間・高さ・材質を持ち、セグメントのない区間（ドア）や
+//! セグメントの高さより上は開口部としてボールが囲いの外へ出られる。
+//! 床の高さの開口部（ドア）はプレイヤーも通り抜けられる。
+//!
+//! ## 区間の座標
+//! - 左右壁: X座標（打ち合い方向）
+//! - 前後壁: Z座標（コート幅方向）
+
+use bevy::prelude::*;
+
+use super::CourtBounds;
+use crate::core::events::{WallMaterial, WallType};
+
+/// 壁セグメント
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallSegment {
+    /// 所属する壁面（天井は対象外）
+    pub wall_type: WallType,
+    /// 区間の始点（左右壁: X、前後壁: Z）
+    pub from: f32,
+    /// 区間の終点（左右壁: X、前後壁: Z）
+    pub to: f32,
+    /// 壁の高さ（m）
+    pub height: f32,
+    /// 材質
+    pub material: WallMaterial,
+}
+
+impl WallSegment {
+    /// 壁面上の点がセグメントの範囲内（区間内かつ高さ以下）か
+    #[inline]
+    pub fn contains(&self, point: Vec3) -> bool {
+        let along = along_wall(self.wall_type, point);
+        along >= self.from.min(self.to) && along <= self.from.max(self.to) && point.y <= self.height
+    }
+}
+
+/// 壁面に沿った座標（左右壁: X、前後壁: Z、天井: 0）
+#[inline]
+fn along_wall(wall_type: WallType, point: Vec3) -> f32 {
+    match wall_type {
+        WallType::LeftWall | WallType::RightWall => point.x,
+        WallType::BackWallLeft | WallType::BackWallRight => point.z,
+        WallType::Ceiling => 0.0,
+    }
+}
+
+/// ドア（床の高さの開口部）
+/// @spec 30503_boundary_behavior.md#beh-30503-010
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Door {
+    /// 所属する壁面
+    pub wall_type: WallType,
+    /// 区間の始点（左右壁: X、前後壁: Z）
+    pub from: f32,
+    /// 区間の終点（左右壁: X、前後壁: Z）
+    pub to: f32,
+}
+
+impl Door {
+    /// 床の高さでのドア中央の位置
+    pub fn center(&self, bounds: &CourtBounds) -> Vec3 {
+        let along = (self.from + self.to) / 2.0;
+        match self.wall_type {
+            WallType::LeftWall => Vec3::new(along, bounds.ground, bounds.left),
+            WallType::RightWall => Vec3::new(along, bounds.ground, bounds.right),
+            WallType::BackWallLeft => Vec3::new(bounds.back_left, bounds.ground, along),
+            WallType::BackWallRight => Vec3::new(bounds.back_right, bounds.ground, along),
+            WallType::Ceiling => Vec3::new(0.0, bounds.ceiling, along),
+        }
+    }
+}
+
+/// コートの囲い（外壁平面 + 壁セグメント）
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+#[derive(Debug, Clone, Default)]
+pub struct CourtEnclosure {
+    /// 外壁平面・地面・天井の位置
+    pub bounds: CourtBounds,
+    /// 壁セグメント一覧
+    pub segments: Vec<WallSegment>,
+}
+
+impl CourtEnclosure {
+    /// 外壁平面と壁セグメントから囲いを生成
+    pub fn new(bounds: CourtBounds, segments: Vec<WallSegment>) -> Self {
+        Self { bounds, segments }
+    }
+
+    /// 壁面上の点にあるセグメント（None なら開口部）
+    pub fn segment_at(&self, wall_type: WallType, point: Vec3) -> Option<&WallSegment> {
+        self.segments
+            .iter()
+            .find(|segment| segment.wall_type == wall_type && segment.contains(point))
+    }
+
+    /// 壁面上の点が囲いの角の内側にあるか
+    ///
+    /// 角の外側（既に囲いの外にいるボールが別の壁面の延長を横切る場合）は判定対象外。
+    pub fn within_corners(&self, wall_type: WallType, point: Vec3) -> bool {
+        let bounds = &self.bounds;
+        match wall_type {
+            WallType::LeftWall | WallType::RightWall => {
+                point.x >= bounds.back_left && point.x <= bounds.back_right
+            }
+            WallType::BackWallLeft | WallType::BackWallRight => {
+                point.z >= bounds.left && point.z <= bounds.right
+            }
+            WallType::Ceiling => {
+                point.x >= bounds.back_left
+                    && point.x <= bounds.back_right
+                    && point.z >= bounds.left
+                    && point.z <= bounds.right
+            }
+        }
+    }
+
+    /// 水平位置が囲いの内側（外壁平面の内側）にあるか
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    pub fn contains(&self, point: Vec3) -> bool {
+        let bounds = &self.bounds;
+        point.x >= bounds.back_left
+            && point.x <= bounds.back_right
+            && point.z >= bounds.left
+            && point.z <= bounds.right
+    }
+
+    /// 壁面上の位置が床の高さで開口部（ドア）か
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    pub fn is_door(&self, wall_type: WallType, point: Vec3) -> bool {
+        let floor_point = Vec3::new(point.x, self.bounds.ground, point.z);
+        self.within_corners(wall_type, floor_point)
+            && self.segment_at(wall_type, floor_point).is_none()
+    }
+
+    /// ドア（床の高さの開口部）の一覧
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    ///
+    /// 壁面ごとに角の内側でセグメントに覆われていない区間を列挙する。
+    pub fn doors(&self) -> Vec<Door> {
+        let bounds = &self.bounds;
+        let walls = [
+            (WallType::LeftWall, bounds.back_left, bounds.back_right),
+            (WallType::RightWall, bounds.back_left, bounds.back_right),
+            (WallType::BackWallLeft, bounds.left, bounds.right),
+            (WallType::BackWallRight, bounds.left, bounds.right),
+        ];
+
+        let mut doors = Vec::new();
+        for (wall_type, start, end) in walls {
+            let mut covered: Vec<(f32, f32)> = self
+                .segments
+                .iter()
+                .filter(|segment| segment.wall_type == wall_type && segment.height > bounds.ground)
+                .map(|segment| (segment.from.min(segment.to), segment.from.max(segment.to)))
+                .collect();
+            covered.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut cursor = start;
+            for (from, to) in covered {
+                if from > cursor {
+                    doors.push(Door {
+                        wall_type,
+                        from: cursor,
+                        to: from.min(end),
+                    });
+                }
+                cursor = cursor.max(to);
+                if cursor >= end {
+                    break;
+                }
+            }
+            if cursor < end {
+                doors.push(Door {
+                    wall_type,
+                    from: cursor,
+                    to: end,
+                });
+            }
+        }
+        doors.retain(|door| door.to > door.from);
+        doors
+    }
+
+    /// 壁面上の点の材質（開口部・天井はガラス扱い）
+    /// @spec 30402_reflection_spec.md#req-30402-102
+    pub fn material_at(&self, wall_type: WallType, point: Vec3) -> WallMaterial {
+        self.segment_at(wall_type, point)
+            .map_or(WallMaterial::Glass, |segment| segment.material)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// テスト用の囲い
+    /// - 後壁: 全幅・高さ4m・ガラス
+    /// - 右壁: 後方2mはガラス3m、その前は金網3m、|X| < 1 はドア
+    fn test_enclosure() -> CourtEnclosure {
+        let bounds = CourtBounds::new(-5.0, 5.0, -10.0, 10.0, 0.0, 8.0);
+        let segment = |wall_type, from, to, height, material| WallSegment {
+            wall_type,
+            from,
+            to,
+            height,
+            material,
+        };
+        CourtEnclosure::new(
+            bounds,
+            vec![
+                segment(WallType::BackWallRight, -5.0, 5.0, 4.0, WallMaterial::Glass),
+                segment(WallType::RightWall, 8.0, 10.0, 3.0, WallMaterial::Glass),
+                segment(WallType::RightWall, 1.0, 8.0, 3.0, WallMaterial::Mesh),
+                segment(WallType::RightWall, -8.0, -1.0, 3.0, WallMaterial::Mesh),
+            ],
+        )
+    }
+
+    /// TST-30504-019: 接触点のセグメントと材質
+    #[test]
+    fn test_beh_30503_009_segment_at() {
+        let enclosure = test_enclosure();
+
+        let glass = Vec3::new(9.0, 1.0, 5.0);
+        let mesh = Vec3::new(-5.9, 1.0, 5.0);
+        assert_eq!(
+            enclosure.material_at(WallType::RightWall, glass),
+            WallMaterial::Glass
+        );
+        assert_eq!(
+            enclosure.material_at(WallType::RightWall, mesh),
+            WallMaterial::Mesh
+        );
+        assert!(enclosure
+            .segment_at(WallType::BackWallRight, Vec3::new(10.0, 3.9, -4.0))
+            .is_some());
+    }
+
+    /// TST-30504-019: ドア・壁の上は開口部
+    #[test]
+    fn test_beh_30503_009_openings() {
+        let enclosure = test_enclosure();
+
+        // ドア（|X| < 1）
+        assert!(enclosure
+            .segment_at(WallType::RightWall, Vec3::new(0.5, 1.0, 5.0))
+            .is_none());
+        // 壁の高さより上
+        assert!(enclosure
+            .segment_at(WallType::RightWall, Vec3::new(5.0, 3.5, 5.0))
+            .is_none());
+        // セグメントのない壁面（左壁）
+        assert!(enclosure
+            .segment_at(WallType::LeftWall, Vec3::new(5.0, 1.0, -5.0))
+            .is_none());
+    }
+
+    /// TST-30504-023: ドアの列挙と判定
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    #[test]
+    fn test_beh_30503_010_doors() {
+        let enclosure = test_enclosure();
+        let doors = enclosure.doors();
+
+        // 右壁の |X| < 1 と後方の -10 < X < -8、セグメントのない左壁・後壁（BackWallLeft）全体
+        let expected = [
+            (WallType::LeftWall, -10.0, 10.0),
+            (WallType::RightWall, -10.0, -8.0),
+            (WallType::RightWall, -1.0, 1.0),
+            (WallType::BackWallLeft, -5.0, 5.0),
+        ];
+        assert_eq!(doors.len(), expected.len());
+        for (wall_type, from, to) in expected {
+            assert!(doors.contains(&Door {
+                wall_type,
+                from,
+                to
+            }));
+        }
+
+        // 床の高さで判定する（壁の高さより上でもドアではない）
+        assert!(enclosure.is_door(WallType::RightWall, Vec3::new(0.5, 1.0, 5.0)));
+        assert!(!enclosure.is_door(WallType::RightWall, Vec3::new(5.0, 3.5, 5.0)));
+        assert!(!enclosure.is_door(WallType::RightWall, Vec3::new(10.5, 0.0, 5.0)));
+
+        let center = doors[0].center(&enclosure.bounds);
+        assert!(enclosure.is_door(doors[0].wall_type, center));
+    }
+
+    /// 囲いの内側判定（水平位置のみ）
+    #[test]
+    fn test_beh_30503_010_contains() {
+        let enclosure = test_enclosure();
+        assert!(enclosure.contains(Vec3::new(9.9, 7.0, -4.9)));
+        assert!(!enclosure.contains(Vec3::new(10.5, 0.0, 0.0)));
+        assert!(!enclosure.contains(Vec3::new(0.0, 0.0, 5.5)));
+    }
+
+    /// 角の外側は判定対象外
+    #[test]
+    fn test_beh_30503_009_within_corners() {
+        let enclosure = test_enclosure();
+
+        assert!(enclosure.within_corners(WallType::RightWall, Vec3::new(9.9, 1.0, 5.0)));
+        assert!(!enclosure.within_corners(WallType::RightWall, Vec3::new(10.5, 1.0, 5.0)));
+        assert!(!enclosure.within_corners(WallType::BackWallRight, Vec3::new(10.0, 1.0, 5.5)));
+    }
+}
@@ -0,0 +1,129 @@
+//! 環境条件（標高・気温・ボール内圧・摩耗）によるボール特性の補正
+//! @spec 30401_trajectory_spec.md#req-30401-105
+//! @spec 30402_reflection_spec.md#req-30402-104
+//!
+//! 基準条件（海抜0m・基準気温・基準内圧・新品）で 1.0 となる倍率を求め、
+//! 空気抵抗・スピン減衰・反発係数に掛けて使う。
+
+/// 標準大気圧（kPa）
+pub const SEA_LEVEL_PRESSURE_KPA: f32 = 101.325;
+
+/// 大気のスケールハイト（m）。気圧・空気密度は標高とともに exp(-h / H) で下がる
+pub const ATMOSPHERE_SCALE_HEIGHT_M: f32 = 8434.0;
+
+/// 摂氏0度の絶対温度（K）
+const CELSIUS_TO_KELVIN: f32 = 273.15;
+
+/// ボール特性の補正倍率
+/// @spec 30401_trajectory_spec.md#req-30401-105
+/// @spec 30402_reflection_spec.md#req-30402-104
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BallConditionFactors {
+    /// 空気抵抗の倍率（空気密度比）
+    pub drag_scale: f32,
+    /// 反発係数（地面・壁）の倍率（内圧・ゴムの温度・摩耗）
+    pub restitution_scale: f32,
+    /// 飛行中のスピン減衰率の倍率（空気密度比）
+    pub spin_decay_scale: f32,
+}
+
+impl Default for BallConditionFactors {
+    /// 基準条件（補正なし）
+    fn default() -> Self {
+        Self {
+            drag_scale: 1.0,
+            restitution_scale: 1.0,
+            spin_decay_scale: 1.0,
+        }
+    }
+}
+
+/// 標高の大気圧（kPa）
+#[inline]
+pub fn atmospheric_pressure_kpa(altitude_m: f32) -> f32 {
+    SEA_LEVEL_PRESSURE_KPA * (-altitude_m / ATMOSPHERE_SCALE_HEIGHT_M).exp()
+}
+
+/// 基準条件（海抜0m・基準気温）に対する空気密度の比
+/// @spec 30401_trajectory_spec.md#req-30401-105
+///
+/// 密度は気圧に比例し、絶対温度に反比例する。
+pub fn air_density_ratio(altitude_m: f32, temperature_c: f32, reference_temperature_c: f32) -> f32 {
+    let pressure_ratio = atmospheric_pressure_kpa(altitude_m) / SEA_LEVEL_PRESSURE_KPA;
+    pressure_ratio * kelvin(reference_temperature_c) / kelvin(temperature_c)
+}
+
+/// 環境条件でのボール内圧（ゲージ圧、kPa）
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// `reference_gauge_kpa` は海抜0m・基準気温で測ったゲージ圧。
+/// ボール内の絶対圧は気温に比例して変わり（体積一定）、
+/// ゲージ圧は外気圧が下がる（標高が上がる）ほど高くなる。
+pub fn ball_gauge_pressure_kpa(
+    reference_gauge_kpa: f32,
+    altitude_m: f32,
+    temperature_c: f32,
+    reference_temperature_c: f32,
+) -> f32 {
+    let absolute = (SEA_LEVEL_PRESSURE_KPA + reference_gauge_kpa) * kelvin(temperature_c)
+        / kelvin(reference_temperature_c);
+    absolute - atmospheric_pressure_kpa(altitude_m)
+}
+
+/// 摩耗による反発係数の倍率
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// 1ゲームごとに wear_per_game ずつ下がり、1.0 - max_wear を下限とする。
+#[inline]
+pub fn wear_restitution_scale(games_played: u32, wear_per_game: f32, max_wear: f32) -> f32 {
+    let wear = (wear_per_game.max(0.0) * games_played as f32).min(max_wear.clamp(0.0, 1.0));
+    1.0 - wear
+}
+
+#[inline]
+fn kelvin(temperature_c: f32) -> f32 {
+    (temperature_c + CELSIUS_TO_KELVIN).max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TST-30404-110: 基準条件では空気密度比 1.0
+    /// @spec 30401_trajectory_spec.md#req-30401-105
+    #[test]
+    fn test_req_30401_105_air_density_ratio_reference() {
+        assert!((air_density_ratio(0.0, 20.0, 20.0) - 1.0).abs() < 1e-6);
+    }
+
+    /// TST-30404-110: 標高が高い・気温が高いほど空気は薄い
+    /// @spec 30401_trajectory_spec.md#req-30401-105
+    #[test]
+    fn test_req_30401_105_air_density_ratio_altitude_and_heat() {
+        let altitude = air_density_ratio(2000.0, 20.0, 20.0);
+        assert!((altitude - (-2000.0_f32 / ATMOSPHERE_SCALE_HEIGHT_M).exp()).abs() < 1e-5);
+
+        let hot = air_density_ratio(0.0, 35.0, 20.0);
+        let cold = air_density_ratio(0.0, 5.0, 20.0);
+        assert!(hot < 1.0);
+        assert!(cold > 1.0);
+    }
+
+    /// TST-30404-111: 基準条件では基準内圧のまま、標高で上がり、低温で下がる
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    #[test]
+    fn test_req_30402_104_ball_gauge_pressure() {
+        assert!((ball_gauge_pressure_kpa(70.0, 0.0, 20.0, 20.0) - 70.0).abs() < 1e-3);
+        assert!(ball_gauge_pressure_kpa(70.0, 2000.0, 20.0, 20.0) > 85.0);
+        assert!(ball_gauge_pressure_kpa(70.0, 0.0, 5.0, 20.0) < 65.0);
+    }
+
+    /// TST-30404-111: 摩耗はゲーム数に比例し、上限で止まる
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    #[test]
+    fn test_req_30402_104_wear_restitution_scale() {
+        assert_eq!(wear_restitution_scale(0, 0.003, 0.1), 1.0);
+        assert!((wear_restitution_scale(10, 0.003, 0.1) - 0.97).abs() < 1e-6);
+        assert!((wear_restitution_scale(100, 0.003, 0.1) - 0.9).abs() < 1e-6);
+    }
+}
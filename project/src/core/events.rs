@@ -66,6 +66,21 @@ pub struct BallHitEvent {
     pub hit_point: Vec3,
 }
 
+/// ボールがプレイヤーの体に当たったイベント（パデルルール）
+/// @spec 30403_collision_spec.md#req-30403-007
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+#[derive(Event, Message, Debug, Clone)]
+pub struct BallBodyHitEvent {
+    /// ボールのEntity
+    pub ball: Entity,
+    /// 当たったプレイヤーID
+    pub player_id: u8,
+    /// 当たったプレイヤーのコート側
+    pub court_side: super::court::CourtSide,
+    /// 衝突位置
+    pub hit_point: Vec3,
+}
+
 /// プレイヤーふっとばしイベント
 /// @spec 30203_knockback_spec.md#req-30203-007
 /// NOTE: デバッグ・ログ出力用のイベント。読み取りハンドラは将来実装予定。
@@ -88,7 +103,7 @@ pub struct PlayerKnockbackEvent {
 /// 壁の種類
 /// @spec 30503_boundary_behavior.md#beh-30503-004
 /// 新座標系: LeftWall/RightWall = Z方向（コート幅）, BackWall = X方向（打ち合い方向）
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
 pub enum WallType {
     /// 左壁（Z = -Court.Width/2、コート幅方向）
     LeftWall,
@@ -106,7 +121,6 @@ impl WallType {
     /// 壁の法線ベクトルを返す
     /// @spec 30503_boundary_behavior.md#beh-30503-004
     /// 新座標系: LeftWall/RightWall = Z方向, BackWall = X方向
-    #[allow(dead_code)]
     #[inline]
     pub fn normal(&self) -> Vec3 {
         match self {
@@ -131,10 +145,37 @@ impl WallType {
     pub fn is_back_wall(&self) -> bool {
         matches!(self, WallType::BackWallLeft | WallType::BackWallRight)
     }
+
+    /// 壁が属するコート側を返す（天井は None）
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
+    /// 左右壁は接触点のX座標（ネット基準）で判定する
+    pub fn court_side(&self, contact_point: Vec3, net_x: f32) -> Option<super::court::CourtSide> {
+        match self {
+            WallType::BackWallLeft => Some(super::court::CourtSide::Left),
+            WallType::BackWallRight => Some(super::court::CourtSide::Right),
+            WallType::LeftWall | WallType::RightWall => Some(
+                super::court::determine_court_side(contact_point.x, net_x),
+            ),
+            WallType::Ceiling => None,
+        }
+    }
+}
+
+/// 壁面の材質
+/// @spec 30402_reflection_spec.md#req-30402-102
+///
+/// 壁セグメントごとに設定する（BEH-30503-009）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+pub enum WallMaterial {
+    /// ガラス（反発が強く、素直に跳ね返る）
+    Glass,
+    /// 金網（エネルギーを吸収し、不規則に跳ねる）
+    Mesh,
 }
 
 /// 壁反射イベント
 /// @spec 30503_boundary_behavior.md#beh-30503-004
+/// @spec 30402_reflection_spec.md#req-30402-102
 /// NOTE: デバッグ・ログ出力用のイベント。読み取りハンドラは将来実装予定。
 #[derive(Message, Debug, Clone)]
 pub struct WallReflectionEvent {
@@ -144,6 +185,9 @@ pub struct WallReflectionEvent {
     /// 反射した壁の種類
     #[allow(dead_code)]
     pub wall_type: WallType,
+    /// 接触した壁面の材質
+    #[allow(dead_code)]
+    pub material: WallMaterial,
     /// 接触点の座標
     #[allow(dead_code)]
     pub contact_point: Vec3,
@@ -155,6 +199,33 @@ pub struct WallReflectionEvent {
     pub reflected_velocity: Vec3,
 }
 
+/// 囲い離脱イベント（ドア・壁の上から囲いの外へ出た）
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+/// @spec 30901_point_judgment_spec.md#req-30901-007
+#[derive(Message, Debug, Clone)]
+pub struct BallLeftCourtEvent {
+    /// 囲いの外へ出たボールのEntity
+    pub ball: Entity,
+    /// 通過した壁面
+    pub wall_type: WallType,
+    /// 通過点の座標
+    pub exit_point: Vec3,
+}
+
+/// ネット接触の結果
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetContact {
+    /// ネット本体に当たって跳ね返った
+    Body,
+    /// 白帯に当たって相手コート側へ越えた
+    CordRollOver,
+    /// 白帯に当たって打った側へ落ちた
+    CordDropBack,
+    /// ポストに当たって跳ね返った
+    Post,
+}
+
 /// ネット接触イベント
 /// @spec 30503_boundary_behavior.md#beh-30503-005
 #[derive(Message, Debug, Clone)]
@@ -164,6 +235,8 @@ pub struct NetHitEvent {
     /// 接触点の座標
     #[allow(dead_code)]
     pub contact_point: Vec3,
+    /// 接触の結果（本体・白帯を越えた・白帯で落ちた・ポスト）
+    pub contact: NetContact,
 }
 
 /// 地面バウンドイベント
@@ -190,6 +263,48 @@ pub struct BallOutOfBoundsEvent {
     pub final_position: Vec3,
 }
 
+/// ショット種類
+/// @spec 30607_shot_type_spec.md#req-30607-001
+///
+/// 入力・打点の高さ・コート上の位置から選ばれ、種類ごとの威力・スピン・弾道意図を決める。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShotType {
+    /// グラウンドストローク（既定）
+    #[default]
+    Drive,
+    /// ノーバウンドで返すボレー
+    Volley,
+    /// 相手の頭上を越える高い弾道
+    Lob,
+    /// ジャンプして叩き込むオーバーヘッド
+    Smash,
+    /// スライスで深く返す守備的なオーバーヘッド
+    Bandeja,
+    /// サイドスピンをかけた攻撃的なオーバーヘッド
+    Vibora,
+    /// ネット際に短く落とす
+    DropShot,
+    /// 後方からネット前の相手の足元へ沈める低く遅いボール
+    Chiquita,
+    /// サーブ（サーブ専用の弾道計算を使う）
+    Serve,
+}
+
+/// ショット種類の指定（専用ボタン）
+/// @spec 30607_shot_type_spec.md#req-30607-003
+///
+/// ロブ・ドロップショットは方向入力やホールドではなく専用ボタンで選ぶ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShotModifier {
+    /// 指定なし（ドライブ・ボレー）
+    #[default]
+    None,
+    /// ロブボタン
+    Lob,
+    /// ドロップショットボタン（ネットから遠ければチキータ）
+    DropShot,
+}
+
 /// ショットイベント
 /// @spec 30601_shot_input_spec.md#req-30601-001
 /// @spec 30601_shot_input_spec.md#req-30601-006
@@ -212,6 +327,9 @@ pub struct ShotEvent {
     pub hit_position: Option<Vec3>,
     /// サーブトス上向き初速度（サーブ時のみ使用）
     pub serve_toss_velocity_y: Option<f32>,
+    /// ショット種類（サーブは ShotType::Serve）
+    /// @spec 30607_shot_type_spec.md#req-30607-005
+    pub shot_type: ShotType,
 }
 
 /// スイング意図イベント
@@ -227,6 +345,9 @@ pub struct SwingIntentEvent {
     pub direction: Vec2,
     /// ホールド時間（ミリ秒）
     pub hold_time_ms: f32,
+    /// ショット種類の指定（ロブ・ドロップショットボタン）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub modifier: ShotModifier,
 }
 
 /// ラケット接触イベント
@@ -259,6 +380,9 @@ pub struct ShotExecutedEvent {
     /// ジャンプショットかどうか
     #[allow(dead_code)]
     pub is_jump_shot: bool,
+    /// ショット種類
+    /// @spec 30607_shot_type_spec.md#req-30607-005
+    pub shot_type: ShotType,
 }
 
 /// ショット属性計算完了イベント（トレース用）
@@ -311,13 +435,19 @@ pub struct RallyEndEvent {
 /// @spec 30701_point_spec.md
 /// @spec 30103_point_end_spec.md
 /// @spec 30902_fault_spec.md
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum RallyEndReason {
     /// ツーバウンド
     /// @spec 30103_point_end_spec.md#req-30103-001
     DoubleBounce,
-    /// アウト（パデルでは通常発生しない、安全弁）
+    /// アウト（受け手コートでバウンドする前にコート外へ着地）
     Out,
+    /// ダイレクト壁ヒット（受け手側の壁に床より先に当たった）
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
+    DirectWallHit,
+    /// フェンス越え（ボールがコートの囲いの外へ出た）
+    /// @spec 30901_point_judgment_spec.md#req-30901-007
+    BallThroughFence,
     /// ネットタッチ（ボールがネットに当たり相手コートに届かなかった）
     /// @spec 30103_point_end_spec.md#req-30103-002
     NetFault,
@@ -327,6 +457,37 @@ pub enum RallyEndReason {
     /// ダブルフォルト（サーブを2回連続でミス）
     /// @spec 30902_fault_spec.md#req-30902-002
     DoubleFault,
+    /// ボディヒット（ボールがバウンド前に受け手の体に当たった）
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    BodyHit,
+    /// ダブルヒット（同じ側が相手より先に続けてボールに触れた）
+    /// @spec 30901_point_judgment_spec.md#req-30901-053
+    DoubleHit,
+    /// オーバーネット（相手コート側でボールを打った）
+    /// @spec 30901_point_judgment_spec.md#req-30901-054
+    ReachOverNet,
+    /// タッチネット（プレイヤーの体がネットに触れた）
+    /// @spec 30901_point_judgment_spec.md#req-30901-051
+    TouchNet,
+}
+
+/// ゲーム内のポイント状況（デュース／アドバンテージ）
+/// @spec 30701_point_spec.md#req-30701-050
+/// @spec 30701_point_spec.md#req-30701-051
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PointSituation {
+    /// 通常進行（どちらかが40未満）
+    #[default]
+    Regular,
+    /// デュース（40-40、アドバンテージ方式）
+    Deuce,
+    /// アドバンテージ（指定サイドが1ポイントリード）
+    Advantage(super::court::CourtSide),
+    /// ゴールデンポイント（40-40、次のポイントで決着）
+    GoldenPoint,
+    /// タイブレーク中（スーパータイブレーク含む）
+    /// @spec 30702_game_spec.md#req-30702-050
+    Tiebreak,
 }
 
 /// ポイント獲得イベント
@@ -337,6 +498,9 @@ pub struct PointScoredEvent {
     pub scorer: super::court::CourtSide,
     /// 獲得後のポイント値（表示用）
     pub new_point_value: u32,
+    /// 獲得後のポイント状況（HUD・トレースでのデュース／AD表示用）
+    /// @spec 30701_point_spec.md#req-30701-054
+    pub situation: PointSituation,
 }
 
 /// ゲーム勝利イベント
@@ -350,6 +514,9 @@ pub struct GameWonEvent {
     /// 勝利後のゲーム数
     #[allow(dead_code)]
     pub games_won: u32,
+    /// 決着ポイント直前のポイント状況（AD からの勝利、ゴールデンポイント等）
+    #[allow(dead_code)]
+    pub deciding_situation: PointSituation,
 }
 
 /// セット勝利イベント
@@ -376,6 +543,11 @@ pub struct MatchWonEvent {
     pub winner: super::court::CourtSide,
 }
 
+/// ポイント取り消し要求イベント（練習・審判による訂正用）
+/// @spec 30704_score_log_spec.md#req-30704-002
+#[derive(Event, Message, Debug, Clone, Default)]
+pub struct UndoPointEvent;
+
 /// 試合開始イベント
 /// @spec 30101_flow_spec.md#req-30101-005
 /// NOTE: デバッグ・ログ出力用のイベント。読み取りハンドラは将来実装予定。
@@ -386,6 +558,19 @@ pub struct MatchStartEvent {
     pub first_server: super::court::CourtSide,
 }
 
+/// エンドチェンジイベント
+/// @spec 30101_flow_spec.md#req-30101-051
+/// 両チームのサイドが入れ替わった（奇数ゲーム終了後・タイブレークの規定ポイントごと）
+#[derive(Event, Message, Debug, Clone)]
+pub struct ChangeEndsEvent {
+    /// 入れ替え後に両チームが開始時と逆サイドにいるか
+    pub ends_swapped: bool,
+    /// マッチ通算の完了ゲーム数
+    pub games_played: u32,
+    /// タイブレーク中のエンドチェンジか
+    pub in_tiebreak: bool,
+}
+
 /// フォールトイベント
 /// @spec 30902_fault_spec.md#req-30902-001
 /// @spec 30902_fault_spec.md#req-30902-003
@@ -408,9 +593,47 @@ pub enum FaultReason {
     /// サービスボックス外への着地
     OutOfServiceBox,
     /// ネットフォールト（サーブがネットに当たりサービスボックスに入らなかった）
-    /// NOTE: 仕様書で定義済み、将来実装予定
-    #[allow(dead_code)]
+    /// @spec 30902_fault_spec.md#req-30902-057
     NetFault,
+    /// フット・フォルト（サービスラインより前、または逆サイドの半面からサーブした）
+    /// @spec 30902_fault_spec.md#req-30902-050
+    FootFault,
+    /// トスしたボールをバウンドさせずに打った
+    /// @spec 30902_fault_spec.md#req-30902-052
+    NoBounceBeforeHit,
+    /// 腰の高さより上で打った（アンダーハンド違反）
+    /// @spec 30902_fault_spec.md#req-30902-053
+    ContactAboveWaist,
+    /// サービスボックスに入った後、レシーバーが打つ前にフェンス（金網）に当たった
+    /// @spec 30902_fault_spec.md#req-30902-054
+    FenceAfterBounce,
+}
+
+/// サーブレットイベント
+/// @spec 30902_fault_spec.md#req-30902-055
+/// サーブがネットに触れてサービスボックスに入り、打ち直し（let）になった
+#[derive(Event, Message, Debug, Clone)]
+pub struct ServeLetEvent {
+    /// サーバー側
+    pub server: super::court::CourtSide,
+    /// 着地位置
+    pub bounce_point: Vec3,
+    /// 同一サーブでの連続レット回数（このレットを含む）
+    pub consecutive_lets: u32,
+}
+
+/// サーブネットフォールトイベント
+/// @spec 30902_fault_spec.md#req-30902-057
+/// サーブがネットに触れてサービスボックス外に着地した
+#[derive(Event, Message, Debug, Clone)]
+pub struct ServeNetFaultEvent {
+    /// サーバー側
+    pub server: super::court::CourtSide,
+    /// 着地位置
+    pub bounce_point: Vec3,
+    /// このフォールト後のフォールトカウント
+    #[allow(dead_code)]
+    pub fault_count: u32,
 }
 
 /// ダブルフォールトイベント
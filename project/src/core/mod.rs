@@ -2,9 +2,21 @@
 //! @spec 20001_layers.md#layer-1-core
 
 pub mod court;
+pub mod enclosure;
+pub mod environment;
 pub mod events;
+pub mod spin;
+pub mod sweep;
+pub mod team;
 pub mod wall;
+pub mod wind;
 
 pub use court::*;
+pub use enclosure::*;
+pub use environment::*;
 pub use events::*;
+pub use spin::*;
+pub use sweep::*;
+pub use team::*;
 pub use wall::*;
+pub use wind::*;
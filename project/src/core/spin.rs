@@ -0,0 +1,130 @@
+//! スピン（角速度ベクトル）の計算
+//! @spec 30401_trajectory_spec.md#req-30401-103
+//!
+//! ## 表現
+//! スピンはワールド座標の角速度ベクトル（右手系の回転軸 × スピン量）で表す。
+//! 大きさ 1.0 を最大スピンとする正規化単位。
+//!
+//! - トップスピン: 回転軸 = Y × 進行方向（水平）。正: トップスピン、負: スライス
+//! - サイドスピン: 回転軸 = Y。正: 上から見て反時計回り（進行方向の左へ曲がる）
+
+use bevy::prelude::*;
+
+/// 進行方向に対するトップスピンの回転軸（水平、正規化済み）
+///
+/// 水平方向の移動がない場合は `None`
+#[inline]
+pub fn topspin_axis(direction: Vec3) -> Option<Vec3> {
+    Vec3::Y
+        .cross(Vec3::new(direction.x, 0.0, direction.z))
+        .try_normalize()
+}
+
+/// 打球方向とトップスピン・サイドスピン量から角速度ベクトルを生成
+/// @spec 30401_trajectory_spec.md#req-30401-103
+///
+/// # Arguments
+/// * `direction` - 打球方向
+/// * `topspin` - トップスピン量（正: トップスピン、負: スライス）
+/// * `sidespin` - サイドスピン量（正: 上から見て反時計回り）
+pub fn spin_from_shot(direction: Vec3, topspin: f32, sidespin: f32) -> Vec3 {
+    let top = topspin_axis(direction).map_or(Vec3::ZERO, |axis| axis * topspin);
+    top + Vec3::Y * sidespin
+}
+
+/// 進行方向に対するトップスピン成分（正: トップスピン、負: スライス）
+/// @spec 30401_trajectory_spec.md#req-30401-103
+///
+/// 壁で進行方向が変わると、同じ角速度でもトップスピン・スライスが入れ替わる。
+#[inline]
+pub fn topspin_component(spin: Vec3, velocity: Vec3) -> f32 {
+    topspin_axis(velocity).map_or(0.0, |axis| spin.dot(axis))
+}
+
+/// サイドスピンによるマグヌス加速度（水平方向に曲がる）
+/// @spec 30401_trajectory_spec.md#req-30401-104
+///
+/// a = factor × (ω_y·Y) × v。
+/// 縦方向のマグヌス効果は実効重力（REQ-30401-100）で扱うため、鉛直軸成分のみを用いる。
+#[inline]
+pub fn magnus_side_acceleration(spin: Vec3, velocity: Vec3, factor: f32) -> Vec3 {
+    (Vec3::Y * spin.y).cross(velocity) * factor
+}
+
+/// 接触面の摩擦によりスピンが与える速度変化の向き（接触面の接線方向）
+/// @spec 30402_reflection_spec.md#req-30402-101
+///
+/// 接触点の表面速度と逆向きに摩擦が働くため、ω × n となる。
+///
+/// # Arguments
+/// * `spin` - 角速度ベクトル
+/// * `normal` - 接触面の法線（ボール側を向く）
+#[inline]
+pub fn contact_spin_kick(spin: Vec3, normal: Vec3) -> Vec3 {
+    spin.cross(normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-5;
+
+    /// TST-30404-105: トップスピン軸は進行方向とY軸に直交
+    #[test]
+    fn test_req_30401_103_topspin_axis() {
+        // +X方向: 軸 = -Z
+        let axis = topspin_axis(Vec3::new(10.0, 3.0, 0.0)).unwrap();
+        assert!((axis - Vec3::NEG_Z).length() < EPS);
+
+        // 真上への移動は軸なし
+        assert!(topspin_axis(Vec3::Y).is_none());
+    }
+
+    /// TST-30404-105: 打球方向から生成したスピンを進行方向基準で取り出せる
+    #[test]
+    fn test_req_30401_103_spin_round_trip() {
+        let direction = Vec3::new(-8.0, 2.0, 3.0);
+        let spin = spin_from_shot(direction, 0.6, -0.4);
+
+        assert!((topspin_component(spin, direction) - 0.6).abs() < EPS);
+        assert!((spin.y - (-0.4)).abs() < EPS);
+
+        // 逆方向に進むとトップスピンはスライスになる
+        assert!((topspin_component(spin, -direction) + 0.6).abs() < EPS);
+    }
+
+    /// TST-30404-106: サイドスピン（反時計回り）は進行方向の左へ曲がる
+    #[test]
+    fn test_req_30401_104_magnus_side() {
+        let velocity = Vec3::new(20.0, 0.0, 0.0);
+
+        // +X方向に進むとき、左は -Z
+        let accel = magnus_side_acceleration(Vec3::Y * 0.5, velocity, 0.2);
+        assert!((accel - Vec3::new(0.0, 0.0, -2.0)).length() < EPS);
+
+        // トップスピン成分は横方向に曲げない
+        let topspin = spin_from_shot(velocity, 1.0, 0.0);
+        assert!(magnus_side_acceleration(topspin, velocity, 0.2).length() < EPS);
+    }
+
+    /// TST-30404-107: トップスピンは地面で前へ、後壁で上へ押し出す
+    #[test]
+    fn test_req_30402_101_contact_kick() {
+        let velocity = Vec3::new(10.0, -2.0, 0.0);
+        let topspin = spin_from_shot(velocity, 1.0, 0.0);
+
+        // 地面（法線 +Y）: 進行方向（+X）へ
+        let floor = contact_spin_kick(topspin, Vec3::Y);
+        assert!((floor - Vec3::X).length() < EPS);
+
+        // 後壁（法線 -X）: 上へ
+        let back_wall = contact_spin_kick(topspin, Vec3::NEG_X);
+        assert!((back_wall - Vec3::Y).length() < EPS);
+
+        // サイドスピンは地面では効かず、壁では横へ
+        let side = Vec3::Y;
+        assert!(contact_spin_kick(side, Vec3::Y).length() < EPS);
+        assert!((contact_spin_kick(side, Vec3::NEG_X) - Vec3::Z).length() < EPS);
+    }
+}
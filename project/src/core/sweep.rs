@@ -0,0 +1,432 @@
+//! 連続衝突判定（スイープ判定）
+//! @spec 30401_trajectory_spec.md#req-30401-052
+//!
+//! 1フレームの移動を線分（start → start + velocity * delta）として扱い、
+//! ネット面・地面・外壁（天井含む）との交差時刻と交差点を求める。
+//! 終端位置のみを判定する方式では、高速なスマッシュがネットや壁の角を
+//! すり抜けるため、交差の有無を線分で判定する。
+//!
+//! ## 交差の条件
+//! - 面に向かって移動している
+//! - 始点が面の手前（面上を含まない）、終点が面上または面の向こう側
+//!
+//! 面上から離れる方向の移動は交差としない（反射直後の再接触を防ぐ）。
+//!
+//! ## 壁セグメント（BEH-30503-009）
+//! 外壁平面との交差点に壁セグメントがあれば壁接触、なければ開口部の通過
+//! （囲いの外へ出る）とする。囲いの角の外側での交差は判定しない。
+//!
+//! 囲いの外から内側へ向かう交差も判定する。壁セグメントがあれば外側の面で壁接触とし、
+//! 囲いの外のボールが戻れるのは開口部（ドア・壁の上）からのみとする。
+
+use bevy::prelude::*;
+
+use super::{CourtEnclosure, NetInfo, NetZone};
+use crate::core::events::WallType;
+
+/// スイープ判定の接触面
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweptSurface {
+    /// ネット（通過位置のネット高さ未満でネット面を通過）と接触部位
+    Net(NetZone),
+    /// 地面
+    Floor,
+    /// 壁・天井
+    Wall(WallType),
+    /// 開口部（ドア・壁の上）を通過して囲いの外へ出る
+    Opening(WallType),
+}
+
+/// スイープ判定の接触結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweptContact {
+    /// 接触面
+    pub surface: SweptSurface,
+    /// 接触までの時間（秒、0.0〜delta）
+    pub time: f32,
+    /// 接触点
+    pub point: Vec3,
+}
+
+/// 1軸の面との交差時刻を求める
+///
+/// 始点が面の手前にあり、面に向かって移動し、delta 内に面へ到達する場合のみ Some を返す。
+#[inline]
+fn plane_crossing_time(start: f32, velocity: f32, plane: f32, delta: f32) -> Option<f32> {
+    let towards = (start < plane && velocity > 0.0) || (start > plane && velocity < 0.0);
+    if !towards {
+        return None;
+    }
+    let time = (plane - start) / velocity;
+    (time <= delta).then_some(time)
+}
+
+/// 1フレームの移動線分と、ネット・地面・壁の最初の接触を求める
+/// @spec 30401_trajectory_spec.md#req-30401-052
+///
+/// # Arguments
+/// * `start` - フレーム開始時の位置
+/// * `velocity` - 速度（フレーム内は一定とみなす）
+/// * `delta` - 移動時間（秒）
+/// * `net` - ネット情報
+/// * `enclosure` - コートの囲い（外壁平面と壁セグメント）
+///
+/// # Returns
+/// 最も早い接触。同時刻の場合はネット → 地面 → 左右壁 → 前後壁 → 天井の順で優先する
+pub fn sweep_first_contact(
+    start: Vec3,
+    velocity: Vec3,
+    delta: f32,
+    net: &NetInfo,
+    enclosure: &CourtEnclosure,
+) -> Option<SweptContact> {
+    if delta <= 0.0 || velocity.length_squared() < f32::EPSILON {
+        return None;
+    }
+
+    let walls = &enclosure.bounds;
+    // 外壁平面との交差: セグメントがあれば壁、角の内側の開口部なら囲いの外へ
+    // 内側へ向かう交差は、セグメントがあれば外側の面で壁、開口部なら囲いの内へ戻る（接触なし）
+    let wall_surface = |wall_type: WallType, time: f32, outward: bool| {
+        let point = start + velocity * time;
+        if enclosure.segment_at(wall_type, point).is_some() {
+            Some(SweptSurface::Wall(wall_type))
+        } else if outward && enclosure.within_corners(wall_type, point) {
+            Some(SweptSurface::Opening(wall_type))
+        } else {
+            None
+        }
+    };
+
+    let mut first: Option<SweptContact> = None;
+    let mut consider = |surface: SweptSurface, time: f32| {
+        if first.is_none_or(|contact| time < contact.time) {
+            first = Some(SweptContact {
+                surface,
+                time,
+                point: start + velocity * time,
+            });
+        }
+    };
+
+    // ネット: ネット面を通過する時点の高さがその位置のネット未満なら接触（囲いの幅の内側のみ）
+    if let Some(time) = plane_crossing_time(start.x, velocity.x, net.x, delta) {
+        let point = start + velocity * time;
+        if point.z >= walls.left && point.z <= walls.right {
+            if let Some(zone) = net.zone_at(point.y, point.z) {
+                consider(SweptSurface::Net(zone), time);
+            }
+        }
+    }
+
+    // 地面: 下向きに地面へ到達
+    if let Some(time) = plane_crossing_time(start.y, velocity.y, walls.ground, delta) {
+        if velocity.y < 0.0 {
+            consider(SweptSurface::Floor, time);
+        }
+    }
+
+    // 左右壁（Z方向）・前後壁（X方向）: 外側へ向かう（法線と逆向きの）移動は内側の面、
+    // 内側へ向かう移動は外側の面との交差
+    let wall_planes = [
+        (WallType::LeftWall, walls.left),
+        (WallType::RightWall, walls.right),
+        (WallType::BackWallLeft, walls.back_left),
+        (WallType::BackWallRight, walls.back_right),
+    ];
+    for (wall_type, plane) in wall_planes {
+        let outward = velocity.dot(wall_type.normal()) < 0.0;
+        let (start_axis, velocity_axis) = if wall_type.is_side_wall() {
+            (start.z, velocity.z)
+        } else {
+            (start.x, velocity.x)
+        };
+        if let Some(time) = plane_crossing_time(start_axis, velocity_axis, plane, delta) {
+            if let Some(surface) = wall_surface(wall_type, time, outward) {
+                consider(surface, time);
+            }
+        }
+    }
+
+    // 天井（囲いの内側のみ）
+    if let Some(time) = plane_crossing_time(start.y, velocity.y, walls.ceiling, delta) {
+        if velocity.y > 0.0 && enclosure.within_corners(WallType::Ceiling, start + velocity * time)
+        {
+            consider(SweptSurface::Wall(WallType::Ceiling), time);
+        }
+    }
+
+    first.map(|mut contact| {
+        // 浮動小数点誤差で面からずれないよう、接触面の座標を面上に揃える
+        match contact.surface {
+            SweptSurface::Net(_) => contact.point.x = net.x,
+            SweptSurface::Floor => contact.point.y = walls.ground,
+            SweptSurface::Wall(wall_type) | SweptSurface::Opening(wall_type) => match wall_type {
+                WallType::LeftWall => contact.point.z = walls.left,
+                WallType::RightWall => contact.point.z = walls.right,
+                WallType::BackWallLeft => contact.point.x = walls.back_left,
+                WallType::BackWallRight => contact.point.x = walls.back_right,
+                WallType::Ceiling => contact.point.y = walls.ceiling,
+            },
+        }
+        contact
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::WallMaterial;
+    use crate::core::{CourtBounds, WallSegment};
+
+    const DELTA: f32 = 1.0 / 60.0;
+
+    /// テスト用の囲い
+    /// - Z方向: left=-5, right=5
+    /// - X方向: back_left=-10, back_right=10
+    /// - Y方向: ground=0, ceiling=8
+    /// - 4面とも全区間・高さ4mの壁。ただし右壁の |X| < 1 はドア
+    fn test_walls() -> CourtEnclosure {
+        let bounds = CourtBounds::new(-5.0, 5.0, -10.0, 10.0, 0.0, 8.0);
+        let segment = |wall_type, from, to| WallSegment {
+            wall_type,
+            from,
+            to,
+            height: 4.0,
+            material: WallMaterial::Glass,
+        };
+        CourtEnclosure::new(
+            bounds,
+            vec![
+                segment(WallType::LeftWall, -10.0, 10.0),
+                segment(WallType::RightWall, -10.0, -1.0),
+                segment(WallType::RightWall, 1.0, 10.0),
+                segment(WallType::BackWallLeft, -5.0, 5.0),
+                segment(WallType::BackWallRight, -5.0, 5.0),
+            ],
+        )
+    }
+
+    fn test_net() -> NetInfo {
+        NetInfo::new(0.0, 1.0)
+    }
+
+    /// TST-30404-052: 高速ボールがネット面をすり抜けない
+    /// @spec 30401_trajectory_spec.md#req-30401-052
+    #[test]
+    fn test_req_30401_052_net_tunneling() {
+        // 60m/s: 1フレームで1m進む（従来の許容範囲 ±0.3 を飛び越える）
+        let start = Vec3::new(-0.5, 0.5, 1.0);
+        let velocity = Vec3::new(60.0, 0.0, 0.0);
+
+        let contact = sweep_first_contact(start, velocity, DELTA, &test_net(), &test_walls())
+            .expect("should hit net");
+
+        assert_eq!(contact.surface, SweptSurface::Net(NetZone::Body));
+        assert!((contact.time - 0.5 / 60.0).abs() < 1e-6);
+        assert_eq!(contact.point.x, 0.0);
+        assert!((contact.point.y - 0.5).abs() < 1e-6);
+        assert!((contact.point.z - 1.0).abs() < 1e-6);
+    }
+
+    /// ネット高さ以上で通過する場合は接触しない
+    #[test]
+    fn test_req_30401_052_pass_over_net() {
+        let start = Vec3::new(-0.5, 1.5, 0.0);
+        let velocity = Vec3::new(60.0, 0.0, 0.0);
+
+        let contact = sweep_first_contact(start, velocity, DELTA, &test_net(), &test_walls());
+
+        assert!(contact.is_none());
+    }
+
+    /// TST-30504-021: たるんだネットの白帯・ポストへの接触
+    #[test]
+    fn test_beh_30503_005_net_cord_and_post() {
+        let net = NetInfo {
+            post_height: 1.2,
+            post_z: 5.0,
+            post_width: 0.1,
+            cord_band: 0.05,
+            ..test_net()
+        };
+        let velocity = Vec3::new(60.0, 0.0, 0.0);
+        let sweep = |start: Vec3| {
+            sweep_first_contact(start, velocity, DELTA, &net, &test_walls())
+                .map(|contact| contact.surface)
+        };
+
+        // 中央では越える高さでも、ポスト寄りでは白帯に当たる
+        assert_eq!(sweep(Vec3::new(-0.5, 1.1, 0.0)), None);
+        assert_eq!(
+            sweep(Vec3::new(-0.5, 1.1, 4.0)),
+            Some(SweptSurface::Net(NetZone::Cord))
+        );
+        assert_eq!(
+            sweep(Vec3::new(-0.5, 0.98, 0.0)),
+            Some(SweptSurface::Net(NetZone::Cord))
+        );
+        // ポスト
+        assert_eq!(
+            sweep(Vec3::new(-0.5, 1.1, -4.95)),
+            Some(SweptSurface::Net(NetZone::Post))
+        );
+    }
+
+    /// 地面への接触点はフレーム途中の正確な位置
+    #[test]
+    fn test_req_30401_052_floor_contact_point() {
+        let start = Vec3::new(2.0, 0.2, 0.0);
+        let velocity = Vec3::new(30.0, -24.0, 6.0);
+
+        let contact = sweep_first_contact(start, velocity, DELTA, &test_net(), &test_walls())
+            .expect("should hit floor");
+
+        assert_eq!(contact.surface, SweptSurface::Floor);
+        let expected_time = 0.2 / 24.0;
+        assert!((contact.time - expected_time).abs() < 1e-6);
+        assert_eq!(contact.point.y, 0.0);
+        assert!((contact.point.x - (2.0 + 30.0 * expected_time)).abs() < 1e-5);
+        assert!((contact.point.z - 6.0 * expected_time).abs() < 1e-5);
+    }
+
+    /// 壁の角では先に到達する壁を返す
+    #[test]
+    fn test_req_30401_052_corner_order() {
+        // Z方向は残り0.1m、X方向は残り0.3m → 右壁が先
+        let start = Vec3::new(9.7, 2.0, 4.9);
+        let velocity = Vec3::new(30.0, 0.0, 30.0);
+
+        let contact = sweep_first_contact(start, velocity, DELTA, &test_net(), &test_walls())
+            .expect("should hit wall");
+
+        assert_eq!(contact.surface, SweptSurface::Wall(WallType::RightWall));
+        assert_eq!(contact.point.z, 5.0);
+        assert!((contact.point.x - 9.8).abs() < 1e-5);
+
+        // 反射後（Z反転）の残り移動で後壁に接触
+        let remaining = DELTA - contact.time;
+        let reflected = Vec3::new(velocity.x, velocity.y, -velocity.z);
+        let next = sweep_first_contact(
+            contact.point,
+            reflected,
+            remaining,
+            &test_net(),
+            &test_walls(),
+        )
+        .expect("should hit back wall");
+
+        assert_eq!(next.surface, SweptSurface::Wall(WallType::BackWallRight));
+        assert_eq!(next.point.x, 10.0);
+        assert!(contact.time + next.time <= DELTA);
+    }
+
+    /// 面上から離れる方向の移動は接触しない（反射直後の再接触防止）
+    #[test]
+    fn test_req_30401_052_leaving_surface() {
+        let walls = test_walls();
+
+        let on_wall = Vec3::new(0.0, 2.0, 5.0);
+        let away = Vec3::new(0.0, 0.0, -30.0);
+        assert!(sweep_first_contact(on_wall, away, DELTA, &test_net(), &walls).is_none());
+
+        let on_net = Vec3::new(0.0, 0.5, 0.0);
+        let back = Vec3::new(-10.0, 0.0, 0.0);
+        assert!(sweep_first_contact(on_net, back, DELTA, &test_net(), &walls).is_none());
+    }
+
+    /// 到達しない場合・静止時は接触しない
+    #[test]
+    fn test_req_30401_052_no_contact() {
+        let walls = test_walls();
+        let start = Vec3::new(3.0, 2.0, 0.0);
+
+        let slow = Vec3::new(1.0, -1.0, 1.0);
+        assert!(sweep_first_contact(start, slow, DELTA, &test_net(), &walls).is_none());
+        assert!(sweep_first_contact(start, Vec3::ZERO, DELTA, &test_net(), &walls).is_none());
+    }
+
+    /// TST-30504-019: ドアを通過するボールは囲いの外へ出る
+    #[test]
+    fn test_beh_30503_009_exit_through_door() {
+        let start = Vec3::new(0.5, 1.0, 4.8);
+        let velocity = Vec3::new(0.0, 0.0, 30.0);
+
+        let contact = sweep_first_contact(start, velocity, DELTA, &test_net(), &test_walls())
+            .expect("should pass the door");
+
+        assert_eq!(contact.surface, SweptSurface::Opening(WallType::RightWall));
+        assert_eq!(contact.point.z, 5.0);
+
+        // 囲いの外ではネット・壁の延長に接触しない
+        let outside = Vec3::new(-0.5, 0.5, 6.0);
+        let across = Vec3::new(60.0, 0.0, 0.0);
+        assert!(sweep_first_contact(outside, across, DELTA, &test_net(), &test_walls()).is_none());
+
+        // ドアからは囲いの内へ戻れる（接触なし）
+        let back_in = Vec3::new(0.5, 1.0, 5.2);
+        let inward = Vec3::new(0.0, 0.0, -30.0);
+        assert!(sweep_first_contact(back_in, inward, DELTA, &test_net(), &test_walls()).is_none());
+    }
+
+    /// TST-30504-019: 囲いの外から内側へ打たれたボールはガラスの外側の面で跳ね返る
+    #[test]
+    fn test_beh_30503_009_inward_hits_glass() {
+        let inward = Vec3::new(0.0, 0.0, -30.0);
+
+        let glass = sweep_first_contact(
+            Vec3::new(5.0, 2.0, 5.3),
+            inward,
+            DELTA,
+            &test_net(),
+            &test_walls(),
+        )
+        .expect("should hit the outer face of the glass");
+        assert_eq!(glass.surface, SweptSurface::Wall(WallType::RightWall));
+        assert_eq!(glass.point.z, 5.0);
+        assert!((glass.time - 0.3 / 30.0).abs() < 1e-6);
+
+        // 壁の高さより上からは囲いの内へ戻れる
+        let over = sweep_first_contact(
+            Vec3::new(5.0, 5.0, 5.3),
+            inward,
+            DELTA,
+            &test_net(),
+            &test_walls(),
+        );
+        assert!(over.is_none());
+
+        // 外側の面から離れる移動は接触しない
+        let on_outer_face = Vec3::new(5.0, 2.0, 5.0);
+        let away = Vec3::new(0.0, 0.0, 30.0);
+        assert!(
+            sweep_first_contact(on_outer_face, away, DELTA, &test_net(), &test_walls()).is_none()
+        );
+    }
+
+    /// TST-30504-019: 壁の高さより上を越えるボールは囲いの外へ出る
+    #[test]
+    fn test_beh_30503_009_exit_over_wall() {
+        let velocity = Vec3::new(30.0, 0.0, 0.0);
+
+        let over = sweep_first_contact(
+            Vec3::new(9.8, 5.0, 0.0),
+            velocity,
+            DELTA,
+            &test_net(),
+            &test_walls(),
+        )
+        .expect("should pass over the wall");
+        assert_eq!(over.surface, SweptSurface::Opening(WallType::BackWallRight));
+
+        let below = sweep_first_contact(
+            Vec3::new(9.8, 3.0, 0.0),
+            velocity,
+            DELTA,
+            &test_net(),
+            &test_walls(),
+        )
+        .expect("should hit the wall");
+        assert_eq!(below.surface, SweptSurface::Wall(WallType::BackWallRight));
+    }
+}
@@ -0,0 +1,115 @@
+//! チーム（ダブルス）定義
+//! @spec 30105_doubles_spec.md
+//!
+//! ## プレイヤーID割り当て (REQ-30105-001)
+//! - 奇数ID（1, 3）: Left側チーム
+//! - 偶数ID（2, 4）: Right側チーム
+//! - チーム内スロット: (id - 1) / 2（0 = ドライブ、1 = レベス）
+//!
+//! シングルスでは各チーム1人（ID 1, 2）となり、従来の割り当てと一致する。
+
+use super::court::CourtSide;
+
+/// ダブルスの担当サイド（レシーブ時のポジション）
+/// @spec 30105_doubles_spec.md#req-30105-003
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum CourtLane {
+    /// ドライブ（フォア側、デュースサイドでレシーブ）
+    Drive,
+    /// レベス（バック側、アドサイドでレシーブ）
+    Reves,
+}
+
+impl CourtLane {
+    /// チーム内スロットから担当サイドを返す
+    #[inline]
+    pub fn from_slot(slot: usize) -> Self {
+        if slot == 0 {
+            CourtLane::Drive
+        } else {
+            CourtLane::Reves
+        }
+    }
+
+    /// 担当サイドのチーム内スロット
+    #[inline]
+    pub fn slot(&self) -> usize {
+        match self {
+            CourtLane::Drive => 0,
+            CourtLane::Reves => 1,
+        }
+    }
+
+    /// 担当サイドのZ方向符号
+    /// 両チームはネット越しに向かい合うため、同じ担当サイドでも符号は逆になる
+    /// Left側: ドライブ = +Z、レベス = -Z
+    /// Right側: ドライブ = -Z、レベス = +Z
+    #[inline]
+    pub fn z_sign(&self, side: CourtSide) -> f32 {
+        match (side, self) {
+            (CourtSide::Left, CourtLane::Drive) | (CourtSide::Right, CourtLane::Reves) => 1.0,
+            (CourtSide::Left, CourtLane::Reves) | (CourtSide::Right, CourtLane::Drive) => -1.0,
+        }
+    }
+}
+
+/// チームとスロットからプレイヤーIDを返す
+/// @spec 30105_doubles_spec.md#req-30105-001
+#[inline]
+pub fn team_player_id(side: CourtSide, slot: usize) -> u8 {
+    let base = match side {
+        CourtSide::Left => 1,
+        CourtSide::Right => 2,
+    };
+    base + 2 * slot as u8
+}
+
+/// プレイヤーIDから所属チーム（コート側）を返す
+/// @spec 30105_doubles_spec.md#req-30105-001
+#[inline]
+pub fn player_team_side(player_id: u8) -> CourtSide {
+    if player_id % 2 == 1 {
+        CourtSide::Left
+    } else {
+        CourtSide::Right
+    }
+}
+
+/// プレイヤーIDからチーム内スロットを返す
+/// @spec 30105_doubles_spec.md#req-30105-001
+#[inline]
+pub fn player_team_slot(player_id: u8) -> usize {
+    (player_id.saturating_sub(1) / 2) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TST-30106-001: プレイヤーIDとチームの対応
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    #[test]
+    fn test_req_30105_001_player_id_mapping() {
+        assert_eq!(team_player_id(CourtSide::Left, 0), 1);
+        assert_eq!(team_player_id(CourtSide::Right, 0), 2);
+        assert_eq!(team_player_id(CourtSide::Left, 1), 3);
+        assert_eq!(team_player_id(CourtSide::Right, 1), 4);
+
+        for id in 1..=4 {
+            let side = player_team_side(id);
+            let slot = player_team_slot(id);
+            assert_eq!(team_player_id(side, slot), id);
+        }
+    }
+
+    /// TST-30106-003: 担当サイドのZ方向
+    /// @spec 30105_doubles_spec.md#req-30105-003
+    #[test]
+    fn test_req_30105_003_lane_z_sign() {
+        // 向かい合うドライブ同士は対角線上（Z符号が逆）
+        assert_eq!(CourtLane::Drive.z_sign(CourtSide::Left), 1.0);
+        assert_eq!(CourtLane::Drive.z_sign(CourtSide::Right), -1.0);
+        assert_eq!(CourtLane::Reves.z_sign(CourtSide::Left), -1.0);
+        assert_eq!(CourtLane::Reves.z_sign(CourtSide::Right), 1.0);
+    }
+}
@@ -12,12 +12,59 @@
 //!
 //! ## 優先順位 (BEH-30503-007)
 //! 左右壁（Z） → 前後壁（X） → 天井（Y）
+//!
+//! ## 材質 (REQ-30402-102)
+//! ガラスと金網で反発係数・摩擦・ランダム偏向が異なる
+//!
+//! ## スピン (REQ-30402-103)
+//! 壁に対するトップスピンで跳ね返りと接線方向の速度が変わり、接触でスピン自体も反転・減衰する
 
 use bevy::prelude::*;
 
-use super::CourtBounds;
+use super::{contact_spin_kick, CourtBounds};
 use crate::core::events::WallType;
 
+/// 壁面の反射特性
+/// @spec 30402_reflection_spec.md#req-30402-102
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallSurface {
+    /// 反発係数（法線方向の速度維持率）
+    pub restitution: f32,
+    /// 摩擦（接線方向の速度減少率、0.0〜1.0）
+    pub friction: f32,
+}
+
+/// 壁接触時のスピン伝達パラメータ
+/// @spec 30402_reflection_spec.md#req-30402-103
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallSpinTransfer {
+    /// スピン軸が与える接線方向の速度変化（m/s、スピン1.0あたり）
+    pub tangential_factor: f32,
+    /// 壁に対するトップスピンによる法線方向（跳ね返り）の変動割合
+    pub normal_factor: f32,
+    /// 壁の接線軸まわりのスピンが反転する割合（0.0: 維持、0.5: 消失、1.0: 完全反転）
+    pub reversal: f32,
+}
+
+/// 壁接触のスピン伝達結果
+#[derive(Debug, Clone, Copy)]
+pub struct WallSpinResult {
+    /// 接触後の速度
+    pub velocity: Vec3,
+    /// 接触後の角速度
+    pub spin: Vec3,
+}
+
+/// 壁面の接線方向（水平, 垂直）。天井は（X, Z）
+#[inline]
+fn wall_tangents(wall_type: WallType) -> (Vec3, Vec3) {
+    match wall_type {
+        WallType::LeftWall | WallType::RightWall => (Vec3::X, Vec3::Y),
+        WallType::BackWallLeft | WallType::BackWallRight => (Vec3::Z, Vec3::Y),
+        WallType::Ceiling => (Vec3::X, Vec3::Z),
+    }
+}
+
 /// 壁反射計算結果
 #[derive(Debug, Clone)]
 pub struct WallReflectionResult {
@@ -25,7 +72,10 @@ pub struct WallReflectionResult {
     pub wall_type: WallType,
     /// 接触点
     pub contact_point: Vec3,
-    /// 反射後の速度
+    /// 反射後の速度（反射係数のみ適用）
+    /// ball_wall_reflection_system は材質・スピンを考慮して wall_bounce で計算し直すため、
+    /// メインバイナリでは未使用（テスト・ライブラリ利用側で参照）
+    #[allow(dead_code)]
     pub reflected_velocity: Vec3,
 }
 
@@ -98,7 +148,6 @@ impl WallReflection {
     ///
     /// # Returns
     /// 反射後の速度
-    #[allow(dead_code)] // テストで使用、将来の壁反射統合で使用予定
     pub fn reflect(wall_type: WallType, velocity: Vec3, bounce_factor: f32) -> Vec3 {
         match wall_type {
             WallType::LeftWall | WallType::RightWall => {
@@ -111,6 +160,83 @@ impl WallReflection {
         }
     }
 
+    /// 壁面の材質を考慮した反射計算
+    /// @spec 30402_reflection_spec.md#req-30402-102
+    ///
+    /// 法線方向は反発係数で反転・減衰し、接線方向は摩擦で減衰する。
+    /// その後、反射方向を壁の接線方向（水平・垂直）へ偏向角だけ傾ける（速さは維持）。
+    ///
+    /// # Arguments
+    /// * `wall_type` - 壁の種類
+    /// * `velocity` - 入射速度
+    /// * `surface` - 壁面の反射特性
+    /// * `deflection` - 偏向角（ラジアン、x: 水平方向, y: 垂直方向）
+    ///
+    /// # Returns
+    /// 反射後の速度（壁から離れる向きは常に維持）
+    pub fn reflect_surface(
+        wall_type: WallType,
+        velocity: Vec3,
+        surface: WallSurface,
+        deflection: Vec2,
+    ) -> Vec3 {
+        let normal = wall_type.normal();
+        let reflected = Self::reflect(wall_type, velocity, surface.restitution);
+        let normal_speed = reflected.dot(normal);
+        let tangential = reflected - normal * normal_speed;
+        let base = normal * normal_speed + tangential * (1.0 - surface.friction).clamp(0.0, 1.0);
+
+        if deflection == Vec2::ZERO || normal_speed <= 0.0 {
+            return base;
+        }
+
+        // 法線方向の成分を保ったまま傾けるため、壁の内側へ向かうことはない
+        let (horizontal, vertical) = wall_tangents(wall_type);
+        let tilted =
+            base + (horizontal * deflection.x.tan() + vertical * deflection.y.tan()) * normal_speed;
+        tilted.normalize_or_zero() * base.length()
+    }
+
+    /// 壁接触時のスピン伝達
+    /// @spec 30402_reflection_spec.md#req-30402-103
+    ///
+    /// 地面バウンドのスピン変化（REQ-30402-100）に相当する壁での変化:
+    /// - 壁に対するトップスピン（壁を上る向きの回転）で跳ね返りが弱まり、スライスで強まる
+    ///   `normal *= 1 - climb × normal_factor`
+    /// - 接触面の摩擦で接線方向に `(ω × n) × tangential_factor` の速度変化（トップスピンは上る、スライスは沈む）
+    /// - 壁の接線軸まわりのスピンは `1 - 2 × reversal` 倍になる（法線軸まわりは維持）
+    ///
+    /// # Arguments
+    /// * `wall_type` - 壁の種類
+    /// * `reflected` - 材質による反射後の速度（reflect_surface の結果）
+    /// * `spin` - 接触前の角速度
+    /// * `transfer` - スピン伝達パラメータ
+    pub fn apply_spin(
+        wall_type: WallType,
+        reflected: Vec3,
+        spin: Vec3,
+        transfer: WallSpinTransfer,
+    ) -> WallSpinResult {
+        let normal = wall_type.normal();
+        let kick = contact_spin_kick(spin, normal);
+        // 壁を上る向きの速度変化 = 壁に対するトップスピン量（天井では 0）
+        let climb = kick.y;
+
+        let normal_speed = reflected.dot(normal);
+        let tangential = reflected - normal * normal_speed;
+        let normal_speed = normal_speed * (1.0 - climb * transfer.normal_factor).max(0.0);
+        let velocity = tangential + normal * normal_speed + kick * transfer.tangential_factor;
+
+        let spin_normal = normal * spin.dot(normal);
+        let spin_tangential = spin - spin_normal;
+        let reversal = transfer.reversal.clamp(0.0, 1.0);
+
+        WallSpinResult {
+            velocity,
+            spin: spin_normal + spin_tangential * (1.0 - 2.0 * reversal),
+        }
+    }
+
     /// 位置が壁に接触しているかチェックし、接触していれば反射結果を返す
     /// @spec 30503_boundary_behavior.md#beh-30503-007
     ///
@@ -380,4 +506,169 @@ mod tests {
         // 天井: -Y方向の法線
         assert_eq!(WallType::Ceiling.normal(), Vec3::NEG_Y);
     }
+
+    /// TST-30404-108: 反発係数は法線方向、摩擦は接線方向に効く
+    #[test]
+    fn test_req_30402_102_surface_restitution_friction() {
+        let velocity = Vec3::new(10.0, 4.0, 6.0);
+        let surface = WallSurface {
+            restitution: 0.5,
+            friction: 0.25,
+        };
+
+        let reflected =
+            WallReflection::reflect_surface(WallType::RightWall, velocity, surface, Vec2::ZERO);
+
+        assert!((reflected - Vec3::new(7.5, 3.0, -3.0)).length() < 1e-5);
+    }
+
+    /// TST-30404-108: 偏向は速さを保ち、壁から離れる向きを維持する
+    #[test]
+    fn test_req_30402_102_surface_deflection() {
+        let velocity = Vec3::new(12.0, 0.0, 0.0);
+        let surface = WallSurface {
+            restitution: 0.5,
+            friction: 0.0,
+        };
+
+        let straight =
+            WallReflection::reflect_surface(WallType::BackWallRight, velocity, surface, Vec2::ZERO);
+        let deflected = WallReflection::reflect_surface(
+            WallType::BackWallRight,
+            velocity,
+            surface,
+            Vec2::new(0.3, -0.2),
+        );
+
+        assert!((deflected.length() - straight.length()).abs() < 1e-4);
+        assert!(deflected.x < 0.0);
+        // 水平偏向は +Z、垂直偏向は -Y へ
+        assert!(deflected.z > 0.0);
+        assert!(deflected.y < 0.0);
+        let angle = straight.angle_between(Vec3::new(deflected.x, 0.0, deflected.z));
+        assert!((angle - 0.3).abs() < 0.05);
+    }
+
+    /// TST-30404-109: 壁接触時のスピン伝達（テーブル駆動）
+    #[test]
+    fn test_req_30402_103_wall_spin_transfer() {
+        let transfer = WallSpinTransfer {
+            tangential_factor: 2.0,
+            normal_factor: 0.5,
+            reversal: 0.25,
+        };
+        // 後壁（+X側、法線 -X）に当たって跳ね返った速度
+        let off_back_wall = Vec3::new(-10.0, 0.0, 0.0);
+
+        // (説明, 壁, 反射後の速度, 接触前のスピン, 期待する速度, 期待するスピン)
+        let cases = [
+            (
+                "スピンなし: 変化なし",
+                WallType::BackWallRight,
+                off_back_wall,
+                Vec3::ZERO,
+                off_back_wall,
+                Vec3::ZERO,
+            ),
+            (
+                "トップスピン: 上る・跳ね返りが弱まる・スピン半減",
+                WallType::BackWallRight,
+                off_back_wall,
+                Vec3::NEG_Z,
+                Vec3::new(-5.0, 2.0, 0.0),
+                Vec3::NEG_Z * 0.5,
+            ),
+            (
+                "スライス: 沈む・跳ね返りが強まる",
+                WallType::BackWallRight,
+                off_back_wall,
+                Vec3::Z,
+                Vec3::new(-15.0, -2.0, 0.0),
+                Vec3::Z * 0.5,
+            ),
+            (
+                "サイドスピン: 横へ跳ねる・法線軸まわりではないので反転対象",
+                WallType::BackWallRight,
+                off_back_wall,
+                Vec3::Y,
+                Vec3::new(-10.0, 0.0, 2.0),
+                Vec3::Y * 0.5,
+            ),
+            (
+                "法線軸まわりのスピン: 速度・スピンとも維持",
+                WallType::BackWallRight,
+                off_back_wall,
+                Vec3::X,
+                off_back_wall,
+                Vec3::X,
+            ),
+            (
+                "右壁（法線 -Z）で上る向きのスピン",
+                WallType::RightWall,
+                Vec3::new(6.0, 0.0, -8.0),
+                Vec3::X,
+                Vec3::new(6.0, 2.0, -4.0),
+                Vec3::X * 0.5,
+            ),
+            (
+                "天井: 上る向きがなく跳ね返りは変わらない（接線方向の変化のみ）",
+                WallType::Ceiling,
+                Vec3::new(5.0, -4.0, 0.0),
+                Vec3::Z,
+                Vec3::new(7.0, -4.0, 0.0),
+                Vec3::Z * 0.5,
+            ),
+        ];
+
+        for (name, wall_type, reflected, spin, expected_velocity, expected_spin) in cases {
+            let result = WallReflection::apply_spin(wall_type, reflected, spin, transfer);
+            assert!(
+                (result.velocity - expected_velocity).length() < 1e-5,
+                "{name}: velocity {:?} != {:?}",
+                result.velocity,
+                expected_velocity
+            );
+            assert!(
+                (result.spin - expected_spin).length() < 1e-5,
+                "{name}: spin {:?} != {:?}",
+                result.spin,
+                expected_spin
+            );
+        }
+    }
+
+    /// TST-30404-109: 反転割合によるスピンの変化（テーブル駆動）
+    #[test]
+    fn test_req_30402_103_spin_reversal() {
+        let topspin = Vec3::NEG_Z;
+
+        // (反転割合, 接触後のスピン)
+        let cases = [
+            (0.0, topspin),
+            (0.5, Vec3::ZERO),
+            (1.0, -topspin),
+            // 範囲外はクランプ
+            (1.5, -topspin),
+        ];
+
+        for (reversal, expected) in cases {
+            let transfer = WallSpinTransfer {
+                tangential_factor: 0.0,
+                normal_factor: 0.0,
+                reversal,
+            };
+            let result = WallReflection::apply_spin(
+                WallType::BackWallRight,
+                Vec3::new(-10.0, 0.0, 0.0),
+                topspin,
+                transfer,
+            );
+            assert!(
+                (result.spin - expected).length() < 1e-5,
+                "reversal {reversal}: {:?} != {:?}",
+                result.spin,
+                expected
+            );
+        }
+    }
 }
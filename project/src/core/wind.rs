@@ -0,0 +1,83 @@
+//! 風（屋外コート）の計算
+//! @spec 30401_trajectory_spec.md#req-30401-051
+//!
+//! 風速は基準高さでの値で表し、高さによる強さの変化（べき乗則）を掛けて使う。
+//! 風はボールに一定の加速度（風速 × 係数）として働く。
+
+use bevy::prelude::*;
+
+/// 高さによる風の強さの倍率（べき乗則）
+/// @spec 30401_trajectory_spec.md#req-30401-051
+///
+/// scale = (max(height, min_height) / reference_height) ^ exponent
+/// 地表付近ほど弱く、基準高さで 1.0、それより上では強くなる。
+pub fn wind_height_scale(
+    height: f32,
+    reference_height: f32,
+    exponent: f32,
+    min_height: f32,
+) -> f32 {
+    if reference_height <= 0.0 {
+        return 1.0;
+    }
+    let height = height.max(min_height).max(0.001);
+    (height / reference_height).powf(exponent)
+}
+
+/// 突風成分を目標値へ近づける（一次遅れ）
+/// @spec 30401_trajectory_spec.md#req-30401-051
+///
+/// gust += (target - gust) × min(delta / smoothing, 1)
+#[inline]
+pub fn advance_gust(gust: Vec3, target: Vec3, delta: f32, smoothing: f32) -> Vec3 {
+    let t = if smoothing > 0.0 {
+        (delta / smoothing).min(1.0)
+    } else {
+        1.0
+    };
+    gust + (target - gust) * t
+}
+
+/// 一定の風加速度による飛行中の水平方向のズレ
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+///
+/// drift = 0.5 × a × t²（鉛直成分は扱わない）
+#[inline]
+pub fn wind_drift(acceleration: Vec3, flight_time: f32) -> Vec3 {
+    Vec3::new(acceleration.x, 0.0, acceleration.z) * 0.5 * flight_time * flight_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 基準高さで 1.0、低いほど弱く高いほど強い
+    #[test]
+    fn test_wind_height_scale() {
+        assert!((wind_height_scale(2.0, 2.0, 0.2, 0.3) - 1.0).abs() < 1e-6);
+        assert!(wind_height_scale(0.5, 2.0, 0.2, 0.3) < 1.0);
+        assert!(wind_height_scale(6.0, 2.0, 0.2, 0.3) > 1.0);
+        // 最低高さより下は最低高さの値
+        assert_eq!(
+            wind_height_scale(0.0, 2.0, 0.2, 0.3),
+            wind_height_scale(0.3, 2.0, 0.2, 0.3)
+        );
+    }
+
+    /// 突風は目標値へ滑らかに近づき、行き過ぎない
+    #[test]
+    fn test_advance_gust() {
+        let target = Vec3::new(2.0, 0.0, -1.0);
+        let half = advance_gust(Vec3::ZERO, target, 0.5, 1.0);
+        assert!((half - target * 0.5).length() < 1e-6);
+        assert_eq!(advance_gust(Vec3::ZERO, target, 2.0, 1.0), target);
+        assert_eq!(advance_gust(Vec3::ZERO, target, 0.1, 0.0), target);
+    }
+
+    /// ズレは時間の2乗に比例し、水平成分のみ
+    #[test]
+    fn test_wind_drift() {
+        let drift = wind_drift(Vec3::new(2.0, 5.0, -1.0), 2.0);
+        assert_eq!(drift, Vec3::new(4.0, 0.0, -2.0));
+    }
+}
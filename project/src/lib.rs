@@ -27,5 +27,5 @@ pub use resource::config::{load_game_config, GameConfig, GameConfigHandle, GameC
 pub use resource::MatchFlowState;
 pub use systems::{
     AiServePlugin, BallCollisionPlugin, BallTrajectoryPlugin, BoundaryPlugin, FaultJudgmentPlugin,
-    GameSystemSet, MatchFlowPlugin, PointJudgmentPlugin, ScoringPlugin,
+    FixedTickPlugin, GameSystemSet, MatchFlowPlugin, PointJudgmentPlugin, ScoringPlugin,
 };
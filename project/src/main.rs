@@ -14,34 +14,36 @@ use bevy::{asset::AssetPlugin, prelude::*};
 use character::CharacterPlugin;
 use components::HumanControlled;
 use core::{
-    BallHitEvent, FaultEvent, GroundBounceEvent, PlayerJumpEvent, PlayerKnockbackEvent,
-    PlayerLandEvent, PlayerMoveEvent, PointScoredEvent, RacketContactEvent, RallyEndEvent,
-    ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent, SwingIntentEvent,
+    BallHitEvent, BallLeftCourtEvent, FaultEvent, GroundBounceEvent, PlayerJumpEvent,
+    PlayerKnockbackEvent, PlayerLandEvent, PlayerMoveEvent, PointScoredEvent, RacketContactEvent,
+    RallyEndEvent, ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent, SwingIntentEvent,
     WallReflectionEvent,
 };
 use presentation::{
     ball_spin_color_system, despawn_ball_shadow_system, player_hold_visual_system,
-    save_player_original_color_system, spawn_ball_shadow_system, spawn_player_shadow_system,
-    sync_shadow_system, sync_transform_system, DebugUiPlugin, WORLD_SCALE,
+    save_player_original_color_system, spawn_ball_shadow_system, spawn_court_walls,
+    spawn_player_shadow_system, sync_shadow_system, sync_transform_system, DebugUiPlugin,
+    ResultsScreenPlugin, WORLD_SCALE,
 };
 use resource::config::{load_game_config, GameConfig, GameConfigHandle, GameConfigLoader};
 use resource::debug::LastShotDebugInfo;
 use resource::MatchFlowState;
 use resource::{
-    compose_effective_config, load_runtime_overrides, DebugRuntimeOverrides, FixedDeltaTime,
-    GameRng, DEBUG_RUNTIME_CONFIG_PATH,
+    compose_effective_config, load_runtime_overrides, DebugRuntimeOverrides, GameRng,
+    DEBUG_RUNTIME_CONFIG_PATH,
 };
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 use systems::{
-    ai_movement_system, ai_shot_system, ceiling_collision_system, debug_marker_system,
-    gamepad_input_system, gravity_system, human_input_system, jump_system,
+    ai_movement_system, ai_shot_system, ceiling_collision_system, clear_input_edges_system,
+    debug_marker_system, gamepad_input_system, gravity_system, human_input_system, jump_system,
     knockback_movement_system, knockback_start_system, knockback_timer_system, landing_system,
     movement_system, plan_racket_swing_system, shot_cooldown_system, shot_direction_system,
-    shot_input_system, update_racket_swing_system, vertical_movement_system, AiServePlugin,
-    BallCollisionPlugin, BallTrajectoryPlugin, BoundaryPlugin, FaultJudgmentPlugin, GameSystemSet,
-    MatchFlowPlugin, PointJudgmentPlugin, ScoringPlugin,
+    shot_input_system, undo_point_input_system, update_racket_swing_system,
+    vertical_movement_system, AiServePlugin, BallCollisionPlugin, BallTrajectoryPlugin,
+    BoundaryPlugin, FaultJudgmentPlugin, FixedTickPlugin, GameSystemSet, MatchFlowPlugin,
+    PointJudgmentPlugin, ScoringPlugin,
 };
 
 #[derive(Resource, Clone)]
@@ -89,6 +91,8 @@ fn main() {
     add_default_plugins(&mut app);
 
     // ゲームプラグイン追加
+    // @spec 77100_headless_sim.md#req-77100-001: ゲームロジックは固定ティックで実行
+    app.add_plugins(FixedTickPlugin::realtime(base_config.physics.tick_rate));
     add_game_plugins(&mut app);
 
     // リソース・メッセージ初期化
@@ -102,13 +106,10 @@ fn main() {
 
     // システム登録
     app.add_systems(Startup, (setup, load_config_asset));
-    app.configure_sets(
-        Update,
-        GameSystemSet::Input.before(GameSystemSet::GameLogic),
-    );
     app.add_systems(Update, escape_to_exit);
     add_input_systems(&mut app);
     add_game_logic_systems(&mut app);
+    add_presentation_systems(&mut app);
 
     app.run();
 }
@@ -153,7 +154,6 @@ fn add_resources(
         .insert_resource(StartupEnvOverrides(startup_env_overrides))
         .insert_resource(RuntimeOverrides(runtime_overrides))
         .insert_resource(RuntimeOverridesWatcher::new(DEBUG_RUNTIME_CONFIG_PATH))
-        .init_resource::<FixedDeltaTime>()
         .init_resource::<GameRng>()
         .init_resource::<LastShotDebugInfo>();
 }
@@ -170,6 +170,8 @@ fn add_game_plugins(app: &mut App) {
         // @spec 30102_serve_spec.md#req-30102-070: AI自動サーブ
         .add_plugins(AiServePlugin)
         .add_plugins(DebugUiPlugin)
+        // @spec 30704_score_log_spec.md#req-30704-003: 試合結果画面
+        .add_plugins(ResultsScreenPlugin)
         .add_plugins(CharacterPlugin)
         // @spec 77103_replay_spec.md: リプレイ記録機能
         .add_plugins(replay::ReplayRecordPlugin);
@@ -190,12 +192,17 @@ fn add_messages(app: &mut App) {
         .add_message::<ShotAttributesCalculatedEvent>()
         .add_message::<GroundBounceEvent>()
         .add_message::<WallReflectionEvent>()
+        .add_message::<BallLeftCourtEvent>()
         .add_message::<RallyEndEvent>()
         .add_message::<PointScoredEvent>()
         .add_message::<FaultEvent>();
 }
 
-/// 入力システムを追加（Input セット）
+/// 入力システムを追加
+/// @spec 77100_headless_sim.md#req-77100-001
+///
+/// デバイス入力は描画フレームごとに読み取り、押下エッジ（jump_pressed / shot_pressed）は
+/// 次のティックで消費されるまで保持する。消費後のクリアはティックの最後に行う。
 fn add_input_systems(app: &mut App) {
     app.add_systems(
         Update,
@@ -206,21 +213,23 @@ fn add_input_systems(app: &mut App) {
             // ゲームパッド入力読み取り（device_id=1 の HumanControlled）
             // @spec 20006_input_system.md#req-20006-050
             gamepad_input_system,
+            // ポイント取り消し（練習・審判による訂正用）
+            // @spec 30704_score_log_spec.md#req-30704-002
+            undo_point_input_system,
         )
-            .chain()
-            .in_set(GameSystemSet::Input),
+            .chain(),
+    );
+    app.add_systems(
+        FixedUpdate,
+        clear_input_edges_system.after(GameSystemSet::GameLogic),
     );
 }
 
-/// ゲームロジックシステムを追加（GameLogic セット）
+/// ゲームロジックシステムを追加（GameLogic セット、固定ティック）
 fn add_game_logic_systems(app: &mut App) {
     app.add_systems(
-        Update,
+        FixedUpdate,
         (
-            // 設定ホットリロード
-            update_config_on_change,
-            // 実行中デバッグ上書き設定の反映
-            update_debug_runtime_overrides,
             // ふっとばし開始（BallHitEvent を処理）
             knockback_start_system,
             // ジャンプ・重力
@@ -243,7 +252,23 @@ fn add_game_logic_systems(app: &mut App) {
             (knockback_movement_system, knockback_timer_system),
             // 境界チェック
             (ceiling_collision_system, landing_system),
-            // 座標変換（論理座標→表示座標）
+        )
+            .chain()
+            .in_set(GameSystemSet::GameLogic),
+    );
+}
+
+/// 表示・設定反映システムを追加（描画フレームごと）
+fn add_presentation_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            // 設定ホットリロード
+            update_config_on_change,
+            // 実行中デバッグ上書き設定の反映
+            update_debug_runtime_overrides,
+            // 座標変換（論理座標→表示座標、ティック間を補間）
+            // @spec 77100_headless_sim.md#req-77100-002
             sync_transform_system,
             // 影システム
             (
@@ -262,8 +287,7 @@ fn add_game_logic_systems(app: &mut App) {
             // デバッグマーカー（Xボタンで弾道情報をログ出力）
             debug_marker_system,
         )
-            .chain()
-            .in_set(GameSystemSet::GameLogic),
+            .chain(),
     );
 }
 
@@ -288,12 +312,8 @@ fn setup(mut commands: Commands, config: Res<GameConfig>) {
     // 論理座標系: X=打ち合い方向, Y=高さ, Z=コート幅
     // @spec 20006_input_system.md
     // @spec 31001_parts_spec.md - パーツ分離キャラクター
-    let player1_pos = Vec3::new(
-        config.player.x_min + 1.0, // 1Pコート側（-X方向）
-        0.0,                       // 地面
-        0.0,                       // コート中央
-    );
-    let (r, g, b) = config.player_visual.player1_color;
+    let player1_pos = systems::initial_player_position(1, &config);
+    let (r, g, b) = config.player_visual.color_for(1);
     let player1_color = Color::srgb(r, g, b);
     let player1_entity =
         character::spawn_articulated_player(&mut commands, 1, player1_pos, player1_color);
@@ -302,26 +322,22 @@ fn setup(mut commands: Commands, config: Res<GameConfig>) {
         .insert(HumanControlled::default());
     info!("Player 1 (Human/Articulated) spawned at {:?}", player1_pos);
 
-    // Player 2 をスポーン（2Pコート側: 画面右側）- AI操作
+    // Player 2 以降をスポーン（2Pコート側: 画面右側、ダブルスでは味方パートナーも）- AI操作
     // @spec 30301_ai_movement_spec.md
+    // @spec 30105_doubles_spec.md#req-30105-001
     // @spec 31001_parts_spec.md - パーツ分離キャラクター
-    let player2_pos = Vec3::new(
-        config.player.x_max - 1.0, // 2Pコート側（+X方向）
-        0.0,                       // 地面
-        0.0,                       // コート中央
-    );
-    let (r, g, b) = config.player_visual.player2_color;
-    let player2_color = Color::srgb(r, g, b);
-    let player2_entity =
-        character::spawn_articulated_player(&mut commands, 2, player2_pos, player2_color);
-    commands
-        .entity(player2_entity)
-        .insert(components::AiController {
-            home_position: player2_pos,
-            target_position: player2_pos,
+    for player_id in 2..=config.team.total_players() as u8 {
+        let position = systems::initial_player_position(player_id, &config);
+        let (r, g, b) = config.player_visual.color_for(player_id);
+        let color = Color::srgb(r, g, b);
+        let entity = character::spawn_articulated_player(&mut commands, player_id, position, color);
+        commands.entity(entity).insert(components::AiController {
+            home_position: position,
+            target_position: position,
             ..Default::default()
         });
-    info!("Player 2 (AI) spawned at {:?}", player2_pos);
+        info!("Player {} (AI) spawned at {:?}", player_id, position);
+    }
 }
 
 /// スプライト（矩形）を生成するヘルパー
@@ -398,7 +414,8 @@ fn spawn_court(commands: &mut Commands, config: &GameConfig) {
         0.0,
         white,
     ); // 下
-       // サービスライン（縦線）
+
+    // サービスライン（縦線）
     spawn_rect(
         commands,
         line,
@@ -417,10 +434,15 @@ fn spawn_court(commands: &mut Commands, config: &GameConfig) {
         0.0,
         white,
     ); // 2P側
-       // センターサービスライン（ネットからサービスラインまでの中央線）
+
+    // センターサービスライン（ネットからサービスラインまでの中央線）
     spawn_rect(commands, service_x, line, -service_x / 2.0, 0.0, 0.0, white); // 1P側
     spawn_rect(commands, service_x, line, service_x / 2.0, 0.0, 0.0, white); // 2P側
 
+    // 壁セグメント（ガラス・金網、ドアは開口部）
+    // @spec 30503_boundary_behavior.md#beh-30503-009
+    spawn_court_walls(commands, config);
+
     info!(
         "Court spawned: {}x{} pixels (直接マッピング)",
         court_display_width, court_display_height
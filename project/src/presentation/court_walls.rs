@@ -0,0 +1,82 @@
+//! コートの壁描画
+//! @spec 30503_boundary_behavior.md#beh-30503-009
+//!
+//! CourtConfig の壁セグメントを上から見た帯として描画する。
+//! 帯は外壁平面の外側に置き、太さで壁の高さ、色で材質を表す。
+//! セグメントのない区間（ドア）は描画しない。
+
+use bevy::prelude::*;
+
+use super::WORLD_SCALE;
+use crate::core::events::{WallMaterial, WallType};
+use crate::resource::config::GameConfig;
+use crate::systems::create_court_enclosure;
+
+/// 壁の高さ1mあたりの帯の太さ（ピクセル）
+const WALL_THICKNESS_PER_METER: f32 = 3.0;
+
+/// 壁の描画レイヤー（コート背景より手前、ライン・キャラクターより奥）
+const WALL_Z_LAYER: f32 = -0.9;
+
+/// 材質ごとの描画色
+fn wall_color(material: WallMaterial) -> Color {
+    match material {
+        WallMaterial::Glass => Color::srgba(0.6, 0.85, 1.0, 0.8),
+        WallMaterial::Mesh => Color::srgb(0.55, 0.55, 0.55),
+    }
+}
+
+/// 壁セグメントを描画
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// 表示座標: 論理X → 画面X、論理Z → 画面Y
+pub fn spawn_court_walls(commands: &mut Commands, config: &GameConfig) {
+    let enclosure = create_court_enclosure(&config.court);
+    let bounds = &enclosure.bounds;
+
+    for segment in &enclosure.segments {
+        let thickness = segment.height * WALL_THICKNESS_PER_METER;
+        let length = (segment.to - segment.from) * WORLD_SCALE;
+        let center = (segment.from + segment.to) / 2.0 * WORLD_SCALE;
+
+        // (幅, 高さ, 画面X, 画面Y)。帯は外壁平面の外側に接する
+        let (width, height, x, y) = match segment.wall_type {
+            WallType::LeftWall => (
+                length,
+                thickness,
+                center,
+                bounds.left * WORLD_SCALE - thickness / 2.0,
+            ),
+            WallType::RightWall => (
+                length,
+                thickness,
+                center,
+                bounds.right * WORLD_SCALE + thickness / 2.0,
+            ),
+            WallType::BackWallLeft => (
+                thickness,
+                length,
+                bounds.back_left * WORLD_SCALE - thickness / 2.0,
+                center,
+            ),
+            WallType::BackWallRight => (
+                thickness,
+                length,
+                bounds.back_right * WORLD_SCALE + thickness / 2.0,
+                center,
+            ),
+            WallType::Ceiling => continue,
+        };
+
+        commands.spawn((
+            Sprite {
+                color: wall_color(segment.material),
+                custom_size: Some(Vec2::new(width, height)),
+                ..default()
+            },
+            Transform::from_xyz(x, y, WALL_Z_LAYER),
+        ));
+    }
+
+    info!("Court walls spawned: {} segments", enclosure.segments.len());
+}
@@ -4,9 +4,9 @@
 use bevy::prelude::*;
 
 use crate::components::{Ball, BounceCount, KnockbackState, Player, ShotState};
-use crate::core::CourtSide;
+use crate::core::{CourtSide, SweptSurface};
 use crate::presentation::WORLD_SCALE;
-use crate::resource::{GameConfig, MatchScore, RallyPhase, RallyState};
+use crate::resource::{BallPrediction, GameConfig, MatchScore, RallyPhase, RallyState, ScoreLog};
 
 /// デバッグUIプラグイン
 pub struct DebugUiPlugin;
@@ -19,6 +19,7 @@ impl Plugin for DebugUiPlugin {
                 update_debug_ui,
                 draw_hit_range_gizmos,
                 draw_racket_swing_gizmos,
+                draw_ball_prediction_gizmos,
             ),
         );
     }
@@ -50,25 +51,35 @@ fn setup_debug_ui(mut commands: Commands) {
 
 /// スコア表示テキストを生成
 fn format_score_text(match_score: &MatchScore, point_values: &[u32]) -> String {
-    let p1_point = match_score.get_point_display(CourtSide::Left, point_values);
-    let p2_point = match_score.get_point_display(CourtSide::Right, point_values);
-    let p1_score = match_score.get_score(CourtSide::Left);
-    let p2_score = match_score.get_score(CourtSide::Right);
-    format!(
+    // エンドチェンジ後もチーム（開始時のサイド）順で表示
+    let p1 = match_score.side_of_team(CourtSide::Left);
+    let p2 = match_score.side_of_team(CourtSide::Right);
+    let p1_point = match_score.get_point_display(p1, point_values);
+    let p2_point = match_score.get_point_display(p2, point_values);
+    let p1_score = match_score.get_score(p1);
+    let p2_score = match_score.get_score(p2);
+    let mut text = format!(
         "Score: {} - {} (G: {}-{}, S: {}-{})",
         p1_point, p2_point, p1_score.games, p2_score.games, p1_score.sets, p2_score.sets,
-    )
+    );
+    if match_score.is_tiebreak() {
+        text.push_str(" TIEBREAK");
+    }
+    if !match_score.set_history.is_empty() {
+        text.push_str(&format!(" [{}]", match_score.set_history_display()));
+    }
+    text
 }
 
 /// フェーズ情報テキストを生成
-fn format_phase_info(rally_state: &RallyState) -> String {
+fn format_phase_info(rally_state: &RallyState, match_score: &MatchScore) -> String {
     let phase = match rally_state.phase {
         RallyPhase::WaitingServe => "WAITING SERVE",
         RallyPhase::Serving => "SERVING",
         RallyPhase::Rally => "RALLY",
         RallyPhase::PointEnded => "POINT END",
     };
-    let server = match rally_state.server {
+    let server = match match_score.team_of(rally_state.server) {
         CourtSide::Left => "P1",
         CourtSide::Right => "P2",
     };
@@ -79,9 +90,12 @@ fn format_phase_info(rally_state: &RallyState) -> String {
 }
 
 /// バウンス情報テキストを生成
-fn format_bounce_info(ball_query: &Query<&BounceCount, With<Ball>>) -> String {
+fn format_bounce_info(
+    ball_query: &Query<&BounceCount, With<Ball>>,
+    match_score: &MatchScore,
+) -> String {
     if let Some(bounce) = ball_query.iter().next() {
-        let side = match bounce.last_court_side {
+        let side = match bounce.last_court_side.map(|side| match_score.team_of(side)) {
             Some(CourtSide::Left) => "P1",
             Some(CourtSide::Right) => "P2",
             None => "-",
@@ -92,6 +106,15 @@ fn format_bounce_info(ball_query: &Query<&BounceCount, With<Ball>>) -> String {
     }
 }
 
+/// 直前のポイント情報テキストを生成
+/// @spec 30704_score_log_spec.md#req-30704-003
+fn format_last_point(score_log: &ScoreLog) -> String {
+    match score_log.last() {
+        Some(record) => format!("Last: {}", record.display()),
+        None => "Last: -".to_string(),
+    }
+}
+
 /// プレイヤー状態テキストを生成
 fn format_player_states(player_query: &Query<(&Player, &KnockbackState, &ShotState)>) -> String {
     let mut states = Vec::new();
@@ -120,6 +143,7 @@ fn format_player_states(player_query: &Query<(&Player, &KnockbackState, &ShotSta
 fn update_debug_ui(
     match_score: Res<MatchScore>,
     rally_state: Res<RallyState>,
+    score_log: Res<ScoreLog>,
     config: Res<GameConfig>,
     ball_query: Query<&BounceCount, With<Ball>>,
     player_query: Query<(&Player, &KnockbackState, &ShotState)>,
@@ -130,13 +154,14 @@ fn update_debug_ui(
     };
 
     let score_text = format_score_text(&match_score, &config.scoring.point_values);
-    let phase_info = format_phase_info(&rally_state);
-    let bounce_info = format_bounce_info(&ball_query);
+    let phase_info = format_phase_info(&rally_state, &match_score);
+    let bounce_info = format_bounce_info(&ball_query, &match_score);
+    let last_point = format_last_point(&score_log);
     let player_states = format_player_states(&player_query);
 
     **text = format!(
-        "{}\n{}\n{}\n{}",
-        score_text, phase_info, bounce_info, player_states
+        "{}\n{}\n{}\n{}\n{}",
+        score_text, phase_info, bounce_info, last_point, player_states
     );
 }
 
@@ -153,7 +178,7 @@ fn draw_hit_range_gizmos(
 
     // 各プレイヤーの当たり判定範囲を描画
     for (player, transform) in player_query.iter() {
-        let color = if player.id == 1 {
+        let color = if player.court_side == CourtSide::Left {
             Color::srgba(0.0, 1.0, 1.0, 0.5) // シアン（半透明）
         } else {
             Color::srgba(1.0, 0.5, 0.0, 0.5) // オレンジ（半透明）
@@ -187,7 +212,7 @@ fn draw_racket_swing_gizmos(mut gizmos: Gizmos, player_query: Query<(&Player, &S
             continue;
         }
 
-        let color = if player.id == 1 {
+        let color = if player.court_side == CourtSide::Left {
             Color::srgba(1.0, 0.2, 0.2, 0.9)
         } else {
             Color::srgba(0.2, 0.8, 1.0, 0.9)
@@ -207,6 +232,39 @@ fn draw_racket_swing_gizmos(mut gizmos: Gizmos, player_query: Query<(&Player, &S
     }
 }
 
+/// ボールの予測軌道を描画
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// 折れ線と接触点（地面: 白、壁: 水色、ネット: 赤、開口部: 黄）を表示する。
+fn draw_ball_prediction_gizmos(mut gizmos: Gizmos, prediction: Option<Res<BallPrediction>>) {
+    let Some(path) = prediction
+        .as_ref()
+        .and_then(|prediction| prediction.path.as_ref())
+    else {
+        return;
+    };
+
+    gizmos.linestrip_2d(
+        path.points
+            .iter()
+            .map(|point| logical_to_display_2d(point.position)),
+        Color::srgba(1.0, 1.0, 0.0, 0.4),
+    );
+    for contact in &path.contacts {
+        let color = match contact.surface {
+            SweptSurface::Floor => Color::srgba(1.0, 1.0, 1.0, 0.8),
+            SweptSurface::Wall(_) => Color::srgba(0.3, 0.9, 1.0, 0.8),
+            SweptSurface::Net(_) => Color::srgba(1.0, 0.3, 0.3, 0.8),
+            SweptSurface::Opening(_) => Color::srgba(1.0, 0.8, 0.0, 0.8),
+        };
+        gizmos.circle_2d(
+            Isometry2d::from_translation(logical_to_display_2d(contact.point)),
+            3.0,
+            color,
+        );
+    }
+}
+
 #[inline]
 fn logical_to_display_2d(pos: Vec3) -> Vec2 {
     Vec2::new(pos.x * WORLD_SCALE, (pos.z + pos.y) * WORLD_SCALE)
@@ -1,17 +1,23 @@
 //! Presentation層: Bevy Sprite、Transform、UI
 //! @spec 20001_layers.md#layer-5-presentation
 
+mod court_walls;
 mod debug_ui;
+mod results_screen;
 mod visual_feedback;
 
+pub use court_walls::spawn_court_walls;
 pub use debug_ui::DebugUiPlugin;
+pub use results_screen::ResultsScreenPlugin;
 pub use visual_feedback::{
     ball_spin_color_system, player_hold_visual_system, save_player_original_color_system,
 };
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, HasShadow, LogicalPosition, Player, Shadow};
+use crate::components::{
+    Ball, HasShadow, LogicalPosition, Player, PreviousLogicalPosition, Shadow,
+};
 use crate::resource::config::GameConfig;
 
 /// ワールド座標→ピクセル座標変換用スケール
@@ -23,40 +29,69 @@ pub const WORLD_SCALE: f32 = 50.0;
 /// 論理座標系: X=打ち合い方向, Y=高さ（ジャンプ）, Z=コート幅（奥行き）
 /// 表示座標系: X=打ち合い方向（左右）, Y=コート幅+高さ（上下）, Z=レイヤー深度
 /// @spec REQ-30801-005
-pub fn sync_transform_system(mut query: Query<(&LogicalPosition, &mut Transform)>) {
-    for (logical_pos, mut transform) in query.iter_mut() {
+/// @spec 77100_headless_sim.md#req-77100-002
+///
+/// ゲームロジックは固定ティックで進むため、直前のティックと現在のティックの
+/// 論理座標を、次のティックまでの経過割合（overstep）で補間して表示する。
+pub fn sync_transform_system(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(
+        &LogicalPosition,
+        Option<&PreviousLogicalPosition>,
+        &mut Transform,
+    )>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (logical_pos, previous, mut transform) in query.iter_mut() {
+        let pos = interpolated_position(logical_pos, previous, alpha);
         // 論理座標を表示座標に直接マッピング（回転不要）
         // X: 論理X（打ち合い方向）→ 画面左右
         // Y: 論理Z（コート幅）+ 論理Y（高さ）→ 画面上下
         // Z: レイヤー深度
-        let display_x = logical_pos.value.x * WORLD_SCALE;
-        let display_y = logical_pos.value.z * WORLD_SCALE + logical_pos.value.y * WORLD_SCALE;
-        let display_z = 1.0 - logical_pos.value.z * 0.01; // 奥行きでレイヤー調整
+        let display_x = pos.x * WORLD_SCALE;
+        let display_y = pos.z * WORLD_SCALE + pos.y * WORLD_SCALE;
+        let display_z = 1.0 - pos.z * 0.01; // 奥行きでレイヤー調整
 
         transform.translation = Vec3::new(display_x, display_y, display_z);
     }
 }
 
+/// 表示用の論理座標（直前のティックがなければ現在の座標）
+#[inline]
+fn interpolated_position(
+    current: &LogicalPosition,
+    previous: Option<&PreviousLogicalPosition>,
+    alpha: f32,
+) -> Vec3 {
+    previous.map_or(current.value, |previous| {
+        previous.interpolate(current.value, alpha)
+    })
+}
+
 /// 影の位置を更新するシステム
 /// 影は所有者の足元（地面）に表示される
 /// @spec REQ-30801-003
+/// @spec 77100_headless_sim.md#req-77100-002
 pub fn sync_shadow_system(
     config: Res<GameConfig>,
-    owner_query: Query<&LogicalPosition>,
+    fixed_time: Res<Time<Fixed>>,
+    owner_query: Query<(&LogicalPosition, Option<&PreviousLogicalPosition>)>,
     player_query: Query<Entity, With<Player>>,
     mut shadow_query: Query<(&Shadow, &mut Transform)>,
 ) {
+    let alpha = fixed_time.overstep_fraction();
     for (shadow, mut transform) in shadow_query.iter_mut() {
-        if let Ok(owner_pos) = owner_query.get(shadow.owner) {
+        if let Ok((logical_pos, previous)) = owner_query.get(shadow.owner) {
+            let owner_pos = interpolated_position(logical_pos, previous, alpha);
             // 影は地面（Y=0）に表示（直接マッピング）
-            let display_x = owner_pos.value.x * WORLD_SCALE;
+            let display_x = owner_pos.x * WORLD_SCALE;
             // プレイヤーの影は足元にオフセット、ボールの影はオフセットなし
             let y_offset = if player_query.get(shadow.owner).is_ok() {
                 config.shadow.player_y_offset
             } else {
                 config.shadow.ball_y_offset
             };
-            let display_y = owner_pos.value.z * WORLD_SCALE - y_offset;
+            let display_y = owner_pos.z * WORLD_SCALE - y_offset;
             // 影は背面に表示
             let display_z = config.shadow.z_layer;
 
@@ -0,0 +1,130 @@
+//! 試合結果画面
+//! @spec 30704_score_log_spec.md#req-30704-003
+
+use bevy::prelude::*;
+
+use crate::core::CourtSide;
+use crate::resource::{team_label, GameState, MatchFlowState, MatchScore, ScoreLog};
+
+/// 結果画面に表示する直近のポイント数
+const RESULTS_RECENT_POINTS: usize = 20;
+
+/// 試合結果画面プラグイン
+pub struct ResultsScreenPlugin;
+
+impl Plugin for ResultsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MatchFlowState::MatchEnd), spawn_results_screen)
+            .add_systems(OnExit(MatchFlowState::MatchEnd), despawn_results_screen);
+    }
+}
+
+/// 結果画面マーカー
+#[derive(Component)]
+pub struct ResultsScreen;
+
+/// 結果画面を表示
+/// @spec 30704_score_log_spec.md#req-30704-003
+fn spawn_results_screen(
+    mut commands: Commands,
+    match_score: Res<MatchScore>,
+    score_log: Res<ScoreLog>,
+) {
+    commands
+        .spawn((
+            ResultsScreen,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format_results(&match_score, &score_log)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// 結果画面を閉じる（取り消しで試合が再開した場合など）
+fn despawn_results_screen(mut commands: Commands, query: Query<Entity, With<ResultsScreen>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// 結果画面のテキストを生成
+/// @spec 30704_score_log_spec.md#req-30704-003
+///
+/// 勝者・セットスコア・チームごとの獲得ポイント数と、直近のポイント履歴を表示する。
+fn format_results(match_score: &MatchScore, score_log: &ScoreLog) -> String {
+    let mut lines = Vec::new();
+    if let GameState::MatchWon(winner) = match_score.game_state {
+        lines.push(format!(
+            "{} wins the match",
+            team_label(match_score.team_of(winner))
+        ));
+    }
+    lines.push(match_score.set_history_display());
+    lines.push(format!(
+        "Points won: {} {} - {} {}",
+        team_label(CourtSide::Left),
+        score_log.points_won(CourtSide::Left),
+        score_log.points_won(CourtSide::Right),
+        team_label(CourtSide::Right),
+    ));
+    lines.push(String::new());
+
+    let shown = score_log.points.len().min(RESULTS_RECENT_POINTS);
+    lines.push(format!(
+        "Last {} of {} points:",
+        shown,
+        score_log.points.len()
+    ));
+    lines.extend(score_log.recent_display(RESULTS_RECENT_POINTS));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RallyEndReason;
+    use crate::resource::config::ServeSide;
+
+    /// TST-30708-004: 結果画面に勝者・セットスコア・獲得ポイント数・ポイント履歴を表示する
+    /// @spec 30704_score_log_spec.md#req-30704-003
+    #[test]
+    fn test_req_30704_003_results_text() {
+        let mut match_score = MatchScore::new();
+        let mut score_log = ScoreLog::new();
+        for scorer in [CourtSide::Left, CourtSide::Right, CourtSide::Left] {
+            let before = match_score.clone();
+            match_score.add_point(scorer);
+            score_log.record_point(
+                &before,
+                &match_score,
+                scorer,
+                ServeSide::Deuce,
+                RallyEndReason::Out,
+            );
+        }
+        match_score.game_state = GameState::MatchWon(CourtSide::Left);
+
+        let text = format_results(&match_score, &score_log);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "P1 wins the match");
+        assert_eq!(lines[2], "Points won: P1 2 - 1 P2");
+        assert_eq!(lines[4], "Last 3 of 3 points:");
+        assert_eq!(lines[5..].len(), 3);
+        assert!(lines[7].starts_with("#3 P1 won"));
+    }
+}
@@ -3,7 +3,7 @@
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, BallSpin, InputState, Player};
+use crate::components::{Ball, BallSpin, InputState, Player, Velocity};
 use crate::resource::config::GameConfig;
 
 /// プレイヤーの元の色を保存するコンポーネント
@@ -73,12 +73,12 @@ pub fn save_player_original_color_system(
 /// @spec 30802_visual_feedback_spec.md#req-30802-005
 pub fn ball_spin_color_system(
     config: Res<GameConfig>,
-    mut query: Query<(&BallSpin, &mut Sprite), With<Ball>>,
+    mut query: Query<(&BallSpin, &Velocity, &mut Sprite), With<Ball>>,
 ) {
-    for (ball_spin, mut sprite) in query.iter_mut() {
-        // スピン値に応じた色を計算
+    for (ball_spin, velocity, mut sprite) in query.iter_mut() {
+        // 進行方向に対するトップスピン量に応じた色を計算
         // @spec 30802_visual_feedback_spec.md#req-30802-003
-        let spin = ball_spin.value;
+        let spin = ball_spin.topspin(velocity.value);
 
         let topspin_color = Color::srgba(
             config.visual_feedback.ball_color_topspin.0,
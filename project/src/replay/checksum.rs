@@ -0,0 +1,407 @@
+//! Replay State Checksum
+//! @spec 77103_replay_spec.md#req-77103-010
+//! @spec 77103_replay_spec.md#req-77103-011
+//!
+//! リプレイの決定性検証用に、ティック終了時のゲーム状態をハッシュ化する。
+//! - 記録時: チェックサム（毎フレームまたは N フレームごと）と、
+//!   フィールド比較用のキーフレーム（状態の値そのもの）を保存
+//! - 再生時: 同じフレームのチェックサムを比較し、最初にずれたフレームを報告
+
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::{Ball, BallSpin, LogicalPosition, Player, Velocity};
+use crate::core::CourtSide;
+use crate::resource::{GameRng, MatchScore};
+
+/// FNV-1a（64bit）のオフセット基底
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a（64bit）の素数
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// ボールの状態
+/// @spec 77103_replay_spec.md#req-77103-010
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BallSnapshot {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub spin: Vec3,
+}
+
+/// プレイヤーの状態
+/// @spec 77103_replay_spec.md#req-77103-010
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub id: u8,
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+/// スコアの状態（[Left側, Right側]）
+/// @spec 77103_replay_spec.md#req-77103-010
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSnapshot {
+    pub points: [u32; 2],
+    pub games: [u32; 2],
+    pub sets: [u32; 2],
+    pub server: CourtSide,
+}
+
+impl ScoreSnapshot {
+    /// MatchScore から変換
+    pub fn from_match_score(score: &MatchScore) -> Self {
+        let sides = [CourtSide::Left, CourtSide::Right];
+        Self {
+            points: sides.map(|side| score.get_point_index(side) as u32),
+            games: sides.map(|side| score.get_score(side).games),
+            sets: sides.map(|side| score.get_score(side).sets),
+            server: score.server,
+        }
+    }
+}
+
+/// ティック終了時のゲーム状態
+/// @spec 77103_replay_spec.md#req-77103-010
+///
+/// チェックサムの対象: ボール、全プレイヤー（ID順）、スコア、乱数の消費回数
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// ボール（存在しない場合は None）
+    pub ball: Option<BallSnapshot>,
+    /// プレイヤー（ID順）
+    pub players: Vec<PlayerSnapshot>,
+    /// スコア
+    pub score: ScoreSnapshot,
+    /// GameRng の消費回数
+    pub rng_draws: u64,
+}
+
+impl StateSnapshot {
+    /// 現在の状態を取得（プレイヤーはID順に並べ替える）
+    pub fn capture(
+        ball: Option<BallSnapshot>,
+        players: impl IntoIterator<Item = PlayerSnapshot>,
+        score: &MatchScore,
+        rng_draws: u64,
+    ) -> Self {
+        let mut players: Vec<PlayerSnapshot> = players.into_iter().collect();
+        players.sort_by_key(|player| player.id);
+        Self {
+            ball,
+            players,
+            score: ScoreSnapshot::from_match_score(score),
+            rng_draws,
+        }
+    }
+
+    /// 状態のチェックサム（FNV-1a 64bit、浮動小数点はビット列で比較）
+    /// @spec 77103_replay_spec.md#req-77103-010
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = StateHasher::new();
+        match &self.ball {
+            Some(ball) => {
+                hasher.write_u8(1);
+                hasher.write_vec3(ball.position);
+                hasher.write_vec3(ball.velocity);
+                hasher.write_vec3(ball.spin);
+            }
+            None => hasher.write_u8(0),
+        }
+        hasher.write_u32(self.players.len() as u32);
+        for player in &self.players {
+            hasher.write_u8(player.id);
+            hasher.write_vec3(player.position);
+            hasher.write_vec3(player.velocity);
+        }
+        for side in 0..2 {
+            hasher.write_u32(self.score.points[side]);
+            hasher.write_u32(self.score.games[side]);
+            hasher.write_u32(self.score.sets[side]);
+        }
+        hasher.write_u8(self.score.server as u8);
+        hasher.write_u64(self.rng_draws);
+        hasher.finish()
+    }
+
+    /// 記録された状態（self）と再生中の状態の差分をフィールド単位で列挙
+    /// @spec 77103_replay_spec.md#req-77103-011
+    pub fn diff(&self, actual: &StateSnapshot) -> Vec<FieldDiff> {
+        let mut diffs = Vec::new();
+
+        match (&self.ball, &actual.ball) {
+            (Some(recorded), Some(actual)) => {
+                push_diff(
+                    &mut diffs,
+                    "ball.position",
+                    recorded.position,
+                    actual.position,
+                );
+                push_diff(
+                    &mut diffs,
+                    "ball.velocity",
+                    recorded.velocity,
+                    actual.velocity,
+                );
+                push_diff(&mut diffs, "ball.spin", recorded.spin, actual.spin);
+            }
+            (recorded, actual) => push_diff(
+                &mut diffs,
+                "ball",
+                recorded.map(|b| b.position),
+                actual.map(|b| b.position),
+            ),
+        }
+
+        push_diff(
+            &mut diffs,
+            "players.len",
+            self.players.len(),
+            actual.players.len(),
+        );
+        for (recorded, actual) in self.players.iter().zip(&actual.players) {
+            let prefix = format!("players[{}]", recorded.id);
+            push_diff(&mut diffs, &format!("{prefix}.id"), recorded.id, actual.id);
+            push_diff(
+                &mut diffs,
+                &format!("{prefix}.position"),
+                recorded.position,
+                actual.position,
+            );
+            push_diff(
+                &mut diffs,
+                &format!("{prefix}.velocity"),
+                recorded.velocity,
+                actual.velocity,
+            );
+        }
+
+        push_diff(
+            &mut diffs,
+            "score.points",
+            self.score.points,
+            actual.score.points,
+        );
+        push_diff(
+            &mut diffs,
+            "score.games",
+            self.score.games,
+            actual.score.games,
+        );
+        push_diff(&mut diffs, "score.sets", self.score.sets, actual.score.sets);
+        push_diff(
+            &mut diffs,
+            "score.server",
+            self.score.server,
+            actual.score.server,
+        );
+        push_diff(&mut diffs, "rng_draws", self.rng_draws, actual.rng_draws);
+
+        diffs
+    }
+}
+
+/// ボールの状態取得用クエリ
+pub type BallStateQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static LogicalPosition,
+        &'static Velocity,
+        Option<&'static BallSpin>,
+    ),
+    With<Ball>,
+>;
+
+/// プレイヤーの状態取得用クエリ
+pub type PlayerStateQuery<'w, 's> =
+    Query<'w, 's, (&'static Player, &'static LogicalPosition, &'static Velocity)>;
+
+/// ECS から現在の状態を取得
+/// @spec 77103_replay_spec.md#req-77103-010
+pub fn capture_state(
+    balls: &BallStateQuery,
+    players: &PlayerStateQuery,
+    score: &MatchScore,
+    rng: &GameRng,
+) -> StateSnapshot {
+    let ball = balls
+        .iter()
+        .next()
+        .map(|(position, velocity, spin)| BallSnapshot {
+            position: position.value,
+            velocity: velocity.value,
+            spin: spin.map_or(Vec3::ZERO, |spin| spin.angular_velocity),
+        });
+    let players = players
+        .iter()
+        .map(|(player, position, velocity)| PlayerSnapshot {
+            id: player.id,
+            position: position.value,
+            velocity: velocity.value,
+        });
+    StateSnapshot::capture(ball, players, score, rng.draw_count())
+}
+
+/// 値が異なる場合に差分を追加
+fn push_diff<T: PartialEq + fmt::Debug>(
+    diffs: &mut Vec<FieldDiff>,
+    field: &str,
+    recorded: T,
+    actual: T,
+) {
+    if recorded != actual {
+        diffs.push(FieldDiff {
+            field: field.to_string(),
+            recorded: format!("{:?}", recorded),
+            actual: format!("{:?}", actual),
+        });
+    }
+}
+
+/// フィールド単位の差分
+/// @spec 77103_replay_spec.md#req-77103-011
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    /// フィールド名（例: "ball.position"）
+    pub field: String,
+    /// 記録時の値
+    pub recorded: String,
+    /// 再生時の値
+    pub actual: String,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: recorded={} actual={}",
+            self.field, self.recorded, self.actual
+        )
+    }
+}
+
+/// 1フレーム分のチェックサム
+/// @spec 77103_replay_spec.md#req-77103-010
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateChecksum {
+    /// フレーム番号
+    pub frame: u32,
+    /// 状態のチェックサム
+    pub hash: u64,
+}
+
+/// フィールド比較用のキーフレーム（状態の値そのもの）
+/// @spec 77103_replay_spec.md#req-77103-011
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateKeyframe {
+    /// フレーム番号
+    pub frame: u32,
+    /// 状態
+    pub snapshot: StateSnapshot,
+}
+
+/// 状態ハッシュ計算（FNV-1a 64bit）
+///
+/// std の DefaultHasher は実行ごと・バージョンごとの一致が保証されないため使わない。
+struct StateHasher(u64);
+
+impl StateHasher {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    fn write_vec3(&mut self, value: Vec3) {
+        for component in value.to_array() {
+            self.write_u32(component.to_bits());
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> StateSnapshot {
+        StateSnapshot::capture(
+            Some(BallSnapshot {
+                position: Vec3::new(1.0, 2.0, 3.0),
+                velocity: Vec3::new(-4.0, 0.5, 0.0),
+                spin: Vec3::ZERO,
+            }),
+            [
+                PlayerSnapshot {
+                    id: 2,
+                    position: Vec3::new(7.0, 0.0, 1.0),
+                    velocity: Vec3::ZERO,
+                },
+                PlayerSnapshot {
+                    id: 1,
+                    position: Vec3::new(-7.0, 0.0, -1.0),
+                    velocity: Vec3::X,
+                },
+            ],
+            &MatchScore::default(),
+            12,
+        )
+    }
+
+    /// TST-77103-010: 同じ状態は同じチェックサム、異なる状態は異なるチェックサム
+    /// @spec 77103_replay_spec.md#req-77103-010
+    #[test]
+    fn test_req_77103_010_checksum_is_stable() {
+        let recorded = snapshot();
+        assert_eq!(recorded.players[0].id, 1, "players are sorted by id");
+        assert_eq!(recorded.checksum(), snapshot().checksum());
+
+        let mut drifted = snapshot();
+        drifted.ball.as_mut().unwrap().position.x += f32::EPSILON;
+        assert_ne!(recorded.checksum(), drifted.checksum());
+
+        let mut drifted = snapshot();
+        drifted.rng_draws += 1;
+        assert_ne!(recorded.checksum(), drifted.checksum());
+    }
+
+    /// TST-77103-011: 差分はずれたフィールドだけを列挙する
+    /// @spec 77103_replay_spec.md#req-77103-011
+    #[test]
+    fn test_req_77103_011_field_level_diff() {
+        let recorded = snapshot();
+        assert!(recorded.diff(&snapshot()).is_empty());
+
+        let mut actual = snapshot();
+        actual.players[1].velocity = Vec3::Z;
+        actual.rng_draws = 13;
+        let fields: Vec<String> = recorded
+            .diff(&actual)
+            .into_iter()
+            .map(|diff| diff.field)
+            .collect();
+        assert_eq!(fields, vec!["players[2].velocity", "rng_draws"]);
+    }
+}
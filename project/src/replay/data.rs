@@ -7,7 +7,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::checksum::{StateChecksum, StateKeyframe, StateSnapshot};
 use crate::core::CourtSide;
+use crate::resource::DEFAULT_TICK_RATE;
 
 /// プレイヤーのコントロールタイプ
 /// @spec REQ-77103-002
@@ -21,13 +23,19 @@ pub enum ControlType {
 }
 
 /// リプレイデータ全体
-/// @spec REQ-77103-001, REQ-77103-002
+/// @spec REQ-77103-001, REQ-77103-002, REQ-77103-010
 #[derive(Debug, Clone, Serialize, Deserialize, Resource)]
 pub struct ReplayData {
     /// メタデータ
     pub metadata: ReplayMetadata,
     /// フレームごとの入力データ
     pub frames: Vec<FrameInput>,
+    /// 状態チェックサム（フレーム番号順）
+    #[serde(default)]
+    pub checksums: Vec<StateChecksum>,
+    /// フィールド比較用のキーフレーム（フレーム番号順）
+    #[serde(default)]
+    pub keyframes: Vec<StateKeyframe>,
 }
 
 impl ReplayData {
@@ -36,6 +44,8 @@ impl ReplayData {
         Self {
             metadata,
             frames: Vec::new(),
+            checksums: Vec::new(),
+            keyframes: Vec::new(),
         }
     }
 
@@ -43,6 +53,31 @@ impl ReplayData {
     pub fn push_frame(&mut self, frame: FrameInput) {
         self.frames.push(frame);
     }
+
+    /// 指定フレームの記録済みチェックサム（未記録なら None）
+    /// @spec REQ-77103-011
+    pub fn checksum_for(&self, frame: u32) -> Option<u64> {
+        self.checksums
+            .binary_search_by_key(&frame, |checksum| checksum.frame)
+            .ok()
+            .map(|index| self.checksums[index].hash)
+    }
+
+    /// 指定フレーム以降で最初のキーフレーム
+    /// @spec REQ-77103-011
+    pub fn keyframe_at_or_after(&self, frame: u32) -> Option<&StateKeyframe> {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.frame < frame);
+        self.keyframes.get(index)
+    }
+
+    /// 指定フレームのキーフレームの状態
+    pub fn keyframe_for(&self, frame: u32) -> Option<&StateSnapshot> {
+        self.keyframe_at_or_after(frame)
+            .filter(|keyframe| keyframe.frame == frame)
+            .map(|keyframe| &keyframe.snapshot)
+    }
 }
 
 /// リプレイメタデータ
@@ -57,12 +92,19 @@ pub struct ReplayMetadata {
     pub seed: u64,
     /// 最初のサーブ側
     pub initial_serve_side: CourtSide,
-    /// Left側プレイヤーのコントロールタイプ
-    #[serde(default)]
-    pub left_control: ControlType,
-    /// Right側プレイヤーのコントロールタイプ
-    #[serde(default)]
-    pub right_control: ControlType,
+    /// 各プレイヤーのコントロールタイプ（インデックス = プレイヤーID - 1）
+    /// シングルス: [Left, Right]、ダブルス: [P1, P2, P3, P4]
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub controls: Vec<ControlType>,
+    /// 記録時のティックレート（Hz、1フレーム = 1ティック）
+    /// @spec 77100_headless_sim.md#req-77100-001
+    #[serde(default = "default_tick_rate")]
+    pub tick_rate: f32,
+}
+
+/// ティックレート未記録のリプレイは 60Hz で記録されたものとして扱う
+fn default_tick_rate() -> f32 {
+    DEFAULT_TICK_RATE
 }
 
 impl ReplayMetadata {
@@ -70,16 +112,16 @@ impl ReplayMetadata {
     pub fn new(
         seed: u64,
         initial_serve_side: CourtSide,
-        left_control: ControlType,
-        right_control: ControlType,
+        controls: Vec<ControlType>,
+        tick_rate: f32,
     ) -> Self {
         Self {
             game_version: env!("CARGO_PKG_VERSION").to_string(),
             recorded_at: chrono::Utc::now().to_rfc3339(),
             seed,
             initial_serve_side,
-            left_control,
-            right_control,
+            controls,
+            tick_rate,
         }
     }
 
@@ -87,6 +129,24 @@ impl ReplayMetadata {
     pub fn is_version_compatible(&self) -> bool {
         self.game_version == env!("CARGO_PKG_VERSION")
     }
+
+    /// 記録されたプレイヤー数
+    pub fn player_count(&self) -> usize {
+        self.controls.len()
+    }
+
+    /// 1チームあたりの人数（1 = シングルス、2 = ダブルス）
+    pub fn players_per_team(&self) -> u32 {
+        (self.player_count() / 2).max(1) as u32
+    }
+
+    /// 指定プレイヤーIDのコントロールタイプ（未記録ならAI）
+    pub fn control_for(&self, player_id: u8) -> ControlType {
+        self.controls
+            .get(player_id.saturating_sub(1) as usize)
+            .copied()
+            .unwrap_or_default()
+    }
 }
 
 /// 1フレームの入力データ
@@ -95,16 +155,19 @@ impl ReplayMetadata {
 pub struct FrameInput {
     /// フレーム番号
     pub frame: u32,
-    /// Player 1 の入力
-    pub p1: InputSnapshot,
-    /// Player 2 の入力
-    pub p2: InputSnapshot,
+    /// 各プレイヤーの入力（インデックス = プレイヤーID - 1）
+    pub inputs: Vec<InputSnapshot>,
 }
 
 impl FrameInput {
     /// 新しいフレーム入力を作成
-    pub fn new(frame: u32, p1: InputSnapshot, p2: InputSnapshot) -> Self {
-        Self { frame, p1, p2 }
+    pub fn new(frame: u32, inputs: Vec<InputSnapshot>) -> Self {
+        Self { frame, inputs }
+    }
+
+    /// 指定プレイヤーIDの入力
+    pub fn input_for(&self, player_id: u8) -> Option<&InputSnapshot> {
+        self.inputs.get(player_id.saturating_sub(1) as usize)
     }
 }
 
@@ -121,6 +184,12 @@ pub struct InputSnapshot {
     pub shot_pressed: bool,
     /// ショットボタンを保持中か
     pub holding: bool,
+    /// ロブボタンを押しているか
+    #[serde(default)]
+    pub lob_held: bool,
+    /// ドロップショットボタンを押しているか
+    #[serde(default)]
+    pub drop_shot_held: bool,
 }
 
 impl InputSnapshot {
@@ -131,6 +200,8 @@ impl InputSnapshot {
             jump_pressed: input.jump_pressed,
             shot_pressed: input.shot_pressed,
             holding: input.holding,
+            lob_held: input.lob_held,
+            drop_shot_held: input.drop_shot_held,
         }
     }
 }
@@ -139,14 +210,12 @@ impl InputSnapshot {
 // バイナリシリアライズ用構造体
 // ============================================================================
 
-/// バイナリ形式のフレーム入力（6バイト）
+/// バイナリ形式のフレーム入力（プレイヤー数 × 3バイト）
 /// @spec REQ-77103-001
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct BinaryFrameInput {
-    /// Player 1 の入力（3バイト）
-    pub p1: BinaryInputSnapshot,
-    /// Player 2 の入力（3バイト）
-    pub p2: BinaryInputSnapshot,
+    /// 各プレイヤーの入力（各3バイト、プレイヤーID順）
+    pub inputs: Vec<BinaryInputSnapshot>,
 }
 
 /// バイナリ形式の入力スナップショット（3バイト）
@@ -157,15 +226,20 @@ pub struct BinaryInputSnapshot {
     pub movement_x: i8,
     /// 移動Y（-127〜127 → -1.0〜1.0）
     pub movement_y: i8,
-    /// フラグ（bit0: jump, bit1: shot, bit2: holding）
+    /// フラグ（bit0: jump, bit1: shot, bit2: holding, bit3: lob, bit4: drop_shot）
     pub flags: u8,
 }
 
 impl BinaryInputSnapshot {
+    /// 1プレイヤー分のバイト数
+    pub const SIZE: usize = 3;
+
     /// フラグビット定義
     const FLAG_JUMP: u8 = 0b001;
     const FLAG_SHOT: u8 = 0b010;
     const FLAG_HOLDING: u8 = 0b100;
+    const FLAG_LOB: u8 = 0b1000;
+    const FLAG_DROP_SHOT: u8 = 0b10000;
 
     /// InputSnapshot からバイナリ形式に変換
     pub fn from_snapshot(snapshot: &InputSnapshot) -> Self {
@@ -182,6 +256,12 @@ impl BinaryInputSnapshot {
         if snapshot.holding {
             flags |= Self::FLAG_HOLDING;
         }
+        if snapshot.lob_held {
+            flags |= Self::FLAG_LOB;
+        }
+        if snapshot.drop_shot_held {
+            flags |= Self::FLAG_DROP_SHOT;
+        }
 
         Self {
             movement_x,
@@ -200,6 +280,8 @@ impl BinaryInputSnapshot {
             jump_pressed: (self.flags & Self::FLAG_JUMP) != 0,
             shot_pressed: (self.flags & Self::FLAG_SHOT) != 0,
             holding: (self.flags & Self::FLAG_HOLDING) != 0,
+            lob_held: (self.flags & Self::FLAG_LOB) != 0,
+            drop_shot_held: (self.flags & Self::FLAG_DROP_SHOT) != 0,
         }
     }
 
@@ -221,40 +303,51 @@ impl BinaryInputSnapshot {
 }
 
 impl BinaryFrameInput {
+    /// 指定プレイヤー数の1フレームのバイト数
+    pub fn byte_size(player_count: usize) -> usize {
+        player_count * BinaryInputSnapshot::SIZE
+    }
+
     /// FrameInput からバイナリ形式に変換
     pub fn from_frame_input(frame: &FrameInput) -> Self {
         Self {
-            p1: BinaryInputSnapshot::from_snapshot(&frame.p1),
-            p2: BinaryInputSnapshot::from_snapshot(&frame.p2),
+            inputs: frame
+                .inputs
+                .iter()
+                .map(BinaryInputSnapshot::from_snapshot)
+                .collect(),
         }
     }
 
     /// バイナリ形式から FrameInput に変換
-    pub fn to_frame_input(self, frame_number: u32) -> FrameInput {
+    pub fn to_frame_input(&self, frame_number: u32) -> FrameInput {
         FrameInput {
             frame: frame_number,
-            p1: self.p1.to_snapshot(),
-            p2: self.p2.to_snapshot(),
+            inputs: self
+                .inputs
+                .iter()
+                .map(|input| input.to_snapshot())
+                .collect(),
         }
     }
 
-    /// バイト配列に書き込み（6バイト）
-    pub fn write_to(&self, buf: &mut [u8; 6]) {
-        let mut p1_buf = [0u8; 3];
-        let mut p2_buf = [0u8; 3];
-        self.p1.write_to(&mut p1_buf);
-        self.p2.write_to(&mut p2_buf);
-        buf[0..3].copy_from_slice(&p1_buf);
-        buf[3..6].copy_from_slice(&p2_buf);
+    /// バイト列に書き込み（プレイヤー数 × 3バイト）
+    pub fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.clear();
+        for input in &self.inputs {
+            let mut input_buf = [0u8; BinaryInputSnapshot::SIZE];
+            input.write_to(&mut input_buf);
+            buf.extend_from_slice(&input_buf);
+        }
     }
 
-    /// バイト配列から読み込み（6バイト）
-    pub fn read_from(buf: &[u8; 6]) -> Self {
-        let p1_buf: [u8; 3] = [buf[0], buf[1], buf[2]];
-        let p2_buf: [u8; 3] = [buf[3], buf[4], buf[5]];
+    /// バイト列から読み込み（プレイヤー数 × 3バイト）
+    pub fn read_from(buf: &[u8]) -> Self {
         Self {
-            p1: BinaryInputSnapshot::read_from(&p1_buf),
-            p2: BinaryInputSnapshot::read_from(&p2_buf),
+            inputs: buf
+                .chunks_exact(BinaryInputSnapshot::SIZE)
+                .map(|chunk| BinaryInputSnapshot::read_from(&[chunk[0], chunk[1], chunk[2]]))
+                .collect(),
         }
     }
 }
@@ -267,6 +360,9 @@ pub struct ReplayConfig {
     pub file_management: FileManagementConfig,
     /// クリーンアップポリシー
     pub cleanup_policy: CleanupPolicyConfig,
+    /// 状態チェックサム設定
+    #[serde(default)]
+    pub checksum: ChecksumConfig,
 }
 
 /// ファイル管理設定
@@ -308,6 +404,40 @@ impl Default for CleanupPolicyConfig {
     }
 }
 
+/// 状態チェックサム設定
+/// @spec REQ-77103-010
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChecksumConfig {
+    /// チェックサムを記録する間隔（フレーム、0 = 記録しない）
+    pub interval: u32,
+    /// キーフレーム（状態の値）を記録する間隔（フレーム、0 = 記録しない）
+    pub keyframe_interval: u32,
+}
+
+impl Default for ChecksumConfig {
+    fn default() -> Self {
+        Self {
+            interval: 1,
+            keyframe_interval: 60,
+        }
+    }
+}
+
+impl ChecksumConfig {
+    /// 指定フレームでチェックサムを記録するか
+    #[inline]
+    pub fn records_checksum(&self, frame: u32) -> bool {
+        self.interval > 0 && frame.is_multiple_of(self.interval)
+    }
+
+    /// 指定フレームでキーフレームを記録するか
+    #[inline]
+    pub fn records_keyframe(&self, frame: u32) -> bool {
+        self.keyframe_interval > 0 && frame.is_multiple_of(self.keyframe_interval)
+    }
+}
+
 /// 設定ファイルを読み込む
 /// @data 87103_replay_config.md
 pub fn load_replay_config<P: AsRef<std::path::Path>>(path: P) -> Result<ReplayConfig, String> {
@@ -9,10 +9,16 @@ use std::fs::{self, File};
 use std::io::{BufReader, Read};
 use std::path::Path;
 
+use super::checksum::{StateChecksum, StateKeyframe};
 use super::data::{BinaryFrameInput, ReplayData, ReplayMetadata};
+use super::manager::REPLAY_VERSION;
 
 /// リプレイファイルのマジックナンバー
 const REPLAY_MAGIC: &[u8; 4] = b"RPLY";
+/// 読み込める最古のファイルバージョン（2: 操作種別・フレーム入力をプレイヤーごとに保存）
+const MIN_REPLAY_VERSION: u16 = 2;
+/// 状態チェックサムを含む最初のファイルバージョン
+const CHECKSUM_VERSION: u16 = 3;
 
 /// リプレイファイルを読み込む
 /// @spec REQ-77103-006
@@ -59,8 +65,14 @@ fn load_binary_replay(path: &Path) -> Result<ReplayData, String> {
         return Err("Invalid replay file: bad magic number".to_string());
     }
 
-    // バージョン確認（将来の拡張用）
-    let _version = u16::from_le_bytes([header[4], header[5]]);
+    // バージョン確認（2より前はメタデータ・フレームの形式が異なる。3以降はチェックサムを含む）
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    if !(MIN_REPLAY_VERSION..=REPLAY_VERSION).contains(&version) {
+        return Err(format!(
+            "Unsupported replay version: {} (supported: {}-{})",
+            version, MIN_REPLAY_VERSION, REPLAY_VERSION
+        ));
+    }
 
     // フレーム数
     let frame_count = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
@@ -77,9 +89,9 @@ fn load_binary_replay(path: &Path) -> Result<ReplayData, String> {
     let metadata: ReplayMetadata = bincode::deserialize(&metadata_bytes)
         .map_err(|e| format!("Failed to deserialize metadata: {}", e))?;
 
-    // フレームデータ読み込み
+    // フレームデータ読み込み（1フレーム = プレイヤー数 × 3バイト）
     let mut frames = Vec::with_capacity(frame_count as usize);
-    let mut frame_buf = [0u8; 6];
+    let mut frame_buf = vec![0u8; BinaryFrameInput::byte_size(metadata.player_count())];
 
     for i in 0..frame_count {
         reader
@@ -89,7 +101,53 @@ fn load_binary_replay(path: &Path) -> Result<ReplayData, String> {
         frames.push(binary_frame.to_frame_input(i));
     }
 
-    Ok(ReplayData { metadata, frames })
+    let mut data = ReplayData {
+        metadata,
+        frames,
+        checksums: Vec::new(),
+        keyframes: Vec::new(),
+    };
+    if version >= CHECKSUM_VERSION {
+        read_checksums(&mut reader, &mut data)?;
+    }
+
+    Ok(data)
+}
+
+/// チェックサム・キーフレームを読み込む
+/// @spec REQ-77103-010
+fn read_checksums(reader: &mut impl Read, data: &mut ReplayData) -> Result<(), String> {
+    let mut word = [0u8; 4];
+    reader
+        .read_exact(&mut word)
+        .map_err(|e| format!("Failed to read checksum count: {}", e))?;
+    let checksum_count = u32::from_le_bytes(word);
+
+    data.checksums.reserve(checksum_count as usize);
+    let mut entry = [0u8; 12];
+    for i in 0..checksum_count {
+        reader
+            .read_exact(&mut entry)
+            .map_err(|e| format!("Failed to read checksum {}: {}", i, e))?;
+        data.checksums.push(StateChecksum {
+            frame: u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]),
+            hash: u64::from_le_bytes([
+                entry[4], entry[5], entry[6], entry[7], entry[8], entry[9], entry[10], entry[11],
+            ]),
+        });
+    }
+
+    reader
+        .read_exact(&mut word)
+        .map_err(|e| format!("Failed to read keyframe size: {}", e))?;
+    let mut keyframe_bytes = vec![0u8; u32::from_le_bytes(word) as usize];
+    reader
+        .read_exact(&mut keyframe_bytes)
+        .map_err(|e| format!("Failed to read keyframes: {}", e))?;
+    data.keyframes = bincode::deserialize::<Vec<StateKeyframe>>(&keyframe_bytes)
+        .map_err(|e| format!("Failed to deserialize keyframes: {}", e))?;
+
+    Ok(())
 }
 
 /// RON形式のリプレイを読み込む（旧形式互換）
@@ -117,3 +175,38 @@ pub fn load_replay_unchecked<P: AsRef<Path>>(path: P) -> Result<ReplayData, Stri
         _ => Err(format!("Unknown replay format: {:?}", path)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /// ヘッダーのみのリプレイファイルを書き出す（フレーム数・メタデータサイズは0）
+    fn write_header_only(path: &Path, version: u16) {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.extend_from_slice(&version.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 10]);
+        fs::write(path, bytes).unwrap();
+    }
+
+    /// TST-77103-014: 対応範囲外のバージョンのリプレイは形式を解釈せずにエラーにする
+    /// @spec 77103_replay_spec.md#req-77103-006
+    #[test]
+    fn test_req_77103_006_rejects_unsupported_version() {
+        let dir = tempdir().unwrap();
+
+        for version in [1, REPLAY_VERSION + 1] {
+            let path = dir.path().join(format!("v{}.replay", version));
+            write_header_only(&path, version);
+
+            let err = load_replay(&path).unwrap_err();
+            assert!(
+                err.starts_with(&format!("Unsupported replay version: {}", version)),
+                "version {}: {}",
+                version,
+                err
+            );
+        }
+    }
+}
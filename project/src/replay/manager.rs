@@ -15,8 +15,8 @@ use super::loader;
 
 /// リプレイファイルのマジックナンバー
 const REPLAY_MAGIC: &[u8; 4] = b"RPLY";
-/// リプレイファイルのバージョン
-const REPLAY_VERSION: u16 = 1;
+/// リプレイファイルのバージョン（3: 状態チェックサム・キーフレームを追加）
+pub(super) const REPLAY_VERSION: u16 = 3;
 /// リプレイファイルの拡張子
 const REPLAY_EXTENSION: &str = "replay";
 
@@ -131,8 +131,9 @@ impl ReplayManager {
             .write_all(&metadata_bytes)
             .map_err(|e| format!("Failed to write metadata: {}", e))?;
 
-        // フレームデータ書き込み（各6バイト）
-        let mut frame_buf = [0u8; 6];
+        // フレームデータ書き込み（各 プレイヤー数 × 3バイト）
+        let mut frame_buf =
+            Vec::with_capacity(BinaryFrameInput::byte_size(data.metadata.player_count()));
         for frame in &data.frames {
             let binary_frame = BinaryFrameInput::from_frame_input(frame);
             binary_frame.write_to(&mut frame_buf);
@@ -141,6 +142,29 @@ impl ReplayManager {
                 .map_err(|e| format!("Failed to write frame: {}", e))?;
         }
 
+        // チェックサム書き込み（件数 4バイト + 各 フレーム番号4バイト・ハッシュ8バイト）
+        // @spec REQ-77103-010
+        let checksum_count = data.checksums.len() as u32;
+        writer
+            .write_all(&checksum_count.to_le_bytes())
+            .map_err(|e| format!("Failed to write checksum count: {}", e))?;
+        for checksum in &data.checksums {
+            writer
+                .write_all(&checksum.frame.to_le_bytes())
+                .and_then(|_| writer.write_all(&checksum.hash.to_le_bytes()))
+                .map_err(|e| format!("Failed to write checksum: {}", e))?;
+        }
+
+        // キーフレーム書き込み（サイズ 4バイト + bincode）
+        let keyframe_bytes = bincode::serialize(&data.keyframes)
+            .map_err(|e| format!("Failed to serialize keyframes: {}", e))?;
+        writer
+            .write_all(&(keyframe_bytes.len() as u32).to_le_bytes())
+            .map_err(|e| format!("Failed to write keyframe size: {}", e))?;
+        writer
+            .write_all(&keyframe_bytes)
+            .map_err(|e| format!("Failed to write keyframes: {}", e))?;
+
         writer
             .flush()
             .map_err(|e| format!("Failed to flush: {}", e))?;
@@ -340,3 +364,79 @@ pub fn startup_cleanup_system(manager: Res<ReplayManager>) {
         error!("Replay cleanup failed: {}", e);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::CourtSide;
+    use crate::replay::checksum::{BallSnapshot, StateChecksum, StateKeyframe, StateSnapshot};
+    use crate::replay::data::{ControlType, FileManagementConfig, FrameInput, InputSnapshot};
+    use tempfile::tempdir;
+
+    /// TST-77103-006: v3 形式で保存したリプレイを読み込むと入力・チェックサム・キーフレームが一致する
+    /// @spec 77103_replay_spec.md#req-77103-003
+    /// @spec 77103_replay_spec.md#req-77103-006
+    /// @spec 77103_replay_spec.md#req-77103-010
+    #[test]
+    fn test_req_77103_006_save_load_round_trip() {
+        let dir = tempdir().unwrap();
+        let manager = ReplayManager::new(ReplayConfig {
+            file_management: FileManagementConfig {
+                save_directory: dir.path().to_string_lossy().into_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let mut data = ReplayData::new(ReplayMetadata::new(
+            42,
+            CourtSide::Right,
+            vec![ControlType::Human, ControlType::Ai],
+            120.0,
+        ));
+        // 移動入力は 1/127 刻みで保存されるため、往復で変わらない値を使う
+        let input = InputSnapshot {
+            movement: Vec2::new(1.0, -1.0),
+            shot_pressed: true,
+            lob_held: true,
+            ..Default::default()
+        };
+        for frame in 0..3 {
+            data.push_frame(FrameInput::new(
+                frame,
+                vec![input, InputSnapshot::default()],
+            ));
+            data.checksums.push(StateChecksum {
+                frame,
+                hash: 0x0123_4567_89ab_cdef ^ u64::from(frame),
+            });
+        }
+        data.keyframes.push(StateKeyframe {
+            frame: 2,
+            snapshot: StateSnapshot {
+                ball: Some(BallSnapshot {
+                    position: Vec3::new(1.5, 0.25, -3.0),
+                    velocity: Vec3::new(-12.0, 4.0, 0.5),
+                    spin: Vec3::Y,
+                }),
+                rng_draws: 9,
+                ..Default::default()
+            },
+        });
+
+        let path = manager.save_replay(&data).unwrap();
+        assert_eq!(path.extension().and_then(|e| e.to_str()), Some("replay"));
+        let loaded = manager.load_replay(&path).unwrap();
+
+        assert_eq!(loaded.metadata.seed, 42);
+        assert_eq!(loaded.metadata.initial_serve_side, CourtSide::Right);
+        assert_eq!(loaded.metadata.tick_rate, 120.0);
+        assert_eq!(loaded.frames.len(), 3);
+        let restored = loaded.frames[1].input_for(1).unwrap();
+        assert_eq!(restored.movement, input.movement);
+        assert!(restored.shot_pressed && restored.lob_held && !restored.drop_shot_held);
+        assert_eq!(loaded.checksums, data.checksums);
+        assert_eq!(loaded.keyframes, data.keyframes);
+        assert_eq!(loaded.checksum_for(1), data.checksum_for(1));
+    }
+}
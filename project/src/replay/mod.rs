@@ -6,6 +6,7 @@
 //! - リプレイファイルの保存/読み込み
 //! - リプレイの再生
 
+pub mod checksum;
 pub mod data;
 pub mod loader;
 pub mod manager;
@@ -15,8 +16,11 @@ pub mod recorder;
 use bevy::{app::Last, ecs::message::MessageReader, prelude::*};
 
 use crate::components::{HumanControlled, Player};
-use crate::core::CourtSide;
-use crate::resource::{GameRng, MatchFlowState};
+use crate::resource::config::GameConfig;
+use crate::resource::{FixedDeltaTime, GameRng, MatchFlowState, MatchScore};
+use crate::systems::GameSystemSet;
+
+use checksum::{capture_state, BallStateQuery, PlayerStateQuery};
 
 pub use data::ControlType;
 pub use manager::ReplayManager;
@@ -38,15 +42,22 @@ impl Plugin for ReplayRecordPlugin {
                 OnEnter(MatchFlowState::MatchStart),
                 start_replay_on_match_start,
             )
+            // 入力はティックごとに記録する（入力注入後、ゲームロジック前）
+            // @spec 77100_headless_sim.md#req-77100-001
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     recorder::start_recording_system,
-                    recorder::record_frame_system,
+                    recorder::record_frame_system
+                        .after(GameSystemSet::Input)
+                        .before(GameSystemSet::GameLogic),
                     recorder::stop_recording_system,
                     auto_save_on_match_end_system,
                 ),
             )
+            // ティック終了時の状態チェックサムを記録
+            // @spec REQ-77103-010
+            .add_systems(FixedPostUpdate, record_state_checksum_system)
             // アプリ終了時にリプレイ保存（途中終了対応）
             .add_systems(Last, save_replay_on_exit);
     }
@@ -56,8 +67,10 @@ impl Plugin for ReplayRecordPlugin {
 /// @spec REQ-77103-002
 fn start_replay_on_match_start(
     mut recorder: ResMut<ReplayRecorder>,
-    match_score: Res<crate::resource::MatchScore>,
+    match_score: Res<MatchScore>,
     game_rng: Res<GameRng>,
+    fixed_dt: Res<FixedDeltaTime>,
+    config: Res<GameConfig>,
     players: Query<(&Player, Option<&HumanControlled>)>,
 ) {
     // GameRng から現在のシードを取得
@@ -66,27 +79,45 @@ fn start_replay_on_match_start(
     // ゲーム状態から初期サーブ側を取得
     let initial_serve_side = match_score.server;
 
-    // プレイヤーのコントロールタイプを判定
-    let mut left_control = ControlType::Ai;
-    let mut right_control = ControlType::Ai;
-
-    for (player, human) in players.iter() {
-        let control = if human.is_some() {
-            ControlType::Human
-        } else {
-            ControlType::Ai
-        };
-        match player.court_side {
-            CourtSide::Left => left_control = control,
-            CourtSide::Right => right_control = control,
-        }
-    }
+    // プレイヤーのコントロールタイプを判定（プレイヤーID順）
+    // 試合開始時点でまだ生成されていないプレイヤーは AI とする
+    let controls: Vec<ControlType> = (1..=config.team.total_players() as u8)
+        .map(|player_id| {
+            let human = players
+                .iter()
+                .any(|(player, human)| player.id == player_id && human.is_some());
+            if human {
+                ControlType::Human
+            } else {
+                ControlType::Ai
+            }
+        })
+        .collect();
 
-    recorder.start_recording(seed, initial_serve_side, left_control, right_control);
     info!(
-        "Replay recording started with seed: {}, initial_serve_side: {:?}, left: {:?}, right: {:?}",
-        seed, initial_serve_side, left_control, right_control
+        "Replay recording started with seed: {}, initial_serve_side: {:?}, controls: {:?}",
+        seed, initial_serve_side, controls
     );
+    recorder.start_recording(seed, initial_serve_side, controls, fixed_dt.tick_rate());
+}
+
+/// 状態チェックサム記録システム
+/// @spec REQ-77103-010
+///
+/// 入力を記録したティックの終了時の状態を、設定した間隔でリプレイに保存する。
+fn record_state_checksum_system(
+    mut recorder: ResMut<ReplayRecorder>,
+    manager: Res<ReplayManager>,
+    balls: BallStateQuery,
+    players: PlayerStateQuery,
+    match_score: Res<MatchScore>,
+    game_rng: Res<GameRng>,
+) {
+    if !recorder.is_recording() {
+        return;
+    }
+    let snapshot = capture_state(&balls, &players, &match_score, &game_rng);
+    recorder.record_state(&snapshot, &manager.config().checksum);
 }
 
 /// 試合終了時に自動保存
@@ -10,9 +10,9 @@ use std::sync::Arc;
 use bevy::prelude::*;
 
 use crate::components::{AiController, InputState, Player};
-use crate::core::CourtSide;
-use crate::resource::FixedDeltaTime;
+use crate::resource::{FixedDeltaTime, GameRng, MatchFlowState, MatchScore};
 
+use super::checksum::{capture_state, BallStateQuery, FieldDiff, PlayerStateQuery};
 use super::data::{InputSnapshot, ReplayData};
 
 /// リプレイ再生リソース
@@ -27,10 +27,8 @@ pub struct ReplayPlayer {
     is_playing: bool,
     /// 再生完了かどうか
     is_finished: bool,
-    /// Left側プレイヤーのhold_time累積
-    left_hold_time: f32,
-    /// Right側プレイヤーのhold_time累積
-    right_hold_time: f32,
+    /// 各プレイヤーのhold_time累積（インデックス = プレイヤーID - 1）
+    hold_times: Vec<f32>,
 }
 
 impl ReplayPlayer {
@@ -43,12 +41,11 @@ impl ReplayPlayer {
     /// Arc<ReplayData> を受け取ることで、clone コストを削減
     pub fn start_playback(&mut self, data: Arc<ReplayData>) {
         info!("Starting replay playback: {} frames", data.frames.len());
+        self.hold_times = vec![0.0; data.metadata.player_count()];
         self.data = Some(data);
         self.current_frame = 0;
         self.is_playing = true;
         self.is_finished = false;
-        self.left_hold_time = 0.0;
-        self.right_hold_time = 0.0;
     }
 
     /// 再生を停止
@@ -68,7 +65,7 @@ impl ReplayPlayer {
     }
 
     /// 現在のフレーム入力を取得してフレームを進める
-    pub fn advance_frame(&mut self) -> Option<(InputSnapshot, InputSnapshot)> {
+    pub fn advance_frame(&mut self) -> Option<Vec<InputSnapshot>> {
         if !self.is_playing || self.is_finished {
             return None;
         }
@@ -85,7 +82,7 @@ impl ReplayPlayer {
         let frame = &data.frames[self.current_frame];
         self.current_frame += 1;
 
-        Some((frame.p1, frame.p2))
+        Some(frame.inputs.clone())
     }
 
     /// 現在のフレーム番号
@@ -98,6 +95,11 @@ impl ReplayPlayer {
         self.data.as_ref().map_or(0, |d| d.frames.len())
     }
 
+    /// リプレイデータへの参照
+    pub fn data(&self) -> Option<&ReplayData> {
+        self.data.as_deref()
+    }
+
     /// メタデータへの参照
     pub fn metadata(&self) -> Option<&super::data::ReplayMetadata> {
         self.data.as_ref().map(|d| &d.metadata)
@@ -111,56 +113,179 @@ impl ReplayPlayer {
 
 /// 入力注入システム
 /// @spec REQ-77103-008
-/// ECS設計原則: court_sideベースでプレイヤーを識別（固定IDを排除）
+/// @spec 30105_doubles_spec.md#req-30105-001
+/// プレイヤーIDで記録済み入力を対応付ける
 /// AIプレイヤー（AiController持ち）には入力を注入しない
+///
+/// 記録側（record_frame_system）と同じく Rally / Serve 状態のティックでのみ
+/// フレームを進める（記録時とフレーム番号をそろえるため）。
 pub fn replay_input_system(
     fixed_dt: Res<FixedDeltaTime>,
+    match_state: Res<State<MatchFlowState>>,
     mut replay_player: ResMut<ReplayPlayer>,
     mut players: Query<(&Player, &mut InputState), Without<AiController>>,
 ) {
     if !replay_player.is_playing() {
         return;
     }
+    if !matches!(
+        match_state.get(),
+        MatchFlowState::Rally | MatchFlowState::Serve
+    ) {
+        return;
+    }
 
-    // 次のフレーム入力を取得
-    // p1 = Left側, p2 = Right側
-    let Some((left_snapshot, right_snapshot)) = replay_player.advance_frame() else {
+    // 次のフレーム入力を取得（プレイヤーID順）
+    let Some(snapshots) = replay_player.advance_frame() else {
         return;
     };
 
     // hold_time の計算（フレーム時間を累積）
     let delta_secs = fixed_dt.delta_secs();
-
-    // Left側のhold_time更新
-    if left_snapshot.holding {
-        replay_player.left_hold_time += delta_secs * 1000.0; // ミリ秒として累積
-    } else {
-        replay_player.left_hold_time = 0.0;
-    }
-
-    // Right側のhold_time更新
-    if right_snapshot.holding {
-        replay_player.right_hold_time += delta_secs * 1000.0;
-    } else {
-        replay_player.right_hold_time = 0.0;
+    replay_player.hold_times.resize(snapshots.len(), 0.0);
+    for (hold_time, snapshot) in replay_player.hold_times.iter_mut().zip(&snapshots) {
+        if snapshot.holding {
+            *hold_time += delta_secs * 1000.0; // ミリ秒として累積
+        } else {
+            *hold_time = 0.0;
+        }
     }
 
-    let left_hold_time = replay_player.left_hold_time;
-    let right_hold_time = replay_player.right_hold_time;
-
     // 各プレイヤーに入力を注入（AiControllerを持たないプレイヤーのみ）
     for (player, mut input) in players.iter_mut() {
-        let (snapshot, hold_time) = match player.court_side {
-            CourtSide::Left => (&left_snapshot, left_hold_time),
-            CourtSide::Right => (&right_snapshot, right_hold_time),
+        let index = player.id.saturating_sub(1) as usize;
+        let Some(snapshot) = snapshots.get(index) else {
+            continue;
         };
+        let hold_time = replay_player.hold_times[index];
 
         input.movement = snapshot.movement;
         input.jump_pressed = snapshot.jump_pressed;
         input.shot_pressed = snapshot.shot_pressed;
         input.holding = snapshot.holding;
         input.hold_time = hold_time;
+        input.lob_held = snapshot.lob_held;
+        input.drop_shot_held = snapshot.drop_shot_held;
+    }
+}
+
+/// 最初に検出した状態のずれ
+/// @spec REQ-77103-011
+#[derive(Debug, Clone)]
+pub struct ReplayDesync {
+    /// チェックサムが最初に一致しなかったフレーム
+    pub frame: u32,
+    /// 記録されたチェックサム
+    pub recorded_hash: u64,
+    /// 再生中のチェックサム
+    pub actual_hash: u64,
+    /// フィールド差分を比較したキーフレーム（None = 以降にキーフレームがない）
+    pub keyframe: Option<u32>,
+    /// キーフレームでのフィールド差分
+    pub diffs: Vec<FieldDiff>,
+}
+
+/// リプレイ決定性検証リソース
+/// @spec REQ-77103-011
+#[derive(Resource, Default)]
+pub struct ReplayVerifier {
+    /// 検証済みのフレーム数（次に検証するフレーム番号）
+    next_frame: u32,
+    /// 一致したチェックサムの数
+    verified: u32,
+    /// 最初に検出したずれ
+    desync: Option<ReplayDesync>,
+    /// フィールド差分の比較待ち（ずれを検出したフレーム以降のキーフレーム）
+    pending_keyframe: Option<u32>,
+}
+
+impl ReplayVerifier {
+    /// 一致したチェックサムの数
+    pub fn verified(&self) -> u32 {
+        self.verified
+    }
+
+    /// 最初に検出したずれ（なければ None）
+    pub fn desync(&self) -> Option<&ReplayDesync> {
+        self.desync.as_ref()
+    }
+
+    /// フィールド差分の比較待ちか
+    pub fn is_awaiting_keyframe(&self) -> bool {
+        self.pending_keyframe.is_some()
+    }
+}
+
+/// 状態チェックサム検証システム
+/// @spec REQ-77103-011
+///
+/// 入力を注入したティックの終了時の状態を記録済みチェックサムと比較する。
+/// 最初にずれたフレームを記録し、そのフレーム以降で最初のキーフレームで
+/// フィールド単位の差分を取る（キーフレームは記録時の状態の値そのもの）。
+pub fn verify_state_checksum_system(
+    replay_player: Res<ReplayPlayer>,
+    mut verifier: ResMut<ReplayVerifier>,
+    balls: BallStateQuery,
+    players: PlayerStateQuery,
+    match_score: Res<MatchScore>,
+    game_rng: Res<GameRng>,
+) {
+    let Some(data) = replay_player.data() else {
+        return;
+    };
+    // このティックで入力を注入したフレーム
+    let consumed = replay_player.current_frame() as u32;
+    if consumed <= verifier.next_frame {
+        return;
+    }
+    let frame = consumed - 1;
+    verifier.next_frame = consumed;
+
+    let snapshot = capture_state(&balls, &players, &match_score, &game_rng);
+
+    if let Some(keyframe) = verifier.pending_keyframe {
+        if frame == keyframe {
+            if let (Some(recorded), Some(desync)) =
+                (data.keyframe_for(frame), verifier.desync.as_mut())
+            {
+                desync.diffs = recorded.diff(&snapshot);
+            }
+            verifier.pending_keyframe = None;
+        }
+        return;
+    }
+    if verifier.desync.is_some() {
+        return;
+    }
+
+    let Some(recorded_hash) = data.checksum_for(frame) else {
+        return;
+    };
+    let actual_hash = snapshot.checksum();
+    if recorded_hash == actual_hash {
+        verifier.verified += 1;
+        return;
     }
+
+    let keyframe = data
+        .keyframe_at_or_after(frame)
+        .map(|keyframe| keyframe.frame);
+    warn!(
+        "Replay desync at frame {}: recorded={:016x} actual={:016x}",
+        frame, recorded_hash, actual_hash
+    );
+    let diffs = match data.keyframe_for(frame) {
+        Some(recorded) => recorded.diff(&snapshot),
+        None => Vec::new(),
+    };
+    verifier.pending_keyframe = keyframe.filter(|keyframe| *keyframe != frame);
+    verifier.desync = Some(ReplayDesync {
+        frame,
+        recorded_hash,
+        actual_hash,
+        keyframe,
+        diffs,
+    });
 }
 
 /// リプレイ再生完了イベント
@@ -183,3 +308,114 @@ pub fn replay_finished_check_system(
 
     *prev_finished = is_finished;
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::components::{Ball, LogicalPosition, Velocity};
+    use crate::core::CourtSide;
+    use crate::replay::checksum::{BallSnapshot, StateChecksum, StateKeyframe, StateSnapshot};
+    use crate::replay::data::{ControlType, FrameInput, ReplayMetadata};
+
+    const FRAMES: u32 = 4;
+
+    /// 記録時の状態: ボールが X 方向に 1 フレーム 1m 進む
+    fn recorded_state(frame: u32) -> StateSnapshot {
+        let ball = BallSnapshot {
+            position: Vec3::new(frame as f32, 1.0, 0.0),
+            velocity: Vec3::X,
+            spin: Vec3::ZERO,
+        };
+        StateSnapshot::capture(Some(ball), [], &MatchScore::default(), 0)
+    }
+
+    /// 全フレームのチェックサムと、フレーム2のキーフレームを持つリプレイ
+    fn recorded_replay() -> ReplayData {
+        let metadata = ReplayMetadata::new(1, CourtSide::Left, vec![ControlType::Ai; 2], 60.0);
+        let mut data = ReplayData::new(metadata);
+        for frame in 0..FRAMES {
+            data.push_frame(FrameInput::new(frame, Vec::new()));
+            data.checksums.push(StateChecksum {
+                frame,
+                hash: recorded_state(frame).checksum(),
+            });
+        }
+        data.keyframes.push(StateKeyframe {
+            frame: 2,
+            snapshot: recorded_state(2),
+        });
+        data
+    }
+
+    /// 1フレームずつ再生し、`drift_from` 以降のフレームでボールを記録からずらす
+    fn verify_replay(drift_from: u32) -> World {
+        let mut player = ReplayPlayer::new();
+        player.start_playback(Arc::new(recorded_replay()));
+
+        let mut world = World::new();
+        world.insert_resource(player);
+        world.init_resource::<ReplayVerifier>();
+        world.insert_resource(MatchScore::default());
+        world.insert_resource(GameRng::from_seed(1));
+        let ball = world
+            .spawn((
+                Ball,
+                LogicalPosition::default(),
+                Velocity { value: Vec3::X },
+            ))
+            .id();
+
+        for frame in 0..FRAMES {
+            world.resource_mut::<ReplayPlayer>().advance_frame();
+            let drift = if frame >= drift_from { 0.5 } else { 0.0 };
+            world.get_mut::<LogicalPosition>(ball).unwrap().value =
+                Vec3::new(frame as f32 + drift, 1.0, 0.0);
+            world.run_system_once(verify_state_checksum_system).unwrap();
+        }
+        world
+    }
+
+    /// TST-77103-012: 記録どおりに再生すると全フレームのチェックサムが一致する
+    /// @spec 77103_replay_spec.md#req-77103-011
+    #[test]
+    fn test_req_77103_011_verifier_matches_recording() {
+        let world = verify_replay(FRAMES);
+        let verifier = world.resource::<ReplayVerifier>();
+
+        assert_eq!(verifier.verified(), FRAMES);
+        assert!(verifier.desync().is_none());
+    }
+
+    /// TST-77103-012: 最初にずれたフレームと、以降のキーフレームでのフィールド差分を報告する
+    /// @spec 77103_replay_spec.md#req-77103-011
+    #[test]
+    fn test_req_77103_011_verifier_reports_first_desync() {
+        let world = verify_replay(1);
+        let verifier = world.resource::<ReplayVerifier>();
+
+        assert_eq!(verifier.verified(), 1);
+        assert!(!verifier.is_awaiting_keyframe());
+        let desync = verifier.desync().expect("should detect the desync");
+        assert_eq!(desync.frame, 1);
+        assert_eq!(desync.recorded_hash, recorded_state(1).checksum());
+        assert_ne!(desync.actual_hash, desync.recorded_hash);
+        // フレーム1にはキーフレームがないため、次のキーフレーム（フレーム2）で比較する
+        assert_eq!(desync.keyframe, Some(2));
+        let fields: Vec<&str> = desync
+            .diffs
+            .iter()
+            .map(|diff| diff.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["ball.position"]);
+        assert_eq!(
+            desync.diffs[0].recorded,
+            format!("{:?}", Vec3::new(2.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            desync.diffs[0].actual,
+            format!("{:?}", Vec3::new(2.5, 1.0, 0.0))
+        );
+    }
+}
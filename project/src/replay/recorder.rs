@@ -9,9 +9,12 @@ use bevy::prelude::*;
 
 use crate::components::{InputState, Player};
 use crate::core::CourtSide;
-use crate::resource::MatchFlowState;
+use crate::resource::{FixedDeltaTime, MatchFlowState};
 
-use super::data::{ControlType, FrameInput, InputSnapshot, ReplayData, ReplayMetadata};
+use super::checksum::{StateChecksum, StateKeyframe, StateSnapshot};
+use super::data::{
+    ChecksumConfig, ControlType, FrameInput, InputSnapshot, ReplayData, ReplayMetadata,
+};
 
 /// リプレイ記録リソース
 /// @spec REQ-77103-001, REQ-77103-002
@@ -23,6 +26,8 @@ pub struct ReplayRecorder {
     frame_count: u32,
     /// 記録中かどうか
     is_recording: bool,
+    /// 状態を記録済みの最後のフレーム番号
+    last_state_frame: Option<u32>,
 }
 
 impl ReplayRecorder {
@@ -37,13 +42,14 @@ impl ReplayRecorder {
         &mut self,
         seed: u64,
         initial_serve_side: CourtSide,
-        left_control: ControlType,
-        right_control: ControlType,
+        controls: Vec<ControlType>,
+        tick_rate: f32,
     ) {
-        let metadata = ReplayMetadata::new(seed, initial_serve_side, left_control, right_control);
+        let metadata = ReplayMetadata::new(seed, initial_serve_side, controls, tick_rate);
         self.data = Some(ReplayData::new(metadata));
         self.frame_count = 0;
         self.is_recording = true;
+        self.last_state_frame = None;
         info!("Replay recording started");
     }
 
@@ -61,9 +67,9 @@ impl ReplayRecorder {
         self.is_recording
     }
 
-    /// フレーム入力を記録
+    /// フレーム入力を記録（プレイヤーID順）
     /// @spec REQ-77103-001
-    pub fn record_frame(&mut self, p1_input: &InputState, p2_input: &InputState) {
+    pub fn record_frame(&mut self, inputs: &[&InputState]) {
         if !self.is_recording {
             return;
         }
@@ -71,14 +77,46 @@ impl ReplayRecorder {
         if let Some(ref mut data) = self.data {
             let frame = FrameInput::new(
                 self.frame_count,
-                InputSnapshot::from_input_state(p1_input),
-                InputSnapshot::from_input_state(p2_input),
+                inputs
+                    .iter()
+                    .map(|input| InputSnapshot::from_input_state(input))
+                    .collect(),
             );
             data.push_frame(frame);
             self.frame_count += 1;
         }
     }
 
+    /// 直前に記録したフレームのティック終了時の状態を記録
+    /// @spec REQ-77103-010
+    ///
+    /// 入力を記録したティックごとに1回だけ記録する（入力を記録しないティックは対象外）。
+    pub fn record_state(&mut self, snapshot: &StateSnapshot, config: &ChecksumConfig) {
+        if !self.is_recording || self.frame_count == 0 {
+            return;
+        }
+        let frame = self.frame_count - 1;
+        if self.last_state_frame == Some(frame) {
+            return;
+        }
+        self.last_state_frame = Some(frame);
+
+        if let Some(ref mut data) = self.data {
+            if config.records_checksum(frame) {
+                data.checksums.push(StateChecksum {
+                    frame,
+                    hash: snapshot.checksum(),
+                });
+            }
+            if config.records_keyframe(frame) {
+                data.keyframes.push(StateKeyframe {
+                    frame,
+                    snapshot: snapshot.clone(),
+                });
+            }
+        }
+    }
+
     /// 記録したリプレイデータを取得（消費）
     pub fn take_data(&mut self) -> Option<ReplayData> {
         self.is_recording = false;
@@ -102,8 +140,7 @@ impl ReplayRecorder {
 pub struct StartReplayRecording {
     pub seed: u64,
     pub initial_serve_side: CourtSide,
-    pub left_control: ControlType,
-    pub right_control: ControlType,
+    pub controls: Vec<ControlType>,
 }
 
 /// 記録停止イベント
@@ -114,21 +151,23 @@ pub struct StopReplayRecording;
 /// @spec REQ-77103-002
 pub fn start_recording_system(
     mut recorder: ResMut<ReplayRecorder>,
+    fixed_dt: Res<FixedDeltaTime>,
     mut events: MessageReader<StartReplayRecording>,
 ) {
     for event in events.read() {
         recorder.start_recording(
             event.seed,
             event.initial_serve_side,
-            event.left_control,
-            event.right_control,
+            event.controls.clone(),
+            fixed_dt.tick_rate(),
         );
     }
 }
 
 /// フレーム入力記録システム
 /// @spec REQ-77103-001
-/// ECS設計原則: court_sideベースでプレイヤーを識別（固定IDを排除）
+/// @spec 30105_doubles_spec.md#req-30105-001
+/// プレイヤーID順に全プレイヤーの入力を保存（シングルス: 2人、ダブルス: 4人）
 pub fn record_frame_system(
     mut recorder: ResMut<ReplayRecorder>,
     match_state: Res<State<MatchFlowState>>,
@@ -144,21 +183,20 @@ pub fn record_frame_system(
         return;
     }
 
-    // Left側, Right側 の入力を取得
-    let mut left_input: Option<&InputState> = None;
-    let mut right_input: Option<&InputState> = None;
+    let expected_count = recorder
+        .data()
+        .map_or(0, |data| data.metadata.player_count());
 
-    for (player, input) in players.iter() {
-        match player.court_side {
-            CourtSide::Left => left_input = Some(input),
-            CourtSide::Right => right_input = Some(input),
-        }
-    }
+    let mut inputs: Vec<(u8, &InputState)> = players
+        .iter()
+        .map(|(player, input)| (player.id, input))
+        .collect();
+    inputs.sort_by_key(|(id, _)| *id);
 
-    // 両方の入力が揃ったら記録
-    // p1 = Left側, p2 = Right側 として保存
-    if let (Some(left), Some(right)) = (left_input, right_input) {
-        recorder.record_frame(left, right);
+    // 全プレイヤーの入力が揃ったら記録
+    if inputs.len() == expected_count {
+        let inputs: Vec<&InputState> = inputs.into_iter().map(|(_, input)| input).collect();
+        recorder.record_frame(&inputs);
     }
 }
 
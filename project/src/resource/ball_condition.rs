@@ -0,0 +1,19 @@
+//! ボール状態リソース
+//! @spec 30401_trajectory_spec.md#req-30401-105
+//! @spec 30402_reflection_spec.md#req-30402-104
+
+use bevy::prelude::*;
+
+use crate::core::BallConditionFactors;
+
+/// 現在のボール状態（環境条件・摩耗による補正倍率）
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// GameConfig の environment / ball_condition と経過ゲーム数から毎ティック導出する。
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct BallCondition {
+    /// 補正倍率
+    pub factors: BallConditionFactors,
+    /// 導出に使った経過ゲーム数
+    pub games_played: u32,
+}
@@ -0,0 +1,184 @@
+//! ボール軌道予測リソース
+//! @spec 30401_trajectory_spec.md#req-30401-106
+
+use bevy::prelude::*;
+
+use crate::core::SweptSurface;
+
+/// 予測軌道上の1点（ティックごと）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathPoint {
+    /// 予測開始からの時間（秒）
+    pub time: f32,
+    /// 位置
+    pub position: Vec3,
+    /// 速度
+    pub velocity: Vec3,
+}
+
+/// 予測軌道上の接触（ネット・地面・壁・開口部）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathContact {
+    /// 接触面
+    pub surface: SweptSurface,
+    /// 予測開始からの接触時刻（秒）
+    pub time: f32,
+    /// 接触点
+    pub point: Vec3,
+    /// 接触後の速度
+    pub velocity: Vec3,
+}
+
+/// 予測軌道（時刻付きの折れ線と接触）
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// `points` の先頭は予測開始時点のボール状態（time = 0）。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BallPath {
+    /// 時刻付きの折れ線
+    pub points: Vec<PathPoint>,
+    /// 接触（時刻順）
+    pub contacts: Vec<PathContact>,
+}
+
+impl BallPath {
+    /// 指定時刻の位置（折れ線を線形補間、範囲外は端点）
+    ///
+    /// 接触点は折れ線の頂点にないため、接触をまたぐ区間では接触点を経由して補間する。
+    pub fn position_at(&self, time: f32) -> Option<Vec3> {
+        let first = self.points.first()?;
+        if time <= first.time {
+            return Some(first.position);
+        }
+        let index = self.points.partition_point(|point| point.time <= time);
+        let Some(next) = self.points.get(index) else {
+            return self.points.last().map(|point| point.position);
+        };
+        let prev = &self.points[index - 1];
+
+        // 区間内の接触点を経由する
+        let (mut start_time, mut start_position) = (prev.time, prev.position);
+        for contact in self
+            .contacts
+            .iter()
+            .filter(|contact| contact.time > prev.time && contact.time < next.time)
+        {
+            if contact.time >= time {
+                return Some(lerp_position(
+                    start_time,
+                    start_position,
+                    contact.time,
+                    contact.point,
+                    time,
+                ));
+            }
+            start_time = contact.time;
+            start_position = contact.point;
+        }
+        Some(lerp_position(
+            start_time,
+            start_position,
+            next.time,
+            next.position,
+            time,
+        ))
+    }
+
+    /// 最初に条件を満たす接触
+    pub fn first_contact(&self, filter: impl Fn(&PathContact) -> bool) -> Option<&PathContact> {
+        self.contacts.iter().find(|&contact| filter(contact))
+    }
+
+    /// 最初の地面バウンド
+    #[inline]
+    pub fn first_bounce(&self) -> Option<&PathContact> {
+        self.first_contact(|contact| contact.surface == SweptSurface::Floor)
+    }
+
+    /// 最初にX座標 `x` を通過する時刻（折れ線の区間で線形補間）
+    pub fn time_at_x(&self, x: f32) -> Option<f32> {
+        self.points.windows(2).find_map(|pair| {
+            let (a, b) = (&pair[0], &pair[1]);
+            let (da, db) = (a.position.x - x, b.position.x - x);
+            if da == 0.0 {
+                Some(a.time)
+            } else if da * db < 0.0 {
+                Some(a.time + (b.time - a.time) * da / (da - db))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// 2点間を時刻で線形補間
+#[inline]
+fn lerp_position(t0: f32, p0: Vec3, t1: f32, p1: Vec3, time: f32) -> Vec3 {
+    if t1 - t0 <= f32::EPSILON {
+        return p1;
+    }
+    p0.lerp(p1, ((time - t0) / (t1 - t0)).clamp(0.0, 1.0))
+}
+
+/// 現在のボールの予測軌道（ティックごとに1回だけ計算して共有する）
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// ball_prediction_system がティックの最初にボールの状態から計算する。
+/// ボールがない場合は None。
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct BallPrediction {
+    /// 予測軌道
+    pub path: Option<BallPath>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(time: f32, x: f32, y: f32) -> PathPoint {
+        PathPoint {
+            time,
+            position: Vec3::new(x, y, 0.0),
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// TST-30404-112: 折れ線の補間は区間内の接触点を経由する
+    /// @spec 30401_trajectory_spec.md#req-30401-106
+    #[test]
+    fn test_req_30401_106_position_at() {
+        let path = BallPath {
+            points: vec![point(0.0, 0.0, 1.0), point(1.0, 4.0, 1.0)],
+            contacts: vec![PathContact {
+                surface: SweptSurface::Floor,
+                time: 0.5,
+                point: Vec3::new(3.0, 0.0, 0.0),
+                velocity: Vec3::ZERO,
+            }],
+        };
+
+        assert_eq!(path.position_at(-1.0), Some(Vec3::new(0.0, 1.0, 0.0)));
+        assert_eq!(path.position_at(0.25), Some(Vec3::new(1.5, 0.5, 0.0)));
+        assert_eq!(path.position_at(0.75), Some(Vec3::new(3.5, 0.5, 0.0)));
+        assert_eq!(path.position_at(2.0), Some(Vec3::new(4.0, 1.0, 0.0)));
+        assert_eq!(BallPath::default().position_at(0.0), None);
+    }
+
+    /// TST-30404-113: X座標の通過時刻と最初のバウンド
+    /// @spec 30401_trajectory_spec.md#req-30401-106
+    #[test]
+    fn test_req_30401_106_time_at_x() {
+        let path = BallPath {
+            points: vec![
+                point(0.0, 0.0, 1.0),
+                point(1.0, 2.0, 1.0),
+                point(2.0, 0.0, 1.0),
+            ],
+            contacts: Vec::new(),
+        };
+
+        assert!((path.time_at_x(1.0).unwrap() - 0.5).abs() < 1e-6);
+        assert_eq!(path.time_at_x(3.0), None);
+        assert!(path.first_bounce().is_none());
+    }
+}
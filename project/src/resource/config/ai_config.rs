@@ -82,6 +82,9 @@ pub struct AiConfig {
     /// サーブ攻めマージン（m）- サービスエリア端からのマージン
     /// @spec 30303_ai_tactics_spec.md#req-30303-032
     pub serve_offensive_margin: f32,
+    /// 着地予測で風による流れを見込むか
+    /// @spec 30301_ai_movement_spec.md#req-30301-v07-004
+    pub predict_wind: bool,
 }
 
 impl Default for AiConfig {
@@ -110,6 +113,7 @@ impl Default for AiConfig {
             offensive_margin: 0.8,
             serve_offensive_probability: 0.5,
             serve_offensive_margin: 0.3,
+            predict_wind: true,
         }
     }
 }
@@ -18,8 +18,6 @@ pub struct BallConfig {
     pub radius: f32,
     /// 最小バウンド速度（Y速度が0の場合に適用）
     pub min_bounce_velocity: f32,
-    /// 壁反射係数（壁バウンド時の速度減衰）
-    pub wall_bounce_factor: f32,
 }
 
 impl Default for BallConfig {
@@ -30,7 +28,6 @@ impl Default for BallConfig {
             bounce_factor: 0.8,
             radius: 0.2,
             min_bounce_velocity: 1.0,
-            wall_bounce_factor: 0.8,
         }
     }
 }
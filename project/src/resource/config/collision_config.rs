@@ -10,6 +10,15 @@ use serde::Deserialize;
 pub struct CollisionConfig {
     pub character_radius: f32,
     pub z_tolerance: f32,
+    /// ボールが体に当たったときの扱い
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    pub body_hit_rule: BodyHitRule,
+    /// 体の当たり判定の半径（m）。ラケットの打球範囲（shot.max_distance）とは別
+    /// @spec 30403_collision_spec.md#req-30403-007
+    pub body_radius: f32,
+    /// 体の当たり判定の高さ（m、足元から）
+    /// @spec 30403_collision_spec.md#req-30403-007
+    pub body_height: f32,
 }
 
 impl Default for CollisionConfig {
@@ -17,10 +26,24 @@ impl Default for CollisionConfig {
         Self {
             character_radius: 0.5,
             z_tolerance: 0.3,
+            body_hit_rule: BodyHitRule::default(),
+            body_radius: 0.3,
+            body_height: 1.8,
         }
     }
 }
 
+/// ボールが体に当たったときの扱い
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum BodyHitRule {
+    /// ふっとばし（アーケード。knockback.enabled が有効な場合のみ）
+    #[default]
+    Knockback,
+    /// 当たったプレイヤーの失点（パデルルール）
+    PointLoss,
+}
+
 /// ふっとばしパラメータ
 /// @data 80101_game_constants.md#knockback-config
 #[derive(Deserialize, Clone, Debug)]
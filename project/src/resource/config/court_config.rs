@@ -3,6 +3,8 @@
 
 use serde::Deserialize;
 
+use crate::core::{WallMaterial, WallType};
+
 /// コートサイズ・範囲
 /// @data 80101_game_constants.md#court-config
 #[derive(Deserialize, Clone, Debug)]
@@ -14,7 +16,18 @@ pub struct CourtConfig {
     /// TODO: v0.2でジャンプ高さ制限として使用予定
     #[allow(dead_code)]
     pub max_jump_height: f32,
+    /// ネット中央の高さ（最も低い位置）
+    /// @spec 30501_court_spec.md#req-30501-005
     pub net_height: f32,
+    /// ポスト位置でのネットの高さ（中央との差がたるみ）
+    /// @spec 30501_court_spec.md#req-30501-005
+    pub net_post_height: f32,
+    /// ネットポストの幅（Z方向）。ポストは左右壁（outer_wall_z）に接して立つ
+    /// @spec 30503_boundary_behavior.md#beh-30503-005
+    pub net_post_width: f32,
+    /// ネット上端の白帯の幅
+    /// @spec 30503_boundary_behavior.md#beh-30503-005
+    pub net_cord_band: f32,
     pub net_x: f32,
     /// サービスボックスの奥行き（ネットからの距離）
     /// @spec 30902_fault_spec.md#req-30902-001
@@ -25,6 +38,16 @@ pub struct CourtConfig {
     /// 外壁位置（打ち合い方向、X軸）
     /// @spec 30503_boundary_behavior.md#beh-30503-002
     pub outer_wall_x: f32,
+    /// 壁セグメント一覧（セグメントのない区間はドア等の開口部）
+    /// @spec 30503_boundary_behavior.md#beh-30503-009
+    pub walls: Vec<WallSegmentConfig>,
+    /// 受け手コートでバウンド後に囲いの外へ出たボールを、外でのバウンドまでインプレーとするか
+    /// （パデルの「ポル・トレス／ポル・クアトロ」）
+    /// @spec 30901_point_judgment_spec.md#req-30901-008
+    pub outside_play: bool,
+    /// 囲いの外でプレイヤーが動ける範囲（外壁からの距離、m）
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    pub outside_zone_depth: f32,
 }
 
 impl Default for CourtConfig {
@@ -34,11 +57,156 @@ impl Default for CourtConfig {
             depth: 6.0,
             ceiling_height: 5.0,
             max_jump_height: 5.0,
-            net_height: 1.0,
+            net_height: 0.88,
+            net_post_height: 0.92,
+            net_post_width: 0.1,
+            net_cord_band: 0.05,
             net_x: 0.0,
             service_box_depth: 1.5,
             outer_wall_z: 8.0,
             outer_wall_x: 10.0,
+            walls: default_wall_segments(),
+            outside_play: false,
+            outside_zone_depth: 3.0,
+        }
+    }
+}
+
+/// 壁セグメント
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+/// @data 80101_game_constants.md#court-config
+///
+/// 区間は壁面に沿った座標で指定する（左右壁: X、前後壁: Z）。
+/// 壁面の位置は outer_wall_x / outer_wall_z で決まる。
+#[derive(Deserialize, Clone, Debug)]
+pub struct WallSegmentConfig {
+    /// 所属する壁面（LeftWall / RightWall / BackWallLeft / BackWallRight）
+    pub wall: WallType,
+    /// 区間の始点
+    pub from: f32,
+    /// 区間の終点
+    pub to: f32,
+    /// 壁の高さ（m）
+    pub height: f32,
+    /// 材質
+    pub material: WallMaterial,
+}
+
+/// デフォルトの壁セグメント（outer_wall_x: 10.0, outer_wall_z: 8.0 に対応）
+///
+/// - 後壁: 全幅・高さ4mのガラス
+/// - 左右壁: 後壁から2mは高さ3m、続く2mは高さ2mのガラス、ネット寄りは高さ3mの金網
+/// - 左右壁の 1 < |X| < 2 はドア（開口部）
+fn default_wall_segments() -> Vec<WallSegmentConfig> {
+    let segment = |wall, from, to, height, material| WallSegmentConfig {
+        wall,
+        from,
+        to,
+        height,
+        material,
+    };
+    let mut segments = vec![
+        segment(WallType::BackWallLeft, -8.0, 8.0, 4.0, WallMaterial::Glass),
+        segment(WallType::BackWallRight, -8.0, 8.0, 4.0, WallMaterial::Glass),
+    ];
+    for wall in [WallType::LeftWall, WallType::RightWall] {
+        segments.extend([
+            segment(wall, -10.0, -8.0, 3.0, WallMaterial::Glass),
+            segment(wall, -8.0, -6.0, 2.0, WallMaterial::Glass),
+            segment(wall, -6.0, -2.0, 3.0, WallMaterial::Mesh),
+            segment(wall, -1.0, 1.0, 3.0, WallMaterial::Mesh),
+            segment(wall, 2.0, 6.0, 3.0, WallMaterial::Mesh),
+            segment(wall, 6.0, 8.0, 2.0, WallMaterial::Glass),
+            segment(wall, 8.0, 10.0, 3.0, WallMaterial::Glass),
+        ]);
+    }
+    segments
+}
+
+/// 壁面材質ごとの反射パラメータ
+/// @spec 30402_reflection_spec.md#req-30402-102
+/// @data 80101_game_constants.md#wall-material-config
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WallSurfaceConfig {
+    /// 反発係数（法線方向の速度維持率）
+    pub restitution: f32,
+    /// 摩擦（接線方向の速度減少率、0.0〜1.0）
+    pub friction: f32,
+    /// ランダム偏向の最大角度（度、水平・垂直それぞれ ±）
+    pub max_deflection_deg: f32,
+}
+
+impl Default for WallSurfaceConfig {
+    fn default() -> Self {
+        Self {
+            restitution: 0.8,
+            friction: 0.05,
+            max_deflection_deg: 0.0,
+        }
+    }
+}
+
+/// 壁面材質（ガラス・金網）の反射パラメータ
+/// @spec 30402_reflection_spec.md#req-30402-102
+/// @data 80101_game_constants.md#wall-material-config
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WallMaterialConfig {
+    /// ガラス（バックウォール、サイドウォール後方）
+    pub glass: WallSurfaceConfig,
+    /// 金網（サイドウォールのネット寄り）
+    pub mesh: WallSurfaceConfig,
+}
+
+impl Default for WallMaterialConfig {
+    fn default() -> Self {
+        Self {
+            glass: WallSurfaceConfig::default(),
+            mesh: WallSurfaceConfig {
+                restitution: 0.45,
+                friction: 0.2,
+                max_deflection_deg: 12.0,
+            },
+        }
+    }
+}
+
+impl WallMaterialConfig {
+    /// 材質に対応する反射パラメータを取得
+    #[inline]
+    pub fn surface(&self, material: WallMaterial) -> &WallSurfaceConfig {
+        match material {
+            WallMaterial::Glass => &self.glass,
+            WallMaterial::Mesh => &self.mesh,
+        }
+    }
+}
+
+/// ネット接触時の応答パラメータ
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+/// @data 80101_game_constants.md#net-contact-config
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct NetContactConfig {
+    /// 白帯の最上部に当たったときに相手コート側へ越える確率（0.0〜1.0）
+    /// 白帯の下端に向かって線形に 0 まで下がる
+    pub cord_roll_over_chance: f32,
+    /// 白帯を越えたときの速度維持率
+    pub cord_roll_over_speed_factor: f32,
+    /// ネット本体・白帯で落ちたときの速度維持率
+    pub bounce_factor: f32,
+    /// ポストの反発係数（X方向の速度維持率）
+    pub post_restitution: f32,
+}
+
+impl Default for NetContactConfig {
+    fn default() -> Self {
+        Self {
+            cord_roll_over_chance: 0.5,
+            cord_roll_over_speed_factor: 0.4,
+            bounce_factor: 0.3,
+            post_restitution: 0.6,
         }
     }
 }
@@ -0,0 +1,175 @@
+//! 環境条件・ボール状態パラメータ
+//! @data 80101_game_constants.md#environment-config
+//! @data 80101_game_constants.md#ball-condition-config
+
+use serde::Deserialize;
+
+use crate::core::{
+    air_density_ratio, ball_gauge_pressure_kpa, wear_restitution_scale, BallConditionFactors,
+};
+
+/// 環境条件（会場の標高・気温）
+/// @spec 30401_trajectory_spec.md#req-30401-105
+/// @data 80101_game_constants.md#environment-config
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct EnvironmentConfig {
+    /// 標高（m）
+    pub altitude_m: f32,
+    /// 気温（℃）
+    pub temperature_c: f32,
+}
+
+impl Default for EnvironmentConfig {
+    fn default() -> Self {
+        Self {
+            altitude_m: 0.0,
+            temperature_c: 20.0,
+        }
+    }
+}
+
+/// ボール状態（内圧・摩耗）
+/// @spec 30402_reflection_spec.md#req-30402-104
+/// @data 80101_game_constants.md#ball-condition-config
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BallConditionConfig {
+    /// ボール内圧（ゲージ圧、kPa。海抜0m・基準気温で測った値）
+    pub pressure_kpa: f32,
+    /// ball.bounce_factor 等の反発係数が想定する基準内圧（kPa）
+    pub reference_pressure_kpa: f32,
+    /// 空気抵抗・反発係数の基準気温（℃）
+    pub reference_temperature_c: f32,
+    /// 内圧の変化率に対する反発係数の感度（内圧 +10% で 反発 +感度×10%）
+    pub pressure_restitution_factor: f32,
+    /// ゴムの温度による反発係数の変化（1℃あたり）
+    pub temperature_restitution_factor: f32,
+    /// 1ゲームあたりの摩耗による反発係数の低下率
+    pub wear_per_game: f32,
+    /// 摩耗による反発係数の低下率の上限
+    pub max_wear: f32,
+}
+
+impl Default for BallConditionConfig {
+    fn default() -> Self {
+        Self {
+            pressure_kpa: 70.0,
+            reference_pressure_kpa: 70.0,
+            reference_temperature_c: 20.0,
+            pressure_restitution_factor: 0.3,
+            temperature_restitution_factor: 0.004,
+            wear_per_game: 0.003,
+            max_wear: 0.1,
+        }
+    }
+}
+
+impl BallConditionConfig {
+    /// 環境条件と経過ゲーム数からボール特性の補正倍率を求める
+    /// @spec 30401_trajectory_spec.md#req-30401-105
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    pub fn factors(
+        &self,
+        environment: &EnvironmentConfig,
+        games_played: u32,
+    ) -> BallConditionFactors {
+        let density = air_density_ratio(
+            environment.altitude_m,
+            environment.temperature_c,
+            self.reference_temperature_c,
+        );
+
+        let pressure = ball_gauge_pressure_kpa(
+            self.pressure_kpa,
+            environment.altitude_m,
+            environment.temperature_c,
+            self.reference_temperature_c,
+        );
+        let pressure_change = if self.reference_pressure_kpa > 0.0 {
+            pressure / self.reference_pressure_kpa - 1.0
+        } else {
+            0.0
+        };
+        let temperature_change = environment.temperature_c - self.reference_temperature_c;
+        let condition = 1.0
+            + pressure_change * self.pressure_restitution_factor
+            + temperature_change * self.temperature_restitution_factor;
+        let wear = wear_restitution_scale(games_played, self.wear_per_game, self.max_wear);
+
+        BallConditionFactors {
+            drag_scale: density,
+            restitution_scale: (condition * wear).max(0.0),
+            spin_decay_scale: density,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TST-30404-110: デフォルト（海抜0m・20℃・基準内圧・新品）では補正なし
+    /// @spec 30401_trajectory_spec.md#req-30401-105
+    #[test]
+    fn test_req_30401_105_default_factors_are_identity() {
+        let factors = BallConditionConfig::default().factors(&EnvironmentConfig::default(), 0);
+        assert!((factors.drag_scale - 1.0).abs() < 1e-5);
+        assert!((factors.restitution_scale - 1.0).abs() < 1e-5);
+        assert!((factors.spin_decay_scale - 1.0).abs() < 1e-5);
+    }
+
+    /// TST-30404-110: 高地: 空気抵抗・スピン減衰が小さく、よく弾む
+    /// @spec 30401_trajectory_spec.md#req-30401-105
+    #[test]
+    fn test_req_30401_105_altitude_lowers_drag_and_raises_bounce() {
+        let environment = EnvironmentConfig {
+            altitude_m: 2000.0,
+            ..Default::default()
+        };
+        let factors = BallConditionConfig::default().factors(&environment, 0);
+        assert!(factors.drag_scale < 0.8);
+        assert!(factors.spin_decay_scale < 0.8);
+        assert!(factors.restitution_scale > 1.05);
+    }
+
+    /// TST-30404-111: 低温: 空気は重く、弾まない
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    #[test]
+    fn test_req_30402_104_cold_lowers_bounce() {
+        let environment = EnvironmentConfig {
+            temperature_c: 5.0,
+            ..Default::default()
+        };
+        let factors = BallConditionConfig::default().factors(&environment, 0);
+        assert!(factors.drag_scale > 1.0);
+        assert!(factors.restitution_scale < 0.95);
+    }
+
+    /// TST-30404-111: 減圧した高地用ボールは海抜0mで弾まない
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    #[test]
+    fn test_req_30402_104_low_pressure_ball_lowers_bounce() {
+        let config = BallConditionConfig {
+            pressure_kpa: 50.0,
+            ..Default::default()
+        };
+        let factors = config.factors(&EnvironmentConfig::default(), 0);
+        assert!(factors.restitution_scale < 0.95);
+        assert!((factors.drag_scale - 1.0).abs() < 1e-5);
+    }
+
+    /// TST-30404-111: 摩耗: ゲームが進むほど弾まなくなり、上限で止まる
+    /// @spec 30402_reflection_spec.md#req-30402-104
+    #[test]
+    fn test_req_30402_104_wear_lowers_bounce_over_games() {
+        let config = BallConditionConfig::default();
+        let environment = EnvironmentConfig::default();
+        let fresh = config.factors(&environment, 0).restitution_scale;
+        let worn = config.factors(&environment, 12).restitution_scale;
+        let capped = config.factors(&environment, 1000).restitution_scale;
+        assert!(worn < fresh);
+        assert!((capped - (1.0 - config.max_wear)).abs() < 1e-5);
+        assert!((config.factors(&environment, 12).drag_scale - 1.0).abs() < 1e-5);
+    }
+}
@@ -55,6 +55,15 @@ pub struct InputKeysConfig {
     pub jump: KeyCode,
     /// ショットキー（デフォルト: V）
     pub shot: KeyCode,
+    /// ロブキー（デフォルト: C、ショットキーと同時に押す）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub lob: KeyCode,
+    /// ドロップショットキー（デフォルト: X、ショットキーと同時に押す）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub drop_shot: KeyCode,
+    /// 直前のポイント取り消しキー（デフォルト: Backspace）
+    /// @spec 30704_score_log_spec.md#req-30704-002
+    pub undo_point: KeyCode,
 }
 
 impl Default for InputKeysConfig {
@@ -70,6 +79,9 @@ impl Default for InputKeysConfig {
             move_right_alt: KeyCode::ArrowRight,
             jump: KeyCode::KeyB,
             shot: KeyCode::KeyV,
+            lob: KeyCode::KeyC,
+            drop_shot: KeyCode::KeyX,
+            undo_point: KeyCode::Backspace,
         }
     }
 }
@@ -84,6 +96,12 @@ pub struct GamepadButtonsConfig {
     pub jump: GamepadButton,
     /// ショットボタン（デフォルト: East = B on Xbox, ○ on PlayStation）
     pub shot: GamepadButton,
+    /// ロブボタン（デフォルト: North = Y on Xbox, △ on PlayStation）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub lob: GamepadButton,
+    /// ドロップショットボタン（デフォルト: RightTrigger = RB on Xbox, R1 on PlayStation）
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    pub drop_shot: GamepadButton,
     /// スティックデッドゾーン（入力が無視される範囲）
     pub stick_deadzone: f32,
 }
@@ -93,6 +111,8 @@ impl Default for GamepadButtonsConfig {
         Self {
             jump: GamepadButton::South,
             shot: GamepadButton::East,
+            lob: GamepadButton::North,
+            drop_shot: GamepadButton::RightTrigger,
             stick_deadzone: 0.1,
         }
     }
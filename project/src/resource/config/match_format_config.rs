@@ -0,0 +1,106 @@
+//! マッチ形式プリセット
+//! @data 80703_set_config.md#match_format
+//! @spec 30703_set_spec.md#req-30703-056
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use super::{DeuceMode, ScoringConfig};
+
+/// マッチ形式設定（名前付きプリセットと選択中のプリセット）
+/// @data 80703_set_config.md#match_format
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct MatchFormatConfig {
+    /// 選択中のプリセット名（None = ScoringConfig をそのまま使用）
+    pub selected: Option<String>,
+    /// 名前付きプリセット（名前順に列挙できるよう BTreeMap で保持）
+    pub presets: BTreeMap<String, MatchFormatPreset>,
+}
+
+impl MatchFormatConfig {
+    /// 登録済みプリセット名の一覧（名前順）
+    pub fn names(&self) -> Vec<&str> {
+        self.presets.keys().map(String::as_str).collect()
+    }
+
+    /// 名前でプリセットを取得（未登録ならエラー）
+    pub fn get(&self, name: &str) -> Result<&MatchFormatPreset, String> {
+        self.presets.get(name).ok_or_else(|| {
+            format!(
+                "Unknown match format '{}' (available: {})",
+                name,
+                self.names().join(", ")
+            )
+        })
+    }
+}
+
+/// マッチ形式プリセット
+/// @data 80703_set_config.md#match_format
+///
+/// ScoringConfig のうちマッチ形式に関わる項目だけを上書きする。
+/// ポイント進行値やエンドチェンジ設定は ScoringConfig 側の値を維持する。
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct MatchFormatPreset {
+    /// 形式の説明（レポート表示用）
+    pub description: String,
+    /// セット獲得に必要なゲーム数
+    pub games_to_win_set: u32,
+    /// セット獲得に必要なゲーム差
+    pub set_win_margin: u32,
+    /// マッチ勝利に必要なセット数
+    pub sets_to_win_match: u32,
+    /// タイブレークを行うか
+    pub tiebreak_enabled: bool,
+    /// タイブレークを開始するゲーム数（None = games_to_win_set）
+    pub tiebreak_at_games: Option<u32>,
+    /// タイブレークの勝利ポイント数
+    pub tiebreak_points: u32,
+    /// 最終セットをスーパータイブレークで代替するか
+    pub final_set_super_tiebreak: bool,
+    /// スーパータイブレークの勝利ポイント数
+    pub super_tiebreak_points: u32,
+    /// 40-40 到達時の進行方式
+    pub deuce_mode: DeuceMode,
+    /// 試合時間の上限（秒、None = 時間制限なし）
+    pub time_limit_secs: Option<f32>,
+}
+
+impl Default for MatchFormatPreset {
+    fn default() -> Self {
+        let scoring = ScoringConfig::default();
+        Self {
+            description: String::new(),
+            games_to_win_set: scoring.games_to_win_set,
+            set_win_margin: scoring.set_win_margin,
+            sets_to_win_match: scoring.sets_to_win_match,
+            tiebreak_enabled: scoring.tiebreak_enabled,
+            tiebreak_at_games: scoring.tiebreak_at_games,
+            tiebreak_points: scoring.tiebreak_points,
+            final_set_super_tiebreak: scoring.final_set_super_tiebreak,
+            super_tiebreak_points: scoring.super_tiebreak_points,
+            deuce_mode: scoring.deuce_mode,
+            time_limit_secs: scoring.time_limit_secs,
+        }
+    }
+}
+
+impl MatchFormatPreset {
+    /// プリセットの値を ScoringConfig に反映
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub fn apply_to(&self, scoring: &mut ScoringConfig) {
+        scoring.games_to_win_set = self.games_to_win_set;
+        scoring.set_win_margin = self.set_win_margin;
+        scoring.sets_to_win_match = self.sets_to_win_match;
+        scoring.tiebreak_enabled = self.tiebreak_enabled;
+        scoring.tiebreak_at_games = self.tiebreak_at_games;
+        scoring.tiebreak_points = self.tiebreak_points;
+        scoring.final_set_super_tiebreak = self.final_set_super_tiebreak;
+        scoring.super_tiebreak_points = self.super_tiebreak_points;
+        scoring.deuce_mode = self.deuce_mode;
+        scoring.time_limit_secs = self.time_limit_secs;
+    }
+}
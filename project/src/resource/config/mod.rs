@@ -6,30 +6,42 @@ mod ball_config;
 mod character_config;
 mod collision_config;
 mod court_config;
+mod environment_config;
 mod input_config;
+mod match_format_config;
 mod physics_config;
 mod player_config;
 mod scoring_config;
 mod serve_config;
 mod shot_config;
+mod team_config;
 mod visual_config;
+mod wind_config;
 
 // Re-exports
 pub use ai_config::AiConfig;
 pub use ball_config::BallConfig;
 pub use character_config::CharacterConfig;
-pub use collision_config::{CollisionConfig, KnockbackConfig};
-pub use court_config::CourtConfig;
+pub use collision_config::{BodyHitRule, CollisionConfig, KnockbackConfig};
+pub use court_config::{CourtConfig, NetContactConfig, WallMaterialConfig};
+// WallSegmentConfig はテスト用設定の構築でのみ参照される（メインバイナリでは未使用）
+#[allow(unused_imports)]
+pub use court_config::WallSegmentConfig;
+pub use environment_config::{BallConditionConfig, EnvironmentConfig};
 pub use input_config::{GamepadButtonsConfig, InputConfig, InputKeysConfig};
+pub use match_format_config::MatchFormatConfig;
 pub use physics_config::{PhysicsConfig, SpinPhysicsConfig};
 pub use player_config::{PlayerConfig, PlayerVisualConfig};
-pub use scoring_config::ScoringConfig;
+pub use scoring_config::{DeuceMode, ScoringConfig};
 pub use serve_config::{ServeConfig, ServeSide};
 pub use shot_config::{
     ApproachCurvePoint, DistanceCurvePoint, HeightCurvePoint, RacketSwingConfig,
-    ShotAttributesConfig, ShotConfig, SpinCurvePoint, TimingCurvePoint, TrajectoryConfig,
+    ShotAttributesConfig, ShotConfig, ShotTypeConfig, ShotTypeParams, SpinCurvePoint,
+    TimingCurvePoint, TrajectoryConfig,
 };
+pub use team_config::TeamConfig;
 pub use visual_config::{ShadowConfig, VisualFeedbackConfig};
+pub use wind_config::WindConfig;
 
 use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext},
@@ -39,7 +51,7 @@ use serde::Deserialize;
 
 /// ゲーム全体の設定
 /// @data 80101_game_constants.md#gameconfig-構造
-#[derive(Asset, TypePath, Resource, Deserialize, Clone, Debug)]
+#[derive(Asset, TypePath, Resource, Deserialize, Clone, Debug, Default)]
 pub struct GameConfig {
     pub physics: PhysicsConfig,
     pub court: CourtConfig,
@@ -84,13 +96,66 @@ pub struct GameConfig {
     /// @spec 30605_trajectory_calculation_spec.md
     #[serde(default)]
     pub trajectory: TrajectoryConfig,
+    /// チーム構成（シングルス / ダブルス）
+    /// @spec 30105_doubles_spec.md
+    #[serde(default)]
+    pub team: TeamConfig,
+    /// マッチ形式プリセット（selected のプリセットをロード時に scoring へ反映）
+    /// @data 80703_set_config.md#match_format
+    #[serde(default)]
+    pub match_format: MatchFormatConfig,
+    /// 壁面材質（ガラス・金網）の反射パラメータ
+    /// @data 80101_game_constants.md#wall-material-config
+    #[serde(default)]
+    pub wall_material: WallMaterialConfig,
+    /// ネット接触時の応答（白帯・ポスト）
+    /// @data 80101_game_constants.md#net-contact-config
+    #[serde(default)]
+    pub net_contact: NetContactConfig,
+    /// 環境条件（標高・気温）
+    /// @data 80101_game_constants.md#environment-config
+    #[serde(default)]
+    pub environment: EnvironmentConfig,
+    /// ボール状態（内圧・摩耗）
+    /// @data 80101_game_constants.md#ball-condition-config
+    #[serde(default)]
+    pub ball_condition: BallConditionConfig,
+    /// 風（屋外コート）
+    /// @data 80101_game_constants.md#wind-config
+    #[serde(default)]
+    pub wind: WindConfig,
+}
+
+impl GameConfig {
+    /// 選択中のマッチ形式プリセットを scoring へ反映
+    /// @spec 30703_set_spec.md#req-30703-056
+    ///
+    /// selected が None の場合は何もしない。未登録の名前はエラー。
+    pub fn apply_match_format(&mut self) -> Result<(), String> {
+        match self.match_format.selected.clone() {
+            Some(name) => self.select_match_format(&name),
+            None => Ok(()),
+        }
+    }
+
+    /// マッチ形式プリセットを名前で選択して scoring へ反映
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub fn select_match_format(&mut self, name: &str) -> Result<(), String> {
+        let preset = self.match_format.get(name)?.clone();
+        self.match_format.selected = Some(name.to_string());
+        preset.apply_to(&mut self.scoring);
+        Ok(())
+    }
 }
 
 /// RONファイルからGameConfigをロード
 pub fn load_game_config(path: &str) -> Result<GameConfig, String> {
     let config_str =
         std::fs::read_to_string(path).map_err(|e| format!("Failed to read config file: {}", e))?;
-    ron::from_str(&config_str).map_err(|e| format!("Failed to parse config: {}", e))
+    let mut config: GameConfig =
+        ron::from_str(&config_str).map_err(|e| format!("Failed to parse config: {}", e))?;
+    config.apply_match_format()?;
+    Ok(config)
 }
 
 // ============================================================================
@@ -113,6 +178,8 @@ pub enum GameConfigLoaderError {
     Io(#[from] std::io::Error),
     #[error("Failed to parse RON: {0}")]
     Ron(#[from] ron::error::SpannedError),
+    #[error("Invalid match format: {0}")]
+    MatchFormat(String),
 }
 
 impl AssetLoader for GameConfigLoader {
@@ -128,7 +195,10 @@ impl AssetLoader for GameConfigLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
-        let config: GameConfig = ron::de::from_bytes(&bytes)?;
+        let mut config: GameConfig = ron::de::from_bytes(&bytes)?;
+        config
+            .apply_match_format()
+            .map_err(GameConfigLoaderError::MatchFormat)?;
         Ok(config)
     }
 
@@ -10,6 +10,12 @@ use serde::Deserialize;
 pub struct PhysicsConfig {
     pub gravity: f32,
     pub max_fall_speed: f32,
+    /// ゲームロジックのティックレート（Hz、起動時のみ反映）
+    /// @spec 77100_headless_sim.md#req-77100-001
+    pub tick_rate: f32,
+    /// ボール軌道予測の時間（秒）
+    /// @spec 30401_trajectory_spec.md#req-30401-106
+    pub prediction_horizon: f32,
 }
 
 impl Default for PhysicsConfig {
@@ -17,6 +23,8 @@ impl Default for PhysicsConfig {
         Self {
             gravity: -9.8,
             max_fall_speed: -20.0,
+            tick_rate: 60.0,
+            prediction_horizon: 4.0,
         }
     }
 }
@@ -25,7 +33,10 @@ impl Default for PhysicsConfig {
 /// @spec 30401_trajectory_spec.md#req-30401-100
 /// @spec 30401_trajectory_spec.md#req-30401-101
 /// @spec 30401_trajectory_spec.md#req-30401-102
+/// @spec 30401_trajectory_spec.md#req-30401-104
 /// @spec 30402_reflection_spec.md#req-30402-100
+/// @spec 30402_reflection_spec.md#req-30402-101
+/// @spec 30402_reflection_spec.md#req-30402-103
 /// @data 80101_game_constants.md#spin-physics-config
 #[derive(Deserialize, Clone, Debug)]
 #[serde(default)]
@@ -42,6 +53,16 @@ pub struct SpinPhysicsConfig {
     pub spin_drag_factor: f32,
     /// スピン時間減衰率（1秒あたり）
     pub spin_decay_rate: f32,
+    /// サイドスピンによるマグヌス効果の係数（横方向加速度 = 係数 × サイドスピン × 速度）
+    pub magnus_side_factor: f32,
+    /// 地面バウンド時に進行方向と直交するスピン成分が与える横方向の速度変化（m/s、スピン1.0あたり）
+    pub bounce_spin_lateral_factor: f32,
+    /// 壁反射時にスピン軸が与える接線方向の速度変化（m/s、スピン1.0あたり）
+    pub wall_spin_tangential_factor: f32,
+    /// 壁反射時の法線方向（跳ね返り）へのスピンの影響度（壁に対するトップスピンで弱まる）
+    pub wall_spin_normal_factor: f32,
+    /// 壁接触で接線軸まわりのスピンが反転する割合（0.0: 維持、0.5: 消失、1.0: 完全反転）
+    pub wall_spin_reversal: f32,
 }
 
 impl Default for SpinPhysicsConfig {
@@ -53,6 +74,11 @@ impl Default for SpinPhysicsConfig {
             base_air_drag: 0.0,
             spin_drag_factor: 0.3,
             spin_decay_rate: 0.5,
+            magnus_side_factor: 0.15,
+            bounce_spin_lateral_factor: 1.5,
+            wall_spin_tangential_factor: 1.5,
+            wall_spin_normal_factor: 0.2,
+            wall_spin_reversal: 0.3,
         }
     }
 }
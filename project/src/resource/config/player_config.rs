@@ -46,16 +46,37 @@ pub struct PlayerVisualConfig {
     pub player1_color: (f32, f32, f32),
     /// Right側（画面右）の色（RGB）
     pub player2_color: (f32, f32, f32),
+    /// Left側パートナー（ダブルス、プレイヤー3）の色（RGB）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub player3_color: (f32, f32, f32),
+    /// Right側パートナー（ダブルス、プレイヤー4）の色（RGB）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub player4_color: (f32, f32, f32),
     /// プレイヤーのサイズ（幅, 高さ）ピクセル
     #[allow(dead_code)]
     pub size: (f32, f32),
 }
 
+impl PlayerVisualConfig {
+    /// プレイヤーIDに対応する色（RGB）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub fn color_for(&self, player_id: u8) -> (f32, f32, f32) {
+        match player_id {
+            1 => self.player1_color,
+            2 => self.player2_color,
+            3 => self.player3_color,
+            _ => self.player4_color,
+        }
+    }
+}
+
 impl Default for PlayerVisualConfig {
     fn default() -> Self {
         Self {
             player1_color: (0.2, 0.4, 0.8),
             player2_color: (0.8, 0.2, 0.2),
+            player3_color: (0.2, 0.6, 0.9),
+            player4_color: (0.9, 0.4, 0.2),
             size: (40.0, 60.0),
         }
     }
@@ -18,11 +18,44 @@ pub struct ScoringConfig {
     /// 勝利に必要なゲーム数（6ゲーム先取でセット獲得）
     /// @data 80703_set_config.md#games_to_win_set
     pub games_to_win_set: u32,
+    /// セット獲得に必要なゲーム差（2ゲーム差）
+    /// @data 80703_set_config.md#set_win_margin
+    pub set_win_margin: u32,
     /// 勝利に必要なセット数（1セット先取でマッチ勝利）
     /// @data 80703_set_config.md#sets_to_win_match
     pub sets_to_win_match: u32,
+    /// games_to_win_set 同数（6-6）でタイブレークを行うか
+    /// @data 80703_set_config.md#tiebreak_config
+    pub tiebreak_enabled: bool,
+    /// タイブレークを開始するゲーム数（None = games_to_win_set、プロセットは 8、Fast4 は 3）
+    /// @data 80703_set_config.md#tiebreak_config
+    pub tiebreak_at_games: Option<u32>,
+    /// タイブレークの勝利ポイント数（7ポイント先取、2ポイント差）
+    /// @data 80703_set_config.md#tiebreak_config
+    pub tiebreak_points: u32,
+    /// 最終セットをスーパータイブレークで代替するか
+    /// @data 80703_set_config.md#super_tiebreak_config
+    pub final_set_super_tiebreak: bool,
+    /// スーパータイブレークの勝利ポイント数（10ポイント先取、2ポイント差）
+    /// @data 80703_set_config.md#super_tiebreak_config
+    pub super_tiebreak_points: u32,
     /// ポイント終了後の待機時間（秒）
     pub point_end_delay: f32,
+    /// エンドチェンジ（奇数ゲーム終了後・タイブレーク中の規定ポイントごと）を行うか
+    /// @data 80703_set_config.md#change_ends_config
+    pub change_ends_enabled: bool,
+    /// タイブレーク中にエンドチェンジするポイント間隔（6ポイントごと）
+    /// @data 80703_set_config.md#change_ends_config
+    pub tiebreak_change_ends_interval: u32,
+    /// エンドチェンジ時の休憩時間（秒）
+    /// @data 80703_set_config.md#change_ends_config
+    pub changeover_break_secs: f32,
+    /// 40-40 到達時の進行方式
+    /// @data 80701_point_config.md#deuce_config
+    pub deuce_mode: DeuceMode,
+    /// 試合時間の上限（秒、None = 時間制限なし）
+    /// @data 80703_set_config.md#time_limit_config
+    pub time_limit_secs: Option<f32>,
 }
 
 impl Default for ScoringConfig {
@@ -30,8 +63,39 @@ impl Default for ScoringConfig {
         Self {
             point_values: vec![0, 15, 30, 40],
             games_to_win_set: 6,
+            set_win_margin: 2,
             sets_to_win_match: 1,
+            tiebreak_enabled: true,
+            tiebreak_at_games: None,
+            tiebreak_points: 7,
+            final_set_super_tiebreak: false,
+            super_tiebreak_points: 10,
             point_end_delay: 1.5,
+            change_ends_enabled: true,
+            tiebreak_change_ends_interval: 6,
+            changeover_break_secs: 3.0,
+            deuce_mode: DeuceMode::default(),
+            time_limit_secs: None,
         }
     }
 }
+
+impl ScoringConfig {
+    /// タイブレークを開始するゲーム数
+    /// @spec 30703_set_spec.md#req-30703-057
+    #[inline]
+    pub fn tiebreak_trigger_games(&self) -> u32 {
+        self.tiebreak_at_games.unwrap_or(self.games_to_win_set)
+    }
+}
+
+/// 40-40（デュース）時の進行方式
+/// @spec 30701_point_spec.md#req-30701-050
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum DeuceMode {
+    /// アドバンテージ方式（2ポイント差がつくまで継続）
+    #[default]
+    Advantage,
+    /// ゴールデンポイント方式（40-40 の次のポイントでゲーム決着）
+    GoldenPoint,
+}
@@ -2,7 +2,7 @@
 //! @spec 30102_serve_spec.md#req-30102-060
 //! @spec 30102_serve_spec.md#req-30102-080
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// サーブパラメータ
 /// @spec 30102_serve_spec.md#req-30102-060
@@ -62,6 +62,19 @@ pub struct ServeConfig {
     /// Right側のベースライン位置
     /// @spec 30102_serve_spec.md#req-30102-086
     pub serve_baseline_x_p2: f32,
+    /// トスしたボールを一度バウンドさせてから打つ必要があるか（パデルルール）
+    /// @spec 30902_fault_spec.md#req-30902-052
+    pub require_bounce_before_hit: bool,
+    /// 合法な打点の上限高さ＝腰の高さ（m）
+    /// @spec 30902_fault_spec.md#req-30902-053
+    pub max_contact_height: f32,
+    /// フット・フォルト判定の許容量（m、サービスライン・センターラインからのはみ出し）
+    /// @spec 30902_fault_spec.md#req-30902-050
+    pub foot_fault_tolerance: f32,
+    /// 同一サーブで許容する連続レット回数（0 で上限なし）
+    /// 上限に達した後のネットインはレットにせずインプレーとする
+    /// @spec 30902_fault_spec.md#req-30902-056
+    pub max_consecutive_lets: u32,
 }
 
 impl Default for ServeConfig {
@@ -73,27 +86,31 @@ impl Default for ServeConfig {
             p1_default_direction_x: 1.0,
             p2_default_direction_x: -1.0,
             toss_start_offset_y: 1.0,
-            toss_velocity_y: 3.5,
-            toss_velocity_min_y: 2.8,
-            toss_velocity_max_y: 4.8,
+            toss_velocity_y: 2.5,
+            toss_velocity_min_y: 1.5,
+            toss_velocity_max_y: 3.5,
             toss_hold_max_secs: 0.3,
             toss_depth_shift: 0.45,
             toss_launch_angle_bonus_deg: 6.0,
             practice_infinite_mode: false,
-            toss_timeout: 3.0,
-            hit_height_min: 1.8,
-            hit_height_max: 2.7,
-            hit_height_optimal: 2.2,
+            toss_timeout: 4.0,
+            hit_height_min: 0.3,
+            hit_height_max: 1.6,
+            hit_height_optimal: 0.6,
             ai_hit_tolerance: 0.1,
             serve_baseline_x_p1: -8.5,
             serve_baseline_x_p2: 8.5,
+            require_bounce_before_hit: true,
+            max_contact_height: 1.0,
+            foot_fault_tolerance: 0.0,
+            max_consecutive_lets: 3,
         }
     }
 }
 
 /// サーブサイド
 /// @spec 30903_serve_authority_spec.md#req-30903-003
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum ServeSide {
     /// デュースサイド（右側）- ポイント合計が偶数
     #[default]
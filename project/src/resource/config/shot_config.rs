@@ -5,6 +5,8 @@
 
 use serde::Deserialize;
 
+use crate::core::ShotType;
+
 /// ショットシステムパラメータ
 /// @data 80101_game_constants.md#shot-config
 #[derive(Deserialize, Clone, Debug)]
@@ -31,6 +33,25 @@ pub struct ShotConfig {
     /// @spec 30606_racket_contact_spec.md
     #[serde(default)]
     pub racket_swing: RacketSwingConfig,
+    /// ショット種類の選択条件と種類別パラメータ
+    /// @spec 30607_shot_type_spec.md
+    #[serde(default)]
+    pub shot_types: ShotTypeConfig,
+}
+
+impl Default for ShotConfig {
+    fn default() -> Self {
+        Self {
+            max_distance: default_max_distance(),
+            max_height_diff: default_max_height_diff(),
+            cooldown_time: default_cooldown_time(),
+            normal_shot_angle: default_normal_shot_angle(),
+            jump_shot_angle: default_jump_shot_angle(),
+            jump_threshold: default_jump_threshold(),
+            racket_swing: RacketSwingConfig::default(),
+            shot_types: ShotTypeConfig::default(),
+        }
+    }
 }
 
 fn default_max_distance() -> f32 {
@@ -129,6 +150,194 @@ fn default_contact_radius() -> f32 {
     0.28
 }
 
+/// ショット種類の選択条件と種類別パラメータ
+/// @spec 30607_shot_type_spec.md
+#[derive(Deserialize, Clone, Debug)]
+pub struct ShotTypeConfig {
+    /// これ以上の打点高さ（m）をオーバーヘッドとする
+    /// @spec 30607_shot_type_spec.md#req-30607-002
+    #[serde(default = "default_overhead_height")]
+    pub overhead_height: f32,
+    /// 方向入力を種類選択の操作とみなす入力量の閾値
+    /// @spec 30607_shot_type_spec.md#req-30607-002
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    #[serde(default = "default_shot_type_input_threshold")]
+    pub input_threshold: f32,
+    /// ドロップショットになるネットからの最大距離（m）。これより後方ならチキータ
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    #[serde(default = "default_drop_shot_max_net_distance")]
+    pub drop_shot_max_net_distance: f32,
+    #[serde(default)]
+    pub drive: ShotTypeParams,
+    #[serde(default)]
+    pub volley: ShotTypeParams,
+    #[serde(default = "default_lob_params")]
+    pub lob: ShotTypeParams,
+    #[serde(default = "default_smash_params")]
+    pub smash: ShotTypeParams,
+    #[serde(default = "default_bandeja_params")]
+    pub bandeja: ShotTypeParams,
+    #[serde(default = "default_vibora_params")]
+    pub vibora: ShotTypeParams,
+    #[serde(default = "default_drop_shot_params")]
+    pub drop_shot: ShotTypeParams,
+    #[serde(default = "default_chiquita_params")]
+    pub chiquita: ShotTypeParams,
+}
+
+impl Default for ShotTypeConfig {
+    fn default() -> Self {
+        Self {
+            overhead_height: default_overhead_height(),
+            input_threshold: default_shot_type_input_threshold(),
+            drop_shot_max_net_distance: default_drop_shot_max_net_distance(),
+            drive: ShotTypeParams::default(),
+            volley: ShotTypeParams::default(),
+            lob: default_lob_params(),
+            smash: default_smash_params(),
+            bandeja: default_bandeja_params(),
+            vibora: default_vibora_params(),
+            drop_shot: default_drop_shot_params(),
+            chiquita: default_chiquita_params(),
+        }
+    }
+}
+
+impl ShotTypeConfig {
+    /// ショット種類のパラメータを取得
+    /// @spec 30607_shot_type_spec.md#req-30607-004
+    ///
+    /// サーブは専用の弾道計算（REQ-30605-050）を使うため、ドライブの値を返す。
+    pub fn params(&self, shot_type: ShotType) -> &ShotTypeParams {
+        match shot_type {
+            ShotType::Drive | ShotType::Serve => &self.drive,
+            ShotType::Volley => &self.volley,
+            ShotType::Lob => &self.lob,
+            ShotType::Smash => &self.smash,
+            ShotType::Bandeja => &self.bandeja,
+            ShotType::Vibora => &self.vibora,
+            ShotType::DropShot => &self.drop_shot,
+            ShotType::Chiquita => &self.chiquita,
+        }
+    }
+}
+
+/// ショット種類別パラメータ
+/// @spec 30607_shot_type_spec.md#req-30607-004
+/// @spec 30607_shot_type_spec.md#req-30607-006
+#[derive(Deserialize, Clone, Debug)]
+pub struct ShotTypeParams {
+    /// 威力倍率（ショット属性の威力に掛ける）
+    #[serde(default = "default_power_scale")]
+    pub power_scale: f32,
+    /// スピン補正（ショット属性のスピンに加算し、-1.0〜+1.0 に収める）
+    #[serde(default)]
+    pub spin_offset: f32,
+    /// サイドスピン量の下限
+    #[serde(default)]
+    pub min_sidespin: f32,
+    /// 着地深さ（ネットからの距離、m）。None なら入力で決まる深さ（REQ-30605-010）
+    #[serde(default)]
+    pub landing_depth: Option<f32>,
+    /// 最小発射角度（度）。これより低い弾道は同じ着地点のまま角度を上げる
+    #[serde(default = "default_shot_type_min_launch_angle")]
+    pub min_launch_angle: f32,
+    /// 壁狙いの高さ（m）。指定されていれば床バウンド後に相手側の後壁のこの高さへ当てる
+    /// @spec 30607_shot_type_spec.md#req-30607-007
+    #[serde(default)]
+    pub wall_target_height: Option<f32>,
+}
+
+impl Default for ShotTypeParams {
+    fn default() -> Self {
+        Self {
+            power_scale: default_power_scale(),
+            spin_offset: 0.0,
+            min_sidespin: 0.0,
+            landing_depth: None,
+            min_launch_angle: default_shot_type_min_launch_angle(),
+            wall_target_height: None,
+        }
+    }
+}
+
+fn default_overhead_height() -> f32 {
+    1.9
+}
+fn default_shot_type_input_threshold() -> f32 {
+    0.5
+}
+fn default_drop_shot_max_net_distance() -> f32 {
+    4.0
+}
+fn default_power_scale() -> f32 {
+    1.0
+}
+fn default_shot_type_min_launch_angle() -> f32 {
+    -90.0 // 制限なし
+}
+
+/// ロブ: 高い弾道でベースライン際へ落とし、後壁の低い位置で殺す
+fn default_lob_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 0.8,
+        spin_offset: 0.2,
+        landing_depth: Some(6.5),
+        min_launch_angle: 35.0,
+        wall_target_height: Some(1.0),
+        ..Default::default()
+    }
+}
+
+/// スマッシュ: 威力重視のフラット〜トップスピン
+fn default_smash_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 1.3,
+        spin_offset: 0.2,
+        ..Default::default()
+    }
+}
+
+/// バンデハ: スライスで深く返し、ネットポジションを保つ
+fn default_bandeja_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 0.8,
+        spin_offset: -0.5,
+        landing_depth: Some(6.0),
+        ..Default::default()
+    }
+}
+
+/// ビボラ: スライス＋サイドスピンで横壁へ逃がす
+fn default_vibora_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 1.0,
+        spin_offset: -0.3,
+        min_sidespin: 0.6,
+        ..Default::default()
+    }
+}
+
+/// ドロップショット: 逆回転でネット際に落とす
+fn default_drop_shot_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 0.5,
+        spin_offset: -0.6,
+        landing_depth: Some(1.5),
+        ..Default::default()
+    }
+}
+
+/// チキータ: 遅く低いボールをネット前の足元へ
+fn default_chiquita_params() -> ShotTypeParams {
+    ShotTypeParams {
+        power_scale: 0.6,
+        spin_offset: 0.3,
+        landing_depth: Some(2.5),
+        ..Default::default()
+    }
+}
+
 /// 弾道計算パラメータ
 /// @spec 30605_trajectory_calculation_spec.md
 #[derive(Deserialize, Clone, Debug)]
@@ -177,6 +386,10 @@ pub struct TrajectoryConfig {
     #[allow(dead_code)]
     #[serde(default = "default_max_landing_deviation")]
     pub max_landing_deviation: f32,
+    /// 風による流れを見込んで狙う地点をずらすか
+    /// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+    #[serde(default = "default_compensate_wind")]
+    pub compensate_wind: bool,
 }
 
 impl Default for TrajectoryConfig {
@@ -192,6 +405,7 @@ impl Default for TrajectoryConfig {
             distance_speed_min: default_distance_speed_min(),
             distance_speed_max: default_distance_speed_max(),
             max_landing_deviation: default_max_landing_deviation(),
+            compensate_wind: default_compensate_wind(),
         }
     }
 }
@@ -226,6 +440,9 @@ fn default_distance_speed_max() -> f32 {
 fn default_max_landing_deviation() -> f32 {
     1.0
 }
+fn default_compensate_wind() -> bool {
+    true
+}
 
 /// ショット属性パラメータ
 /// @spec 30604_shot_attributes_spec.md
@@ -318,6 +535,10 @@ pub struct ShotAttributesConfig {
     /// @spec 30604_shot_attributes_spec.md#req-30604-066
     #[serde(default = "default_spin_timing_curve")]
     pub spin_timing_curve: Vec<SpinCurvePoint>,
+    /// サイドスピンカーブ（高さ）[(高さ, サイドスピン量)]
+    /// @spec 30604_shot_attributes_spec.md#req-30604-071
+    #[serde(default = "default_sidespin_height_curve")]
+    pub sidespin_height_curve: Vec<SpinCurvePoint>,
 }
 
 impl Default for ShotAttributesConfig {
@@ -341,6 +562,7 @@ impl Default for ShotAttributesConfig {
             volley_factors: default_volley_factors(),
             spin_height_curve: default_spin_height_curve(),
             spin_timing_curve: default_spin_timing_curve(),
+            sidespin_height_curve: default_sidespin_height_curve(),
         }
     }
 }
@@ -625,3 +847,24 @@ fn default_spin_timing_curve() -> Vec<SpinCurvePoint> {
         },
     ]
 }
+
+/// サイドスピンカーブ（高さ）のデフォルト値
+/// @spec 30604_shot_attributes_spec.md#req-30604-071
+///
+/// 肩より高い打点（ビボラ等のオーバーヘッド）ほどサイドスピンがかかる
+fn default_sidespin_height_curve() -> Vec<SpinCurvePoint> {
+    vec![
+        SpinCurvePoint {
+            value: 1.4,
+            spin_factor: 0.0,
+        },
+        SpinCurvePoint {
+            value: 1.8,
+            spin_factor: 0.3,
+        },
+        SpinCurvePoint {
+            value: 2.4,
+            spin_factor: 0.6,
+        },
+    ]
+}
@@ -0,0 +1,47 @@
+//! チーム構成パラメータ
+//! @data 80101_game_constants.md#team-config
+
+use serde::Deserialize;
+
+/// チーム構成パラメータ（シングルス / ダブルス）
+/// @data 80101_game_constants.md#team-config
+/// @spec 30105_doubles_spec.md
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct TeamConfig {
+    /// 1チームあたりの人数（1 = シングルス、2 = ダブルス）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub players_per_team: u32,
+    /// ネット前に立つプレイヤー（サーバー/レシーバーのパートナー）のネットからの距離（m）
+    /// @spec 30105_doubles_spec.md#req-30105-004
+    pub net_player_distance: f32,
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        Self {
+            players_per_team: 1,
+            net_player_distance: 2.5,
+        }
+    }
+}
+
+impl TeamConfig {
+    /// ダブルスかどうか
+    #[inline]
+    pub fn is_doubles(&self) -> bool {
+        self.players_per_team >= 2
+    }
+
+    /// 1チームあたりの人数（1〜2に制限）
+    #[inline]
+    pub fn team_size(&self) -> usize {
+        self.players_per_team.clamp(1, 2) as usize
+    }
+
+    /// コート上の総プレイヤー数
+    #[inline]
+    pub fn total_players(&self) -> usize {
+        self.team_size() * 2
+    }
+}
@@ -0,0 +1,49 @@
+//! 風パラメータ
+//! @data 80101_game_constants.md#wind-config
+
+use serde::Deserialize;
+
+/// 風パラメータ（屋外コート）
+/// @spec 30401_trajectory_spec.md#req-30401-051
+/// @data 80101_game_constants.md#wind-config
+///
+/// 風速はすべて基準高さ（reference_height_m）での値。
+/// base・gust_strength がともに 0 の場合は無風（乱数も消費しない）。
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WindConfig {
+    /// 基本風速のX成分（m/s、+X 向きが正）
+    pub base_x: f32,
+    /// 基本風速のZ成分（m/s、+Z 向きが正）
+    pub base_z: f32,
+    /// 突風の強さ（m/s、水平各軸 ±この値以内で抽選）
+    pub gust_strength: f32,
+    /// 突風の目標値を抽選し直す間隔（秒）
+    pub gust_interval_secs: f32,
+    /// 突風が目標値に近づく時定数（秒）
+    pub gust_smoothing_secs: f32,
+    /// 風速の基準高さ（m）
+    pub reference_height_m: f32,
+    /// 高さによる風の強さのべき指数（0 で高さによらず一定）
+    pub height_exponent: f32,
+    /// 高さ補正の最低高さ（m、これより低い位置はこの高さとして扱う）
+    pub min_height_m: f32,
+    /// 風速 1 m/s あたりのボールへの加速度（m/s²）
+    pub acceleration_factor: f32,
+}
+
+impl Default for WindConfig {
+    fn default() -> Self {
+        Self {
+            base_x: 0.0,
+            base_z: 0.0,
+            gust_strength: 0.0,
+            gust_interval_secs: 2.0,
+            gust_smoothing_secs: 0.8,
+            reference_height_m: 2.0,
+            height_exponent: 0.2,
+            min_height_m: 0.3,
+            acceleration_factor: 0.5,
+        }
+    }
+}
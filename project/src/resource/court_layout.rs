@@ -0,0 +1,28 @@
+//! コートの囲いリソース
+//! @spec 30503_boundary_behavior.md#beh-30503-009
+//! @spec 30503_boundary_behavior.md#beh-30503-010
+
+use bevy::prelude::*;
+
+use crate::core::{CourtEnclosure, Door};
+
+/// 設定から導出したコートの囲い（外壁平面・壁セグメント・ドア）
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// court_layout_system が GameConfig の変更時（起動時・ホットリロード）にだけ作り直す。
+/// 既定値は空の囲いで、最初のティックの前に設定から導出される。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CourtLayout {
+    /// 外壁平面と壁セグメント
+    pub enclosure: CourtEnclosure,
+    /// ドア（床の高さの開口部）
+    pub doors: Vec<Door>,
+}
+
+impl CourtLayout {
+    /// 囲いからドアを列挙して生成
+    pub fn new(enclosure: CourtEnclosure) -> Self {
+        let doors = enclosure.doors();
+        Self { enclosure, doors }
+    }
+}
@@ -39,7 +39,8 @@ pub const SUPPORTED_OVERRIDE_KEYS: &[&str] = &[
     "ball.bounce_factor",
     "ball.radius",
     "ball.min_bounce_velocity",
-    "ball.wall_bounce_factor",
+    "wall_material.glass.restitution",
+    "wall_material.mesh.restitution",
     "serve.serve_speed",
     "serve.serve_angle",
     "serve.toss_velocity_y",
@@ -115,7 +116,10 @@ pub struct DebugRuntimeOverrides {
     pub ball_bounce_factor: Option<f32>,
     pub ball_radius: Option<f32>,
     pub ball_min_bounce_velocity: Option<f32>,
-    pub ball_wall_bounce_factor: Option<f32>,
+
+    // === wall_material ===
+    pub wall_glass_restitution: Option<f32>,
+    pub wall_mesh_restitution: Option<f32>,
 
     // === serve ===
     pub serve_speed: Option<f32>,
@@ -173,7 +177,8 @@ impl Default for DebugRuntimeOverrides {
             ball_bounce_factor: None,
             ball_radius: None,
             ball_min_bounce_velocity: None,
-            ball_wall_bounce_factor: None,
+            wall_glass_restitution: None,
+            wall_mesh_restitution: None,
             serve_speed: None,
             serve_angle: None,
             toss_velocity_y: None,
@@ -269,7 +274,8 @@ pub fn override_field_type(key: &str) -> Option<DebugOverrideType> {
         | "ball.bounce_factor"
         | "ball.radius"
         | "ball.min_bounce_velocity"
-        | "ball.wall_bounce_factor"
+        | "wall_material.glass.restitution"
+        | "wall_material.mesh.restitution"
         | "serve.serve_speed"
         | "serve.serve_angle"
         | "serve.toss_velocity_y"
@@ -334,8 +340,11 @@ pub fn get_override_value(
         "ball.min_bounce_velocity" => overrides
             .ball_min_bounce_velocity
             .map(DebugOverrideValue::Float),
-        "ball.wall_bounce_factor" => overrides
-            .ball_wall_bounce_factor
+        "wall_material.glass.restitution" => overrides
+            .wall_glass_restitution
+            .map(DebugOverrideValue::Float),
+        "wall_material.mesh.restitution" => overrides
+            .wall_mesh_restitution
             .map(DebugOverrideValue::Float),
         "serve.serve_speed" => overrides.serve_speed.map(DebugOverrideValue::Float),
         "serve.serve_angle" => overrides.serve_angle.map(DebugOverrideValue::Float),
@@ -456,10 +465,15 @@ pub fn set_override_value(
             overrides.ball_min_bounce_velocity = Some(v)
         }
         ("ball.min_bounce_velocity", None) => overrides.ball_min_bounce_velocity = None,
-        ("ball.wall_bounce_factor", Some(DebugOverrideValue::Float(v))) => {
-            overrides.ball_wall_bounce_factor = Some(v)
+
+        ("wall_material.glass.restitution", Some(DebugOverrideValue::Float(v))) => {
+            overrides.wall_glass_restitution = Some(v)
+        }
+        ("wall_material.glass.restitution", None) => overrides.wall_glass_restitution = None,
+        ("wall_material.mesh.restitution", Some(DebugOverrideValue::Float(v))) => {
+            overrides.wall_mesh_restitution = Some(v)
         }
-        ("ball.wall_bounce_factor", None) => overrides.ball_wall_bounce_factor = None,
+        ("wall_material.mesh.restitution", None) => overrides.wall_mesh_restitution = None,
 
         ("serve.serve_speed", Some(DebugOverrideValue::Float(v))) => {
             overrides.serve_speed = Some(v)
@@ -643,9 +657,12 @@ pub fn get_config_value(config: &GameConfig, key: &str) -> Option<DebugOverrideV
         "ball.min_bounce_velocity" => {
             Some(DebugOverrideValue::Float(config.ball.min_bounce_velocity))
         }
-        "ball.wall_bounce_factor" => {
-            Some(DebugOverrideValue::Float(config.ball.wall_bounce_factor))
-        }
+        "wall_material.glass.restitution" => Some(DebugOverrideValue::Float(
+            config.wall_material.glass.restitution,
+        )),
+        "wall_material.mesh.restitution" => Some(DebugOverrideValue::Float(
+            config.wall_material.mesh.restitution,
+        )),
         "serve.serve_speed" => Some(DebugOverrideValue::Float(config.serve.serve_speed)),
         "serve.serve_angle" => Some(DebugOverrideValue::Float(config.serve.serve_angle)),
         "serve.toss_velocity_y" => Some(DebugOverrideValue::Float(config.serve.toss_velocity_y)),
@@ -741,8 +758,11 @@ fn apply_override_to_config(
         ("ball.min_bounce_velocity", DebugOverrideValue::Float(v)) => {
             config.ball.min_bounce_velocity = v
         }
-        ("ball.wall_bounce_factor", DebugOverrideValue::Float(v)) => {
-            config.ball.wall_bounce_factor = v
+        ("wall_material.glass.restitution", DebugOverrideValue::Float(v)) => {
+            config.wall_material.glass.restitution = v
+        }
+        ("wall_material.mesh.restitution", DebugOverrideValue::Float(v)) => {
+            config.wall_material.mesh.restitution = v
         }
         ("serve.serve_speed", DebugOverrideValue::Float(v)) => config.serve.serve_speed = v,
         ("serve.serve_angle", DebugOverrideValue::Float(v)) => config.serve.serve_angle = v,
@@ -1,31 +1,55 @@
 //! 固定タイムステップ提供リソース
-//! @spec 77100_headless_sim.md
+//! @spec 77100_headless_sim.md#req-77100-001
 //!
-//! ヘッドレスシミュレーションの高速化を可能にするため、
-//! 物理計算に固定のdelta_secsを提供する。
+//! ゲームロジックは FixedUpdate で固定ティックごとに実行する。
+//! 物理計算には実時間ではなく、ティックレートから求めた固定の delta_secs を使う。
+
+use std::time::Duration;
 
 use bevy::prelude::*;
 
+/// デフォルトのティックレート（Hz）
+pub const DEFAULT_TICK_RATE: f32 = 60.0;
+
 /// 固定タイムステップを提供するリソース
-/// @spec 77100_headless_sim.md
-#[derive(Resource)]
+/// @spec 77100_headless_sim.md#req-77100-001
+#[derive(Resource, Debug, Clone, Copy)]
 pub struct FixedDeltaTime {
     delta_secs: f32,
+    tick_rate: f32,
 }
 
 impl Default for FixedDeltaTime {
     fn default() -> Self {
-        // 60FPS固定
-        Self {
-            delta_secs: 1.0 / 60.0,
-        }
+        Self::from_tick_rate(DEFAULT_TICK_RATE)
     }
 }
 
 impl FixedDeltaTime {
+    /// ティックレート（Hz）から生成（1Hz 未満は 1Hz として扱う）
+    pub fn from_tick_rate(tick_rate: f32) -> Self {
+        let tick_rate = tick_rate.max(1.0);
+        Self {
+            delta_secs: 1.0 / tick_rate,
+            tick_rate,
+        }
+    }
+
     /// 固定のdelta_secsを取得
     #[inline]
     pub fn delta_secs(&self) -> f32 {
         self.delta_secs
     }
+
+    /// ティックレート（Hz）
+    #[inline]
+    pub fn tick_rate(&self) -> f32 {
+        self.tick_rate
+    }
+
+    /// 1ティックの長さ（Time<Fixed> のタイムステップに使用）
+    #[inline]
+    pub fn timestep(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.tick_rate as f64)
+    }
 }
@@ -10,6 +10,8 @@ use rand::{rngs::StdRng, Rng, SeedableRng};
 pub struct GameRng {
     rng: StdRng,
     seed: u64,
+    /// 乱数を生成した回数（リプレイのチェックサム用）
+    draws: u64,
 }
 
 impl GameRng {
@@ -18,6 +20,7 @@ impl GameRng {
         Self {
             rng: StdRng::seed_from_u64(seed),
             seed,
+            draws: 0,
         }
     }
 
@@ -35,12 +38,19 @@ impl GameRng {
         self.seed
     }
 
+    /// 乱数を生成した回数
+    /// @spec 77103_replay_spec.md#req-77103-010
+    pub fn draw_count(&self) -> u64 {
+        self.draws
+    }
+
     /// 指定範囲内のランダムな値を生成
     pub fn random_range<T, R>(&mut self, range: R) -> T
     where
         T: rand::distr::uniform::SampleUniform,
         R: rand::distr::uniform::SampleRange<T>,
     {
+        self.draws += 1;
         #[allow(deprecated)]
         self.rng.gen_range(range)
     }
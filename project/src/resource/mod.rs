@@ -1,15 +1,25 @@
 //! Resource層: ゲーム設定、マスタデータ
 //! @spec 20001_layers.md#layer-2-resource
 
+pub mod ball_condition;
+pub mod ball_prediction;
 pub mod config;
+pub mod court_layout;
 pub mod debug;
 pub mod debug_control;
 pub mod fixed_delta;
 pub mod game_rng;
+pub mod score_log;
 pub mod scoring;
+pub mod wind;
 
+pub use ball_condition::*;
+pub use ball_prediction::*;
 pub use config::*;
+pub use court_layout::*;
 pub use debug_control::*;
 pub use fixed_delta::*;
 pub use game_rng::*;
+pub use score_log::*;
 pub use scoring::*;
+pub use wind::*;
@@ -0,0 +1,199 @@
+//! ポイント履歴リソース
+//! @spec 30704_score_log_spec.md
+//!
+//! MatchScore は現在の状態のみを保持するため、ポイントごとの経過をここに記録する。
+//! 記録はチーム（開始時のサイド）順で残し、エンドチェンジの影響を受けない。
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::core::{CourtSide, RallyEndReason};
+use crate::resource::config::ServeSide;
+use crate::resource::scoring::{GameState, MatchScore};
+
+/// ポイント前後のスコア（チーム順: [Left開始チーム, Right開始チーム]）
+/// @spec 30704_score_log_spec.md#req-30704-001
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ScoreSnapshot {
+    /// ポイントインデックス（タイブレーク中はポイント数）
+    pub points: [u32; 2],
+    /// 現在のセットのゲーム数
+    pub games: [u32; 2],
+    /// 獲得セット数
+    pub sets: [u32; 2],
+    /// タイブレーク中か
+    pub tiebreak: bool,
+}
+
+impl ScoreSnapshot {
+    /// MatchScore からスナップショットを作成
+    pub fn capture(match_score: &MatchScore) -> Self {
+        let teams = [
+            match_score.side_of_team(CourtSide::Left),
+            match_score.side_of_team(CourtSide::Right),
+        ];
+        Self {
+            points: teams.map(|side| match_score.get_point_index(side) as u32),
+            games: teams.map(|side| match_score.get_score(side).games),
+            sets: teams.map(|side| match_score.get_score(side).sets),
+            tiebreak: match_score.is_tiebreak(),
+        }
+    }
+
+    /// 表示文字列（例: "S 0-0 G 2-1 P 1-3", タイブレーク中は "TB"）
+    pub fn display(&self) -> String {
+        format!(
+            "S {}-{} G {}-{} {} {}-{}",
+            self.sets[0],
+            self.sets[1],
+            self.games[0],
+            self.games[1],
+            if self.tiebreak { "TB" } else { "P" },
+            self.points[0],
+            self.points[1],
+        )
+    }
+}
+
+/// 1ポイント分の記録
+/// @spec 30704_score_log_spec.md#req-30704-001
+#[derive(Debug, Clone, Serialize)]
+pub struct PointRecord {
+    /// マッチ通算のポイント番号（1始まり）
+    pub number: u32,
+    /// ポイントを獲得したチーム（開始時のサイド）
+    pub winner: CourtSide,
+    /// サーブしたチーム（開始時のサイド）
+    pub server: CourtSide,
+    /// サーバーのプレイヤーID
+    pub server_player_id: u8,
+    /// サーブサイド
+    pub serve_side: ServeSide,
+    /// ラリー終了理由
+    pub reason: RallyEndReason,
+    /// ラリーの長さ（最後のサーブを含むショット数）
+    pub rally_length: u32,
+    /// ポイント前のスコア
+    pub before: ScoreSnapshot,
+    /// ポイント後のスコア
+    pub after: ScoreSnapshot,
+    /// 取り消し用のポイント前の状態
+    #[serde(skip)]
+    restore: MatchScore,
+}
+
+impl PointRecord {
+    /// 表示文字列（例: "#12 P1 won (DoubleBounce, 5 shots) S 0-0 G 2-1 P 3-1"）
+    pub fn display(&self) -> String {
+        format!(
+            "#{} {} won ({:?}, {} shots) {}",
+            self.number,
+            team_label(self.winner),
+            self.reason,
+            self.rally_length,
+            self.after.display()
+        )
+    }
+}
+
+/// チームの表示名（Left開始 = P1、Right開始 = P2）
+pub fn team_label(team: CourtSide) -> &'static str {
+    match team {
+        CourtSide::Left => "P1",
+        CourtSide::Right => "P2",
+    }
+}
+
+/// ポイント履歴リソース
+/// @spec 30704_score_log_spec.md
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScoreLog {
+    /// 記録済みのポイント（古い順）
+    pub points: Vec<PointRecord>,
+    /// 進行中のポイントのショット数
+    pub shots_this_point: u32,
+}
+
+impl ScoreLog {
+    /// 新規作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ショットを記録（サーブは打ち直しを含めて1本目から数え直す）
+    /// @spec 30704_score_log_spec.md#req-30704-001
+    pub fn record_shot(&mut self, is_serve: bool) {
+        if is_serve {
+            self.shots_this_point = 0;
+        }
+        self.shots_this_point += 1;
+    }
+
+    /// ポイントを記録
+    /// @spec 30704_score_log_spec.md#req-30704-001
+    ///
+    /// before はポイント加算前の MatchScore、after は勝敗処理後の MatchScore。
+    pub fn record_point(
+        &mut self,
+        before: &MatchScore,
+        after: &MatchScore,
+        scorer: CourtSide,
+        serve_side: ServeSide,
+        reason: RallyEndReason,
+    ) -> &PointRecord {
+        let record = PointRecord {
+            number: self.points.len() as u32 + 1,
+            winner: before.team_of(scorer),
+            server: before.team_of(before.server),
+            server_player_id: before.server_player_id(),
+            serve_side,
+            reason,
+            rally_length: self.shots_this_point,
+            before: ScoreSnapshot::capture(before),
+            after: ScoreSnapshot::capture(after),
+            restore: before.clone(),
+        };
+        self.shots_this_point = 0;
+        self.points.push(record);
+        &self.points[self.points.len() - 1]
+    }
+
+    /// 直前のポイントを取り消してスコアを戻す
+    /// @spec 30704_score_log_spec.md#req-30704-002
+    ///
+    /// サイド（ends_swapped）もポイント前の状態に戻す。記録後にエンドチェンジしていれば、
+    /// 呼び出し側でプレイヤーを元のサイドへ戻す。試合時間は巻き戻さない。
+    pub fn undo_last_point(&mut self, match_score: &mut MatchScore) -> Option<PointRecord> {
+        let record = self.points.pop()?;
+        let mut restored = record.restore.clone();
+        restored.elapsed_secs = match_score.elapsed_secs;
+        restored.game_state = GameState::Playing;
+        *match_score = restored;
+        self.shots_this_point = 0;
+        Some(record)
+    }
+
+    /// チーム（開始時のサイド）の獲得ポイント数
+    /// @spec 30704_score_log_spec.md#req-30704-003
+    pub fn points_won(&self, team: CourtSide) -> usize {
+        self.points
+            .iter()
+            .filter(|record| record.winner == team)
+            .count()
+    }
+
+    /// 直近のポイント
+    pub fn last(&self) -> Option<&PointRecord> {
+        self.points.last()
+    }
+
+    /// 直近 count ポイントの表示（古い順）
+    /// @spec 30704_score_log_spec.md#req-30704-003
+    pub fn recent_display(&self, count: usize) -> Vec<String> {
+        let start = self.points.len().saturating_sub(count);
+        self.points[start..]
+            .iter()
+            .map(PointRecord::display)
+            .collect()
+    }
+}
@@ -7,8 +7,8 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
-use crate::resource::config::ServeSide;
+use crate::core::{team_player_id, CourtLane, CourtSide, PointSituation};
+use crate::resource::config::{DeuceMode, ServeSide};
 
 /// 試合フロー状態
 /// @spec 30101_flow_spec.md#MatchStateType
@@ -23,6 +23,9 @@ pub enum MatchFlowState {
     Rally,
     /// ポイント終了
     PointEnd,
+    /// エンドチェンジ（サイド入れ替えと休憩）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    Changeover,
     /// 試合終了
     MatchEnd,
 }
@@ -78,6 +81,58 @@ impl PlayerGameScore {
     }
 }
 
+/// タイブレーク種別
+/// @spec 30702_game_spec.md#req-30702-050
+/// @spec 30703_set_spec.md#req-30703-055
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiebreakKind {
+    /// 通常タイブレーク（6-6 で発生、7ポイント先取）
+    Standard,
+    /// スーパータイブレーク（最終セットの代替、10ポイント先取）
+    Super,
+}
+
+/// 進行中のタイブレーク状態
+/// @spec 30702_game_spec.md#req-30702-050
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TiebreakState {
+    /// タイブレーク種別
+    pub kind: TiebreakKind,
+    /// 勝利に必要なポイント数
+    pub target_points: usize,
+    /// タイブレーク最初のサーバー
+    pub first_server: CourtSide,
+}
+
+/// 完了したセットの記録
+/// @spec 30703_set_spec.md#req-30703-051
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetRecord {
+    /// セット終了時のゲーム数 [Left開始チーム, Right開始チーム]
+    pub games: [u32; 2],
+    /// タイブレークで決着した場合のポイント [Left開始チーム, Right開始チーム]
+    pub tiebreak_points: Option<[u32; 2]>,
+    /// スーパータイブレークで決着したか
+    pub super_tiebreak: bool,
+}
+
+impl SetRecord {
+    /// セットスコア表示文字列（例: "6-4", "7-6(5)", "[10-8]"）
+    /// @spec 30703_set_spec.md#req-30703-054
+    pub fn display(&self) -> String {
+        let [left, right] = self.games;
+        match self.tiebreak_points {
+            Some([tb_left, tb_right]) if self.super_tiebreak => {
+                format!("[{}-{}]", tb_left, tb_right)
+            }
+            Some([tb_left, tb_right]) => {
+                format!("{}-{}({})", left, right, tb_left.min(tb_right))
+            }
+            None => format!("{}-{}", left, right),
+        }
+    }
+}
+
 /// マッチ全体のスコア状態
 /// @spec 30701_point_spec.md
 /// ECS設計原則: CourtSideベースの配列アクセス（固定識別子を排除）
@@ -91,6 +146,30 @@ pub struct MatchScore {
     pub server: CourtSide,
     /// 現在のゲーム状態
     pub game_state: GameState,
+    /// 進行中のタイブレーク（None = 通常ゲーム）
+    /// @spec 30702_game_spec.md#req-30702-050
+    pub tiebreak: Option<TiebreakState>,
+    /// 完了したセットの記録
+    /// @spec 30703_set_spec.md#req-30703-051
+    pub set_history: Vec<SetRecord>,
+    /// 1チームあたりの人数（1 = シングルス、2 = ダブルス）
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    pub players_per_team: usize,
+    /// 各チームの次のサーバースロット [Left側, Right側]
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    pub serve_slots: [usize; 2],
+    /// エンドチェンジ済みか（true = 両チームが開始時と逆サイドにいる）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub ends_swapped: bool,
+    /// 次のポイント開始前にエンドチェンジを行う予約
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub end_change_pending: bool,
+    /// 試合開始からの経過時間（秒、時間制マッチ判定用）
+    /// @spec 30703_set_spec.md#req-30703-058
+    pub elapsed_secs: f32,
+    /// マッチ通算の獲得ポイント数 [Left開始チーム, Right開始チーム]
+    /// @spec 30703_set_spec.md#req-30703-058
+    pub points_won: [u32; 2],
 }
 
 /// ゲーム状態
@@ -117,6 +196,14 @@ impl Default for MatchScore {
             scores: [PlayerGameScore::default(), PlayerGameScore::default()],
             server: CourtSide::Left,
             game_state: GameState::default(),
+            tiebreak: None,
+            set_history: Vec::new(),
+            players_per_team: 1,
+            serve_slots: [0, 0],
+            ends_swapped: false,
+            end_change_pending: false,
+            elapsed_secs: 0.0,
+            points_won: [0, 0],
         }
     }
 }
@@ -128,6 +215,110 @@ impl MatchScore {
         Self::default()
     }
 
+    /// チーム人数を指定して新規マッチを開始
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    pub fn with_players_per_team(players_per_team: usize) -> Self {
+        Self {
+            players_per_team: players_per_team.max(1),
+            ..Self::new()
+        }
+    }
+
+    /// 現在のサーバーのプレイヤーID
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    pub fn server_player_id(&self) -> u8 {
+        team_player_id(
+            self.team_of(self.server),
+            self.serve_slots[self.server as usize],
+        )
+    }
+
+    /// サーブサイドに応じたレシーバーのプレイヤーID
+    /// @spec 30105_doubles_spec.md#req-30105-003
+    ///
+    /// ダブルスではデュースサイドをドライブ、アドサイドをレベスがレシーブする。
+    pub fn receiver_player_id(&self, serve_side: ServeSide) -> u8 {
+        let receiver = self.team_of(self.server.opponent());
+        if self.players_per_team <= 1 {
+            return team_player_id(receiver, 0);
+        }
+        let lane = match serve_side {
+            ServeSide::Deuce => CourtLane::Drive,
+            ServeSide::Ad => CourtLane::Reves,
+        };
+        team_player_id(receiver, lane.slot())
+    }
+
+    /// 指定サイドにいるチーム（開始時のサイドで識別）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    ///
+    /// スコア配列は現在のサイドで管理するため、表示・記録ではこの変換でチームに戻す。
+    #[inline]
+    pub fn team_of(&self, side: CourtSide) -> CourtSide {
+        if self.ends_swapped {
+            side.opponent()
+        } else {
+            side
+        }
+    }
+
+    /// 指定チーム（開始時のサイドで識別）が現在いるサイド
+    /// @spec 30101_flow_spec.md#req-30101-051
+    #[inline]
+    pub fn side_of_team(&self, team: CourtSide) -> CourtSide {
+        // サイド入れ替えは対合（2回で元に戻る）なので team_of と同じ変換になる
+        self.team_of(team)
+    }
+
+    /// マッチ全体で完了したゲーム数（タイブレークは1ゲームとして数える）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub fn games_played(&self) -> u32 {
+        let finished: u32 = self
+            .set_history
+            .iter()
+            .map(|record| record.games.iter().sum::<u32>())
+            .sum();
+        let current: u32 = self.scores.iter().map(|score| score.games).sum();
+        finished + current
+    }
+
+    /// ゲーム終了後にエンドチェンジが必要か（マッチ通算の奇数ゲーム終了時）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub fn end_change_due_after_game(&self) -> bool {
+        !self.games_played().is_multiple_of(2)
+    }
+
+    /// タイブレーク中のポイント終了後にエンドチェンジが必要か（interval ポイントごと）
+    /// @spec 30101_flow_spec.md#req-30101-052
+    pub fn end_change_due_in_tiebreak(&self, interval: usize) -> bool {
+        if !self.is_tiebreak() || interval == 0 {
+            return false;
+        }
+        let played = self.points.iter().map(|p| p.index).sum::<usize>();
+        played > 0 && played.is_multiple_of(interval)
+    }
+
+    /// エンドチェンジ（両チームのサイドを入れ替え、サイド別の状態を付け替える）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub fn change_ends(&mut self) {
+        self.points.swap(0, 1);
+        self.scores.swap(0, 1);
+        self.serve_slots.swap(0, 1);
+        self.server = self.server.opponent();
+        if let Some(tiebreak) = self.tiebreak.as_mut() {
+            tiebreak.first_server = tiebreak.first_server.opponent();
+        }
+        self.ends_swapped = !self.ends_swapped;
+        self.end_change_pending = false;
+    }
+
+    /// 指定チームのサーバーを次のメンバーに進める
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    fn advance_serve_rotation(&mut self, side: CourtSide) {
+        let slot = &mut self.serve_slots[side as usize];
+        *slot = (*slot + 1) % self.players_per_team.max(1);
+    }
+
     /// 指定したサイドのポイントを取得
     #[inline]
     pub fn get_point(&self, side: CourtSide) -> &PlayerPoint {
@@ -162,16 +353,50 @@ impl MatchScore {
     /// @spec 30701_point_spec.md#req-30701-002
     pub fn add_point(&mut self, scorer: CourtSide) {
         self.get_point_mut(scorer).advance();
+        self.points_won[self.team_of(scorer) as usize] += 1;
     }
 
-    /// ゲーム勝利判定（40から得点で勝利、相手が40未満）
+    /// ゲーム勝利判定
     /// @spec 30701_point_spec.md#req-30701-003
-    #[allow(dead_code)]
-    pub fn check_game_win(&self, scorer: CourtSide, win_index: usize) -> bool {
+    /// @spec 30701_point_spec.md#req-30701-052
+    ///
+    /// win_index（= point_values.len()）到達が前提。
+    /// アドバンテージ方式では2ポイント差が必要、ゴールデンポイント方式では不要。
+    pub fn check_game_win(&self, scorer: CourtSide, win_index: usize, mode: DeuceMode) -> bool {
         let scorer_index = self.get_point_index(scorer);
-        // MVP v0.1: デュースなし（40から得点で即勝利）
-        // win_index = 4 の場合、index 3 (40) から得点で勝利
-        scorer_index >= win_index
+        if scorer_index < win_index {
+            return false;
+        }
+        match mode {
+            DeuceMode::Advantage => scorer_index >= self.get_point_index(scorer.opponent()) + 2,
+            DeuceMode::GoldenPoint => true,
+        }
+    }
+
+    /// 現在のポイント状況（デュース／アドバンテージ）を判定
+    /// @spec 30701_point_spec.md#req-30701-050
+    /// @spec 30701_point_spec.md#req-30701-051
+    /// @spec 30701_point_spec.md#req-30701-053
+    pub fn point_situation(&self, win_index: usize, mode: DeuceMode) -> PointSituation {
+        if self.is_tiebreak() {
+            return PointSituation::Tiebreak;
+        }
+
+        let left = self.get_point_index(CourtSide::Left);
+        let right = self.get_point_index(CourtSide::Right);
+        let forty_index = win_index.saturating_sub(1);
+        if left < forty_index || right < forty_index {
+            return PointSituation::Regular;
+        }
+
+        match left.cmp(&right) {
+            std::cmp::Ordering::Equal => match mode {
+                DeuceMode::Advantage => PointSituation::Deuce,
+                DeuceMode::GoldenPoint => PointSituation::GoldenPoint,
+            },
+            std::cmp::Ordering::Greater => PointSituation::Advantage(CourtSide::Left),
+            std::cmp::Ordering::Less => PointSituation::Advantage(CourtSide::Right),
+        }
     }
 
     /// ポイントをリセット（ゲーム終了後）
@@ -184,23 +409,126 @@ impl MatchScore {
 
     /// ゲーム獲得処理
     /// @spec 30702_game_spec.md#req-30702-002
+    /// @spec 30105_doubles_spec.md#req-30105-002
     pub fn win_game(&mut self, winner: CourtSide) {
         self.get_score_mut(winner).win_game();
         self.reset_points();
-        // サーバー交代
+        // サーバー交代（ダブルスではサーブしたチームの次回サーバーをパートナーへ）
+        self.advance_serve_rotation(self.server);
         self.server = self.server.opponent();
     }
 
-    /// セット獲得判定（6ゲーム先取でセット獲得）
-    /// @spec 30703_set_spec.md#req-30703-002
-    pub fn check_set_win(&self, winner: CourtSide, games_to_win: u32) -> bool {
-        self.get_score(winner).games >= games_to_win
+    /// セット獲得判定（games_to_win 先取かつ margin ゲーム差でセット獲得）
+    /// @spec 30702_game_spec.md#req-30702-003
+    /// @spec 30702_game_spec.md#req-30702-004
+    pub fn check_set_win(&self, winner: CourtSide, games_to_win: u32, margin: u32) -> bool {
+        let games = self.get_score(winner).games;
+        let opponent_games = self.get_score(winner.opponent()).games;
+        games >= games_to_win && games >= opponent_games + margin
     }
 
-    /// セット獲得処理
+    /// タイブレーク開始条件（両者が games_to_win に到達）
+    /// @spec 30702_game_spec.md#req-30702-050
+    /// @spec 30703_set_spec.md#req-30703-057
+    ///
+    /// games_to_win にはタイブレーク開始ゲーム数（ScoringConfig::tiebreak_trigger_games）を渡す。
+    pub fn should_start_tiebreak(&self, games_to_win: u32) -> bool {
+        self.scores.iter().all(|score| score.games == games_to_win)
+    }
+
+    /// タイブレーク中かどうか
+    #[inline]
+    pub fn is_tiebreak(&self) -> bool {
+        self.tiebreak.is_some()
+    }
+
+    /// タイブレークを開始（現在のサーバーが最初にサーブする）
+    /// @spec 30702_game_spec.md#req-30702-050
+    /// @spec 30702_game_spec.md#req-30702-052
+    pub fn start_tiebreak(&mut self, kind: TiebreakKind, target_points: usize) {
+        self.reset_points();
+        self.tiebreak = Some(TiebreakState {
+            kind,
+            target_points,
+            first_server: self.server,
+        });
+    }
+
+    /// タイブレーク勝利判定（target_points 先取かつ2ポイント差）
+    /// @spec 30702_game_spec.md#req-30702-053
+    /// @spec 30702_game_spec.md#req-30702-054
+    pub fn check_tiebreak_win(&self, scorer: CourtSide) -> bool {
+        let Some(tiebreak) = self.tiebreak else {
+            return false;
+        };
+        let scorer_points = self.get_point_index(scorer);
+        let opponent_points = self.get_point_index(scorer.opponent());
+        scorer_points >= tiebreak.target_points && scorer_points >= opponent_points + 2
+    }
+
+    /// タイブレークのサーバーを現在のポイント数に合わせて更新
+    /// @spec 30702_game_spec.md#req-30702-052
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    ///
+    /// サーブ権が相手チームへ移る際、直前にサーブしたチームのローテーションを進める。
+    pub fn update_tiebreak_server(&mut self) {
+        let Some(next) = self.tiebreak_server() else {
+            return;
+        };
+        if next != self.server {
+            self.advance_serve_rotation(self.server);
+            self.server = next;
+        }
+    }
+
+    /// タイブレーク中の現在サーバー（1-2-2 ローテーション）
+    /// @spec 30702_game_spec.md#req-30702-052
+    ///
+    /// 最初のサーバーが1ポイント、以降は2ポイントごとに交代する。
+    pub fn tiebreak_server(&self) -> Option<CourtSide> {
+        let tiebreak = self.tiebreak?;
+        let played = self.points.iter().map(|p| p.index).sum::<usize>();
+        if played.div_ceil(2).is_multiple_of(2) {
+            Some(tiebreak.first_server)
+        } else {
+            Some(tiebreak.first_server.opponent())
+        }
+    }
+
+    /// セット獲得処理（セット記録を残し、両者のゲーム数をリセット）
     /// @spec 30703_set_spec.md#req-30703-002
+    /// @spec 30703_set_spec.md#req-30703-051
     pub fn win_set(&mut self, winner: CourtSide) {
+        // セット記録はエンドチェンジに影響されないようチーム順で残す
+        let [first_team, second_team] = [
+            self.side_of_team(CourtSide::Left),
+            self.side_of_team(CourtSide::Right),
+        ];
+        let tiebreak = self.tiebreak.take();
+        let tiebreak_points = tiebreak.map(|_| {
+            [
+                self.get_point_index(first_team) as u32,
+                self.get_point_index(second_team) as u32,
+            ]
+        });
+        self.set_history.push(SetRecord {
+            games: [
+                self.get_score(first_team).games,
+                self.get_score(second_team).games,
+            ],
+            tiebreak_points,
+            super_tiebreak: tiebreak.is_some_and(|tb| tb.kind == TiebreakKind::Super),
+        });
+
+        // タイブレーク後は最初にサーブした側の相手が次セットの最初のサーバー
+        // @spec 30702_game_spec.md#req-30702-052
+        if let Some(tiebreak) = tiebreak {
+            self.server = tiebreak.first_server.opponent();
+        }
+
         self.get_score_mut(winner).win_set();
+        self.get_score_mut(winner.opponent()).games = 0;
+        self.reset_points();
     }
 
     /// マッチ勝利判定（1セット制: 1セット先取でマッチ勝利）
@@ -209,10 +537,82 @@ impl MatchScore {
         self.get_score(winner).sets >= sets_to_win
     }
 
+    /// 現在リードしている側（セット → ゲーム → ポイントの順で比較、同点なら None）
+    /// @spec 30703_set_spec.md#req-30703-058
+    pub fn leading_side(&self) -> Option<CourtSide> {
+        let key = |side: CourtSide| {
+            let score = self.get_score(side);
+            (score.sets, score.games, self.get_point_index(side))
+        };
+        match key(CourtSide::Left).cmp(&key(CourtSide::Right)) {
+            std::cmp::Ordering::Greater => Some(CourtSide::Left),
+            std::cmp::Ordering::Less => Some(CourtSide::Right),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// 時間切れによるマッチ終了（リードしている側の勝利）
+    /// @spec 30703_set_spec.md#req-30703-058
+    ///
+    /// 同点の場合は何もせず None を返す（次のポイントで再判定）。
+    /// 進行中のセットにゲームが入っていれば、途中経過としてセット記録に残す。
+    pub fn finish_on_time(&mut self) -> Option<CourtSide> {
+        let winner = self.leading_side()?;
+        let [first_team, second_team] = [
+            self.side_of_team(CourtSide::Left),
+            self.side_of_team(CourtSide::Right),
+        ];
+        let games = [
+            self.get_score(first_team).games,
+            self.get_score(second_team).games,
+        ];
+        if games.iter().any(|&g| g > 0) {
+            self.set_history.push(SetRecord {
+                games,
+                tiebreak_points: None,
+                super_tiebreak: false,
+            });
+        }
+        self.game_state = GameState::MatchWon(winner);
+        Some(winner)
+    }
+
+    /// 完了したセットのスコア表示（例: "Set 1: 6-4, Set 2: 7-6(5)"）
+    /// @spec 30703_set_spec.md#req-30703-054
+    pub fn set_history_display(&self) -> String {
+        self.set_history
+            .iter()
+            .enumerate()
+            .map(|(i, record)| format!("Set {}: {}", i + 1, record.display()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     /// ポイント表示用文字列を取得
     /// @spec 30701_point_spec.md#req-30701-004
+    /// @spec 30701_point_spec.md#req-30701-054
+    ///
+    /// 双方が40以上の場合はデュース圏内として "40"（デュース）/ "AD" を返す。
+    /// タイブレーク中はポイント数をそのまま返す（REQ-30702-051）。
     pub fn get_point_display(&self, player: CourtSide, point_values: &[u32]) -> String {
         let index = self.get_point_index(player);
+        if self.is_tiebreak() {
+            return index.to_string();
+        }
+
+        let opponent_index = self.get_point_index(player.opponent());
+        let forty_index = point_values.len().saturating_sub(1);
+
+        if let Some(forty) = point_values.last() {
+            if index >= forty_index && opponent_index >= forty_index {
+                return match index.saturating_sub(opponent_index) {
+                    0 => forty.to_string(),
+                    1 => "AD".to_string(),
+                    _ => "Game".to_string(),
+                };
+            }
+        }
+
         if index < point_values.len() {
             point_values[index].to_string()
         } else {
@@ -254,6 +654,9 @@ pub struct ServeState {
     pub toss_charge_started: bool,
     /// サーブトスチャージの実測時間（秒）
     pub toss_charge_elapsed_secs: f32,
+    /// トスボールが床でバウンドした回数
+    /// @spec 30902_fault_spec.md#req-30902-052
+    pub toss_bounces: u32,
 }
 
 impl Default for ServeState {
@@ -266,6 +669,7 @@ impl Default for ServeState {
             toss_velocity_y: 0.0,
             toss_charge_started: false,
             toss_charge_elapsed_secs: 0.0,
+            toss_bounces: 0,
         }
     }
 }
@@ -285,6 +689,7 @@ impl ServeState {
         self.toss_velocity_y = toss_velocity_y;
         self.toss_charge_started = false;
         self.toss_charge_elapsed_secs = 0.0;
+        self.toss_bounces = 0;
     }
 
     /// トス時間を更新
@@ -318,6 +723,14 @@ impl ServeState {
         self.toss_velocity_y = 0.0;
         self.toss_charge_started = false;
         self.toss_charge_elapsed_secs = 0.0;
+        self.toss_bounces = 0;
+    }
+
+    /// トスボールがバウンド済みか
+    /// @spec 30902_fault_spec.md#req-30902-052
+    #[inline]
+    pub fn toss_bounced(&self) -> bool {
+        self.toss_bounces > 0
     }
 
     /// ヒット成功時のリセット（Rallyへ遷移するため）
@@ -338,6 +751,14 @@ impl ServeState {
     }
 }
 
+/// エンドチェンジ休憩タイマー
+/// @spec 30101_flow_spec.md#req-30101-053
+#[derive(Resource, Default)]
+pub struct ChangeoverTimer {
+    /// 残り休憩時間（秒）
+    pub remaining: f32,
+}
+
 /// ポイント終了時のディレイタイマー
 #[derive(Resource, Default)]
 pub struct PointEndTimer {
@@ -384,6 +805,15 @@ pub struct RallyState {
     pub point_scored_this_rally: bool,
     /// サーブ中にネット接触したかどうか（レット判定用）
     pub serve_touched_net: bool,
+    /// 有効なサーブがレシーバーに打ち返されるのを待っているか（フェンス判定用）
+    /// @spec 30902_fault_spec.md#req-30902-054
+    pub serve_awaiting_return: bool,
+    /// 同一サーブでの連続レット回数
+    /// @spec 30902_fault_spec.md#req-30902-056
+    pub consecutive_lets: u32,
+    /// このポイントで最後にボールに触れた側（サーブはサーバーの接触として扱う）
+    /// @spec 30901_point_judgment_spec.md#req-30901-053
+    pub last_contact_side: Option<CourtSide>,
 }
 
 impl Default for RallyState {
@@ -396,6 +826,9 @@ impl Default for RallyState {
             rally_end_event_sent_this_frame: false,
             point_scored_this_rally: false,
             serve_touched_net: false,
+            serve_awaiting_return: false,
+            consecutive_lets: 0,
+            last_contact_side: None,
         }
     }
 }
@@ -412,6 +845,9 @@ impl RallyState {
             rally_end_event_sent_this_frame: false,
             point_scored_this_rally: false,
             serve_touched_net: false,
+            serve_awaiting_return: false,
+            consecutive_lets: 0,
+            last_contact_side: None,
         }
     }
 
@@ -428,12 +864,15 @@ impl RallyState {
         // 新しいサーブ開始時にスコア加算フラグをリセット
         self.point_scored_this_rally = false;
         self.serve_touched_net = false;
+        self.last_contact_side = Some(self.server);
     }
 
     /// ラリー開始（サーブが有効に入った）
     pub fn start_rally(&mut self) {
         self.phase = RallyPhase::Rally;
         self.serve_touched_net = false;
+        self.serve_awaiting_return = true;
+        self.consecutive_lets = 0;
     }
 
     /// ポイント終了
@@ -441,6 +880,8 @@ impl RallyState {
         self.phase = RallyPhase::PointEnded;
         self.fault_count = 0;
         self.serve_touched_net = false;
+        self.serve_awaiting_return = false;
+        self.consecutive_lets = 0;
     }
 
     /// 次のサーブへ（サーバー変更なし）
@@ -449,11 +890,27 @@ impl RallyState {
         // 次のポイントに向けてスコア加算フラグをリセット
         self.point_scored_this_rally = false;
         self.serve_touched_net = false;
+        self.serve_awaiting_return = false;
+        self.last_contact_side = None;
     }
 
     /// ファウル記録
     pub fn record_fault(&mut self) {
         self.fault_count += 1;
+        self.consecutive_lets = 0;
+    }
+
+    /// レット記録（fault_countは変更しない）
+    /// @spec 30902_fault_spec.md#req-30902-055
+    pub fn record_let(&mut self) {
+        self.consecutive_lets += 1;
+    }
+
+    /// 連続レットの上限に達しているか（0 は上限なし）
+    /// @spec 30902_fault_spec.md#req-30902-056
+    #[inline]
+    pub fn lets_exhausted(&self, max_consecutive_lets: u32) -> bool {
+        max_consecutive_lets > 0 && self.consecutive_lets >= max_consecutive_lets
     }
 
     /// ダブルフォルトか判定
@@ -559,4 +1016,46 @@ mod tests {
         rally_state.next_serve();
         assert!(!rally_state.serve_touched_net);
     }
+
+    /// TST-30106-002: ダブルスのサーブローテーション（P1 → P2 → P3 → P4）
+    /// @spec 30105_doubles_spec.md#req-30105-002
+    #[test]
+    fn test_req_30105_002_doubles_serve_rotation() {
+        let mut score = MatchScore::with_players_per_team(2);
+        let mut servers = vec![score.server_player_id()];
+        for _ in 0..4 {
+            let winner = score.server;
+            score.win_game(winner);
+            servers.push(score.server_player_id());
+        }
+        assert_eq!(servers, vec![1, 2, 3, 4, 1]);
+
+        // シングルスは従来通り 1 と 2 の交互
+        let mut singles = MatchScore::new();
+        singles.win_game(CourtSide::Left);
+        assert_eq!(singles.server_player_id(), 2);
+        singles.win_game(CourtSide::Left);
+        assert_eq!(singles.server_player_id(), 1);
+    }
+
+    /// TST-30106-005: ダブルスのレシーバー決定（デュース = ドライブ、アド = レベス）
+    /// @spec 30105_doubles_spec.md#req-30105-003
+    #[test]
+    fn test_req_30105_003_doubles_receiver() {
+        let mut score = MatchScore::with_players_per_team(2);
+        // Left側サーブ → Right側がレシーブ
+        assert_eq!(score.receiver_player_id(ServeSide::Deuce), 2);
+        assert_eq!(score.receiver_player_id(ServeSide::Ad), 4);
+
+        // サーバーのローテーションに関わらずレシーバーは担当サイドで固定
+        score.win_game(CourtSide::Left);
+        score.win_game(CourtSide::Left);
+        assert_eq!(score.server_player_id(), 3);
+        assert_eq!(score.receiver_player_id(ServeSide::Deuce), 2);
+        assert_eq!(score.receiver_player_id(ServeSide::Ad), 4);
+
+        // シングルスは常に相手1人
+        let singles = MatchScore::new();
+        assert_eq!(singles.receiver_player_id(ServeSide::Ad), 2);
+    }
 }
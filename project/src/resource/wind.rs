@@ -0,0 +1,150 @@
+//! 風リソース
+//! @spec 30401_trajectory_spec.md#req-30401-051
+
+use bevy::prelude::*;
+
+use crate::core::{advance_gust, wind_height_scale};
+use crate::resource::config::WindConfig;
+use crate::resource::GameRng;
+
+/// 現在の風の状態（基準高さでの風速）
+/// @spec 30401_trajectory_spec.md#req-30401-051
+///
+/// 基本風は GameConfig の wind から毎ティック取得し、
+/// 突風は GameRng で抽選した目標値へ滑らかに近づける（シード指定で再現可能）。
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct WindField {
+    /// 基本風（m/s）
+    pub base: Vec3,
+    /// 突風成分（m/s）
+    pub gust: Vec3,
+    /// 突風の目標値（m/s）
+    pub gust_target: Vec3,
+    /// 次に突風の目標値を抽選するまでの残り時間（秒）
+    pub gust_timer: f32,
+}
+
+impl WindField {
+    /// 基準高さでの風速（基本風 + 突風）
+    #[inline]
+    pub fn velocity(&self) -> Vec3 {
+        self.base + self.gust
+    }
+
+    /// 指定した高さでの風速
+    pub fn velocity_at(&self, height: f32, config: &WindConfig) -> Vec3 {
+        self.velocity()
+            * wind_height_scale(
+                height,
+                config.reference_height_m,
+                config.height_exponent,
+                config.min_height_m,
+            )
+    }
+
+    /// 指定した高さでボールが受ける風の加速度
+    ///
+    /// `drag_scale` は空気密度比（BallCondition）。空気が薄いほど風の影響は小さい。
+    pub fn acceleration_at(&self, height: f32, config: &WindConfig, drag_scale: f32) -> Vec3 {
+        self.velocity_at(height, config) * config.acceleration_factor * drag_scale
+    }
+
+    /// 1ティック分進める
+    /// @spec 30401_trajectory_spec.md#req-30401-051
+    ///
+    /// gust_strength が 0 の場合は突風なし（乱数を消費しない）。
+    pub fn advance(&mut self, config: &WindConfig, delta: f32, game_rng: &mut GameRng) {
+        self.base = Vec3::new(config.base_x, 0.0, config.base_z);
+
+        let strength = config.gust_strength;
+        if strength > 0.0 {
+            self.gust_timer -= delta;
+            if self.gust_timer <= 0.0 {
+                self.gust_target = Vec3::new(
+                    game_rng.random_range(-strength..=strength),
+                    0.0,
+                    game_rng.random_range(-strength..=strength),
+                );
+                self.gust_timer += config.gust_interval_secs.max(delta);
+            }
+        } else {
+            self.gust_target = Vec3::ZERO;
+            self.gust_timer = 0.0;
+        }
+        self.gust = advance_gust(
+            self.gust,
+            self.gust_target,
+            delta,
+            config.gust_smoothing_secs,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn windy_config() -> WindConfig {
+        WindConfig {
+            base_x: 3.0,
+            base_z: -1.0,
+            gust_strength: 2.0,
+            ..Default::default()
+        }
+    }
+
+    /// 無風設定では風速 0 のまま、乱数も消費しない
+    #[test]
+    fn test_calm_wind_does_not_draw() {
+        let mut wind = WindField::default();
+        let mut rng = GameRng::from_seed(1);
+        for _ in 0..120 {
+            wind.advance(&WindConfig::default(), 1.0 / 60.0, &mut rng);
+        }
+        assert_eq!(wind.velocity(), Vec3::ZERO);
+        assert_eq!(rng.draw_count(), 0);
+    }
+
+    /// 突風は抽選範囲内に収まり、同じシードなら同じ風になる
+    #[test]
+    fn test_gust_is_bounded_and_reproducible() {
+        let config = windy_config();
+        let run = |seed| {
+            let mut wind = WindField::default();
+            let mut rng = GameRng::from_seed(seed);
+            let mut history = Vec::new();
+            for _ in 0..600 {
+                wind.advance(&config, 1.0 / 60.0, &mut rng);
+                let gust = wind.velocity() - Vec3::new(3.0, 0.0, -1.0);
+                assert!(gust.x.abs() <= 2.0 + 1e-4 && gust.z.abs() <= 2.0 + 1e-4);
+                assert_eq!(gust.y, 0.0);
+                history.push(wind.velocity());
+            }
+            history
+        };
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    /// 高い位置ほど風が強い
+    #[test]
+    fn test_velocity_at_height() {
+        let config = windy_config();
+        let mut wind = WindField::default();
+        wind.advance(
+            &WindConfig {
+                gust_strength: 0.0,
+                ..config.clone()
+            },
+            1.0 / 60.0,
+            &mut GameRng::from_seed(1),
+        );
+        let low = wind.velocity_at(0.5, &config).length();
+        let reference = wind
+            .velocity_at(config.reference_height_m, &config)
+            .length();
+        let high = wind.velocity_at(6.0, &config).length();
+        assert!(low < reference && reference < high);
+        assert!((reference - wind.velocity().length()).abs() < 1e-5);
+    }
+}
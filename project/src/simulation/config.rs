@@ -8,10 +8,13 @@
 
 #![allow(dead_code)]
 
+use ron::extensions::Extensions;
 use serde::Deserialize;
 use std::fs;
 use std::path::Path;
 
+use crate::resource::config::{BallConditionConfig, EnvironmentConfig, GameConfig, WindConfig};
+
 /// 実行設定
 #[derive(Clone, Debug, Deserialize)]
 pub struct ExecutionConfig {
@@ -23,6 +26,10 @@ pub struct ExecutionConfig {
     pub seed: Option<u64>,
     /// 詳細ログ
     pub verbose: bool,
+    /// マッチ形式プリセット名（None = game_config.ron の選択を使用）
+    /// @spec 30703_set_spec.md#req-30703-056
+    #[serde(default)]
+    pub match_format: Option<String>,
 }
 
 impl Default for ExecutionConfig {
@@ -32,6 +39,7 @@ impl Default for ExecutionConfig {
             timeout_secs: 300,
             seed: None,
             verbose: false,
+            match_format: None,
         }
     }
 }
@@ -147,6 +155,132 @@ impl Default for DebugConfig {
     }
 }
 
+/// 環境条件スイープの1ケース
+/// @spec 77100_headless_sim.md#req-77100-003
+///
+/// 指定した項目だけ game_config.ron の値を上書きする（項目単位でマージ）。
+#[derive(Clone, Debug, Deserialize)]
+pub struct EnvironmentCase {
+    /// ケース名（結果ファイル名・サマリーに使用）
+    pub name: String,
+    /// 環境条件（None = game_config.ron の値）
+    #[serde(default)]
+    pub environment: Option<EnvironmentOverride>,
+    /// ボール状態（None = game_config.ron の値）
+    #[serde(default)]
+    pub ball_condition: Option<BallConditionOverride>,
+    /// 風（None = game_config.ron の値）
+    /// @spec 30401_trajectory_spec.md#req-30401-051
+    #[serde(default)]
+    pub wind: Option<WindOverride>,
+}
+
+impl EnvironmentCase {
+    /// ケースの条件を GameConfig に反映
+    pub fn apply_to(&self, game_config: &mut GameConfig) {
+        if let Some(environment) = &self.environment {
+            environment.apply_to(&mut game_config.environment);
+        }
+        if let Some(ball_condition) = &self.ball_condition {
+            ball_condition.apply_to(&mut game_config.ball_condition);
+        }
+        if let Some(wind) = &self.wind {
+            wind.apply_to(&mut game_config.wind);
+        }
+    }
+}
+
+/// 指定されていれば上書き
+fn override_with(target: &mut f32, value: Option<f32>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+/// 環境条件の上書き（None の項目は game_config.ron の値のまま）
+/// @spec 77100_headless_sim.md#req-77100-003
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EnvironmentOverride {
+    pub altitude_m: Option<f32>,
+    pub temperature_c: Option<f32>,
+}
+
+impl EnvironmentOverride {
+    fn apply_to(&self, config: &mut EnvironmentConfig) {
+        override_with(&mut config.altitude_m, self.altitude_m);
+        override_with(&mut config.temperature_c, self.temperature_c);
+    }
+}
+
+/// ボール状態の上書き（None の項目は game_config.ron の値のまま）
+/// @spec 77100_headless_sim.md#req-77100-003
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BallConditionOverride {
+    pub pressure_kpa: Option<f32>,
+    pub reference_pressure_kpa: Option<f32>,
+    pub reference_temperature_c: Option<f32>,
+    pub pressure_restitution_factor: Option<f32>,
+    pub temperature_restitution_factor: Option<f32>,
+    pub wear_per_game: Option<f32>,
+    pub max_wear: Option<f32>,
+}
+
+impl BallConditionOverride {
+    fn apply_to(&self, config: &mut BallConditionConfig) {
+        override_with(&mut config.pressure_kpa, self.pressure_kpa);
+        override_with(
+            &mut config.reference_pressure_kpa,
+            self.reference_pressure_kpa,
+        );
+        override_with(
+            &mut config.reference_temperature_c,
+            self.reference_temperature_c,
+        );
+        override_with(
+            &mut config.pressure_restitution_factor,
+            self.pressure_restitution_factor,
+        );
+        override_with(
+            &mut config.temperature_restitution_factor,
+            self.temperature_restitution_factor,
+        );
+        override_with(&mut config.wear_per_game, self.wear_per_game);
+        override_with(&mut config.max_wear, self.max_wear);
+    }
+}
+
+/// 風の上書き（None の項目は game_config.ron の値のまま）
+/// @spec 77100_headless_sim.md#req-77100-003
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct WindOverride {
+    pub base_x: Option<f32>,
+    pub base_z: Option<f32>,
+    pub gust_strength: Option<f32>,
+    pub gust_interval_secs: Option<f32>,
+    pub gust_smoothing_secs: Option<f32>,
+    pub reference_height_m: Option<f32>,
+    pub height_exponent: Option<f32>,
+    pub min_height_m: Option<f32>,
+    pub acceleration_factor: Option<f32>,
+}
+
+impl WindOverride {
+    fn apply_to(&self, config: &mut WindConfig) {
+        override_with(&mut config.base_x, self.base_x);
+        override_with(&mut config.base_z, self.base_z);
+        override_with(&mut config.gust_strength, self.gust_strength);
+        override_with(&mut config.gust_interval_secs, self.gust_interval_secs);
+        override_with(&mut config.gust_smoothing_secs, self.gust_smoothing_secs);
+        override_with(&mut config.reference_height_m, self.reference_height_m);
+        override_with(&mut config.height_exponent, self.height_exponent);
+        override_with(&mut config.min_height_m, self.min_height_m);
+        override_with(&mut config.acceleration_factor, self.acceleration_factor);
+    }
+}
+
 /// シミュレーター設定ファイル構造
 #[derive(Clone, Debug, Deserialize, Default)]
 pub struct SimulationFileConfig {
@@ -165,6 +299,10 @@ pub struct SimulationFileConfig {
     /// デバッグログ設定
     #[serde(default)]
     pub debug: DebugConfig,
+    /// 環境条件スイープ（空 = game_config.ron の条件で1回だけ実行）
+    /// @spec 77100_headless_sim.md#req-77100-003
+    #[serde(default)]
+    pub environment_sweep: Vec<EnvironmentCase>,
 }
 
 /// シミュレーター設定をファイルから読み込む
@@ -179,5 +317,59 @@ pub fn load_simulation_config<P: AsRef<Path>>(path: P) -> Result<SimulationFileC
     let content =
         fs::read_to_string(path).map_err(|e| format!("Failed to read simulation config: {}", e))?;
 
-    ron::from_str(&content).map_err(|e| format!("Failed to parse simulation config: {}", e))
+    parse_simulation_config(&content)
+}
+
+/// シミュレーター設定を RON 文字列から読み込む
+///
+/// environment_sweep の上書き項目を `Some(..)` なしで書けるよう implicit_some を有効にする。
+fn parse_simulation_config(content: &str) -> Result<SimulationFileConfig, String> {
+    ron::Options::default()
+        .with_default_extension(Extensions::IMPLICIT_SOME)
+        .from_str(content)
+        .map_err(|e| format!("Failed to parse simulation config: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ケースで指定しなかった項目は game_config.ron の値のまま
+    /// @spec 77100_headless_sim.md#req-77100-003
+    #[test]
+    fn test_req_77100_003_case_overrides_only_given_fields() {
+        let sim_config = parse_simulation_config(
+            r#"(
+                environment_sweep: [
+                    (name: "sea_level"),
+                    (
+                        name: "old_ball",
+                        environment: Some((temperature_c: 5.0)),
+                        ball_condition: Some((pressure_kpa: 55.0)),
+                        wind: Some((base_z: 4.0)),
+                    ),
+                ],
+            )"#,
+        )
+        .expect("sweep config should parse");
+
+        let mut base = GameConfig::default();
+        base.environment.altitude_m = 1500.0;
+        base.ball_condition.wear_per_game = 0.01;
+        base.wind.gust_strength = 2.0;
+
+        let mut sea_level = base.clone();
+        sim_config.environment_sweep[0].apply_to(&mut sea_level);
+        assert_eq!(sea_level.environment.altitude_m, 1500.0);
+        assert_eq!(sea_level.ball_condition.wear_per_game, 0.01);
+
+        let mut old_ball = base.clone();
+        sim_config.environment_sweep[1].apply_to(&mut old_ball);
+        assert_eq!(old_ball.environment.temperature_c, 5.0);
+        assert_eq!(old_ball.environment.altitude_m, 1500.0);
+        assert_eq!(old_ball.ball_condition.pressure_kpa, 55.0);
+        assert_eq!(old_ball.ball_condition.wear_per_game, 0.01);
+        assert_eq!(old_ball.wind.base_z, 4.0);
+        assert_eq!(old_ball.wind.gust_strength, 2.0);
+    }
 }
@@ -15,12 +15,40 @@ pub enum GameEvent {
         position: Vec3,
         court_side: CourtSide,
     },
-    /// 壁反射
-    WallReflect { position: Vec3, wall_type: String },
+    /// 壁反射（material: 壁面材質 Glass / Mesh）
+    WallReflect {
+        position: Vec3,
+        wall_type: String,
+        material: String,
+    },
     /// ポイント獲得
     Point { winner: u8, reason: String },
+    /// スコア状況（デュース／アドバンテージ等）
+    ScoreSituation { scorer: u8, situation: String },
+    /// ゲーム獲得
+    GameWon {
+        winner: u8,
+        deciding_situation: String,
+    },
     /// フォールト
     Fault { fault_type: String },
+    /// サーブレット（ネットイン後の打ち直し）
+    /// @spec 30902_fault_spec.md#req-30902-055
+    ServeLet {
+        server: u8,
+        position: Vec3,
+        consecutive_lets: u32,
+    },
+    /// サーブネットフォールト（ネット接触後のサービスボックス外）
+    /// @spec 30902_fault_spec.md#req-30902-057
+    ServeNetFault { server: u8, position: Vec3 },
+    /// エンドチェンジ
+    /// @spec 30101_flow_spec.md#req-30101-051
+    ChangeEnds {
+        ends_swapped: bool,
+        games_played: u32,
+        in_tiebreak: bool,
+    },
     /// 状態遷移
     StateChange { from: String, to: String },
     /// ショット属性計算詳細
@@ -77,7 +105,12 @@ impl GameEvent {
             GameEvent::Bounce { .. } => "Bounce",
             GameEvent::WallReflect { .. } => "WallReflect",
             GameEvent::Point { .. } => "Point",
+            GameEvent::ScoreSituation { .. } => "ScoreSituation",
+            GameEvent::GameWon { .. } => "GameWon",
             GameEvent::Fault { .. } => "Fault",
+            GameEvent::ServeLet { .. } => "ServeLet",
+            GameEvent::ServeNetFault { .. } => "ServeNetFault",
+            GameEvent::ChangeEnds { .. } => "ChangeEnds",
             GameEvent::StateChange { .. } => "StateChange",
             GameEvent::ShotAttributesCalculated { .. } => "ShotAttributesCalculated",
             GameEvent::AiMovementDecision { .. } => "AiMovementDecision",
@@ -23,18 +23,54 @@ impl GameEvent {
             GameEvent::WallReflect {
                 position,
                 wall_type,
+                material,
             } => {
                 format!(
-                    "pos=({:.2},{:.2},{:.2}),type={}",
-                    position.x, position.y, position.z, wall_type
+                    "pos=({:.2},{:.2},{:.2}),type={},material={}",
+                    position.x, position.y, position.z, wall_type, material
                 )
             }
             GameEvent::Point { winner, reason } => {
                 format!("winner={},reason={}", winner, reason)
             }
+            GameEvent::ScoreSituation { scorer, situation } => {
+                format!("scorer={},situation={}", scorer, situation)
+            }
+            GameEvent::GameWon {
+                winner,
+                deciding_situation,
+            } => {
+                format!("winner={},deciding={}", winner, deciding_situation)
+            }
             GameEvent::Fault { fault_type } => {
                 format!("type={}", fault_type)
             }
+            GameEvent::ServeLet {
+                server,
+                position,
+                consecutive_lets,
+            } => {
+                format!(
+                    "server={},pos=({:.2},{:.2},{:.2}),consecutive={}",
+                    server, position.x, position.y, position.z, consecutive_lets
+                )
+            }
+            GameEvent::ChangeEnds {
+                ends_swapped,
+                games_played,
+                in_tiebreak,
+            } => {
+                format!(
+                    "swapped={},games={},tiebreak={}",
+                    ends_swapped, games_played, in_tiebreak
+                )
+            }
+            GameEvent::ServeNetFault { server, position } => {
+                format!(
+                    "server={},pos=({:.2},{:.2},{:.2})",
+                    server, position.x, position.y, position.z
+                )
+            }
             GameEvent::StateChange { from, to } => {
                 format!("from={},to={}", from, to)
             }
@@ -166,10 +202,11 @@ impl GameEvent {
             GameEvent::WallReflect {
                 position,
                 wall_type,
+                material,
             } => {
                 format!(
-                    "{{\"type\": \"WallReflect\", \"position\": [{:.2}, {:.2}, {:.2}], \"wall_type\": \"{}\"}}",
-                    position.x, position.y, position.z, wall_type
+                    "{{\"type\": \"WallReflect\", \"position\": [{:.2}, {:.2}, {:.2}], \"wall_type\": \"{}\", \"material\": \"{}\"}}",
+                    position.x, position.y, position.z, wall_type, material
                 )
             }
             GameEvent::Point { winner, reason } => {
@@ -178,12 +215,53 @@ impl GameEvent {
                     winner, reason
                 )
             }
+            GameEvent::ScoreSituation { scorer, situation } => {
+                format!(
+                    "{{\"type\": \"ScoreSituation\", \"scorer\": {}, \"situation\": \"{}\"}}",
+                    scorer, situation
+                )
+            }
+            GameEvent::GameWon {
+                winner,
+                deciding_situation,
+            } => {
+                format!(
+                    "{{\"type\": \"GameWon\", \"winner\": {}, \"deciding_situation\": \"{}\"}}",
+                    winner, deciding_situation
+                )
+            }
             GameEvent::Fault { fault_type } => {
                 format!(
                     "{{\"type\": \"Fault\", \"fault_type\": \"{}\"}}",
                     fault_type
                 )
             }
+            GameEvent::ServeLet {
+                server,
+                position,
+                consecutive_lets,
+            } => {
+                format!(
+                    "{{\"type\": \"ServeLet\", \"server\": {}, \"position\": [{:.2}, {:.2}, {:.2}], \"consecutive_lets\": {}}}",
+                    server, position.x, position.y, position.z, consecutive_lets
+                )
+            }
+            GameEvent::ServeNetFault { server, position } => {
+                format!(
+                    "{{\"type\": \"ServeNetFault\", \"server\": {}, \"position\": [{:.2}, {:.2}, {:.2}]}}",
+                    server, position.x, position.y, position.z
+                )
+            }
+            GameEvent::ChangeEnds {
+                ends_swapped,
+                games_played,
+                in_tiebreak,
+            } => {
+                format!(
+                    "{{\"type\": \"ChangeEnds\", \"ends_swapped\": {}, \"games_played\": {}, \"in_tiebreak\": {}}}",
+                    ends_swapped, games_played, in_tiebreak
+                )
+            }
             GameEvent::StateChange { from, to } => {
                 format!(
                     "{{\"type\": \"StateChange\", \"from\": \"{}\", \"to\": \"{}\"}}",
@@ -314,9 +392,12 @@ impl FrameTrace {
         let events_json: Vec<String> = self.events.iter().map(|e| e.to_json()).collect();
 
         format!(
-            "{{\"frame\": {}, \"timestamp\": {:.3}, \"entities\": [{}], \"events\": [{}]}}",
+            "{{\"frame\": {}, \"timestamp\": {:.3}, \"wind\": [{:.2}, {:.2}, {:.2}], \"entities\": [{}], \"events\": [{}]}}",
             self.frame,
             self.timestamp,
+            self.wind.x,
+            self.wind.y,
+            self.wind.z,
             entities_json.join(", "),
             events_json.join(", ")
         )
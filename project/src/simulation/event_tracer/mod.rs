@@ -37,6 +37,9 @@ pub struct EventTracer {
     last_position_frame: u64,
     /// 現在フレームのイベントバッファ
     pending_events: Vec<GameEvent>,
+    /// エンドチェンジ済みか（サイドからプレイヤー番号への変換用）
+    /// @spec 30101_flow_spec.md#req-30101-051
+    pub ends_swapped: bool,
 }
 
 impl EventTracer {
@@ -73,8 +76,8 @@ impl EventTracer {
         self.current_frame >= self.last_position_frame + interval
     }
 
-    /// 位置・速度データを記録
-    pub fn record_positions(&mut self, timestamp: f32, entities: Vec<EntityTrace>) {
+    /// 位置・速度データと風の状態を記録
+    pub fn record_positions(&mut self, timestamp: f32, entities: Vec<EntityTrace>, wind: Vec3) {
         if !self.enabled {
             return;
         }
@@ -87,6 +90,7 @@ impl EventTracer {
             timestamp,
             entities,
             events,
+            wind,
         };
         self.frames.push(frame_trace);
         self.last_position_frame = self.current_frame;
@@ -12,16 +12,33 @@ pub enum EntityType {
     Player1,
     /// プレイヤー2（Right側）
     Player2,
+    /// プレイヤー3（Left側パートナー、ダブルス）
+    Player3,
+    /// プレイヤー4（Right側パートナー、ダブルス）
+    Player4,
     /// ボール
     Ball,
 }
 
 impl EntityType {
+    /// プレイヤーIDからエンティティ種別を取得
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub fn from_player_id(player_id: u8) -> Self {
+        match player_id {
+            1 => EntityType::Player1,
+            2 => EntityType::Player2,
+            3 => EntityType::Player3,
+            _ => EntityType::Player4,
+        }
+    }
+
     /// 文字列表現を取得
     pub(crate) fn as_str(&self) -> &'static str {
         match self {
             EntityType::Player1 => "Player1",
             EntityType::Player2 => "Player2",
+            EntityType::Player3 => "Player3",
+            EntityType::Player4 => "Player4",
             EntityType::Ball => "Ball",
         }
     }
@@ -49,6 +66,9 @@ pub struct FrameTrace {
     pub entities: Vec<EntityTrace>,
     /// このフレームで発生したイベント
     pub events: Vec<GameEvent>,
+    /// 風速（基準高さ、m/s。無風なら Vec3::ZERO）
+    /// @spec 30401_trajectory_spec.md#req-30401-051
+    pub wind: Vec3,
 }
 
 impl FrameTrace {
@@ -59,6 +79,7 @@ impl FrameTrace {
             timestamp,
             entities: Vec::new(),
             events: Vec::new(),
+            wind: Vec3::ZERO,
         }
     }
 }
@@ -46,6 +46,15 @@ impl EventTracer {
                 )?;
             }
 
+            // 風の行（位置を記録したフレームのみ。風速を vel 列に出力）
+            if !frame.entities.is_empty() {
+                writeln!(
+                    writer,
+                    "{},{:.3},Wind,,,,{:.2},{:.2},{:.2},,",
+                    frame.frame, frame.timestamp, frame.wind.x, frame.wind.y, frame.wind.z,
+                )?;
+            }
+
             // イベント行
             for event in &frame.events {
                 writeln!(
@@ -75,6 +84,11 @@ impl EventTracer {
             writeln!(writer, "    {{")?;
             writeln!(writer, "      \"frame\": {},", frame.frame)?;
             writeln!(writer, "      \"timestamp\": {:.3},", frame.timestamp)?;
+            writeln!(
+                writer,
+                "      \"wind\": [{:.2}, {:.2}, {:.2}],",
+                frame.wind.x, frame.wind.y, frame.wind.z
+            )?;
 
             // entities
             let entities_json: Vec<String> = frame.entities.iter().map(|e| e.to_json()).collect();
@@ -3,14 +3,15 @@
 //!
 //! ヘッドレス実行用のプラグインセット。
 //! 描画系を除外し、ゲームロジックのみを含む。
+//! 固定ティックの設定（FixedTickPlugin）は実行側で追加する。
 
 use bevy::prelude::*;
 
 use crate::character::CharacterPlugin;
 use crate::core::{
-    BallHitEvent, FaultEvent, GroundBounceEvent, PlayerJumpEvent, PlayerKnockbackEvent,
-    PlayerLandEvent, PlayerMoveEvent, PointScoredEvent, RacketContactEvent, RallyEndEvent,
-    ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent, SwingIntentEvent,
+    BallHitEvent, BallLeftCourtEvent, FaultEvent, GroundBounceEvent, PlayerJumpEvent,
+    PlayerKnockbackEvent, PlayerLandEvent, PlayerMoveEvent, PointScoredEvent, RacketContactEvent,
+    RallyEndEvent, ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent, SwingIntentEvent,
     WallReflectionEvent,
 };
 use crate::replay::ReplayRecordPlugin;
@@ -65,19 +66,16 @@ impl Plugin for HeadlessPlugins {
             .add_message::<ShotAttributesCalculatedEvent>()
             .add_message::<GroundBounceEvent>()
             .add_message::<WallReflectionEvent>()
+            .add_message::<BallLeftCourtEvent>()
             .add_message::<RallyEndEvent>()
             .add_message::<PointScoredEvent>()
             .add_message::<FaultEvent>();
 
-        // SystemSet の順序を設定
-        app.configure_sets(
-            Update,
-            GameSystemSet::Input.before(GameSystemSet::GameLogic),
-        );
-
         // ゲームロジックシステム（入力は AI が担当するため human_input_system は不要）
+        // @spec 77100_headless_sim.md#req-77100-001: ウィンドウ版と同じ固定ティックで実行
+        // （FixedUpdate の SystemSet 順序は FixedTickPlugin が設定する）
         app.add_systems(
-            Update,
+            FixedUpdate,
             (
                 // ふっとばし開始
                 knockback_start_system,
@@ -25,8 +25,8 @@ pub use anomaly_detector::{
 };
 #[allow(unused_imports)]
 pub use config::{
-    load_simulation_config, AnomalyThresholds, DebugConfig, ExecutionConfig, OutputConfig,
-    SimulationFileConfig, TraceConfig,
+    load_simulation_config, AnomalyThresholds, DebugConfig, EnvironmentCase, ExecutionConfig,
+    OutputConfig, SimulationFileConfig, TraceConfig,
 };
 pub use debug_logger::DebugLogger;
 #[allow(unused_imports)]
@@ -14,6 +14,7 @@ use std::fs::File;
 use std::io::Write;
 
 use super::AnomalyReport;
+use crate::resource::PointRecord;
 
 /// 試合結果
 #[derive(Clone, Debug, Serialize)]
@@ -26,6 +27,24 @@ pub struct MatchResult {
     pub duration_secs: f32,
     /// ラリー数
     pub rally_count: u32,
+    /// セットスコア（例: ["6-4", "7-6(5)"]）
+    /// @spec 30703_set_spec.md#req-30703-054
+    pub set_scores: Vec<String>,
+    /// 1チームあたりの人数（1 = シングルス、2 = ダブルス）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub players_per_team: u32,
+    /// プレイヤーごとのショット数（インデックス = プレイヤーID - 1）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub shots_by_player: Vec<u32>,
+    /// 獲得ポイント数 [Player 1, Player 2]
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub points_won: [u32; 2],
+    /// 番狂わせか（総獲得ポイントが少ない側がマッチに勝利）
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub upset: bool,
+    /// ポイントごとの履歴
+    /// @spec 30704_score_log_spec.md#req-30704-003
+    pub point_log: Vec<PointRecord>,
     /// 検出された異常
     #[serde(skip)]
     pub anomalies: Vec<AnomalyReport>,
@@ -42,10 +61,28 @@ pub struct SimulationReport {
     pub total_matches: u32,
     /// 完了試合数
     pub completed_matches: u32,
-    /// Player 1 勝利数
+    /// Player 1 勝利数（ダブルスでは Left側チーム）
     pub player1_wins: u32,
-    /// Player 2 勝利数
+    /// Player 2 勝利数（ダブルスでは Right側チーム）
     pub player2_wins: u32,
+    /// 1チームあたりの人数（1 = シングルス、2 = ダブルス）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub players_per_team: u32,
+    /// マッチ形式（プリセット名）
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub match_format: String,
+    /// 環境条件（標高・気温・ボール内圧）
+    /// @spec 77100_headless_sim.md#req-77100-003
+    pub environment: String,
+    /// 番狂わせ数（総獲得ポイントが少ない側の勝利）
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub upset_count: u32,
+    /// 番狂わせ率（決着した試合に対する割合）
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub upset_rate: f32,
+    /// 1試合あたりの平均ポイント数
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub avg_points_played: f32,
     /// 異常検出数
     pub total_anomalies: u32,
     /// 平均試合時間（秒）
@@ -59,12 +96,32 @@ pub struct SimulationReport {
 /// シミュレーション結果レポーター
 pub struct SimulationReporter {
     results: Vec<MatchResult>,
+    /// マッチ形式（プリセット名）
+    match_format: String,
+    /// 環境条件
+    environment: String,
 }
 
 impl SimulationReporter {
     /// 新規作成
     pub fn new() -> Self {
-        Self { results: vec![] }
+        Self {
+            results: vec![],
+            match_format: String::new(),
+            environment: String::new(),
+        }
+    }
+
+    /// マッチ形式名を設定
+    /// @spec 30703_set_spec.md#req-30703-056
+    pub fn set_match_format(&mut self, match_format: &str) {
+        self.match_format = match_format.to_string();
+    }
+
+    /// 環境条件を設定
+    /// @spec 77100_headless_sim.md#req-77100-003
+    pub fn set_environment(&mut self, environment: &str) {
+        self.environment = environment.to_string();
     }
 
     /// 試合結果を追加
@@ -79,6 +136,25 @@ impl SimulationReporter {
         let player1_wins = self.results.iter().filter(|r| r.winner == Some(1)).count() as u32;
         let player2_wins = self.results.iter().filter(|r| r.winner == Some(2)).count() as u32;
         let total_anomalies: u32 = self.results.iter().map(|r| r.anomalies.len() as u32).sum();
+        let players_per_team = self
+            .results
+            .iter()
+            .map(|r| r.players_per_team)
+            .max()
+            .unwrap_or(1);
+
+        let decided_matches = self.results.iter().filter(|r| r.winner.is_some()).count() as u32;
+        let upset_count = self.results.iter().filter(|r| r.upset).count() as u32;
+        let upset_rate = if decided_matches > 0 {
+            upset_count as f32 / decided_matches as f32
+        } else {
+            0.0
+        };
+        let total_points: u32 = self
+            .results
+            .iter()
+            .map(|r| r.points_won.iter().sum::<u32>())
+            .sum();
 
         let total_duration: f32 = self.results.iter().map(|r| r.duration_secs).sum();
         let total_rallies: u32 = self.results.iter().map(|r| r.rally_count).sum();
@@ -95,6 +171,12 @@ impl SimulationReporter {
             0.0
         };
 
+        let avg_points_played = if total_matches > 0 {
+            total_points as f32 / total_matches as f32
+        } else {
+            0.0
+        };
+
         let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
         SimulationReport {
@@ -103,6 +185,12 @@ impl SimulationReporter {
             completed_matches,
             player1_wins,
             player2_wins,
+            players_per_team,
+            match_format: self.match_format.clone(),
+            environment: self.environment.clone(),
+            upset_count,
+            upset_rate,
+            avg_points_played,
             total_anomalies,
             avg_duration_secs,
             avg_rally_count,
@@ -120,9 +208,18 @@ impl SimulationReporter {
         println!("Completed:         {}", report.completed_matches);
         println!("Player 1 Wins:     {}", report.player1_wins);
         println!("Player 2 Wins:     {}", report.player2_wins);
+        println!("Players per Team:  {}", report.players_per_team);
+        println!("Match Format:      {}", report.match_format);
+        println!("Environment:       {}", report.environment);
         println!("Total Anomalies:   {}", report.total_anomalies);
         println!("Avg Duration:      {:.2}s", report.avg_duration_secs);
         println!("Avg Rally Count:   {:.1}", report.avg_rally_count);
+        println!("Avg Points Played: {:.1}", report.avg_points_played);
+        println!(
+            "Upsets:            {} ({:.1}%)",
+            report.upset_count,
+            report.upset_rate * 100.0
+        );
         println!("========================================\n");
 
         if report.total_anomalies > 0 {
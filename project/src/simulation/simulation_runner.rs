@@ -14,11 +14,13 @@ use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 
 use crate::components::AiController;
-use crate::core::{CourtSide, RallyEndEvent};
+use crate::core::{CourtSide, RallyEndEvent, ShotExecutedEvent};
 use crate::resource::config::GameConfig;
-use crate::resource::scoring::{GameState, MatchScore};
+use crate::resource::scoring::{GameState, MatchScore, SetRecord};
 use crate::resource::MatchFlowState;
-use crate::resource::{FixedDeltaTime, GameRng};
+use crate::resource::{FixedDeltaTime, GameRng, ScoreLog};
+
+use crate::systems::FixedTickPlugin;
 
 use super::{
     AnomalyDetectorResource, AnomalyThresholdsResource, DebugLogger, EventTracer, HeadlessPlugins,
@@ -68,6 +70,9 @@ pub struct SimulationStateResource {
     pub timeout_secs: f32,
     /// ラリー数
     pub rally_count: u32,
+    /// プレイヤーごとのショット数（インデックス = プレイヤーID - 1）
+    /// @spec 30105_doubles_spec.md#req-30105-001
+    pub shots_by_player: Vec<u32>,
 }
 
 impl SimulationStateResource {
@@ -79,6 +84,7 @@ impl SimulationStateResource {
             elapsed_secs: 0.0,
             timeout_secs: timeout_secs as f32,
             rally_count: 0,
+            shots_by_player: Vec::new(),
         }
     }
 }
@@ -118,6 +124,28 @@ impl SimulationRunner {
             println!("Using seed: {}", seed);
         }
 
+        // @spec 30703_set_spec.md#req-30703-056: 形式ごとの比較用にプリセット名を記録
+        let match_format = game_config
+            .match_format
+            .selected
+            .as_deref()
+            .unwrap_or("custom");
+        println!("Match format: {}", match_format);
+        self.reporter.set_match_format(match_format);
+
+        // @spec 77100_headless_sim.md#req-77100-003: 条件ごとの比較用に環境条件を記録
+        let environment = format!(
+            "{:.0}m, {:.1}C, ball {:.0}kPa, wind ({:.1}, {:.1})±{:.1}m/s",
+            game_config.environment.altitude_m,
+            game_config.environment.temperature_c,
+            game_config.ball_condition.pressure_kpa,
+            game_config.wind.base_x,
+            game_config.wind.base_z,
+            game_config.wind.gust_strength
+        );
+        println!("Environment: {}", environment);
+        self.reporter.set_environment(&environment);
+
         for i in 0..self.config.match_count {
             println!("Match {}/{}", i + 1, self.config.match_count);
             let result = self.run_single_match(game_config, i);
@@ -142,10 +170,9 @@ impl SimulationRunner {
         let mut app = App::new();
 
         // MinimalPlugins（時間とタスク処理のみ）
-        // ScheduleRunnerPlugin で固定タイムステップを使用
-        // 60FPS固定タイムステップ
+        // ScheduleRunnerPlugin で固定タイムステップを使用（ティックレートに合わせる）
         app.add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(
-            std::time::Duration::from_secs_f64(1.0 / 60.0),
+            FixedDeltaTime::from_tick_rate(game_config.physics.tick_rate).timestep(),
         )));
 
         // StatesPlugin（State管理用、MinimalPluginsに含まれない）
@@ -160,8 +187,9 @@ impl SimulationRunner {
         // GameConfig リソースを挿入
         app.insert_resource(game_config.clone());
 
-        // FixedDeltaTime リソースを挿入（物理計算用）
-        app.init_resource::<FixedDeltaTime>();
+        // 固定ティック（物理計算用）: app.update() 1回でちょうど1ティック進める
+        // @spec 77100_headless_sim.md#req-77100-001
+        app.add_plugins(FixedTickPlugin::stepped(game_config.physics.tick_rate));
 
         // GameRng リソースを挿入（シード制御）
         let game_rng = if let Some(seed) = self.config.seed {
@@ -172,7 +200,9 @@ impl SimulationRunner {
         app.insert_resource(game_rng);
 
         // シミュレーション状態リソースを挿入
-        app.insert_resource(SimulationStateResource::new(self.config.timeout_secs));
+        let mut sim_state = SimulationStateResource::new(self.config.timeout_secs);
+        sim_state.shots_by_player = vec![0; game_config.team.total_players()];
+        app.insert_resource(sim_state);
 
         // SimulationConfig をリソースとして挿入（デバッグシステム用）
         app.insert_resource(self.config.clone());
@@ -213,6 +243,7 @@ impl SimulationRunner {
             Update,
             (
                 count_rally_end_system,
+                count_shot_system,
                 check_match_end_system,
                 check_timeout_system,
                 debug_simulation_state,
@@ -246,7 +277,22 @@ impl SimulationRunner {
         let winner = sim_state.winner;
         let duration_secs = sim_state.elapsed_secs;
         let rally_count = sim_state.rally_count;
+        let shots_by_player = sim_state.shots_by_player.clone();
         let anomalies = anomaly_detector.detector.anomalies().to_vec();
+        let match_score = app.world().resource::<MatchScore>();
+        let point_log = app.world().resource::<ScoreLog>().points.clone();
+        let set_scores: Vec<String> = match_score
+            .set_history
+            .iter()
+            .map(SetRecord::display)
+            .collect();
+        // @spec 30703_set_spec.md#req-30703-056: 総獲得ポイントが少ない側の勝利を番狂わせとする
+        let points_won = match_score.points_won;
+        let upset = match winner {
+            Some(1) => points_won[0] < points_won[1],
+            Some(2) => points_won[1] < points_won[0],
+            _ => false,
+        };
 
         // トレース統計を出力（有効時のみ）
         if event_tracer.enabled {
@@ -287,9 +333,12 @@ impl SimulationRunner {
 
         if self.config.verbose {
             println!(
-                "  Match {} result: winner={:?}, duration={:.2}s, rallies={}, anomalies={}",
+                "  Match {} result: winner={:?}, sets=[{}], points={}-{}, duration={:.2}s, rallies={}, anomalies={}",
                 match_index + 1,
                 winner,
+                set_scores.join(" "),
+                points_won[0],
+                points_won[1],
                 duration_secs,
                 rally_count,
                 anomalies.len()
@@ -301,6 +350,12 @@ impl SimulationRunner {
             winner,
             duration_secs,
             rally_count,
+            set_scores,
+            players_per_team: game_config.team.team_size() as u32,
+            shots_by_player,
+            points_won,
+            upset,
+            point_log,
             anomalies,
             completed,
         }
@@ -308,31 +363,21 @@ impl SimulationRunner {
 }
 
 /// セットアップシステム（Startup で実行）
+/// @spec 30105_doubles_spec.md#req-30105-001
 fn simulation_setup_system(mut commands: Commands, config: Res<GameConfig>) {
-    // Player 1 (AI) - Left側
-    let player1_pos = Vec3::new(config.player.x_min + 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player1_color;
-    let player1_color = Color::srgb(r, g, b);
-    let player1_entity =
-        crate::character::spawn_articulated_player(&mut commands, 1, player1_pos, player1_color);
-    // Player 1 も AI として動作させる
-    commands.entity(player1_entity).insert(AiController {
-        home_position: player1_pos,
-        target_position: player1_pos,
-        ..Default::default()
-    });
-
-    // Player 2 (AI) - Right側
-    let player2_pos = Vec3::new(config.player.x_max - 1.0, 0.0, 0.0);
-    let (r, g, b) = config.player_visual.player2_color;
-    let player2_color = Color::srgb(r, g, b);
-    let player2_entity =
-        crate::character::spawn_articulated_player(&mut commands, 2, player2_pos, player2_color);
-    commands.entity(player2_entity).insert(AiController {
-        home_position: player2_pos,
-        target_position: player2_pos,
-        ..Default::default()
-    });
+    // 全プレイヤーを AI として動作させる（ダブルスは ID 1〜4）
+    for player_id in 1..=config.team.total_players() as u8 {
+        let position = crate::systems::initial_player_position(player_id, &config);
+        let (r, g, b) = config.player_visual.color_for(player_id);
+        let color = Color::srgb(r, g, b);
+        let entity =
+            crate::character::spawn_articulated_player(&mut commands, player_id, position, color);
+        commands.entity(entity).insert(AiController {
+            home_position: position,
+            target_position: position,
+            ..Default::default()
+        });
+    }
 }
 
 /// ラリー終了イベント数を集計
@@ -344,6 +389,20 @@ fn count_rally_end_system(
     sim_state.rally_count += rally_count;
 }
 
+/// プレイヤーごとのショット数を集計
+/// @spec 30105_doubles_spec.md#req-30105-001
+fn count_shot_system(
+    mut sim_state: ResMut<SimulationStateResource>,
+    mut shot_events: MessageReader<ShotExecutedEvent>,
+) {
+    for event in shot_events.read() {
+        let index = event.player_id.saturating_sub(1) as usize;
+        if let Some(count) = sim_state.shots_by_player.get_mut(index) {
+            *count += 1;
+        }
+    }
+}
+
 /// 試合終了検出システム
 fn check_match_end_system(
     mut sim_state: ResMut<SimulationStateResource>,
@@ -357,9 +416,9 @@ fn check_match_end_system(
         // 勝者を取得
         if let Some(score) = match_score {
             if let GameState::MatchWon(winner_side) = score.game_state {
-                // CourtSide から Player番号に変換
-                // Left (Player 1), Right (Player 2)
-                sim_state.winner = Some(match winner_side {
+                // CourtSide から Player番号に変換（エンドチェンジ後は開始時のサイドへ戻す）
+                // Left開始 (Player 1), Right開始 (Player 2)
+                sim_state.winner = Some(match score.team_of(winner_side) {
                     CourtSide::Left => 1,
                     CourtSide::Right => 2,
                 });
@@ -460,3 +519,104 @@ fn debug_state_transitions(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::replay::data::{FileManagementConfig, ReplayConfig};
+    use crate::replay::player::{
+        replay_input_system, verify_state_checksum_system, ReplayPlayer, ReplayVerifier,
+    };
+    use crate::simulation::DebugConfig;
+    use crate::systems::GameSystemSet;
+
+    /// 記録・再生するティック数（サーブから数ラリー分）
+    const TICKS: usize = 1200;
+
+    /// AI 同士の試合を1回の update で1ティック進める App
+    ///
+    /// リプレイの保存先は `replay_dir`（起動時のクリーンアップも含め、既存のリプレイに触れない）。
+    fn match_app(game_config: &GameConfig, seed: u64, replay_dir: &Path) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .add_plugins(AssetPlugin::default())
+            .insert_resource(ReplayManager::new(ReplayConfig {
+                file_management: FileManagementConfig {
+                    save_directory: replay_dir.to_string_lossy().into_owned(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }))
+            .add_plugins(HeadlessPlugins)
+            .insert_resource(game_config.clone())
+            .add_plugins(FixedTickPlugin::stepped(game_config.physics.tick_rate))
+            .insert_resource(GameRng::from_seed(seed))
+            .insert_resource(EventTracer::default())
+            .insert_resource(DebugLogger::new(DebugConfig::default()))
+            .add_systems(Startup, simulation_setup_system);
+        app
+    }
+
+    /// TST-77103-013: 記録した試合を再生すると、全フレームの状態チェックサムが一致する
+    /// @spec 77103_replay_spec.md#req-77103-007
+    /// @spec 77103_replay_spec.md#req-77103-010
+    /// @spec 77103_replay_spec.md#req-77103-011
+    #[test]
+    fn test_req_77103_011_record_then_replay_matches() {
+        let game_config =
+            crate::resource::config::load_game_config("assets/config/game_config.ron")
+                .expect("game_config load failed");
+        let replay_dir = tempfile::tempdir().unwrap();
+
+        // 記録
+        let mut recording = match_app(&game_config, 7, replay_dir.path());
+        for _ in 0..TICKS {
+            recording.update();
+        }
+        let data = recording
+            .world_mut()
+            .resource_mut::<ReplayRecorder>()
+            .take_data()
+            .expect("match should be recorded");
+        assert!(
+            data.frames.len() > 60,
+            "recorded {} frames",
+            data.frames.len()
+        );
+        assert_eq!(data.checksums.len(), data.frames.len());
+
+        // 同じシードで再生し、ティック終了時の状態を記録と比較する
+        let mut player = ReplayPlayer::new();
+        player.start_playback(Arc::new(data.clone()));
+        let mut replay = match_app(&game_config, data.metadata.seed, replay_dir.path());
+        replay
+            .insert_resource(player)
+            .init_resource::<ReplayVerifier>()
+            .add_systems(
+                FixedUpdate,
+                replay_input_system.in_set(GameSystemSet::Input),
+            )
+            .add_systems(FixedPostUpdate, verify_state_checksum_system);
+        for _ in 0..TICKS {
+            replay.update();
+        }
+
+        let verifier = replay.world().resource::<ReplayVerifier>();
+        if let Some(desync) = verifier.desync() {
+            panic!(
+                "desync at frame {}: {:?}",
+                desync.frame,
+                desync
+                    .diffs
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(verifier.verified() as usize, data.checksums.len());
+    }
+}
@@ -7,10 +7,12 @@ use bevy::prelude::*;
 
 use crate::components::{Ball, LogicalPosition, Player, Velocity};
 use crate::core::events::{
-    FaultEvent, GroundBounceEvent, PointScoredEvent, RallyEndEvent, ShotAttributesCalculatedEvent,
-    ShotExecutedEvent, WallReflectionEvent,
+    ChangeEndsEvent, FaultEvent, GameWonEvent, GroundBounceEvent, MatchStartEvent,
+    PointScoredEvent, RallyEndEvent, ServeLetEvent, ServeNetFaultEvent,
+    ShotAttributesCalculatedEvent, ShotExecutedEvent, WallReflectionEvent,
 };
-use crate::resource::FixedDeltaTime;
+use crate::core::CourtSide;
+use crate::resource::{FixedDeltaTime, WindField};
 
 use super::event_tracer::{EntityTrace, EntityType, EventTracer, GameEvent};
 
@@ -22,11 +24,12 @@ pub fn trace_frame_advance_system(mut tracer: ResMut<EventTracer>) {
     }
 }
 
-/// 位置・速度・イベントを記録するシステム
+/// 位置・速度・風・イベントを記録するシステム
 /// interval_frames ごとに呼ばれる
 pub fn trace_positions_system(
     mut tracer: ResMut<EventTracer>,
     fixed_dt: Res<FixedDeltaTime>,
+    wind: Option<Res<WindField>>,
     players: Query<(&Player, &LogicalPosition, &Velocity)>,
     balls: Query<(&LogicalPosition, &Velocity), With<Ball>>,
 ) {
@@ -42,11 +45,7 @@ pub fn trace_positions_system(
 
         // プレイヤーの位置・速度を記録
         for (player, pos, vel) in players.iter() {
-            let entity_type = if player.id == 1 {
-                EntityType::Player1
-            } else {
-                EntityType::Player2
-            };
+            let entity_type = EntityType::from_player_id(player.id);
             entities.push(EntityTrace {
                 entity_type,
                 position: pos.value,
@@ -73,10 +72,13 @@ pub fn trace_positions_system(
     }
 
     let timestamp = tracer.current_frame() as f32 * fixed_dt.delta_secs();
-    tracer.record_positions(timestamp, entities);
+    // @spec 30401_trajectory_spec.md#req-30401-051: 風の状態（基準高さの風速）
+    let wind = wind.map_or(Vec3::ZERO, |wind| wind.velocity());
+    tracer.record_positions(timestamp, entities, wind);
 }
 
 /// ショット実行イベントを記録するシステム
+/// @spec 30607_shot_type_spec.md#req-30607-005
 pub fn trace_shot_events_system(
     mut tracer: ResMut<EventTracer>,
     mut shot_events: MessageReader<ShotExecutedEvent>,
@@ -86,14 +88,9 @@ pub fn trace_shot_events_system(
     }
 
     for event in shot_events.read() {
-        let shot_type = if event.is_jump_shot {
-            "jump_shot".to_string()
-        } else {
-            "normal".to_string()
-        };
         tracer.record_event(GameEvent::BallHit {
             player: event.player_id,
-            shot_type,
+            shot_type: format!("{:?}", event.shot_type),
         });
     }
 }
@@ -128,33 +125,80 @@ pub fn trace_wall_events_system(
         tracer.record_event(GameEvent::WallReflect {
             position: event.contact_point,
             wall_type: format!("{:?}", event.wall_type),
+            material: format!("{:?}", event.material),
         });
     }
 }
 
-/// ポイント獲得イベントを記録するシステム
+/// トレース用のプレイヤー番号（Left開始チーム=1, Right開始チーム=2）
+/// @spec 30101_flow_spec.md#req-30101-051
+///
+/// イベントは現在のサイドで届くため、エンドチェンジ後は開始時のサイドへ戻して番号化する。
+#[inline]
+fn side_to_player_number(side: CourtSide, ends_swapped: bool) -> u8 {
+    let team = if ends_swapped { side.opponent() } else { side };
+    match team {
+        CourtSide::Left => 1,
+        CourtSide::Right => 2,
+    }
+}
+
+/// ポイント獲得・エンドチェンジイベントを記録するシステム
+/// @spec 30701_point_spec.md#req-30701-054
+/// @spec 30101_flow_spec.md#req-30101-051
 pub fn trace_point_events_system(
     mut tracer: ResMut<EventTracer>,
     mut rally_events: MessageReader<RallyEndEvent>,
     mut point_events: MessageReader<PointScoredEvent>,
+    mut game_events: MessageReader<GameWonEvent>,
+    mut change_ends_events: MessageReader<ChangeEndsEvent>,
+    mut match_start_events: MessageReader<MatchStartEvent>,
 ) {
-    // PointScoredEvent は RallyEndEvent で記録するため消費のみ
-    point_events.read().count();
+    // プレイヤー番号の変換はトレース無効時も追従させる（試合開始で開始サイドに戻る）
+    if match_start_events.read().count() > 0 {
+        tracer.ends_swapped = false;
+    }
+    let change_ends: Vec<ChangeEndsEvent> = change_ends_events.read().cloned().collect();
+    if let Some(last) = change_ends.last() {
+        tracer.ends_swapped = last.ends_swapped;
+    }
 
     if !tracer.enabled || !tracer.config.events {
+        // イベントを消費して破棄
+        point_events.read().count();
+        game_events.read().count();
         return;
     }
 
+    for event in change_ends {
+        tracer.record_event(GameEvent::ChangeEnds {
+            ends_swapped: event.ends_swapped,
+            games_played: event.games_played,
+            in_tiebreak: event.in_tiebreak,
+        });
+    }
+
+    let ends_swapped = tracer.ends_swapped;
     for event in rally_events.read() {
-        let winner = match event.winner {
-            crate::core::CourtSide::Left => 1,
-            crate::core::CourtSide::Right => 2,
-        };
         tracer.record_event(GameEvent::Point {
-            winner,
+            winner: side_to_player_number(event.winner, ends_swapped),
             reason: format!("{:?}", event.reason),
         });
     }
+
+    for event in point_events.read() {
+        tracer.record_event(GameEvent::ScoreSituation {
+            scorer: side_to_player_number(event.scorer, ends_swapped),
+            situation: format!("{:?}", event.situation),
+        });
+    }
+
+    for event in game_events.read() {
+        tracer.record_event(GameEvent::GameWon {
+            winner: side_to_player_number(event.winner, ends_swapped),
+            deciding_situation: format!("{:?}", event.deciding_situation),
+        });
+    }
 }
 
 /// フォールトイベントを記録するシステム
@@ -173,6 +217,35 @@ pub fn trace_fault_events_system(
     }
 }
 
+/// サーブレット・ネットフォールトイベントを記録するシステム
+/// @spec 30902_fault_spec.md#req-30902-055
+/// @spec 30902_fault_spec.md#req-30902-057
+pub fn trace_serve_net_events_system(
+    mut tracer: ResMut<EventTracer>,
+    mut let_events: MessageReader<ServeLetEvent>,
+    mut net_fault_events: MessageReader<ServeNetFaultEvent>,
+) {
+    if !tracer.enabled || !tracer.config.events {
+        return;
+    }
+
+    let ends_swapped = tracer.ends_swapped;
+    for event in let_events.read() {
+        tracer.record_event(GameEvent::ServeLet {
+            server: side_to_player_number(event.server, ends_swapped),
+            position: event.bounce_point,
+            consecutive_lets: event.consecutive_lets,
+        });
+    }
+
+    for event in net_fault_events.read() {
+        tracer.record_event(GameEvent::ServeNetFault {
+            server: side_to_player_number(event.server, ends_swapped),
+            position: event.bounce_point,
+        });
+    }
+}
+
 /// ショット属性詳細イベントを記録するシステム
 /// @spec 77200_telemetry_spec.md#req-77200-001
 /// @spec 77200_telemetry_spec.md#req-77200-002
@@ -224,6 +297,7 @@ impl Plugin for TraceSystemPlugin {
                 trace_wall_events_system,
                 trace_point_events_system,
                 trace_fault_events_system,
+                trace_serve_net_events_system,
             )
                 .chain(),
         );
@@ -4,78 +4,36 @@
 use bevy::prelude::*;
 
 use crate::components::{
-    AiController, AiMovementState, Ball, KnockbackState, LogicalPosition, Player, Velocity,
+    AiController, AiMovementState, Ball, BallSpin, BallSpinExt, KnockbackState, LastShooter,
+    LogicalPosition, Player, Velocity,
 };
-use crate::core::court::CourtSide;
+use crate::core::court::{determine_court_side, CourtSide};
+use crate::core::CourtEnclosure;
 use crate::resource::config::GameConfig;
-use crate::resource::{FixedDeltaTime, GameRng};
+use crate::resource::{
+    BallCondition, BallPath, BallPrediction, CourtLayout, FixedDeltaTime, GameRng, WindField,
+};
 use crate::simulation::DebugLogger;
+use crate::systems::ball::{predict_ball_path, BallEnvironment};
 
-/// 着地時間を計算
-/// @spec 30301_ai_movement_spec.md#req-30301-v07-002
-///
-/// 二次方程式を解いて Y=0 となる時間を求める
-fn calculate_time_to_landing(position: Vec3, velocity: Vec3, gravity: f32) -> Option<f32> {
-    let y0 = position.y;
-    let vy = velocity.y;
-    let g = gravity;
-
-    // すでに地面上または地面以下の場合
-    if y0 <= 0.0 {
-        return Some(0.0);
-    }
-
-    // 二次方程式: 0.5 * g * t² + vy * t + y0 = 0
-    let a = 0.5 * g;
-    let b = vy;
-    let c = y0;
-
-    let discriminant = b * b - 4.0 * a * c;
-
-    if discriminant < 0.0 {
-        return None;
-    }
-
-    let sqrt_d = discriminant.sqrt();
-    let t1 = (-b - sqrt_d) / (2.0 * a);
-    let t2 = (-b + sqrt_d) / (2.0 * a);
-
-    // 正の時間のうち最も近い未来を選択
-    if t1 > 0.0 && t2 > 0.0 {
-        Some(t1.min(t2))
-    } else if t1 > 0.0 {
-        Some(t1)
-    } else if t2 > 0.0 {
-        Some(t2)
-    } else {
-        None
-    }
-}
+/// ドアを通り抜けるときに目指す、ドア中央から先の距離（m）
+const DOOR_PASS_DISTANCE: f32 = 0.5;
 
-/// 着地地点を計算
+/// 予測軌道上の着地地点（最初の地面バウンド）
 /// @spec 30301_ai_movement_spec.md#req-30301-v08-001
+/// @spec 30301_ai_movement_spec.md#req-30301-v07-004
 ///
-/// 放物線軌道から着地時のX, Z座標を計算
-fn calculate_landing_position(ball_pos: Vec3, ball_vel: Vec3, gravity: f32) -> Option<Vec3> {
-    let time_to_land = calculate_time_to_landing(ball_pos, ball_vel, gravity)?;
-    Some(Vec3::new(
-        ball_pos.x + ball_vel.x * time_to_land,
-        0.0,
-        ball_pos.z + ball_vel.z * time_to_land,
-    ))
+/// 共有の予測軌道（空気抵抗・スピン・風・ネット・壁を含む）から求める。
+fn calculate_landing_position(path: &BallPath) -> Option<Vec3> {
+    path.first_bounce().map(|contact| contact.point)
 }
 
 /// 軌道ライン上のZ座標を計算
 /// @spec 30301_ai_movement_spec.md#req-30301-v08-002
 ///
 /// ボール現在位置と着地点を結ぶ線上で、AIのX座標におけるZ座標を計算
-fn calculate_trajectory_line_z(
-    ai_x: f32,
-    ball_pos: Vec3,
-    ball_vel: Vec3,
-    gravity: f32,
-) -> Option<f32> {
-    let landing_pos = calculate_landing_position(ball_pos, ball_vel, gravity)?;
+fn calculate_trajectory_line_z(ai_x: f32, ball_pos: Vec3, path: &BallPath) -> Option<f32> {
+    let landing_pos = calculate_landing_position(path)?;
 
     // ボール位置と着地点を結ぶ線上のZ座標を計算
     let dx = landing_pos.x - ball_pos.x;
@@ -94,24 +52,16 @@ fn calculate_trajectory_line_z(
 /// 短いボール判定
 /// @spec 30301_ai_movement_spec.md#req-30301-v07-002
 ///
-/// ボールがAIのX座標に到達する前に着地するかを判定
-fn is_short_ball(ai_x: f32, ball_pos: Vec3, ball_vel: Vec3, gravity: f32) -> bool {
-    // インターセプト時間を計算
-    if ball_vel.x.abs() < 0.001 {
-        return true; // X方向に動いていない = 短いボールとみなす
-    }
-
-    let time_to_intercept = (ai_x - ball_pos.x) / ball_vel.x;
-    if time_to_intercept < 0.0 {
-        return true; // すでに通り過ぎた = 短いボールとみなす
-    }
-
-    // 着地時間を計算
-    let time_to_landing = calculate_time_to_landing(ball_pos, ball_vel, gravity);
+/// ボールがAIのX座標に到達する前に着地するかを予測軌道で判定
+fn is_short_ball(ai_x: f32, path: &BallPath) -> bool {
+    // 予測時間内にAIのX座標へ到達しない = 短いボールとみなす
+    let Some(time_to_intercept) = path.time_at_x(ai_x) else {
+        return true;
+    };
 
-    match time_to_landing {
-        Some(t_land) => t_land < time_to_intercept,
-        None => true, // 着地しない場合も短いボールとみなす
+    match path.first_bounce() {
+        Some(bounce) => bounce.time < time_to_intercept,
+        None => false,
     }
 }
 
@@ -140,7 +90,14 @@ fn apply_z_error(z: f32, config: &GameConfig, game_rng: &mut GameRng) -> f32 {
 /// @spec 30301_ai_movement_spec.md#req-30301-v05
 ///
 /// ボール位置に応じた動的な待機位置を計算
-fn calculate_idle_position(ball_pos: Vec3, court_side: CourtSide, config: &GameConfig) -> Vec3 {
+/// ダブルスでは担当サイドの中央（lane_z）を基準にする
+/// @spec 30105_doubles_spec.md#req-30105-004
+fn calculate_idle_position(
+    ball_pos: Vec3,
+    court_side: CourtSide,
+    lane_z: f32,
+    config: &GameConfig,
+) -> Vec3 {
     let depth = config.ai.optimal_depth;
     let bias_factor = config.ai.coverage_bias_factor;
     let max_z_offset = config.ai.max_z_offset;
@@ -154,7 +111,89 @@ fn calculate_idle_position(ball_pos: Vec3, court_side: CourtSide, config: &GameC
     // Z軸: ボール位置に応じて調整（相手の返球範囲をカバー）
     let z_offset = (ball_pos.z * bias_factor).clamp(-max_z_offset, max_z_offset);
 
-    Vec3::new(base_x, 0.0, z_offset)
+    Vec3::new(base_x, 0.0, lane_z + z_offset)
+}
+
+/// ダブルスでの担当サイド中央のZ座標（シングルスは0）
+/// @spec 30105_doubles_spec.md#req-30105-004
+fn lane_center_z(player: &Player, config: &GameConfig) -> f32 {
+    if config.team.is_doubles() {
+        player.lane().z_sign(player.court_side) * config.court.width / 4.0
+    } else {
+        0.0
+    }
+}
+
+/// ボールが自分の担当サイドに来るかを判定
+/// @spec 30105_doubles_spec.md#req-30105-004
+///
+/// シングルスでは常に担当。ダブルスでは予測着地点（不明ならボール現在位置）の
+/// Z座標が担当サイド側（中央含む）にある場合のみ担当とする。
+fn is_ball_in_my_lane(lane_z: f32, ball_pos: Vec3, path: &BallPath) -> bool {
+    if lane_z == 0.0 {
+        return true;
+    }
+    let predicted_z = calculate_landing_position(path)
+        .map(|pos| pos.z)
+        .unwrap_or(ball_pos.z);
+    predicted_z * lane_z.signum() >= 0.0
+}
+
+/// 囲いの外に出たボールを自分が追うべきかを判定
+/// @spec 30301_ai_movement_spec.md#req-30301-v07-005
+///
+/// ボールが囲いの外の自コート側にあり、最後に打ったのが相手側の場合のみ追う。
+fn is_outside_ball_mine(
+    court_side: CourtSide,
+    ball_pos: Vec3,
+    last_shooter: Option<CourtSide>,
+    enclosure: &CourtEnclosure,
+    net_x: f32,
+) -> bool {
+    !enclosure.contains(ball_pos)
+        && determine_court_side(ball_pos.x, net_x) == court_side
+        && last_shooter != Some(court_side)
+}
+
+/// 囲いの内外をまたぐ移動の経由点
+/// @spec 30301_ai_movement_spec.md#req-30301-v07-005
+///
+/// AIと目標が囲いの内外に分かれている場合、自コート側のドアのうち
+/// 経路（AI → ドア → 目標）が最短のものを選び、ドア中央から目標側へ
+/// DOOR_PASS_DISTANCE 進んだ位置を返す。内外が同じならドアは不要（None）。
+fn door_waypoint(
+    ai_pos: Vec3,
+    target: Vec3,
+    court_side: CourtSide,
+    layout: &CourtLayout,
+    net_x: f32,
+) -> Option<Vec3> {
+    let enclosure = &layout.enclosure;
+    let ai_inside = enclosure.contains(ai_pos);
+    if ai_inside == enclosure.contains(target) {
+        return None;
+    }
+
+    let horizontal_distance = |a: Vec3, b: Vec3| Vec2::new(a.x - b.x, a.z - b.z).length();
+    layout
+        .doors
+        .iter()
+        .map(|door| (door.wall_type, door.center(&enclosure.bounds)))
+        .filter(|(_, center)| determine_court_side(center.x, net_x) == court_side)
+        .map(|(wall_type, center)| {
+            // 法線は囲いの内向き
+            let inward = wall_type.normal();
+            if ai_inside {
+                center - inward * DOOR_PASS_DISTANCE
+            } else {
+                center + inward * DOOR_PASS_DISTANCE
+            }
+        })
+        .min_by(|a, b| {
+            let path_a = horizontal_distance(ai_pos, *a) + horizontal_distance(*a, target);
+            let path_b = horizontal_distance(ai_pos, *b) + horizontal_distance(*b, target);
+            path_a.total_cmp(&path_b)
+        })
 }
 
 /// 反応遅延タイマーを更新
@@ -197,13 +236,13 @@ fn calculate_tracking_target(
     ai_pos: Vec3,
     ball_pos: Vec3,
     ball_vel: Vec3,
-    gravity: f32,
+    path: &BallPath,
     state_changed: bool,
     config: &GameConfig,
     game_rng: &mut GameRng,
 ) -> Vec3 {
-    let is_short = is_short_ball(ai_pos.x, ball_pos, ball_vel, gravity);
-    let landing_pos = calculate_landing_position(ball_pos, ball_vel, gravity);
+    let is_short = is_short_ball(ai_pos.x, path);
+    let landing_pos = calculate_landing_position(path);
     let current_ball_vel_x_sign = ball_vel.x > 0.0;
 
     let (target_x, target_z) = if state_changed || ai.locked_target_z.is_none() {
@@ -216,8 +255,7 @@ fn calculate_tracking_target(
             }
         } else {
             // 軌道ライン: AIのX座標での軌道ライン上Z座標を予測
-            let z = calculate_trajectory_line_z(ai_pos.x, ball_pos, ball_vel, gravity)
-                .unwrap_or(ball_pos.z);
+            let z = calculate_trajectory_line_z(ai_pos.x, ball_pos, path).unwrap_or(ball_pos.z);
             (ai_pos.x, z)
         };
 
@@ -242,14 +280,8 @@ fn calculate_tracking_target(
 
 /// 到達距離を計算
 /// @spec 30301_ai_movement_spec.md#req-30301-v07-001
-fn calculate_arrival_distance(
-    ai_pos: Vec3,
-    target_pos: Vec3,
-    ball_pos: Vec3,
-    ball_vel: Vec3,
-    gravity: f32,
-) -> f32 {
-    let is_short = is_short_ball(ai_pos.x, ball_pos, ball_vel, gravity);
+fn calculate_arrival_distance(ai_pos: Vec3, target_pos: Vec3, path: &BallPath) -> f32 {
+    let is_short = is_short_ball(ai_pos.x, path);
     if is_short {
         // 短いボール: XZ平面の距離で判定
         let dx = target_pos.x - ai_pos.x;
@@ -262,17 +294,20 @@ fn calculate_arrival_distance(
 }
 
 /// ボール不在時のAI状態をリセットしてホームポジションへ移動
+///
+/// `target` はホームポジション（囲いの外にいる場合はドアの経由点）
 fn handle_no_ball_state(
     ai: &mut AiController,
     logical_pos: &mut LogicalPosition,
     velocity: &mut Velocity,
+    target: Vec3,
     move_speed: f32,
     delta: f32,
 ) {
     ai.locked_target_z = None;
     ai.lock_ball_velocity_x_sign = None;
     ai.reaction_timer = 0.0;
-    move_towards_target(logical_pos, velocity, ai.home_position, move_speed, delta);
+    move_towards_target(logical_pos, velocity, target, move_speed, delta);
 }
 
 /// ボールがAIに向かっているかを判定
@@ -290,7 +325,7 @@ fn determine_ai_target(
     ai_pos: Vec3,
     ball_pos: Vec3,
     ball_vel: Vec3,
-    gravity: f32,
+    path: &BallPath,
     ball_coming_to_me: bool,
     idle_pos: Vec3,
     state_changed: bool,
@@ -308,7 +343,7 @@ fn determine_ai_target(
                 ai_pos,
                 ball_pos,
                 ball_vel,
-                gravity,
+                path,
                 state_changed,
                 config,
                 game_rng,
@@ -357,14 +392,12 @@ fn execute_ai_movement(
     velocity: &mut Velocity,
     ai_pos: Vec3,
     target_pos: Vec3,
-    ball_pos: Vec3,
-    ball_vel: Vec3,
-    gravity: f32,
+    path: &BallPath,
     new_state: AiMovementState,
     config: &GameConfig,
     delta: f32,
 ) {
-    let distance = calculate_arrival_distance(ai_pos, target_pos, ball_pos, ball_vel, gravity);
+    let distance = calculate_arrival_distance(ai_pos, target_pos, path);
     let stop_distance = if matches!(new_state, AiMovementState::Tracking) {
         config.shot.max_distance
     } else {
@@ -390,14 +423,24 @@ fn execute_ai_movement(
 /// @spec 30301_ai_movement_spec.md#req-30301-v07-002
 /// @spec 30301_ai_movement_spec.md#req-30301-v07-003
 ///
+/// @spec 30301_ai_movement_spec.md#req-30301-v07-005
+///
 /// インターセプト方式移動、短いボール判定、目標ロック機構を実装
-#[allow(clippy::type_complexity)]
+/// 囲いの外に出たボールはドアを通って追いかける
+/// 着地予測は共有の予測軌道（BallPrediction）を使う
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn ai_movement_system(
     fixed_dt: Res<FixedDeltaTime>,
     config: Res<GameConfig>,
+    layout: Res<CourtLayout>,
+    prediction: Res<BallPrediction>,
+    condition: Res<BallCondition>,
     mut game_rng: ResMut<GameRng>,
     mut debug_logger: Option<ResMut<DebugLogger>>,
-    ball_query: Query<(&LogicalPosition, &Velocity), (With<Ball>, Without<AiController>)>,
+    ball_query: Query<
+        (&LogicalPosition, &Velocity, &LastShooter, Option<&BallSpin>),
+        (With<Ball>, Without<AiController>),
+    >,
     mut ai_query: Query<
         (
             &Player,
@@ -410,11 +453,32 @@ pub fn ai_movement_system(
     >,
 ) {
     let delta = fixed_dt.delta_secs();
-    let gravity = config.physics.gravity;
-    let ball_info = ball_query
-        .iter()
-        .next()
-        .map(|(pos, vel)| (pos.value, vel.value));
+    let net_x = config.court.net_x;
+    let enclosure = &layout.enclosure;
+    let ball = ball_query.iter().next();
+    let ball_info = ball.map(|(pos, vel, shooter, _)| (pos.value, vel.value, shooter.side));
+    // @spec 30301_ai_movement_spec.md#req-30301-v07-004: 風を見込まない場合は無風で予測し直す
+    let calm_path = match ball {
+        Some((pos, vel, _, spin)) if !config.ai.predict_wind => Some(predict_ball_path(
+            &BallEnvironment {
+                config: &config,
+                enclosure,
+                factors: condition.factors,
+                wind: WindField::default(),
+            },
+            pos.value,
+            vel.value,
+            spin.angular_or_default(),
+            config.physics.prediction_horizon,
+            delta,
+        )),
+        _ => None,
+    };
+    let no_path = BallPath::default();
+    let path = calm_path
+        .as_ref()
+        .or(prediction.path.as_ref())
+        .unwrap_or(&no_path);
 
     for (player, mut ai, mut logical_pos, mut velocity, knockback) in ai_query.iter_mut() {
         if knockback.is_knockback_active() {
@@ -424,19 +488,31 @@ pub fn ai_movement_system(
         let ai_pos = logical_pos.value;
 
         // ボール不在時はホームポジションへ
-        let Some((ball_pos, ball_vel)) = ball_info else {
+        let Some((ball_pos, ball_vel, last_shooter)) = ball_info else {
+            let home = ai.home_position;
+            let target =
+                door_waypoint(ai_pos, home, player.court_side, &layout, net_x).unwrap_or(home);
             handle_no_ball_state(
                 &mut ai,
                 &mut logical_pos,
                 &mut velocity,
+                target,
                 config.ai.move_speed,
                 delta,
             );
             continue;
         };
 
-        let ball_coming_to_me = is_ball_coming_to_ai(player.court_side, ball_vel.x);
-        let idle_pos = calculate_idle_position(ball_pos, player.court_side, &config);
+        // @spec 30105_doubles_spec.md#req-30105-004: ダブルスは担当サイドのボールのみ追跡
+        let lane_z = lane_center_z(player, &config);
+        let in_my_lane = is_ball_in_my_lane(lane_z, ball_pos, path);
+        // @spec 30301_ai_movement_spec.md#req-30301-v07-005: 囲いの外のボールを追う
+        let chasing_outside = config.court.outside_play
+            && in_my_lane
+            && is_outside_ball_mine(player.court_side, ball_pos, last_shooter, enclosure, net_x);
+        let ball_coming_to_me =
+            chasing_outside || (is_ball_coming_to_ai(player.court_side, ball_vel.x) && in_my_lane);
+        let idle_pos = calculate_idle_position(ball_pos, player.court_side, lane_z, &config);
 
         update_reaction_timer(&mut ai, ball_coming_to_me, config.ai.reaction_delay, delta);
         let state_changed = detect_lock_state_change(&ai, ball_vel.x, ball_coming_to_me);
@@ -447,18 +523,26 @@ pub fn ai_movement_system(
         }
 
         let prev_state = ai.movement_state;
-        let (new_state, target_pos) = determine_ai_target(
-            &mut ai,
-            ai_pos,
-            ball_pos,
-            ball_vel,
-            gravity,
-            ball_coming_to_me,
-            idle_pos,
-            state_changed,
-            &config,
-            &mut game_rng,
-        );
+        let (new_state, target_pos) = if chasing_outside {
+            // 囲いの外では着地点（外での2回目のバウンド地点）へ向かう
+            ai.locked_target_z = None;
+            ai.lock_ball_velocity_x_sign = None;
+            let landing = calculate_landing_position(path);
+            (AiMovementState::Tracking, landing.unwrap_or(ball_pos))
+        } else {
+            determine_ai_target(
+                &mut ai,
+                ai_pos,
+                ball_pos,
+                ball_vel,
+                path,
+                ball_coming_to_me,
+                idle_pos,
+                state_changed,
+                &config,
+                &mut game_rng,
+            )
+        };
 
         ai.movement_state = new_state;
         ai.target_position = target_pos;
@@ -475,18 +559,26 @@ pub fn ai_movement_system(
             );
         }
 
-        execute_ai_movement(
-            &mut logical_pos,
-            &mut velocity,
-            ai_pos,
-            target_pos,
-            ball_pos,
-            ball_vel,
-            gravity,
-            new_state,
-            &config,
-            delta,
-        );
+        // 囲いの内外をまたぐ場合はドアを経由する
+        match door_waypoint(ai_pos, target_pos, player.court_side, &layout, net_x) {
+            Some(waypoint) => move_towards_target(
+                &mut logical_pos,
+                &mut velocity,
+                waypoint,
+                config.ai.move_speed,
+                delta,
+            ),
+            None => execute_ai_movement(
+                &mut logical_pos,
+                &mut velocity,
+                ai_pos,
+                target_pos,
+                path,
+                new_state,
+                &config,
+                delta,
+            ),
+        }
     }
 }
 
@@ -516,84 +608,136 @@ fn move_towards_target(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::court_factory::create_court_enclosure;
+
+    /// 予測軌道を作成（重力 -10、風は高さによらず一定の加速度）
+    fn predicted_path(ball_pos: Vec3, ball_vel: Vec3, wind: Vec3) -> BallPath {
+        use crate::core::BallConditionFactors;
+
+        let mut config = GameConfig::default();
+        config.physics.gravity = -10.0;
+        config.wind.height_exponent = 0.0;
+        config.wind.acceleration_factor = 1.0;
+        let enclosure = create_court_enclosure(&config.court);
+        let env = BallEnvironment {
+            config: &config,
+            enclosure: &enclosure,
+            factors: BallConditionFactors::default(),
+            wind: WindField {
+                base: wind,
+                ..Default::default()
+            },
+        };
+        predict_ball_path(&env, ball_pos, ball_vel, Vec3::ZERO, 3.0, 1.0 / 60.0)
+    }
 
     /// 着地時間計算テスト
     /// @spec 30301_ai_movement_spec.md#req-30301-v07-002
     #[test]
     fn test_time_to_landing_calculation() {
-        // 位置: (0, 5, 0)、速度: (10, 0, 5)、重力: -10
-        let position = Vec3::new(0.0, 5.0, 0.0);
-        let velocity = Vec3::new(10.0, 0.0, 5.0);
-        let gravity = -10.0;
+        // 位置: (-5, 5, 0)、速度: (10, 0, 2)、重力: -10
+        let path = predicted_path(
+            Vec3::new(-5.0, 5.0, 0.0),
+            Vec3::new(10.0, 0.0, 2.0),
+            Vec3::ZERO,
+        );
 
-        let result = calculate_time_to_landing(position, velocity, gravity);
-        assert!(result.is_some());
+        let bounce = path.first_bounce();
+        assert!(bounce.is_some());
 
-        let time = result.unwrap();
         // t = √(2h/g) = √(2*5/10) = 1.0秒
-        assert!((time - 1.0).abs() < 0.1);
+        assert!((bounce.unwrap().time - 1.0).abs() < 0.1);
     }
 
     /// 軌道ラインZ座標計算テスト
     /// @spec 30301_ai_movement_spec.md#req-30301-v08-002
     #[test]
     fn test_trajectory_line_z_calculation() {
-        // AI位置X = 5.0, ボール位置 = (0, 5, 0), ボール速度 = (10, 0, 5), 重力 = -10
-        // 着地時間 = √(2*5/10) = 1.0秒
-        // 着地位置X = 0 + 10 * 1.0 = 10.0
-        // 着地位置Z = 0 + 5 * 1.0 = 5.0
-        // AIのX=5.0は軌道の中間点（t=0.5）
-        // trajectory_z = 0 + (5 - 0) * 0.5 = 2.5
-        let ai_x = 5.0;
-        let ball_pos = Vec3::new(0.0, 5.0, 0.0);
-        let ball_vel = Vec3::new(10.0, 0.0, 5.0);
-        let gravity = -10.0;
+        // 着地時間 = 1.0秒、着地位置 = (5, 0, 2)
+        // AIのX=0.0は軌道の中間点 → trajectory_z = 0 + (2 - 0) * 0.5 = 1.0
+        let ball_pos = Vec3::new(-5.0, 5.0, 0.0);
+        let path = predicted_path(ball_pos, Vec3::new(10.0, 0.0, 2.0), Vec3::ZERO);
 
-        let result = calculate_trajectory_line_z(ai_x, ball_pos, ball_vel, gravity);
+        let result = calculate_trajectory_line_z(0.0, ball_pos, &path);
         assert!(result.is_some());
 
         let trajectory_z = result.unwrap();
-        // 軌道ラインの中間点なので Z = 2.5
-        assert!((trajectory_z - 2.5).abs() < 0.1);
+        assert!((trajectory_z - 1.0).abs() < 0.1);
     }
 
     /// 着地地点計算テスト
     /// @spec 30301_ai_movement_spec.md#req-30301-v08-001
     #[test]
     fn test_landing_position_calculation() {
-        // ボール位置 = (0, 5, 0), ボール速度 = (10, 0, 5), 重力 = -10
-        // 着地時間 = √(2*5/10) = 1.0秒
-        // 着地位置 = (10, 0, 5)
-        let ball_pos = Vec3::new(0.0, 5.0, 0.0);
-        let ball_vel = Vec3::new(10.0, 0.0, 5.0);
-        let gravity = -10.0;
-
-        let result = calculate_landing_position(ball_pos, ball_vel, gravity);
+        // 着地時間 = 1.0秒、着地位置 = (5, 0, 2)
+        // 許容誤差はティック単位の積分による着地時刻のずれ（1ティック未満）を含む
+        let path = predicted_path(
+            Vec3::new(-5.0, 5.0, 0.0),
+            Vec3::new(10.0, 0.0, 2.0),
+            Vec3::ZERO,
+        );
+
+        let result = calculate_landing_position(&path);
         assert!(result.is_some());
 
         let landing_pos = result.unwrap();
-        assert!((landing_pos.x - 10.0).abs() < 0.1);
-        assert!((landing_pos.z - 5.0).abs() < 0.1);
+        assert!((landing_pos.x - 5.0).abs() < 0.15);
+        assert!((landing_pos.z - 2.0).abs() < 0.15);
+        assert_eq!(landing_pos.y, 0.0);
+    }
+
+    /// 風を見込んだ着地地点計算テスト
+    /// @spec 30301_ai_movement_spec.md#req-30301-v07-004
+    #[test]
+    fn test_landing_position_with_wind() {
+        // 着地時間 = 1.0秒、風の加速度 (2, 0, -4) → 0.5 × a × t² = (1, 0, -2) 流される
+        let path = predicted_path(
+            Vec3::new(-5.0, 5.0, 0.0),
+            Vec3::new(10.0, 0.0, 2.0),
+            Vec3::new(2.0, 0.0, -4.0),
+        );
+
+        let landing_pos = calculate_landing_position(&path).unwrap();
+        assert!((landing_pos.x - 6.0).abs() < 0.15);
+        assert!(landing_pos.z.abs() < 0.15);
+    }
+
+    /// ネットに当たるボールは打った側に着地すると予測する
+    /// @spec 30301_ai_movement_spec.md#req-30301-v08-001
+    #[test]
+    fn test_landing_position_after_net() {
+        // 放物線なら相手コートに届くが、ネット本体に当たって跳ね返る
+        let path = predicted_path(
+            Vec3::new(-3.0, 0.5, 0.0),
+            Vec3::new(12.0, 0.0, 0.0),
+            Vec3::ZERO,
+        );
+
+        let landing_pos = calculate_landing_position(&path).unwrap();
+        assert!(landing_pos.x < 0.0);
     }
 
     /// 短いボール判定テスト
     /// @spec 30301_ai_movement_spec.md#req-30301-v07-002
     #[test]
     fn test_short_ball_detection() {
-        let gravity = -10.0;
-
-        // 短いボール: 着地が早い
-        let ai_x = 10.0;
-        let ball_pos = Vec3::new(0.0, 1.0, 0.0);
-        let ball_vel = Vec3::new(5.0, 0.0, 0.0); // ゆっくり移動
-
-        assert!(is_short_ball(ai_x, ball_pos, ball_vel, gravity));
+        let ai_x = 5.0;
 
-        // 長いボール: インターセプト可能
-        let ball_pos2 = Vec3::new(0.0, 5.0, 0.0);
-        let ball_vel2 = Vec3::new(20.0, 5.0, 0.0); // 速い
+        // 短いボール: 着地が早い（ゆっくり移動）
+        let path = predicted_path(
+            Vec3::new(-5.0, 1.0, 0.0),
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::ZERO,
+        );
+        assert!(is_short_ball(ai_x, &path));
 
-        assert!(!is_short_ball(ai_x, ball_pos2, ball_vel2, gravity));
+        // 長いボール: インターセプト可能（速い）
+        let path2 = predicted_path(
+            Vec3::new(-5.0, 4.0, 0.0),
+            Vec3::new(20.0, 2.0, 0.0),
+            Vec3::ZERO,
+        );
+        assert!(!is_short_ball(ai_x, &path2));
     }
 
     /// 待機位置計算テスト
@@ -618,6 +762,95 @@ mod tests {
         assert!((expected_x_left - (-5.0)).abs() < 0.01);
     }
 
+    /// TST-30106-004: ダブルスの担当サイド判定
+    /// @spec 30105_doubles_spec.md#req-30105-004
+    #[test]
+    fn test_req_30105_004_lane_coverage() {
+        // 着地点が +Z 側に来るボール
+        let ball_pos = Vec3::new(0.0, 2.0, 1.0);
+        let path = predicted_path(ball_pos, Vec3::new(5.0, 0.0, 1.0), Vec3::ZERO);
+
+        // シングルスは常に担当
+        assert!(is_ball_in_my_lane(0.0, ball_pos, &path));
+        // +Z 担当は追跡、-Z 担当は追跡しない
+        assert!(is_ball_in_my_lane(2.5, ball_pos, &path));
+        assert!(!is_ball_in_my_lane(-2.5, ball_pos, &path));
+    }
+
+    /// 囲いの外のボールは相手が打った自コート側のものだけ追う
+    /// @spec 30301_ai_movement_spec.md#req-30301-v07-005
+    #[test]
+    fn test_req_30301_v07_005_outside_ball_is_mine() {
+        let enclosure = create_court_enclosure(&crate::resource::CourtConfig::default());
+        // デフォルトの囲い: 左右壁 Z = ±8、前後壁 X = ±10
+        let outside_left = Vec3::new(-5.0, 1.0, 9.0);
+
+        assert!(is_outside_ball_mine(
+            CourtSide::Left,
+            outside_left,
+            Some(CourtSide::Right),
+            &enclosure,
+            0.0
+        ));
+        // 自分の側が打った・相手コート側・囲いの内側は追わない
+        assert!(!is_outside_ball_mine(
+            CourtSide::Left,
+            outside_left,
+            Some(CourtSide::Left),
+            &enclosure,
+            0.0
+        ));
+        assert!(!is_outside_ball_mine(
+            CourtSide::Right,
+            outside_left,
+            Some(CourtSide::Left),
+            &enclosure,
+            0.0
+        ));
+        assert!(!is_outside_ball_mine(
+            CourtSide::Left,
+            Vec3::new(-5.0, 1.0, 7.0),
+            Some(CourtSide::Right),
+            &enclosure,
+            0.0
+        ));
+    }
+
+    /// 囲いの内外をまたぐ移動は自コート側のドアを経由する
+    /// @spec 30301_ai_movement_spec.md#req-30301-v07-005
+    #[test]
+    fn test_req_30301_v07_005_door_waypoint() {
+        let layout = CourtLayout::new(create_court_enclosure(
+            &crate::resource::CourtConfig::default(),
+        ));
+        // デフォルトの壁セグメント: 左右壁の 1 < |X| < 2 はドア
+        let ai_pos = Vec3::new(-5.0, 0.0, 6.0);
+        let outside = Vec3::new(-6.0, 0.0, 9.5);
+
+        let waypoint = door_waypoint(ai_pos, outside, CourtSide::Left, &layout, 0.0)
+            .expect("should route through a door");
+        assert!((waypoint.x - -1.5).abs() < 1e-5);
+        assert!(waypoint.z > 8.0);
+
+        // 外から戻るときは内側へ抜けた位置を目指す
+        let back = door_waypoint(outside, ai_pos, CourtSide::Left, &layout, 0.0)
+            .expect("should route through a door");
+        assert!((back.x - -1.5).abs() < 1e-5);
+        assert!(back.z < 8.0);
+
+        // 内外が同じならドアは不要
+        assert_eq!(
+            door_waypoint(
+                ai_pos,
+                Vec3::new(-3.0, 0.0, -2.0),
+                CourtSide::Left,
+                &layout,
+                0.0
+            ),
+            None
+        );
+    }
+
     /// REQ-30301-002: 移動方向正規化テスト
     #[test]
     fn test_direction_normalization() {
@@ -7,17 +7,20 @@
 //! AIプレイヤーがサーブ権を持つ時、
 //! 1. 待機時間後に自動でトスを実行
 //! 2. ボールが最適高さに達したらヒットを実行
+//!    （バウンド必須ルールではバウンド後に打つ）
 
 use bevy::prelude::*;
 
 use crate::components::{
     AiController, Ball, LogicalPosition, Player, TacticsType, TossBall, TossBallBundle,
 };
-use crate::core::ShotEvent;
+use crate::core::{FaultEvent, ShotEvent, ShotType};
 use crate::resource::scoring::{ServeState, ServeSubPhase};
-use crate::resource::{FixedDeltaTime, GameConfig, GameRng, MatchFlowState, MatchScore};
+use crate::resource::{
+    FixedDeltaTime, GameConfig, GameRng, MatchFlowState, MatchScore, RallyState,
+};
 use crate::simulation::DebugLogger;
-use crate::systems::GameSystemSet;
+use crate::systems::{judge_serve_contact, write_serve_fault, GameSystemSet};
 
 /// AIサーブ待機タイマー（リソース）
 /// @spec 30102_serve_spec.md#req-30102-087
@@ -59,7 +62,7 @@ pub fn ai_serve_timer_init_system(
     // AIがサーバーか確認
     let is_ai_server = ai_query
         .iter()
-        .any(|player| player.court_side == match_score.server);
+        .any(|player| player.id == match_score.server_player_id());
 
     if !is_ai_server {
         // 人間がサーバーの場合、タイマーをクリア
@@ -177,7 +180,7 @@ pub fn ai_serve_toss_system(
     // AIサーバーを取得
     let Some((_player, logical_pos)) = ai_query
         .iter()
-        .find(|(p, _)| p.court_side == match_score.server)
+        .find(|(p, _)| p.id == match_score.server_player_id())
     else {
         warn!("AI server not found for toss");
         ai_serve_timer.toss_timer = None;
@@ -218,18 +221,23 @@ pub fn ai_serve_toss_system(
 /// AIヒット実行システム
 /// @spec 30102_serve_spec.md#req-30102-088
 ///
+/// @spec 30902_fault_spec.md#req-30902-052
+///
 /// ボールが最適高さに達したらヒットを実行する。
+/// バウンド必須ルールではトスがバウンドするまで待つ。
 #[allow(clippy::too_many_arguments)]
 pub fn ai_serve_hit_system(
     mut commands: Commands,
     config: Res<GameConfig>,
     match_score: Res<MatchScore>,
     mut serve_state: ResMut<ServeState>,
+    mut rally_state: ResMut<RallyState>,
     mut ai_serve_timer: ResMut<AiServeTimer>,
     mut debug_logger: Option<ResMut<DebugLogger>>,
     ai_query: Query<(&Player, &LogicalPosition), With<AiController>>,
     toss_ball_query: Query<(Entity, &LogicalPosition), With<TossBall>>,
     mut shot_event_writer: MessageWriter<ShotEvent>,
+    mut fault_events: MessageWriter<FaultEvent>,
 ) {
     // Tossing状態でのみヒット可能
     if serve_state.phase != ServeSubPhase::Tossing {
@@ -241,10 +249,15 @@ pub fn ai_serve_hit_system(
         return;
     }
 
+    // @spec 30902_fault_spec.md#req-30902-052: バウンド前は打たない
+    if config.serve.require_bounce_before_hit && !serve_state.toss_bounced() {
+        return;
+    }
+
     // AIサーバーを取得
     let Some((player, player_pos)) = ai_query
         .iter()
-        .find(|(p, _)| p.court_side == match_score.server)
+        .find(|(p, _)| p.id == match_score.server_player_id())
     else {
         return;
     };
@@ -275,6 +288,28 @@ pub fn ai_serve_hit_system(
     // トスボールを削除
     commands.entity(toss_entity).despawn();
 
+    // @spec 30902_fault_spec.md#req-30902-050: 打点の合法性判定（設定次第でAIもフォルトしうる）
+    if let Some(reason) = judge_serve_contact(
+        match_score.server,
+        rally_state.serve_side,
+        player_pos.value,
+        hit_pos,
+        serve_state.toss_bounced(),
+        &config,
+    ) {
+        serve_state.on_hit_success();
+        ai_serve_timer.hit_executed = true;
+        write_serve_fault(&mut rally_state, &mut fault_events, reason);
+        if let Some(ref mut logger) = debug_logger {
+            logger.log_ai(&format!(
+                "SERVE_FAULT server={:?} reason={:?} height={:.2}",
+                match_score.server, reason, ball_height
+            ));
+        }
+        info!("AI Serve fault: {:?} by {:?}", reason, match_score.server);
+        return;
+    }
+
     // サーブ方向（ランダムバリエーション付き Z オフセットを direction.y として渡す）
     // @spec 30102_serve_spec.md#req-30102-071
     let direction = Vec2::new(0.0, ai_serve_timer.direction_z_offset);
@@ -295,6 +330,7 @@ pub fn ai_serve_hit_system(
         is_serve: true,
         hit_position: Some(hit_pos),
         serve_toss_velocity_y: Some(toss_velocity_y),
+        shot_type: ShotType::Serve,
     });
 
     // ヒット済みフラグを設定
@@ -320,7 +356,7 @@ pub struct AiServePlugin;
 impl Plugin for AiServePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AiServeTimer>().add_systems(
-            Update,
+            FixedUpdate,
             (
                 ai_serve_timer_init_system,
                 ai_serve_toss_system,
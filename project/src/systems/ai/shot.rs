@@ -9,6 +9,7 @@ use crate::components::{
     ShotState, TacticsType,
 };
 use crate::core::events::SwingIntentEvent;
+use crate::core::ShotModifier;
 use crate::resource::config::GameConfig;
 use crate::resource::{GameRng, MatchScore, RallyPhase, RallyState};
 use crate::simulation::DebugLogger;
@@ -49,6 +50,15 @@ fn can_ai_shoot(
         }
     }
 
+    // ダブルス: サーブはレシーバー本人のみリターンできる
+    // @spec 30105_doubles_spec.md#req-30105-003
+    if rally_state.phase == RallyPhase::Serving
+        && player.court_side != match_score.server
+        && player.id != match_score.receiver_player_id(rally_state.serve_side)
+    {
+        return false;
+    }
+
     // 自分が打ったボールは打てない（相手が打ち返すまで待つ）
     if last_shooter.side == Some(player.court_side) {
         return false;
@@ -233,6 +243,7 @@ pub fn ai_shot_system(
             court_side: player.court_side,
             direction,
             hold_time_ms: 0.0,
+            modifier: ShotModifier::None,
         });
 
         // AIショットログ出力（戦術情報を追加）
@@ -4,8 +4,9 @@
 use bevy::prelude::*;
 
 use crate::components::{Ball, KnockbackState, LastShooter, LogicalPosition, Player, Velocity};
-use crate::core::events::BallHitEvent;
-use crate::resource::config::GameConfig;
+use crate::core::events::{BallBodyHitEvent, BallHitEvent};
+use crate::core::CourtSide;
+use crate::resource::config::{BodyHitRule, GameConfig};
 
 /// ボール当たり判定プラグイン
 /// @spec 30403_collision_spec.md
@@ -14,9 +15,12 @@ pub struct BallCollisionPlugin;
 impl Plugin for BallCollisionPlugin {
     fn build(&self, app: &mut App) {
         // Note: BallHitEvent は main.rs で add_message 済み
-        app.add_systems(
-            Update,
-            ball_player_collision_system, // @spec 30403_collision_spec.md#req-30403-005
+        app.add_message::<BallBodyHitEvent>().add_systems(
+            FixedUpdate,
+            (
+                ball_player_collision_system, // @spec 30403_collision_spec.md#req-30403-005
+                ball_body_hit_system,         // @spec 30403_collision_spec.md#req-30403-007
+            ),
         );
     }
 }
@@ -35,7 +39,8 @@ pub fn ball_player_collision_system(
     mut event_writer: MessageWriter<BallHitEvent>,
 ) {
     // ふっとばし機能が無効の場合、衝突判定・反射処理をスキップ
-    if !config.knockback.enabled {
+    // パデルルール（PointLoss）では ball_body_hit_system が判定する
+    if !config.knockback.enabled || config.collision.body_hit_rule != BodyHitRule::Knockback {
         return;
     }
 
@@ -157,6 +162,88 @@ fn process_collision(
     );
 }
 
+/// ボールと体の当たり判定システム（パデルルール）
+/// @spec 30403_collision_spec.md#req-30403-007
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+///
+/// 体の当たり判定（足元から body_height までの円柱状のカプセル）に触れたボールについて
+/// `BallBodyHitEvent` を発行する。ボールは反射させず、失点判定は point_judgment が行う。
+/// 打った本人は対象外（打球直後のラケットと体の重なりを除外）。パートナーは対象に含む。
+pub fn ball_body_hit_system(
+    config: Res<GameConfig>,
+    ball_query: Query<(Entity, &LogicalPosition, &LastShooter), With<Ball>>,
+    player_query: Query<(&LogicalPosition, &Player), Without<Ball>>,
+    mut event_writer: MessageWriter<BallBodyHitEvent>,
+) {
+    if config.collision.body_hit_rule != BodyHitRule::PointLoss {
+        return;
+    }
+
+    let collision = &config.collision;
+    for (ball_entity, ball_logical_pos, last_shooter) in ball_query.iter() {
+        // サーブのトス中など、まだ誰も打っていないボールは対象外
+        let Some(shooter) = last_shooter.side else {
+            continue;
+        };
+        let ball_pos = ball_logical_pos.value;
+
+        // REQ-30403-006: 複数プレイヤー衝突時、最も近いプレイヤー優先
+        let closest = player_query
+            .iter()
+            .filter(|(_, player)| !is_shooter(last_shooter, shooter, player))
+            .filter_map(|(player_pos, player)| {
+                body_hit_distance(
+                    ball_pos,
+                    player_pos.value,
+                    config.ball.radius,
+                    collision.body_radius,
+                    collision.body_height,
+                )
+                .map(|distance| (player, distance))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((player, _)) = closest {
+            event_writer.write(BallBodyHitEvent {
+                ball: ball_entity,
+                player_id: player.id,
+                court_side: player.court_side,
+                hit_point: ball_pos,
+            });
+        }
+    }
+}
+
+/// 打った本人かどうか
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+///
+/// 打ったプレイヤーIDが記録されていない場合は打った側全員を本人とみなす。
+fn is_shooter(last_shooter: &LastShooter, shooter: CourtSide, player: &Player) -> bool {
+    match last_shooter.player_id {
+        Some(id) => player.id == id,
+        None => player.court_side == shooter,
+    }
+}
+
+/// ボールと体の当たり判定（カプセル判定）
+/// @spec 30403_collision_spec.md#req-30403-007
+///
+/// 体は足元（player_pos.y）から body_height までの鉛直線分を中心とする半径 body_radius のカプセル。
+/// 当たっている場合は線分からボール中心までの距離を返す。
+fn body_hit_distance(
+    ball_pos: Vec3,
+    player_pos: Vec3,
+    ball_radius: f32,
+    body_radius: f32,
+    body_height: f32,
+) -> Option<f32> {
+    let bottom = player_pos.y + body_radius;
+    let top = (player_pos.y + body_height - body_radius).max(bottom);
+    let closest = Vec3::new(player_pos.x, ball_pos.y.clamp(bottom, top), player_pos.z);
+    let distance = (ball_pos - closest).length();
+    (distance <= ball_radius + body_radius).then_some(distance)
+}
+
 /// XZ平面での2点間距離を計算（水平距離、高さは無視）
 /// Note: 球体判定への移行により未使用となったが、テスト互換性のため残存
 #[allow(dead_code)]
@@ -170,6 +257,7 @@ fn distance_xz(a: Vec3, b: Vec3) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::ecs::system::RunSystemOnce;
 
     /// TST-30404-014: プレイヤー衝突判定テスト（XZ平面）
     /// @spec 30403_collision_spec.md#req-30403-001
@@ -258,6 +346,79 @@ mod tests {
         assert!(dist2 < dist1);
     }
 
+    /// TST-30404-020: 体の当たり判定（カプセル）
+    /// @spec 30403_collision_spec.md#req-30403-007
+    #[test]
+    fn test_body_hit_capsule() {
+        let player_pos = Vec3::new(2.0, 0.0, 1.0);
+        let hit = |ball_pos| body_hit_distance(ball_pos, player_pos, 0.033, 0.3, 1.8);
+
+        // 胸の高さ・体の中心付近は当たる
+        assert!(hit(Vec3::new(2.2, 1.2, 1.0)).is_some());
+        // 足元・頭の近くも当たる
+        assert!(hit(Vec3::new(2.0, 0.1, 1.2)).is_some());
+        assert!(hit(Vec3::new(2.0, 1.8, 1.0)).is_some());
+        // ラケットの打球範囲内でも体から離れていれば当たらない
+        assert!(hit(Vec3::new(3.0, 1.0, 1.0)).is_none());
+        // 頭上は当たらない
+        assert!(hit(Vec3::new(2.0, 2.3, 1.0)).is_none());
+
+        // 近いほど距離が小さい
+        let near = hit(Vec3::new(2.1, 1.0, 1.0)).unwrap();
+        let far = hit(Vec3::new(2.3, 1.0, 1.0)).unwrap();
+        assert!(near < far);
+    }
+
+    /// パデルルールで ball_body_hit_system を1回実行し、
+    /// BallBodyHitEvent が発行されたプレイヤーIDを返す（ボールは原点の高さ 1.0）
+    fn body_hit_events(last_shooter: LastShooter, players: &[(u8, CourtSide, Vec3)]) -> Vec<u8> {
+        let mut config = GameConfig::default();
+        config.collision.body_hit_rule = BodyHitRule::PointLoss;
+
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.init_resource::<Messages<BallBodyHitEvent>>();
+        world.spawn((
+            Ball,
+            LogicalPosition {
+                value: Vec3::new(0.0, 1.0, 0.0),
+            },
+            last_shooter,
+        ));
+        for &(id, court_side, position) in players {
+            world.spawn((
+                Player { id, court_side },
+                LogicalPosition { value: position },
+            ));
+        }
+
+        world.run_system_once(ball_body_hit_system).unwrap();
+        world
+            .resource::<Messages<BallBodyHitEvent>>()
+            .iter_current_update_messages()
+            .map(|event| event.player_id)
+            .collect()
+    }
+
+    /// TST-30901-055: 打った本人は対象外、パートナーと相手は体に当たればイベントを発行する
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    #[test]
+    fn test_req_30901_052_body_hit_system_skips_only_shooter() {
+        let mut last_shooter = LastShooter::default();
+        last_shooter.record(CourtSide::Left, 1);
+        let shooter = (1, CourtSide::Left, Vec3::new(0.0, 0.0, 0.0));
+        let partner = (3, CourtSide::Left, Vec3::new(0.0, 0.0, 0.2));
+        let opponent = (2, CourtSide::Right, Vec3::new(0.0, 0.0, -0.2));
+
+        // 打った本人にしか重なっていなければ発行しない
+        assert!(body_hit_events(last_shooter, &[shooter]).is_empty());
+        // 打った本人より遠くてもパートナー・相手には発行する
+        assert_eq!(body_hit_events(last_shooter, &[shooter, partner]), vec![3]);
+        assert_eq!(body_hit_events(last_shooter, &[shooter, opponent]), vec![2]);
+        // まだ誰も打っていないボールは対象外
+        assert!(body_hit_events(LastShooter::default(), &[partner, opponent]).is_empty());
+    }
+
     /// TST-30404-016: 無敵状態テスト
     /// @spec 30403_collision_spec.md#req-30403-003
     #[test]
@@ -3,19 +3,167 @@
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, BallSpin, BallSpinExt, LogicalPosition, Velocity};
-use crate::core::events::{BallOutOfBoundsEvent, GroundBounceEvent, WallReflectionEvent};
-use crate::core::WallReflection;
+use crate::components::{
+    Ball, BallSpin, BallSpinExt, LogicalPosition, PreviousLogicalPosition, Velocity,
+};
+use crate::core::events::{
+    BallOutOfBoundsEvent, GroundBounceEvent, WallMaterial, WallReflectionEvent, WallType,
+};
+use crate::core::{
+    contact_spin_kick, topspin_component, CourtBounds, WallReflection, WallSpinResult,
+    WallSpinTransfer, WallSurface,
+};
 use crate::resource::config::GameConfig;
 use crate::resource::debug::LastShotDebugInfo;
-use crate::systems::court_factory::create_outer_wall_bounds;
+use crate::resource::{BallCondition, CourtLayout, GameRng};
+
+/// 地面バウンド後の速度を計算
+/// @spec 30402_reflection_spec.md#req-30402-001
+/// @spec 30402_reflection_spec.md#req-30402-100
+/// @spec 30402_reflection_spec.md#req-30402-101
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// `restitution_scale` はボール状態（内圧・気温・摩耗）による反発係数の倍率。
+pub(crate) fn ground_bounce_velocity(
+    config: &GameConfig,
+    velocity: Vec3,
+    spin: Vec3,
+    restitution_scale: f32,
+) -> Vec3 {
+    let base_bounce_factor = config.ball.bounce_factor * restitution_scale;
+    let h_factor = config.spin_physics.bounce_spin_horizontal_factor;
+    let v_factor = config.spin_physics.bounce_spin_vertical_factor;
+    let spin_value = topspin_component(spin, velocity);
+
+    // REQ-30402-100: スピンによるバウンド挙動変化
+    // 水平方向（X, Z）: velocity *= base_bounce * (1.0 + spin * h_factor)
+    // トップスピン（spin > 0）: 水平維持率上昇 → 低く伸びる
+    // スライス（spin < 0）: 水平維持率低下 → 高く止まる
+    let horizontal_bounce = base_bounce_factor * (1.0 + spin_value * h_factor);
+
+    // 垂直方向（Y）: velocity.y = -velocity.y * base_bounce * (1.0 - spin * v_factor)
+    // トップスピン（spin > 0）: 垂直維持率低下 → 低く伸びる
+    // スライス（spin < 0）: 垂直維持率上昇 → 高く止まる
+    let vertical_bounce = base_bounce_factor * (1.0 - spin_value * v_factor);
+    let bounced_y = -velocity.y * vertical_bounce;
+
+    // REQ-30402-101: 進行方向と直交する成分のみ横方向に跳ねる
+    // （進行方向の成分はトップスピン・スライスとして上で扱っている）
+    let kick = contact_spin_kick(spin, Vec3::Y);
+    let lateral_kick = match Vec3::new(velocity.x, 0.0, velocity.z).try_normalize() {
+        Some(forward) => kick - forward * kick.dot(forward),
+        None => kick,
+    };
+    let lateral = lateral_kick * config.spin_physics.bounce_spin_lateral_factor;
+
+    Vec3::new(
+        velocity.x * horizontal_bounce + lateral.x,
+        // 最小バウンド速度を保証（Y速度が0でも軽く跳ねる）
+        bounced_y.max(config.ball.min_bounce_velocity),
+        velocity.z * horizontal_bounce + lateral.z,
+    )
+}
+
+/// 壁・天井反射後の速度とスピンを計算
+/// @spec 30503_boundary_behavior.md#beh-30503-004
+/// @spec 30402_reflection_spec.md#req-30402-101
+/// @spec 30402_reflection_spec.md#req-30402-102
+/// @spec 30402_reflection_spec.md#req-30402-103
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// 材質の反発係数（ボール状態の倍率を掛ける）・摩擦で反射し、金網ではランダムに偏向させる（GameRng で再現可能）。
+/// さらにスピンに応じて跳ね返り・接線方向の速度が変化し、スピン自体も反転・減衰する。
+pub(super) fn wall_bounce(
+    config: &GameConfig,
+    wall_type: WallType,
+    material: WallMaterial,
+    velocity: Vec3,
+    spin: Vec3,
+    restitution_scale: f32,
+    game_rng: &mut GameRng,
+) -> WallSpinResult {
+    let surface = config.wall_material.surface(material);
+    let max_deflection = surface.max_deflection_deg.to_radians();
+    // 偏向なしの材質では乱数を消費しない
+    let deflection = if max_deflection > 0.0 {
+        Vec2::new(
+            game_rng.random_range(-max_deflection..=max_deflection),
+            game_rng.random_range(-max_deflection..=max_deflection),
+        )
+    } else {
+        Vec2::ZERO
+    };
+    wall_contact_response(
+        config,
+        wall_type,
+        material,
+        velocity,
+        spin,
+        restitution_scale,
+        deflection,
+    )
+}
+
+/// 偏向角を指定して壁・天井反射後の速度とスピンを計算
+/// @spec 30402_reflection_spec.md#req-30402-102
+/// @spec 30402_reflection_spec.md#req-30402-103
+///
+/// 弾道の予測では偏向なし（Vec2::ZERO）で呼び出す。
+pub(crate) fn wall_contact_response(
+    config: &GameConfig,
+    wall_type: WallType,
+    material: WallMaterial,
+    velocity: Vec3,
+    spin: Vec3,
+    restitution_scale: f32,
+    deflection: Vec2,
+) -> WallSpinResult {
+    let surface = config.wall_material.surface(material);
+    let reflected = WallReflection::reflect_surface(
+        wall_type,
+        velocity,
+        WallSurface {
+            restitution: surface.restitution * restitution_scale,
+            friction: surface.friction,
+        },
+        deflection,
+    );
+    let spin_physics = &config.spin_physics;
+    WallReflection::apply_spin(
+        wall_type,
+        reflected,
+        spin,
+        WallSpinTransfer {
+            tangential_factor: spin_physics.wall_spin_tangential_factor,
+            normal_factor: spin_physics.wall_spin_normal_factor,
+            reversal: spin_physics.wall_spin_reversal,
+        },
+    )
+}
+
+/// 位置が壁・天井の平面より外側にあるか（平面上は内側とみなす）
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+#[inline]
+fn is_beyond_wall(bounds: &CourtBounds, wall_type: WallType, position: Vec3) -> bool {
+    match wall_type {
+        WallType::LeftWall => position.z < bounds.left,
+        WallType::RightWall => position.z > bounds.right,
+        WallType::BackWallLeft => position.x < bounds.back_left,
+        WallType::BackWallRight => position.x > bounds.back_right,
+        WallType::Ceiling => position.y > bounds.ceiling,
+    }
+}
 
 /// 地面バウンドシステム
 /// @spec 30402_reflection_spec.md#req-30402-001
 /// @spec 30402_reflection_spec.md#req-30402-002
 /// @spec 30402_reflection_spec.md#req-30402-100
+///
+/// フレーム途中の着地は ball_swept_motion_system が処理する。
+/// ここでは地面上で下向き・水平に動いているボール（プレイヤー衝突後など）を扱う。
 pub fn ball_ground_bounce_system(
     config: Res<GameConfig>,
+    condition: Res<BallCondition>,
     mut query: Query<
         (
             Entity,
@@ -28,11 +176,7 @@ pub fn ball_ground_bounce_system(
     mut event_writer: MessageWriter<GroundBounceEvent>,
     mut debug_info: ResMut<LastShotDebugInfo>,
 ) {
-    let base_bounce_factor = config.ball.bounce_factor;
-    let min_bounce_velocity = config.ball.min_bounce_velocity;
     let net_x = config.court.net_x;
-    let h_factor = config.spin_physics.bounce_spin_horizontal_factor;
-    let v_factor = config.spin_physics.bounce_spin_vertical_factor;
 
     for (entity, mut velocity, mut logical_pos, ball_spin) in query.iter_mut() {
         let pos = logical_pos.value;
@@ -40,23 +184,12 @@ pub fn ball_ground_bounce_system(
         // REQ-30402-001: ボールが地面（Y <= 0）に接触し、下向きまたは静止中の場合
         // Y速度が0の場合もバウンドさせる（プレイヤー衝突で水平に跳ね返った場合対応）
         if pos.y <= 0.0 && velocity.value.y <= 0.0 {
-            // REQ-30402-100: スピンによるバウンド挙動変化
-            let spin_value = ball_spin.value_or_default();
-
-            // 水平方向（X, Z）: velocity *= base_bounce * (1.0 + spin * h_factor)
-            // トップスピン（spin > 0）: 水平維持率上昇 → 低く伸びる
-            // スライス（spin < 0）: 水平維持率低下 → 高く止まる
-            let horizontal_bounce = base_bounce_factor * (1.0 + spin_value * h_factor);
-            velocity.value.x *= horizontal_bounce;
-            velocity.value.z *= horizontal_bounce;
-
-            // 垂直方向（Y）: velocity.y = -velocity.y * base_bounce * (1.0 - spin * v_factor)
-            // トップスピン（spin > 0）: 垂直維持率低下 → 低く伸びる
-            // スライス（spin < 0）: 垂直維持率上昇 → 高く止まる
-            let vertical_bounce = base_bounce_factor * (1.0 - spin_value * v_factor);
-            let bounced_y = -velocity.value.y * vertical_bounce;
-            // 最小バウンド速度を保証（Y速度が0でも軽く跳ねる）
-            velocity.value.y = bounced_y.max(min_bounce_velocity);
+            velocity.value = ground_bounce_velocity(
+                &config,
+                velocity.value,
+                ball_spin.angular_or_default(),
+                condition.factors.restitution_scale,
+            );
 
             // 位置を地面に補正（めり込み防止）
             logical_pos.value.y = 0.0;
@@ -76,30 +209,79 @@ pub fn ball_ground_bounce_system(
     }
 }
 
-/// 壁・天井反射システム（外壁接触で即アウト判定用イベント発行）
+/// 壁・天井反射システム（壁プレー判定用イベント発行）
 /// @spec 30503_boundary_behavior.md#beh-30503-008
 /// @spec 30402_reflection_spec.md#req-30402-003
 /// @spec 30402_reflection_spec.md#req-30402-004
 /// @spec 30402_reflection_spec.md#req-30402-005
 /// @spec 30402_reflection_spec.md#req-30402-006
 /// @spec 30402_reflection_spec.md#req-30402-007
+///
+/// フレーム途中の壁接触は ball_swept_motion_system が処理する。
+/// ここでは既に壁面上・壁外にあるボール（プレイヤー衝突で押し出された場合など）を扱う。
+/// 開口部（BEH-30503-009）や、ボール半径より深く壁外にある（囲いの外へ出た）ボールは反射しない。
+/// ティック開始時に既に壁外にいたボール（外側の面で跳ね返ったボールなど）も、
+/// 内側へ引き戻さないよう反射しない。
+#[allow(clippy::type_complexity)]
 pub fn ball_wall_reflection_system(
     config: Res<GameConfig>,
-    mut query: Query<(Entity, &mut Velocity, &mut LogicalPosition), With<Ball>>,
+    layout: Res<CourtLayout>,
+    condition: Res<BallCondition>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Velocity,
+            &mut LogicalPosition,
+            Option<&PreviousLogicalPosition>,
+            Option<&mut BallSpin>,
+        ),
+        With<Ball>,
+    >,
     mut event_writer: MessageWriter<WallReflectionEvent>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     // 外壁位置で判定（コートラインではなく）
-    let bounds = create_outer_wall_bounds(&config.court);
-    let bounce_factor = config.ball.wall_bounce_factor;
+    let enclosure = &layout.enclosure;
+    let bounds = enclosure.bounds;
 
-    for (entity, mut velocity, mut logical_pos) in query.iter_mut() {
+    for (entity, mut velocity, mut logical_pos, previous_pos, mut ball_spin) in query.iter_mut() {
         let pos = logical_pos.value;
         let vel = velocity.value;
 
-        // 壁・天井との接触チェックと反射計算
-        if let Some(result) = WallReflection::check_and_reflect(pos, vel, &bounds, bounce_factor) {
-            // 速度を反射後の値に更新
-            velocity.value = result.reflected_velocity;
+        // 壁・天井との接触チェック（反射速度は材質を考慮して下で計算し直す）
+        if let Some(result) = WallReflection::check_and_reflect(pos, vel, &bounds, 1.0) {
+            // BEH-30503-009: 壁のない位置・囲いの外では反射しない
+            let solid = match result.wall_type {
+                WallType::Ceiling => enclosure.within_corners(WallType::Ceiling, pos),
+                wall_type => enclosure
+                    .segment_at(wall_type, result.contact_point)
+                    .is_some(),
+            };
+            if !solid || pos.distance(result.contact_point) > config.ball.radius {
+                continue;
+            }
+            // BEH-30503-009: 壁外から来たボールは囲いの外に留める
+            if previous_pos
+                .is_some_and(|previous| is_beyond_wall(&bounds, result.wall_type, previous.value))
+            {
+                continue;
+            }
+
+            // REQ-30402-101〜103: 材質とスピンを考慮した反射速度・スピンに更新
+            let material = enclosure.material_at(result.wall_type, result.contact_point);
+            let bounce = wall_bounce(
+                &config,
+                result.wall_type,
+                material,
+                vel,
+                ball_spin.as_deref().angular_or_default(),
+                condition.factors.restitution_scale,
+                &mut game_rng,
+            );
+            velocity.value = bounce.velocity;
+            if let Some(spin) = ball_spin.as_mut() {
+                spin.angular_velocity = bounce.spin;
+            }
 
             // REQ-30402-007: 位置を境界内に補正（めり込み防止）
             logical_pos.value.x = bounds.clamp_x(pos.x);
@@ -110,9 +292,10 @@ pub fn ball_wall_reflection_system(
             event_writer.write(WallReflectionEvent {
                 ball: entity,
                 wall_type: result.wall_type,
+                material,
                 contact_point: result.contact_point,
                 incident_velocity: vel,
-                reflected_velocity: result.reflected_velocity,
+                reflected_velocity: velocity.value,
             });
         }
     }
@@ -122,9 +305,8 @@ pub fn ball_wall_reflection_system(
 /// @spec 30901_point_judgment_spec.md#req-30901-001
 ///
 /// ボールがコート境界外に着地（Y <= 0 かつ コート外）した場合にアウトイベントを発行。
-/// テニスルールでは、打ったボールがコート外に着地 = アウト（打った側の失点）。
-/// 通常は壁に当たった時点でアウト（wall_hit_judgment_system）だが、
-/// 壁を超えた場合のフォールバックとして機能。
+/// 受け手コートでバウンドする前にコート外へ着地 = アウト（打った側の失点）。
+/// 壁に当たった場合の判定は wall_hit_judgment_system（パデルの壁プレー）が担当。
 pub fn ball_out_of_bounds_system(
     config: Res<GameConfig>,
     query: Query<(Entity, &LogicalPosition), With<Ball>>,
@@ -5,19 +5,29 @@
 mod bounce;
 mod net_collision;
 mod physics;
+mod prediction;
+mod swept;
 
 #[cfg(test)]
 mod tests;
 
 use bevy::prelude::*;
 
+use crate::resource::{BallCondition, BallPrediction, WindField};
+use crate::systems::GameSystemSet;
+
 pub use bounce::{
     ball_ground_bounce_system, ball_out_of_bounds_system, ball_wall_reflection_system,
 };
-pub use net_collision::ball_net_collision_system;
 pub use physics::{
-    ball_air_drag_system, ball_gravity_system, ball_position_update_system, ball_spin_decay_system,
+    ball_air_drag_system, ball_condition_system, ball_gravity_system, ball_magnus_system,
+    ball_spin_decay_system, ball_wind_system, wind_update_system, BallEnvironment,
 };
+pub use prediction::{ball_prediction_system, predict_ball_path};
+pub use swept::ball_swept_motion_system;
+
+// 弾道計算（壁狙いの予測）と共有する反射計算
+pub(crate) use bounce::{ground_bounce_velocity, wall_contact_response};
 
 /// ボール軌道プラグイン
 /// @spec 30401_trajectory_spec.md
@@ -26,32 +36,50 @@ pub struct BallTrajectoryPlugin;
 impl Plugin for BallTrajectoryPlugin {
     fn build(&self, app: &mut App) {
         use crate::core::events::{
-            BallOutOfBoundsEvent, GroundBounceEvent, NetHitEvent, WallReflectionEvent,
+            BallLeftCourtEvent, BallOutOfBoundsEvent, GroundBounceEvent, NetHitEvent,
+            WallReflectionEvent,
         };
 
-        app.add_message::<BallOutOfBoundsEvent>()
+        app.init_resource::<BallCondition>()
+            .init_resource::<WindField>()
+            .init_resource::<BallPrediction>()
+            .add_message::<BallOutOfBoundsEvent>()
+            .add_message::<BallLeftCourtEvent>()
             .add_message::<GroundBounceEvent>()
             .add_message::<NetHitEvent>()
             .add_message::<WallReflectionEvent>()
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
+                    // 環境条件・摩耗によるボール状態を更新
+                    ball_condition_system,
+                    // 風（突風）を更新
+                    wind_update_system,
                     // スピン減衰を最初に適用（重力計算前にスピン値を更新）
                     ball_spin_decay_system,
                     // 重力適用（スピンによる変動含む）
                     ball_gravity_system,
+                    // サイドスピンによる横方向の曲がり
+                    ball_magnus_system,
+                    // 風による加速度
+                    ball_wind_system,
                     // 空気抵抗適用（スピンによる追加抵抗含む）
                     ball_air_drag_system,
-                    // 位置更新
-                    ball_position_update_system,
-                    // ネット衝突判定（位置更新後、バウンド判定前）
-                    ball_net_collision_system,
-                    // バウンド・反射（スピンによるバウンド変動含む）
+                    // 位置更新（ネット・地面・壁の連続衝突判定を含む）
+                    ball_swept_motion_system,
+                    // フレーム開始時点で接地・壁外にある場合のバウンド・反射
                     ball_ground_bounce_system,
                     ball_wall_reflection_system,
                     ball_out_of_bounds_system,
                 )
                     .chain(),
+            )
+            // ティック開始時点のボールから軌道を予測（ゲームロジックで共有）
+            .add_systems(
+                FixedUpdate,
+                ball_prediction_system
+                    .before(ball_condition_system)
+                    .before(GameSystemSet::GameLogic),
             );
     }
 }
@@ -1,68 +1,119 @@
-//! ネット衝突検出・反射システム
+//! ネット衝突時の反射計算
 //! @spec 30503_boundary_behavior.md#beh-30503-005
+//!
+//! ネット面の通過判定は ball_swept_motion_system（スイープ判定）が担当する。
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, LogicalPosition, Velocity};
-use crate::core::events::NetHitEvent;
-use crate::resource::config::GameConfig;
+use crate::core::events::NetContact;
+use crate::core::{NetInfo, NetZone};
+use crate::resource::config::NetContactConfig;
+use crate::resource::GameRng;
 
-/// ネット衝突検出・反射システム
-///
-/// ボールがネット位置を通過する際、高さがネット未満なら：
-/// 1. NetHitEvent を発行
-/// 2. ボールを跳ね返す（X方向の速度を反転・減衰）
-///
-/// @spec 30503_boundary_behavior.md#beh-30503-005
-pub fn ball_net_collision_system(
-    config: Res<GameConfig>,
-    mut query: Query<(Entity, &mut Velocity, &mut LogicalPosition), With<Ball>>,
-    mut event_writer: MessageWriter<NetHitEvent>,
-) {
-    let net_x = config.court.net_x;
-    let net_height = config.court.net_height;
-    // ネット接触判定の許容範囲（ボールがネットを通過する1フレーム分の距離をカバー）
-    let net_tolerance = 0.3;
-    // ネット反射時の減衰係数
-    let net_bounce_factor = 0.3;
-
-    for (entity, mut velocity, mut logical_pos) in query.iter_mut() {
-        let pos = logical_pos.value;
-        let vel = velocity.value;
-
-        // ネット位置付近（net_x ± tolerance）にいるか
-        let near_net = (pos.x - net_x).abs() < net_tolerance;
-
-        // ネット高さ未満か
-        let below_net_height = pos.y < net_height;
+/// ネット接触後のボールの状態
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct NetResponse {
+    /// 接触の結果
+    pub contact: NetContact,
+    /// 接触後の位置
+    pub position: Vec3,
+    /// 接触後の速度
+    pub velocity: Vec3,
+}
 
-        // ネットに向かって移動中か（X方向の速度がネットに向かっている）
-        let moving_towards_net = (pos.x < net_x && vel.x > 0.0) || (pos.x > net_x && vel.x < 0.0);
+/// ネット接触後の速度を計算
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+///
+/// X方向の速度を反転・減衰し、Y, Z方向も減衰させる。
+#[inline]
+fn net_bounce_velocity(velocity: Vec3, factor: f32) -> Vec3 {
+    Vec3::new(
+        -velocity.x * factor,
+        velocity.y * factor,
+        velocity.z * factor,
+    )
+}
 
-        if near_net && below_net_height && moving_towards_net {
-            info!(
-                "Ball hit net at {:?}, height: {:.2} < {:.2}",
-                pos, pos.y, net_height
-            );
+/// 白帯に当たったときに相手コート側へ越える確率
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+///
+/// 白帯の最上部で cord_roll_over_chance、白帯の下端に向かって線形に 0 まで下がる。
+#[inline]
+pub(super) fn cord_roll_over_chance(config: &NetContactConfig, net: &NetInfo, point: Vec3) -> f32 {
+    if net.cord_band <= 0.0 {
+        return 0.0;
+    }
+    let depth = net.height_at(point.z) - point.y;
+    config.cord_roll_over_chance.clamp(0.0, 1.0) * (1.0 - depth / net.cord_band).clamp(0.0, 1.0)
+}
 
-            // 1. NetHitEvent 発行
-            event_writer.write(NetHitEvent {
-                ball: entity,
-                contact_point: Vec3::new(net_x, pos.y, pos.z),
-            });
+/// ネット接触時の応答を計算
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+///
+/// - 本体: 跳ね返って打った側へ落ちる
+/// - 白帯: GameRng で越える・落ちるを決める。越えた場合はネット上端から
+///   減速して進み続け、上向きに弾む
+/// - ポスト: X方向の速度を反転する（Y, Z方向は維持）
+pub(super) fn net_contact_response(
+    config: &NetContactConfig,
+    net: &NetInfo,
+    zone: NetZone,
+    point: Vec3,
+    velocity: Vec3,
+    rng: &mut GameRng,
+) -> NetResponse {
+    // 白帯に当たった場合のみ乱数を消費する
+    let rolls_over = zone == NetZone::Cord && {
+        let roll: f32 = rng.random_range(0.0..1.0);
+        roll < cord_roll_over_chance(config, net, point)
+    };
+    net_contact_outcome(config, net, zone, point, velocity, rolls_over)
+}
 
-            // 2. X方向の速度を反転・減衰
-            velocity.value.x = -vel.x * net_bounce_factor;
-            // Y, Z方向も減衰
-            velocity.value.y *= net_bounce_factor;
-            velocity.value.z *= net_bounce_factor;
+/// 白帯を越えるかを指定してネット接触時の応答を計算
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+///
+/// 軌道予測では越える確率が高い方を指定する。`rolls_over` は白帯以外では無視する。
+pub(super) fn net_contact_outcome(
+    config: &NetContactConfig,
+    net: &NetInfo,
+    zone: NetZone,
+    point: Vec3,
+    velocity: Vec3,
+    rolls_over: bool,
+) -> NetResponse {
+    let bounce = |contact| NetResponse {
+        contact,
+        position: point,
+        velocity: net_bounce_velocity(velocity, config.bounce_factor),
+    };
 
-            // 3. 位置をネット手前に戻す（めり込み防止）
-            if pos.x < net_x {
-                logical_pos.value.x = net_x - net_tolerance;
+    match zone {
+        NetZone::Body => bounce(NetContact::Body),
+        NetZone::Cord => {
+            if rolls_over {
+                let factor = config.cord_roll_over_speed_factor;
+                NetResponse {
+                    contact: NetContact::CordRollOver,
+                    position: Vec3::new(point.x, net.height_at(point.z), point.z),
+                    velocity: Vec3::new(
+                        velocity.x * factor,
+                        velocity.y.abs() * factor,
+                        velocity.z * factor,
+                    ),
+                }
             } else {
-                logical_pos.value.x = net_x + net_tolerance;
+                bounce(NetContact::CordDropBack)
             }
         }
+        NetZone::Post => NetResponse {
+            contact: NetContact::Post,
+            position: point,
+            velocity: Vec3::new(
+                -velocity.x * config.post_restitution,
+                velocity.y,
+                velocity.z,
+            ),
+        },
     }
 }
@@ -4,8 +4,115 @@
 use bevy::prelude::*;
 
 use crate::components::{Ball, BallSpin, BallSpinExt, LogicalPosition, Velocity};
+use crate::core::{
+    magnus_side_acceleration, topspin_component, BallConditionFactors, CourtEnclosure,
+};
 use crate::resource::config::GameConfig;
-use crate::resource::FixedDeltaTime;
+use crate::resource::{BallCondition, FixedDeltaTime, GameRng, MatchScore, WindField};
+
+/// ボールが飛ぶ環境（設定・囲い・ボール状態・風）
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// FixedUpdate の各システムと軌道予測が同じ値で計算するためにまとめたもの。
+#[derive(Debug, Clone, Copy)]
+pub struct BallEnvironment<'a> {
+    /// ゲーム設定
+    pub config: &'a GameConfig,
+    /// コートの囲い（CourtLayout）
+    pub enclosure: &'a CourtEnclosure,
+    /// ボール状態による補正倍率
+    pub factors: BallConditionFactors,
+    /// 風の状態
+    pub wind: WindField,
+}
+
+/// 1ティック分のスピン減衰後の角速度
+/// @spec 30401_trajectory_spec.md#req-30401-101
+#[inline]
+fn decayed_spin(spin: Vec3, config: &GameConfig, spin_decay_scale: f32, delta: f32) -> Vec3 {
+    let spin_decay_rate = config.spin_physics.spin_decay_rate * spin_decay_scale;
+    spin * (1.0 - spin_decay_rate * delta).max(0.0)
+}
+
+/// スピンによる実効重力
+/// @spec 30401_trajectory_spec.md#req-30401-100
+#[inline]
+fn spin_gravity(config: &GameConfig, topspin: f32) -> f32 {
+    config.physics.gravity * (1.0 + topspin * config.spin_physics.gravity_spin_factor)
+}
+
+/// 1ティック分の水平速度の減衰率（空気抵抗）
+/// @spec 30401_trajectory_spec.md#req-30401-102
+#[inline]
+fn air_drag_decay(config: &GameConfig, spin_amount: f32, drag_scale: f32, delta: f32) -> f32 {
+    let spin_physics = &config.spin_physics;
+    let drag =
+        (spin_physics.base_air_drag + spin_amount * spin_physics.spin_drag_factor) * drag_scale;
+    // 速度減衰（最低0.9を保証して極端な減速を防ぐ）
+    (1.0 - drag * delta).max(0.9)
+}
+
+/// 空中のボールに1ティック分の力を適用
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// FixedUpdate のシステムと同じ順序（スピン減衰 → 重力 → マグヌス → 風 → 空気抵抗）・
+/// 同じ計算で速度とスピンを更新する。地面上（Y ≤ 0）では何もしない。
+pub(super) fn apply_airborne_forces(
+    env: &BallEnvironment,
+    position: Vec3,
+    velocity: &mut Vec3,
+    spin: &mut Vec3,
+    delta: f32,
+) {
+    if position.y <= 0.0 {
+        return;
+    }
+    let config = env.config;
+    *spin = decayed_spin(*spin, config, env.factors.spin_decay_scale, delta);
+    velocity.y += spin_gravity(config, topspin_component(*spin, *velocity)) * delta;
+    *velocity +=
+        magnus_side_acceleration(*spin, *velocity, config.spin_physics.magnus_side_factor) * delta;
+    *velocity += env
+        .wind
+        .acceleration_at(position.y, &config.wind, env.factors.drag_scale)
+        * delta;
+    let decay = air_drag_decay(config, spin.length(), env.factors.drag_scale, delta);
+    velocity.x *= decay;
+    velocity.z *= decay;
+}
+
+/// ボール状態更新システム
+/// @spec 30401_trajectory_spec.md#req-30401-105
+/// @spec 30402_reflection_spec.md#req-30402-104
+///
+/// 環境条件（標高・気温）・ボール内圧と経過ゲーム数（摩耗）から補正倍率を求める。
+/// 設定のホットリロードにも追従するよう毎ティック導出し、変化したときだけ更新する。
+pub fn ball_condition_system(
+    config: Res<GameConfig>,
+    match_score: Option<Res<MatchScore>>,
+    mut condition: ResMut<BallCondition>,
+) {
+    let games_played = match_score.map_or(0, |score| score.games_played());
+    condition.set_if_neq(BallCondition {
+        factors: config
+            .ball_condition
+            .factors(&config.environment, games_played),
+        games_played,
+    });
+}
+
+/// 風更新システム
+/// @spec 30401_trajectory_spec.md#req-30401-051
+///
+/// 基本風を設定から取得し、突風を GameRng で抽選して1ティック分進める。
+pub fn wind_update_system(
+    fixed_dt: Res<FixedDeltaTime>,
+    config: Res<GameConfig>,
+    mut wind: ResMut<WindField>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    wind.advance(&config.wind, fixed_dt.delta_secs(), &mut game_rng);
+}
 
 /// ボール重力適用システム
 /// @spec 30401_trajectory_spec.md#req-30401-001
@@ -17,8 +124,6 @@ pub fn ball_gravity_system(
     mut query: Query<(&mut Velocity, &LogicalPosition, Option<&BallSpin>), With<Ball>>,
 ) {
     let delta = fixed_dt.delta_secs();
-    let base_gravity = config.physics.gravity;
-    let gravity_spin_factor = config.spin_physics.gravity_spin_factor;
 
     for (mut velocity, logical_pos, ball_spin) in query.iter_mut() {
         // REQ-30401-001: ボールが空中にある場合のみ重力を適用
@@ -28,8 +133,8 @@ pub fn ball_gravity_system(
             // effective_gravity = base_gravity * (1.0 + spin * gravity_spin_factor)
             // トップスピン（spin > 0）: 重力増加 → 早く落ちる
             // スライス（spin < 0）: 重力減少 → 浮く
-            let spin_value = ball_spin.value_or_default();
-            let effective_gravity = base_gravity * (1.0 + spin_value * gravity_spin_factor);
+            let spin_value = ball_spin.topspin_or_default(velocity.value);
+            let effective_gravity = spin_gravity(&config, spin_value);
 
             // REQ-30401-004: 速度更新（重力適用）
             velocity.value.y += effective_gravity * delta;
@@ -37,43 +142,81 @@ pub fn ball_gravity_system(
     }
 }
 
-/// ボール位置更新システム
-/// @spec 30401_trajectory_spec.md#req-30401-003
-pub fn ball_position_update_system(
+/// マグヌス効果（サイドスピンによる横方向の曲がり）適用システム
+/// @spec 30401_trajectory_spec.md#req-30401-104
+///
+/// a = magnus_side_factor × (ω_y·Y) × v
+/// 上から見て反時計回り（ω_y > 0）: 進行方向の左へ曲がる
+pub fn ball_magnus_system(
+    fixed_dt: Res<FixedDeltaTime>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Velocity, &LogicalPosition, &BallSpin), With<Ball>>,
+) {
+    let delta = fixed_dt.delta_secs();
+    let magnus_side_factor = config.spin_physics.magnus_side_factor;
+
+    for (mut velocity, logical_pos, ball_spin) in query.iter_mut() {
+        // 空中にある場合のみ適用
+        if logical_pos.value.y > 0.0 {
+            let accel = magnus_side_acceleration(
+                ball_spin.angular_velocity,
+                velocity.value,
+                magnus_side_factor,
+            );
+            velocity.value += accel * delta;
+        }
+    }
+}
+
+/// 風適用システム
+/// @spec 30401_trajectory_spec.md#req-30401-051
+///
+/// ボールの高さでの風速に応じた加速度を与える（空気密度に比例）。
+/// a = wind(height) × acceleration_factor × drag_scale
+pub fn ball_wind_system(
     fixed_dt: Res<FixedDeltaTime>,
-    mut query: Query<(&Velocity, &mut LogicalPosition), With<Ball>>,
+    config: Res<GameConfig>,
+    wind: Res<WindField>,
+    condition: Res<BallCondition>,
+    mut query: Query<(&mut Velocity, &LogicalPosition), With<Ball>>,
 ) {
+    if wind.velocity() == Vec3::ZERO {
+        return;
+    }
     let delta = fixed_dt.delta_secs();
+    let drag_scale = condition.factors.drag_scale;
 
-    for (velocity, mut logical_pos) in query.iter_mut() {
-        // REQ-30401-003: Position += Velocity * deltaTime
-        logical_pos.value += velocity.value * delta;
+    for (mut velocity, logical_pos) in query.iter_mut() {
+        // 空中にある場合のみ適用
+        if logical_pos.value.y > 0.0 {
+            velocity.value +=
+                wind.acceleration_at(logical_pos.value.y, &config.wind, drag_scale) * delta;
+        }
     }
 }
 
 /// ボール空気抵抗システム
 /// @spec 30401_trajectory_spec.md#req-30401-102
+/// @spec 30401_trajectory_spec.md#req-30401-105
 ///
-/// スピン絶対値に応じて空気抵抗を増加させる。
-/// drag = base_air_drag + spin.abs() * spin_drag_factor
+/// スピン量（角速度ベクトルの大きさ）に応じて空気抵抗を増加させる。
+/// 空気抵抗は空気密度（標高・気温）に比例する。
+/// drag = (base_air_drag + |spin| * spin_drag_factor) * drag_scale
 /// 速度減衰: velocity *= (1.0 - drag * delta).max(0.9)
 pub fn ball_air_drag_system(
     fixed_dt: Res<FixedDeltaTime>,
     config: Res<GameConfig>,
+    condition: Res<BallCondition>,
     mut query: Query<(&mut Velocity, &LogicalPosition, Option<&BallSpin>), With<Ball>>,
 ) {
     let delta = fixed_dt.delta_secs();
-    let base_air_drag = config.spin_physics.base_air_drag;
-    let spin_drag_factor = config.spin_physics.spin_drag_factor;
+    let drag_scale = condition.factors.drag_scale;
 
     for (mut velocity, logical_pos, ball_spin) in query.iter_mut() {
         // 空中にある場合のみ適用
         if logical_pos.value.y > 0.0 {
-            let spin_value = ball_spin.value_or_default();
-            let drag = base_air_drag + spin_value.abs() * spin_drag_factor;
-
-            // 速度減衰（最低0.9を保証して極端な減速を防ぐ）
-            let decay_factor = (1.0 - drag * delta).max(0.9);
+            let spin_amount = ball_spin.angular_or_default().length();
+            let decay_factor = air_drag_decay(&config, spin_amount, drag_scale, delta);
             velocity.value.x *= decay_factor;
             velocity.value.z *= decay_factor;
             // Y速度は重力で制御されるため、空気抵抗は水平方向のみ適用
@@ -83,21 +226,24 @@ pub fn ball_air_drag_system(
 
 /// ボールスピン時間減衰システム
 /// @spec 30401_trajectory_spec.md#req-30401-101
+/// @spec 30401_trajectory_spec.md#req-30401-105
 ///
-/// 飛行中にスピン効果を時間経過で減衰させる。
-/// ball_spin.value *= (1.0 - spin_decay_rate * delta).max(0.0)
+/// 飛行中にスピン効果を時間経過で減衰させる（空気が薄いほど減衰しにくい）。
+/// ball_spin.angular_velocity *= (1.0 - spin_decay_rate * spin_decay_scale * delta).max(0.0)
 pub fn ball_spin_decay_system(
     fixed_dt: Res<FixedDeltaTime>,
     config: Res<GameConfig>,
+    condition: Res<BallCondition>,
     mut query: Query<(&mut BallSpin, &LogicalPosition), With<Ball>>,
 ) {
     let delta = fixed_dt.delta_secs();
-    let spin_decay_rate = config.spin_physics.spin_decay_rate;
+    let spin_decay_scale = condition.factors.spin_decay_scale;
 
     for (mut ball_spin, logical_pos) in query.iter_mut() {
         // 空中にある場合のみ減衰
         if logical_pos.value.y > 0.0 {
-            ball_spin.value *= (1.0 - spin_decay_rate * delta).max(0.0);
+            ball_spin.angular_velocity =
+                decayed_spin(ball_spin.angular_velocity, &config, spin_decay_scale, delta);
         }
     }
 }
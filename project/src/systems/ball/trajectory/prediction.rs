@@ -0,0 +1,174 @@
+//! ボール軌道予測
+//! @spec 30401_trajectory_spec.md#req-30401-106
+//!
+//! ボール物理（FixedUpdate のシステム）と同じ計算をティック単位で進め、
+//! 時刻付きの折れ線と接触（ネット・地面・壁・開口部）を求める。
+//! 乱数を使う応答（白帯・金網の偏向）は決定的な値で置き換える。
+
+use bevy::prelude::*;
+
+use super::bounce::{ground_bounce_velocity, wall_contact_response};
+use super::net_collision::{cord_roll_over_chance, net_contact_outcome};
+use super::physics::{apply_airborne_forces, BallEnvironment};
+use super::swept::MAX_CONTACTS_PER_FRAME;
+use crate::components::{Ball, BallSpin, BallSpinExt, LogicalPosition, Velocity};
+use crate::core::{sweep_first_contact, SweptSurface};
+use crate::resource::config::GameConfig;
+use crate::resource::{
+    BallCondition, BallPath, BallPrediction, CourtLayout, FixedDeltaTime, PathContact, PathPoint,
+    WindField,
+};
+use crate::systems::court_factory::create_net_info;
+
+/// ボールの状態から軌道を予測
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// `delta` ごとに `duration` 秒先まで進める（ゲームと同じ固定ティックを渡すと実際の動きと一致する）。
+/// - 空中の力: スピン減衰・重力・マグヌス・風・空気抵抗（風は現在の状態のまま）
+/// - 接触: ネット（白帯は越える確率が 0.5 以上なら越える）・地面・壁（金網の偏向なし）・開口部
+pub fn predict_ball_path(
+    env: &BallEnvironment,
+    position: Vec3,
+    velocity: Vec3,
+    spin: Vec3,
+    duration: f32,
+    delta: f32,
+) -> BallPath {
+    let config = env.config;
+    let net = create_net_info(&config.court);
+    let enclosure = env.enclosure;
+    let restitution_scale = env.factors.restitution_scale;
+
+    let (mut position, mut velocity, mut spin) = (position, velocity, spin);
+    let steps = if delta > 0.0 {
+        (duration.max(0.0) / delta).ceil() as usize
+    } else {
+        0
+    };
+    let mut path = BallPath {
+        points: Vec::with_capacity(steps + 1),
+        contacts: Vec::new(),
+    };
+    path.points.push(PathPoint {
+        time: 0.0,
+        position,
+        velocity,
+    });
+
+    for step in 0..steps {
+        let tick_start = step as f32 * delta;
+        apply_airborne_forces(env, position, &mut velocity, &mut spin, delta);
+
+        // ball_swept_motion_system と同じ接触の解決
+        let mut remaining = delta;
+        for _ in 0..MAX_CONTACTS_PER_FRAME {
+            let Some(contact) = sweep_first_contact(position, velocity, remaining, &net, enclosure)
+            else {
+                break;
+            };
+            position = contact.point;
+            remaining -= contact.time;
+            let time = tick_start + (delta - remaining);
+
+            match contact.surface {
+                SweptSurface::Net(zone) => {
+                    let rolls_over =
+                        cord_roll_over_chance(&config.net_contact, &net, contact.point) >= 0.5;
+                    let response = net_contact_outcome(
+                        &config.net_contact,
+                        &net,
+                        zone,
+                        contact.point,
+                        velocity,
+                        rolls_over,
+                    );
+                    position = response.position;
+                    velocity = response.velocity;
+                }
+                SweptSurface::Floor => {
+                    velocity = ground_bounce_velocity(config, velocity, spin, restitution_scale);
+                    remaining = 0.0;
+                }
+                SweptSurface::Wall(wall_type) => {
+                    let material = enclosure.material_at(wall_type, contact.point);
+                    let response = wall_contact_response(
+                        config,
+                        wall_type,
+                        material,
+                        velocity,
+                        spin,
+                        restitution_scale,
+                        Vec2::ZERO,
+                    );
+                    velocity = response.velocity;
+                    spin = response.spin;
+                }
+                SweptSurface::Opening(_) => {}
+            }
+            path.contacts.push(PathContact {
+                surface: contact.surface,
+                time,
+                point: contact.point,
+                velocity,
+            });
+
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        if remaining > 0.0 {
+            position += velocity * remaining;
+        }
+
+        // ball_ground_bounce_system: 地面上で下向き・水平に動いている場合
+        if position.y <= 0.0 && velocity.y <= 0.0 {
+            velocity = ground_bounce_velocity(config, velocity, spin, restitution_scale);
+            position.y = 0.0;
+            path.contacts.push(PathContact {
+                surface: SweptSurface::Floor,
+                time: tick_start + delta,
+                point: position,
+                velocity,
+            });
+        }
+
+        path.points.push(PathPoint {
+            time: tick_start + delta,
+            position,
+            velocity,
+        });
+    }
+    path
+}
+
+/// ボール軌道予測システム
+/// @spec 30401_trajectory_spec.md#req-30401-106
+///
+/// ティックの最初（ボール物理より前）にボールの状態から予測し、BallPrediction に保持する。
+/// AI・ラケットの打点計画・デバッグ表示は同じティック内でこの予測を共有する。
+pub fn ball_prediction_system(
+    fixed_dt: Res<FixedDeltaTime>,
+    config: Res<GameConfig>,
+    layout: Res<CourtLayout>,
+    condition: Res<BallCondition>,
+    wind: Res<WindField>,
+    query: Query<(&LogicalPosition, &Velocity, Option<&BallSpin>), With<Ball>>,
+    mut prediction: ResMut<BallPrediction>,
+) {
+    let env = BallEnvironment {
+        config: &config,
+        enclosure: &layout.enclosure,
+        factors: condition.factors,
+        wind: *wind,
+    };
+    prediction.path = query.iter().next().map(|(position, velocity, spin)| {
+        predict_ball_path(
+            &env,
+            position.value,
+            velocity.value,
+            spin.angular_or_default(),
+            config.physics.prediction_horizon,
+            fixed_dt.delta_secs(),
+        )
+    });
+}
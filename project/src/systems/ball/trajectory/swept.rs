@@ -0,0 +1,194 @@
+//! ボール移動・連続衝突判定システム
+//! @spec 30401_trajectory_spec.md#req-30401-003
+//! @spec 30401_trajectory_spec.md#req-30401-052
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::bounce::{ground_bounce_velocity, wall_bounce};
+use super::net_collision::net_contact_response;
+use crate::components::{Ball, BallSpin, BallSpinExt, LogicalPosition, Velocity};
+use crate::core::events::{
+    BallLeftCourtEvent, GroundBounceEvent, NetHitEvent, WallReflectionEvent,
+};
+use crate::core::{determine_court_side, sweep_first_contact, SweptSurface};
+use crate::resource::config::GameConfig;
+use crate::resource::debug::LastShotDebugInfo;
+use crate::resource::{BallCondition, CourtLayout, FixedDeltaTime, GameRng};
+use crate::systems::court_factory::create_net_info;
+
+/// 1フレーム内で処理する接触の最大数（角での連続反射を想定）
+pub(super) const MAX_CONTACTS_PER_FRAME: usize = 4;
+
+/// ボール移動に使う設定・コート環境
+/// @spec 30401_trajectory_spec.md#req-30401-052
+#[derive(SystemParam)]
+pub struct SweptMotionEnv<'w> {
+    fixed_dt: Res<'w, FixedDeltaTime>,
+    config: Res<'w, GameConfig>,
+    layout: Res<'w, CourtLayout>,
+    condition: Res<'w, BallCondition>,
+}
+
+/// ボールの接触イベントの送信先
+/// @spec 30401_trajectory_spec.md#req-30401-052
+#[derive(SystemParam)]
+pub struct BallContactWriters<'w> {
+    net: MessageWriter<'w, NetHitEvent>,
+    bounce: MessageWriter<'w, GroundBounceEvent>,
+    wall: MessageWriter<'w, WallReflectionEvent>,
+    left_court: MessageWriter<'w, BallLeftCourtEvent>,
+}
+
+/// ボール移動・連続衝突判定システム
+/// @spec 30401_trajectory_spec.md#req-30401-003
+/// @spec 30401_trajectory_spec.md#req-30401-052
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// 1フレームの移動を線分としてネット・地面・外壁と交差判定し、
+/// 接触があれば接触時刻まで進めて反射させ、残り時間で移動を続ける。
+/// 接触点はフレーム途中の正確な位置でイベントに渡す。
+/// 開口部（ドア・壁の上）を通過した場合は反射せず BallLeftCourtEvent を発行する。
+///
+/// 地面に接触した場合はそのフレームの移動を接地位置で打ち切る
+/// （着地位置 Y=0 をアウト判定 ball_out_of_bounds_system に引き継ぐため）。
+pub fn ball_swept_motion_system(
+    env: SweptMotionEnv,
+    mut query: Query<
+        (
+            Entity,
+            &mut Velocity,
+            &mut LogicalPosition,
+            Option<&mut BallSpin>,
+        ),
+        With<Ball>,
+    >,
+    mut writers: BallContactWriters,
+    mut debug_info: ResMut<LastShotDebugInfo>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let SweptMotionEnv {
+        fixed_dt,
+        config,
+        layout,
+        condition,
+    } = env;
+    let delta = fixed_dt.delta_secs();
+    let net = create_net_info(&config.court);
+    let enclosure = &layout.enclosure;
+
+    for (entity, mut velocity, mut logical_pos, mut ball_spin) in query.iter_mut() {
+        let mut remaining = delta;
+
+        for _ in 0..MAX_CONTACTS_PER_FRAME {
+            let Some(contact) = sweep_first_contact(
+                logical_pos.value,
+                velocity.value,
+                remaining,
+                &net,
+                enclosure,
+            ) else {
+                break;
+            };
+
+            logical_pos.value = contact.point;
+            remaining -= contact.time;
+            let incident = velocity.value;
+
+            match contact.surface {
+                SweptSurface::Net(zone) => {
+                    // BEH-30503-005: 本体・白帯・ポストごとの応答（白帯は GameRng で越えるか決まる）
+                    let response = net_contact_response(
+                        &config.net_contact,
+                        &net,
+                        zone,
+                        contact.point,
+                        incident,
+                        &mut game_rng,
+                    );
+                    info!(
+                        "Ball hit net ({:?}) at {:?}, net height here: {:.2}",
+                        response.contact,
+                        contact.point,
+                        net.height_at(contact.point.z)
+                    );
+                    logical_pos.value = response.position;
+                    velocity.value = response.velocity;
+                    writers.net.write(NetHitEvent {
+                        ball: entity,
+                        contact_point: contact.point,
+                        contact: response.contact,
+                    });
+                }
+                SweptSurface::Floor => {
+                    // REQ-30402-001, REQ-30402-100, REQ-30402-104: スピン・ボール状態を考慮したバウンド
+                    velocity.value = ground_bounce_velocity(
+                        &config,
+                        incident,
+                        ball_spin.as_deref().angular_or_default(),
+                        condition.factors.restitution_scale,
+                    );
+                    // REQ-30402-002: 正確な着地点で GroundBounceEvent 発行
+                    writers.bounce.write(GroundBounceEvent {
+                        ball: entity,
+                        bounce_point: contact.point,
+                        court_side: determine_court_side(contact.point.x, net.x),
+                    });
+                    // デバッグマーカー用: バウンド後はデバッグ情報を無効化
+                    debug_info.is_valid = false;
+                    // 着地位置で移動を打ち切る
+                    remaining = 0.0;
+                }
+                SweptSurface::Wall(wall_type) => {
+                    // REQ-30402-102: 接触点の材質で反射
+                    // REQ-30402-103: スピンによる跳ね返りの変化とスピンの反転
+                    let material = enclosure.material_at(wall_type, contact.point);
+                    let bounce = wall_bounce(
+                        &config,
+                        wall_type,
+                        material,
+                        incident,
+                        ball_spin.as_deref().angular_or_default(),
+                        condition.factors.restitution_scale,
+                        &mut game_rng,
+                    );
+                    velocity.value = bounce.velocity;
+                    if let Some(spin) = ball_spin.as_mut() {
+                        spin.angular_velocity = bounce.spin;
+                    }
+                    // REQ-30402-004: 正確な接触点で WallReflectionEvent 発行
+                    writers.wall.write(WallReflectionEvent {
+                        ball: entity,
+                        wall_type,
+                        material,
+                        contact_point: contact.point,
+                        incident_velocity: incident,
+                        reflected_velocity: velocity.value,
+                    });
+                }
+                SweptSurface::Opening(wall_type) => {
+                    // BEH-30503-009: 開口部を通過して囲いの外へ（速度はそのまま）
+                    info!(
+                        "Ball left the court through {:?} at {:?}",
+                        wall_type, contact.point
+                    );
+                    writers.left_court.write(BallLeftCourtEvent {
+                        ball: entity,
+                        wall_type,
+                        exit_point: contact.point,
+                    });
+                }
+            }
+
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+
+        // REQ-30401-003: 残り時間分の移動（Position += Velocity * deltaTime）
+        if remaining > 0.0 {
+            logical_pos.value += velocity.value * remaining;
+        }
+    }
+}
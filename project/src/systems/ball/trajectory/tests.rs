@@ -132,6 +132,7 @@ fn test_wall_reflection_velocity() {
         service_box_depth: 1.5,
         outer_wall_z: 8.0,
         outer_wall_x: 10.0,
+        ..Default::default()
     };
     let bounds = create_court_bounds(&config);
     let bounce_factor = 0.8_f32;
@@ -167,6 +168,7 @@ fn test_ceiling_reflection() {
         service_box_depth: 1.5,
         outer_wall_z: 8.0,
         outer_wall_x: 10.0,
+        ..Default::default()
     };
     let bounds = create_court_bounds(&config);
     let bounce_factor = 0.8_f32;
@@ -202,6 +204,7 @@ fn test_back_wall_reflection() {
         service_box_depth: 1.5,
         outer_wall_z: 8.0,
         outer_wall_x: 10.0,
+        ..Default::default()
     };
     let bounds = create_court_bounds(&config);
     let bounce_factor = 0.8_f32;
@@ -239,6 +242,7 @@ fn test_position_clamp() {
         service_box_depth: 1.5,
         outer_wall_z: 8.0,
         outer_wall_x: 10.0,
+        ..Default::default()
     };
     let bounds = create_court_bounds(&config);
 
@@ -256,3 +260,201 @@ fn test_position_clamp() {
     assert!((clamped_y - 5.0).abs() < 0.001); // クランプ: 5.0（ceiling）
     assert!((clamped_z - 5.0).abs() < 0.001); // クランプ: 5.0（right）
 }
+
+/// TST-30504-022: ネット接触の応答（本体・白帯・ポスト）
+#[test]
+fn test_beh_30503_005_net_contact_response() {
+    use super::net_collision::net_contact_response;
+    use crate::core::events::NetContact;
+    use crate::core::{NetInfo, NetZone};
+    use crate::resource::config::NetContactConfig;
+    use crate::resource::GameRng;
+
+    let net = NetInfo {
+        post_height: 0.92,
+        post_z: 5.0,
+        post_width: 0.1,
+        cord_band: 0.05,
+        ..NetInfo::new(0.0, 0.88)
+    };
+    let velocity = Vec3::new(20.0, -2.0, 1.0);
+    let cord_top = Vec3::new(0.0, 0.88, 0.0);
+    let mut rng = GameRng::from_seed(42);
+
+    // 本体: 跳ね返る
+    let body = net_contact_response(
+        &NetContactConfig::default(),
+        &net,
+        NetZone::Body,
+        Vec3::new(0.0, 0.5, 0.0),
+        velocity,
+        &mut rng,
+    );
+    assert_eq!(body.contact, NetContact::Body);
+    assert!(body.velocity.x < 0.0);
+
+    // 白帯: 確率 1 なら越え、上向きに弾んで進み続ける
+    let always = NetContactConfig {
+        cord_roll_over_chance: 1.0,
+        ..Default::default()
+    };
+    let over = net_contact_response(&always, &net, NetZone::Cord, cord_top, velocity, &mut rng);
+    assert_eq!(over.contact, NetContact::CordRollOver);
+    assert!(over.velocity.x > 0.0);
+    assert!(over.velocity.y > 0.0);
+    assert!((over.position.y - 0.88).abs() < 1e-6);
+
+    // 白帯: 確率 0 なら打った側へ落ちる
+    let never = NetContactConfig {
+        cord_roll_over_chance: 0.0,
+        ..Default::default()
+    };
+    let back = net_contact_response(&never, &net, NetZone::Cord, cord_top, velocity, &mut rng);
+    assert_eq!(back.contact, NetContact::CordDropBack);
+    assert!(back.velocity.x < 0.0);
+
+    // ポスト: X方向だけ反転
+    let post = net_contact_response(
+        &NetContactConfig::default(),
+        &net,
+        NetZone::Post,
+        Vec3::new(0.0, 0.5, 4.95),
+        velocity,
+        &mut rng,
+    );
+    assert_eq!(post.contact, NetContact::Post);
+    assert!(post.velocity.x < 0.0);
+    assert_eq!(post.velocity.y, velocity.y);
+    assert_eq!(post.velocity.z, velocity.z);
+}
+
+/// TST-30504-022: 白帯を越える確率は上端で最大、下端で 0
+#[test]
+fn test_beh_30503_005_cord_roll_over_chance() {
+    use super::net_collision::cord_roll_over_chance;
+    use crate::core::NetInfo;
+    use crate::resource::config::NetContactConfig;
+
+    let net = NetInfo {
+        cord_band: 0.1,
+        ..NetInfo::new(0.0, 1.0)
+    };
+    let config = NetContactConfig {
+        cord_roll_over_chance: 0.6,
+        ..Default::default()
+    };
+
+    let chance = |y| cord_roll_over_chance(&config, &net, Vec3::new(0.0, y, 0.0));
+    assert!((chance(1.0) - 0.6).abs() < 1e-5);
+    assert!((chance(0.95) - 0.3).abs() < 1e-5);
+    assert!(chance(0.9).abs() < 1e-5);
+}
+
+/// 軌道予測用の環境（基準条件・無風）
+fn prediction_env<'a>(
+    config: &'a crate::resource::config::GameConfig,
+    enclosure: &'a crate::core::CourtEnclosure,
+) -> super::BallEnvironment<'a> {
+    super::BallEnvironment {
+        config,
+        enclosure,
+        factors: crate::core::BallConditionFactors::default(),
+        wind: crate::resource::WindField::default(),
+    }
+}
+
+/// TST-30404-114: 予測軌道はティックごとの折れ線で、途中の状態から予測し直しても一致する
+/// @spec 30401_trajectory_spec.md#req-30401-106
+#[test]
+fn test_req_30401_106_prediction_is_consistent() {
+    use super::predict_ball_path;
+    use crate::resource::config::GameConfig;
+
+    let config = GameConfig::default();
+    let enclosure = crate::systems::court_factory::create_court_enclosure(&config.court);
+    let env = prediction_env(&config, &enclosure);
+    let delta = 1.0 / 60.0;
+    let path = predict_ball_path(
+        &env,
+        Vec3::new(-4.0, 1.5, 0.0),
+        Vec3::new(8.0, 4.0, 1.0),
+        Vec3::ZERO,
+        1.0,
+        delta,
+    );
+
+    assert_eq!(path.points.len(), 61);
+    assert!((path.points[60].time - 1.0).abs() < 1e-4);
+    assert!(path.contacts.is_empty());
+
+    // 放物線（ティック単位の積分誤差を含む）
+    let t = path.points[30].time;
+    let expected_y = 1.5 + 4.0 * t + 0.5 * config.physics.gravity * t * t;
+    assert!((path.points[30].position.y - expected_y).abs() < 0.06);
+
+    // 途中の状態から予測し直すと残りの軌道と一致する（ティックごとの予測を共有できる）
+    let middle = path.points[30];
+    let rest = predict_ball_path(
+        &env,
+        middle.position,
+        middle.velocity,
+        Vec3::ZERO,
+        0.5,
+        delta,
+    );
+    for (a, b) in rest.points.iter().zip(&path.points[30..]) {
+        assert!(a.position.distance(b.position) < 1e-4);
+    }
+}
+
+/// TST-30404-115: 予測軌道はネット・地面・壁との接触を時刻順に含む
+/// @spec 30401_trajectory_spec.md#req-30401-106
+#[test]
+fn test_req_30401_106_prediction_contacts() {
+    use super::predict_ball_path;
+    use crate::core::{NetZone, SweptSurface, WallType};
+    use crate::resource::config::GameConfig;
+
+    let config = GameConfig::default();
+    let enclosure = crate::systems::court_factory::create_court_enclosure(&config.court);
+    let env = prediction_env(&config, &enclosure);
+    let delta = 1.0 / 60.0;
+
+    // ネット本体に当たって打った側へ戻り、着地する
+    let net_path = predict_ball_path(
+        &env,
+        Vec3::new(-3.0, 0.5, 0.0),
+        Vec3::new(12.0, 0.0, 0.0),
+        Vec3::ZERO,
+        1.0,
+        delta,
+    );
+    assert_eq!(
+        net_path.contacts[0].surface,
+        SweptSurface::Net(NetZone::Body)
+    );
+    assert!(net_path.contacts[0].velocity.x < 0.0);
+    let bounce = net_path.first_bounce().unwrap();
+    assert!(bounce.point.x < 0.0);
+    assert!(bounce.time > net_path.contacts[0].time);
+
+    // 相手コートで弾んで後ろの壁に当たり、戻ってくる
+    let wall_path = predict_ball_path(
+        &env,
+        Vec3::new(-4.0, 1.5, 0.0),
+        Vec3::new(14.0, 2.0, 0.0),
+        Vec3::ZERO,
+        2.0,
+        delta,
+    );
+    let wall = wall_path
+        .first_contact(|contact| contact.surface == SweptSurface::Wall(WallType::BackWallRight))
+        .unwrap();
+    assert!((wall.point.x - config.court.outer_wall_x).abs() < 1e-4);
+    assert!(wall.velocity.x < 0.0);
+    assert!(wall_path.first_bounce().unwrap().time < wall.time);
+    assert!(wall_path
+        .contacts
+        .windows(2)
+        .all(|pair| pair[0].time <= pair[1].time));
+}
@@ -4,10 +4,14 @@
 use bevy::prelude::*;
 
 use crate::components::{LogicalPosition, Player, Velocity};
-use crate::core::{CourtSide, NetHitEvent};
-use crate::resource::GameConfig;
+use crate::core::{CourtEnclosure, CourtSide, NetHitEvent, WallType};
+use crate::resource::{CourtLayout, FixedDeltaTime, GameConfig};
 
-use super::court_factory::{create_court_bounds, create_net_info};
+use super::court_factory::{create_court_bounds, create_court_enclosure, create_net_info};
+
+/// 壁で止めたプレイヤーを壁面から離しておく距離（m）
+/// 次のフレームで壁面上から動き出したときの内外の判定を安定させる
+const WALL_CLEARANCE: f32 = 0.01;
 
 /// 境界システムプラグイン
 pub struct BoundaryPlugin;
@@ -17,54 +21,80 @@ impl Plugin for BoundaryPlugin {
         // イベント登録（他システムで使用される）
         app.add_message::<NetHitEvent>();
 
+        // コートの囲いはティック開始時に設定から導出（FixedUpdate の全システムで共有）
+        app.init_resource::<CourtLayout>()
+            .add_systems(FixedFirst, court_layout_system);
+
         // 注意: ball_boundary_system は無効化されました
         // ボールの境界処理は BallTrajectoryPlugin（LogicalPosition使用）に統一
-        app.add_systems(Update, player_boundary_system);
+        app.add_systems(FixedUpdate, player_boundary_system);
+    }
+}
+
+/// コートの囲い更新システム
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// 囲いの生成（セグメントの切り詰め・ドアの列挙）はプレイヤー・ボールごと、ティックごとに
+/// 行うと無駄が大きいため、GameConfig が変わったとき（起動時・ホットリロード）だけ作り直す。
+pub fn court_layout_system(config: Res<GameConfig>, mut layout: ResMut<CourtLayout>) {
+    if config.is_changed() {
+        *layout = CourtLayout::new(create_court_enclosure(&config.court));
     }
 }
 
 /// プレイヤー境界制限システム
 /// @spec 30503_boundary_behavior.md#beh-30503-003
 ///
+/// @spec 30503_boundary_behavior.md#beh-30503-010
+///
 /// 新座標系: X=打ち合い方向, Y=高さ, Z=コート幅
 /// NOTE: B30201-002 でコート外移動を許可
-/// - 左右壁制限(BEH-30503-001): 削除（コート外移動許可）
-/// - 前後壁制限(BEH-30503-002): 削除（コート外移動許可）
+/// - 左右壁・前後壁(BEH-30503-001, 002): ドア以外は通り抜け不可（コートライン外〜外壁は移動可）
+/// - 囲いの外(BEH-30503-010): ドアから出て外壁から outside_zone_depth まで移動可
 /// - ネット: 自コート側に制限（維持）
 /// - 天井・地面: 物理制限（維持）
 pub fn player_boundary_system(
+    fixed_dt: Res<FixedDeltaTime>,
     config: Res<GameConfig>,
+    layout: Res<CourtLayout>,
     mut query: Query<(&Player, &mut LogicalPosition, &mut Velocity)>,
 ) {
     let bounds = create_court_bounds(&config.court);
     let net = create_net_info(&config.court);
+    let enclosure = &layout.enclosure;
+    let delta = fixed_dt.delta_secs();
 
     for (player, mut logical_pos, mut velocity) in query.iter_mut() {
         let pos = &mut logical_pos.value;
 
-        // NOTE: B30201-002 で左右壁制限(BEH-30503-001)を削除（コート外移動許可）
-        // NOTE: B30201-002 で前後壁制限(BEH-30503-002)を削除（コート外移動許可）
-
-        // BEH-30503-003: ネット通過禁止（維持）
-        match player.court_side {
-            CourtSide::Left => {
-                // 1Pは net_x を超えられない
-                if pos.x > net.x {
-                    pos.x = net.x;
-                    if velocity.value.x > 0.0 {
-                        velocity.value.x = 0.0;
-                    }
-                }
-            }
-            CourtSide::Right => {
-                // 2Pは net_x 未満にいけない
-                if pos.x < net.x {
-                    pos.x = net.x;
-                    if velocity.value.x < 0.0 {
-                        velocity.value.x = 0.0;
-                    }
-                }
+        // BEH-30503-001, 002, 010: 壁はドアからのみ通り抜けられる
+        // 前フレームの位置は水平速度から逆算する
+        let horizontal = Vec3::new(velocity.value.x, 0.0, velocity.value.z);
+        let previous = *pos - horizontal * delta;
+        let constrained =
+            constrain_to_enclosure(previous, *pos, enclosure, config.court.outside_zone_depth);
+        if constrained.x != pos.x {
+            pos.x = constrained.x;
+            velocity.value.x = 0.0;
+        }
+        if constrained.z != pos.z {
+            pos.z = constrained.z;
+            velocity.value.z = 0.0;
+        }
+
+        // BEH-30503-003: ネット通過禁止（体がネットに重ならない位置まで）
+        let limited_x = clamp_to_own_side(
+            pos.x,
+            player.court_side,
+            net.x,
+            config.collision.body_radius,
+        );
+        if limited_x != pos.x {
+            // ネット方向の速度を止める
+            if (limited_x - pos.x) * velocity.value.x < 0.0 {
+                velocity.value.x = 0.0;
             }
+            pos.x = limited_x;
         }
 
         // 天井制限（ジャンプ時）
@@ -85,6 +115,84 @@ pub fn player_boundary_system(
     }
 }
 
+/// プレイヤーのX座標を自コート側に制限する
+/// @spec 30503_boundary_behavior.md#beh-30503-003
+///
+/// 体の当たり判定（半径 body_radius）がネットの面に重ならない net_x ∓ body_radius まで。
+/// ネットに立っているだけでタッチネット（REQ-30901-051）にならないようにする。
+fn clamp_to_own_side(x: f32, court_side: CourtSide, net_x: f32, body_radius: f32) -> f32 {
+    match court_side {
+        CourtSide::Left => x.min(net_x - body_radius),
+        CourtSide::Right => x.max(net_x + body_radius),
+    }
+}
+
+/// 囲いの壁と外側の範囲でプレイヤー位置を制限する
+/// @spec 30503_boundary_behavior.md#beh-30503-001
+/// @spec 30503_boundary_behavior.md#beh-30503-002
+/// @spec 30503_boundary_behavior.md#beh-30503-010
+///
+/// 前フレームの位置から外壁平面を横切った場合、横切った位置がドアでなければ
+/// 元いた側の壁の手前で止める。囲いの外は外壁から outside_depth までに制限する。
+pub fn constrain_to_enclosure(
+    previous: Vec3,
+    current: Vec3,
+    enclosure: &CourtEnclosure,
+    outside_depth: f32,
+) -> Vec3 {
+    let bounds = &enclosure.bounds;
+    let mut pos = current;
+
+    let walls = [
+        (WallType::LeftWall, bounds.left),
+        (WallType::RightWall, bounds.right),
+        (WallType::BackWallLeft, bounds.back_left),
+        (WallType::BackWallRight, bounds.back_right),
+    ];
+    for (wall_type, plane) in walls {
+        let normal = wall_type.normal();
+        let (normal_axis, previous_axis, current_axis) = if wall_type.is_side_wall() {
+            (normal.z, previous.z, pos.z)
+        } else {
+            (normal.x, previous.x, pos.x)
+        };
+        let was_inside = (previous_axis - plane) * normal_axis >= 0.0;
+        let is_inside = (current_axis - plane) * normal_axis >= 0.0;
+        if was_inside == is_inside {
+            continue;
+        }
+
+        // 外壁平面を横切った位置がドア、または角の外側なら通過できる
+        let t = (plane - previous_axis) / (current_axis - previous_axis);
+        let crossing = previous + (pos - previous) * t;
+        if !enclosure.within_corners(wall_type, crossing) || enclosure.is_door(wall_type, crossing)
+        {
+            continue;
+        }
+
+        let stop = if was_inside {
+            plane + normal_axis * WALL_CLEARANCE
+        } else {
+            plane - normal_axis * WALL_CLEARANCE
+        };
+        if wall_type.is_side_wall() {
+            pos.z = stop;
+        } else {
+            pos.x = stop;
+        }
+    }
+
+    // BEH-30503-010: 囲いの外で動ける範囲
+    pos.x = pos.x.clamp(
+        bounds.back_left - outside_depth,
+        bounds.back_right + outside_depth,
+    );
+    pos.z = pos
+        .z
+        .clamp(bounds.left - outside_depth, bounds.right + outside_depth);
+    pos
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,6 +205,7 @@ mod tests {
             physics: PhysicsConfig {
                 gravity: -9.8,
                 max_fall_speed: -20.0,
+                ..Default::default()
             },
             court: CourtConfig {
                 width: 10.0,
@@ -108,6 +217,7 @@ mod tests {
                 service_box_depth: 1.5,
                 outer_wall_z: 8.0,
                 outer_wall_x: 10.0,
+                ..Default::default()
             },
             player: PlayerConfig {
                 move_speed: 5.0,
@@ -125,11 +235,11 @@ mod tests {
                 bounce_factor: 0.8,
                 radius: 0.2,
                 min_bounce_velocity: 1.0,
-                wall_bounce_factor: 0.8,
             },
             collision: CollisionConfig {
                 character_radius: 0.5,
                 z_tolerance: 0.3,
+                ..Default::default()
             },
             knockback: KnockbackConfig {
                 enabled: true,
@@ -145,12 +255,24 @@ mod tests {
                 jump_shot_angle: 30.0,
                 jump_threshold: 0.5,
                 racket_swing: RacketSwingConfig::default(),
+                shot_types: ShotTypeConfig::default(),
             },
             scoring: ScoringConfig {
                 point_values: vec![0, 15, 30, 40],
                 games_to_win_set: 6,
+                set_win_margin: 2,
                 sets_to_win_match: 1,
+                tiebreak_enabled: true,
+                tiebreak_at_games: None,
+                tiebreak_points: 7,
+                final_set_super_tiebreak: false,
+                super_tiebreak_points: 10,
                 point_end_delay: 1.5,
+                change_ends_enabled: true,
+                tiebreak_change_ends_interval: 6,
+                changeover_break_secs: 3.0,
+                deuce_mode: DeuceMode::Advantage,
+                time_limit_secs: None,
             },
             input: InputConfig {
                 jump_buffer_time: 0.1,
@@ -158,44 +280,96 @@ mod tests {
                 normalization_threshold: 1.0,
                 input_sensitivity: 1.0,
             },
-            input_keys: InputKeysConfig::default(),
-            gamepad_buttons: GamepadButtonsConfig::default(),
-            shadow: ShadowConfig::default(),
-            shot_attributes: ShotAttributesConfig::default(),
-            ai: AiConfig::default(),
-            visual_feedback: VisualFeedbackConfig::default(),
-            player_visual: PlayerVisualConfig::default(),
-            serve: ServeConfig::default(),
-            spin_physics: SpinPhysicsConfig::default(),
-            trajectory: TrajectoryConfig::default(),
-            character: CharacterConfig::default(),
+            ..Default::default()
         }
     }
 
-    // NOTE: B30201-002 で BEH-30503-001（左右壁制限）を削除（コート外移動許可）
-    // NOTE: B30201-002 で BEH-30503-002（前後壁制限）を削除（コート外移動許可）
+    /// TST-30504-024: プレイヤーはドアからのみ囲いの外へ出入りできる
+    /// @spec 30503_boundary_behavior.md#beh-30503-001
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
+    #[test]
+    fn test_beh_30503_010_player_leaves_through_door() {
+        let config = test_config();
+        let enclosure = create_court_enclosure(&config.court);
+        let depth = 3.0;
+
+        // デフォルトの壁セグメント: 右壁（Z = 8）の 1 < |X| < 2 はドア
+        let through_door = constrain_to_enclosure(
+            Vec3::new(1.5, 0.0, 7.95),
+            Vec3::new(1.5, 0.0, 8.05),
+            &enclosure,
+            depth,
+        );
+        assert_eq!(through_door, Vec3::new(1.5, 0.0, 8.05));
+
+        // 壁は内側からも外側からも通り抜けられない
+        let from_inside = constrain_to_enclosure(
+            Vec3::new(5.0, 0.0, 7.95),
+            Vec3::new(5.0, 0.0, 8.05),
+            &enclosure,
+            depth,
+        );
+        assert!(from_inside.z < 8.0);
+        let from_outside = constrain_to_enclosure(
+            Vec3::new(-5.0, 0.0, -8.05),
+            Vec3::new(-5.0, 0.0, -7.95),
+            &enclosure,
+            depth,
+        );
+        assert!(from_outside.z < -8.0);
+
+        // 壁に沿った移動は止めない
+        let along = constrain_to_enclosure(
+            Vec3::new(4.0, 0.0, 7.95),
+            Vec3::new(4.1, 0.0, 8.05),
+            &enclosure,
+            depth,
+        );
+        assert_eq!(along.x, 4.1);
+    }
 
-    /// TST-30504-013: プレイヤーのネット通過禁止（X軸=打ち合い方向）
+    /// TST-30504-025: 囲いの外で動ける範囲
+    /// @spec 30503_boundary_behavior.md#beh-30503-010
     #[test]
-    fn test_beh_30503_003_player_net_constraint() {
+    fn test_beh_30503_010_outside_zone_limit() {
         let config = test_config();
-        let net = create_net_info(&config.court);
+        let enclosure = create_court_enclosure(&config.court);
 
-        // 1Pがネットを超えようとしている（+X方向へ）
-        let mut pos = Vec3::new(0.5, 0.0, 0.0);
-        let mut vel = Vec3::new(5.0, 0.0, 0.0);
-        let court_side = CourtSide::Left;
+        let pos = Vec3::new(1.5, 0.0, 12.0);
+        let limited = constrain_to_enclosure(pos, pos, &enclosure, 3.0);
+        assert_eq!(limited.z, 11.0);
 
-        // 1Pの場合、net_x より大きくなれない
-        if court_side == CourtSide::Left && pos.x > net.x {
-            pos.x = net.x;
-            if vel.x > 0.0 {
-                vel.x = 0.0;
-            }
-        }
+        let behind = Vec3::new(-14.0, 0.0, 0.0);
+        let limited = constrain_to_enclosure(behind, behind, &enclosure, 3.0);
+        assert_eq!(limited.x, -13.0);
+    }
 
-        assert_eq!(pos.x, 0.0); // ネット位置にクランプ
-        assert_eq!(vel.x, 0.0); // ネット方向の速度を0に
+    /// TST-30504-013: プレイヤーのネット通過禁止（X軸=打ち合い方向）
+    #[test]
+    fn test_beh_30503_003_player_net_constraint() {
+        let config = test_config();
+        let net = create_net_info(&config.court);
+        let body_radius = config.collision.body_radius;
+
+        // 1Pは net_x - body_radius まで、2Pは net_x + body_radius まで
+        let left = clamp_to_own_side(0.5, CourtSide::Left, net.x, body_radius);
+        let right = clamp_to_own_side(-0.5, CourtSide::Right, net.x, body_radius);
+        assert_eq!(left, net.x - body_radius);
+        assert_eq!(right, net.x + body_radius);
+
+        // 自コート側にいれば動かさない
+        assert_eq!(
+            clamp_to_own_side(-2.0, CourtSide::Left, net.x, body_radius),
+            -2.0
+        );
+        assert_eq!(
+            clamp_to_own_side(2.0, CourtSide::Right, net.x, body_radius),
+            2.0
+        );
+
+        // ネット際に立っても体がネットの面に重ならない（REQ-30901-051 のタッチネットにならない）
+        assert!((left - net.x).abs() >= body_radius);
+        assert!((right - net.x).abs() >= body_radius);
     }
 
     /// TST-30504-014: ボールの壁反射判定
@@ -225,13 +399,14 @@ mod tests {
         // ネット位置（X=0）で高さ未満
         let y = 0.5;
         let x = 0.0;
+        let z = 0.0;
         let tolerance = 0.1;
 
-        assert!(net.is_collision(y, x, tolerance));
+        assert!(net.is_collision(y, x, z, tolerance));
 
         // ネット高さ超過
         let y_above = 1.5;
-        assert!(!net.is_collision(y_above, x, tolerance));
+        assert!(!net.is_collision(y_above, x, z, tolerance));
     }
 
     /// TST-30504-016: ボールのコート区分判定（X軸=打ち合い方向）
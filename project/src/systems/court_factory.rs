@@ -2,7 +2,7 @@
 //! @spec 20001_layers.md#layer-4-systems
 //!
 //! このモジュールはSystems層に配置し、Resource層のCourtConfigを
-//! Core層のCourtBounds/NetInfoに変換する役割を持つ。
+//! Core層のCourtBounds/NetInfo/CourtEnclosureに変換する役割を持つ。
 //! これによりCore層のResource層への依存を解消する。
 //!
 //! ## 座標系
@@ -11,6 +11,8 @@
 //! - Z軸: コート幅方向（width）
 
 use crate::core::court::{CourtBounds, NetInfo};
+use crate::core::events::WallType;
+use crate::core::{CourtEnclosure, WallSegment};
 use crate::resource::CourtConfig;
 
 /// CourtConfigからCourtBoundsを生成
@@ -53,16 +55,57 @@ pub fn create_outer_wall_bounds(config: &CourtConfig) -> CourtBounds {
     )
 }
 
+/// CourtConfigからコートの囲い（CourtEnclosure）を生成
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// 外壁平面は create_outer_wall_bounds と同じ。
+/// 壁セグメントの区間は囲いの角（外壁の範囲）に収まるよう切り詰め、
+/// 範囲外のセグメントと天井指定のセグメントは無視する。
+pub fn create_court_enclosure(config: &CourtConfig) -> CourtEnclosure {
+    let segments = config
+        .walls
+        .iter()
+        .filter_map(|wall| {
+            let limit = match wall.wall {
+                WallType::LeftWall | WallType::RightWall => config.outer_wall_x,
+                WallType::BackWallLeft | WallType::BackWallRight => config.outer_wall_z,
+                WallType::Ceiling => return None,
+            };
+            let from = wall.from.min(wall.to).max(-limit);
+            let to = wall.from.max(wall.to).min(limit);
+            (from < to).then_some(WallSegment {
+                wall_type: wall.wall,
+                from,
+                to,
+                height: wall.height,
+                material: wall.material,
+            })
+        })
+        .collect();
+    CourtEnclosure::new(create_outer_wall_bounds(config), segments)
+}
+
 /// CourtConfigからNetInfoを生成
 /// @spec 30501_court_spec.md#req-30501-005
+/// @spec 30503_boundary_behavior.md#beh-30503-005
+///
+/// ネットは左右壁の間に張られ、ポストは左右壁（outer_wall_z）に接して立つ。
 #[inline]
 pub fn create_net_info(config: &CourtConfig) -> NetInfo {
-    NetInfo::new(config.net_x, config.net_height)
+    NetInfo {
+        post_height: config.net_post_height.max(config.net_height),
+        post_z: config.outer_wall_z,
+        post_width: config.net_post_width,
+        cord_band: config.net_cord_band,
+        ..NetInfo::new(config.net_x, config.net_height)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::events::WallMaterial;
+    use crate::resource::config::WallSegmentConfig;
 
     fn test_config() -> CourtConfig {
         CourtConfig {
@@ -71,10 +114,12 @@ mod tests {
             ceiling_height: 5.0,
             max_jump_height: 5.0,
             net_height: 1.0,
+            net_post_height: 1.04,
             net_x: 0.0, // ネットのX座標
             service_box_depth: 1.5,
             outer_wall_z: 8.0,  // 外壁（Z方向）
             outer_wall_x: 10.0, // 外壁（X方向）
+            ..Default::default()
         }
     }
 
@@ -93,6 +138,36 @@ mod tests {
         assert_eq!(bounds.ceiling, 5.0);
     }
 
+    /// TST-30504-019: 壁セグメントは囲いの角に切り詰める
+    #[test]
+    fn test_beh_30503_009_create_court_enclosure() {
+        let mut config = test_config();
+        config.walls = vec![
+            WallSegmentConfig {
+                wall: WallType::RightWall,
+                from: 12.0,
+                to: 6.0,
+                height: 3.0,
+                material: WallMaterial::Glass,
+            },
+            WallSegmentConfig {
+                wall: WallType::BackWallLeft,
+                from: 9.0,
+                to: 12.0,
+                height: 4.0,
+                material: WallMaterial::Glass,
+            },
+        ];
+
+        let enclosure = create_court_enclosure(&config);
+
+        // 右壁は X: 6〜10（外壁位置）に切り詰め、範囲外の後壁セグメントは除外
+        assert_eq!(enclosure.segments.len(), 1);
+        assert_eq!(enclosure.segments[0].from, 6.0);
+        assert_eq!(enclosure.segments[0].to, 10.0);
+        assert_eq!(enclosure.bounds.right, 8.0);
+    }
+
     #[test]
     fn test_create_net_info() {
         let config = test_config();
@@ -100,5 +175,7 @@ mod tests {
 
         assert_eq!(net.x, 0.0);
         assert_eq!(net.height, 1.0);
+        assert_eq!(net.post_z, config.outer_wall_z);
+        assert_eq!(net.height_at(config.outer_wall_z), config.net_post_height);
     }
 }
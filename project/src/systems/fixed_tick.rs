@@ -0,0 +1,109 @@
+//! 固定ティック実行
+//! @spec 77100_headless_sim.md#req-77100-001
+//! @spec 77100_headless_sim.md#req-77100-002
+//!
+//! ゲームロジックは FixedUpdate で固定ティックごとに実行する。
+//! - ウィンドウ版: 実時間の経過に合わせて1フレームに0〜複数ティック実行し、表示は補間する
+//! - ヘッドレス・リプレイ: 1回の app.update() でちょうど1ティック進める
+//!
+//! どちらも同じティック・同じ FixedDeltaTime でシミュレーションするため、
+//! 同じ入力とシードからは同じ結果になる。
+
+use bevy::ecs::schedule::{ExecutorKind, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use super::GameSystemSet;
+use crate::components::{LogicalPosition, PreviousLogicalPosition};
+use crate::resource::FixedDeltaTime;
+
+/// 固定ティック実行プラグイン
+/// @spec 77100_headless_sim.md#req-77100-001
+pub struct FixedTickPlugin {
+    /// ティックレート（Hz）
+    pub tick_rate: f32,
+    /// true: app.update() 1回で1ティック進める（ヘッドレス・リプレイ用）
+    pub step_per_update: bool,
+}
+
+impl FixedTickPlugin {
+    /// 実時間に合わせてティックを実行（ウィンドウ版）
+    pub fn realtime(tick_rate: f32) -> Self {
+        Self {
+            tick_rate,
+            step_per_update: false,
+        }
+    }
+
+    /// app.update() 1回で1ティック進める（ヘッドレス・リプレイ用）
+    pub fn stepped(tick_rate: f32) -> Self {
+        Self {
+            tick_rate,
+            step_per_update: true,
+        }
+    }
+}
+
+impl Plugin for FixedTickPlugin {
+    fn build(&self, app: &mut App) {
+        let fixed_dt = FixedDeltaTime::from_tick_rate(self.tick_rate);
+        let timestep = fixed_dt.timestep();
+
+        app.insert_resource(fixed_dt)
+            .insert_resource(Time::<Fixed>::from_duration(timestep));
+        if self.step_per_update {
+            // 仮想時間を毎回1ティック分だけ進める（実時間に依存しない）
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+        }
+
+        // 順序未指定のシステム同士の実行順が実行ごとに変わらないよう、
+        // 固定ティックのスケジュールはシングルスレッドで実行する（GameRng の消費順など）
+        for label in [
+            FixedFirst.intern(),
+            FixedPreUpdate.intern(),
+            FixedUpdate.intern(),
+            FixedPostUpdate.intern(),
+            FixedLast.intern(),
+            StateTransition.intern(),
+        ] {
+            app.edit_schedule(label, |schedule| {
+                schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            });
+        }
+
+        app.configure_sets(
+            FixedUpdate,
+            GameSystemSet::Input.before(GameSystemSet::GameLogic),
+        )
+        .add_systems(FixedFirst, snapshot_previous_position_system)
+        .add_systems(FixedLast, apply_state_transitions_system);
+    }
+}
+
+/// ティック開始時の論理座標を保存（表示補間用）
+/// @spec 77100_headless_sim.md#req-77100-002
+///
+/// 未付与のエンティティには現在位置で PreviousLogicalPosition を付与する。
+fn snapshot_previous_position_system(
+    mut commands: Commands,
+    mut tracked: Query<(&LogicalPosition, &mut PreviousLogicalPosition)>,
+    untracked: Query<(Entity, &LogicalPosition), Without<PreviousLogicalPosition>>,
+) {
+    for (current, mut previous) in tracked.iter_mut() {
+        previous.value = current.value;
+    }
+    for (entity, current) in untracked.iter() {
+        commands.entity(entity).insert(PreviousLogicalPosition {
+            value: current.value,
+        });
+    }
+}
+
+/// ティックの最後に State 遷移を適用
+/// @spec 77100_headless_sim.md#req-77100-001
+///
+/// 1フレームに複数ティック実行しても、NextState は次のティックの前に反映される
+/// （フレーム単位の遷移だと、ティック数によって遷移のタイミングが変わるため）。
+fn apply_state_transitions_system(world: &mut World) {
+    let _ = world.try_run_schedule(StateTransition);
+}
@@ -64,7 +64,7 @@ pub fn gamepad_input_system(
             // キーボードとゲームパッドのOR演算
             input_state.jump_pressed = input_state.jump_pressed || gamepad_jump;
         } else {
-            input_state.jump_pressed = gamepad_jump;
+            input_state.jump_pressed |= gamepad_jump;
         }
 
         // ショット入力
@@ -95,7 +95,7 @@ pub fn gamepad_input_system(
             // - キーボードでホールド中 → holding = true のまま（human_input_system で設定済み）
             // - キーボードも離されている → holding = false（human_input_system で設定済み）
         } else {
-            input_state.shot_pressed = gamepad_shot_just_pressed;
+            input_state.shot_pressed |= gamepad_shot_just_pressed;
 
             // ショットホールド状態の追跡
             if gamepad_shot_pressed {
@@ -109,6 +109,18 @@ pub fn gamepad_input_system(
                 input_state.holding = false;
             }
         }
+
+        // ショット種類ボタン（REQ-30607-003）
+        let gamepad_lob = gamepad.pressed(gamepad_config.lob);
+        let gamepad_drop_shot = gamepad.pressed(gamepad_config.drop_shot);
+        if human.device_id == 0 {
+            // キーボードとゲームパッドのOR演算
+            input_state.lob_held |= gamepad_lob;
+            input_state.drop_shot_held |= gamepad_drop_shot;
+        } else {
+            input_state.lob_held = gamepad_lob;
+            input_state.drop_shot_held = gamepad_drop_shot;
+        }
     }
 }
 
@@ -4,6 +4,7 @@
 use bevy::prelude::*;
 
 use crate::components::{quantize_movement, HumanControlled, InputState};
+use crate::core::UndoPointEvent;
 use crate::resource::config::GameConfig;
 
 /// 人間入力読み取りシステム
@@ -47,14 +48,14 @@ pub fn human_input_system(
         // 入力感度を適用し、量子化（リプレイ互換のため）
         input_state.movement = quantize_movement(movement * config.input.input_sensitivity);
 
-        // ジャンプ入力
-        input_state.jump_pressed = keyboard.just_pressed(keys.jump);
+        // ジャンプ入力（押下エッジは消費されるまで保持: REQ-77100-001）
+        input_state.jump_pressed |= keyboard.just_pressed(keys.jump);
 
         // ショット入力
         let shot_key_pressed = keyboard.pressed(keys.shot);
         let shot_key_just_pressed = keyboard.just_pressed(keys.shot);
 
-        input_state.shot_pressed = shot_key_just_pressed;
+        input_state.shot_pressed |= shot_key_just_pressed;
 
         // ショットホールド状態の追跡
         if shot_key_pressed {
@@ -71,6 +72,36 @@ pub fn human_input_system(
             input_state.holding = false;
             // hold_time はショット実行時に参照されるのでリセットしない
         }
+
+        // ショット種類ボタン（REQ-30607-003）
+        input_state.lob_held = keyboard.pressed(keys.lob);
+        input_state.drop_shot_held = keyboard.pressed(keys.drop_shot);
+    }
+}
+
+/// 消費済みの押下エッジをクリアするシステム
+/// @spec 77100_headless_sim.md#req-77100-001
+///
+/// 入力は描画フレームごと、ゲームロジックは固定ティックごとに実行されるため、
+/// 押下エッジはティックで消費されるまで保持し、ティックの最後にクリアする。
+/// （ティックが実行されないフレームの入力を取りこぼさないため）
+pub fn clear_input_edges_system(mut query: Query<&mut InputState, With<HumanControlled>>) {
+    for mut input_state in query.iter_mut() {
+        input_state.jump_pressed = false;
+        input_state.shot_pressed = false;
+    }
+}
+
+/// ポイント取り消しキー入力システム
+/// @spec 30704_score_log_spec.md#req-30704-002
+/// キーバインドは GameConfig.input_keys.undo_point から取得
+pub fn undo_point_input_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    mut undo_events: MessageWriter<UndoPointEvent>,
+) {
+    if keyboard.just_pressed(config.input_keys.undo_point) {
+        undo_events.write(UndoPointEvent);
     }
 }
 
@@ -86,5 +117,6 @@ mod tests {
         assert!(!state.shot_pressed);
         assert!(!state.holding);
         assert_eq!(state.hold_time, 0.0);
+        assert_eq!(state.shot_modifier(), crate::core::ShotModifier::None);
     }
 }
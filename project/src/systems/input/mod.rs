@@ -6,5 +6,5 @@ mod human;
 mod shot;
 
 pub use gamepad::gamepad_input_system;
-pub use human::human_input_system;
+pub use human::{clear_input_edges_system, human_input_system, undo_point_input_system};
 pub use shot::*;
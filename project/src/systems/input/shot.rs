@@ -67,6 +67,19 @@ pub fn shot_input_system(
             }
         }
 
+        // ダブルス: サーブはレシーバー本人のみリターンできる
+        // @spec 30105_doubles_spec.md#req-30105-003
+        if rally_state.phase == RallyPhase::Serving
+            && player.court_side != match_score.server
+            && player.id != match_score.receiver_player_id(rally_state.serve_side)
+        {
+            info!(
+                "Player {} shot ignored: not the designated receiver",
+                player.id
+            );
+            continue;
+        }
+
         // 自分が打ったボールは打てない（相手が打ち返すまで待つ）
         if last_shooter.side == Some(player.court_side) {
             info!(
@@ -108,11 +121,15 @@ pub fn shot_input_system(
             court_side: player.court_side,
             direction,
             hold_time_ms: input_state.hold_time,
+            modifier: input_state.shot_modifier(),
         });
 
         info!(
-            "Player {} swing intent! direction: {:?}, height: {:.2}",
-            player.id, direction, player_pos.y
+            "Player {} swing intent! direction: {:?}, modifier: {:?}, height: {:.2}",
+            player.id,
+            direction,
+            input_state.shot_modifier(),
+            player_pos.y
         );
     }
 }
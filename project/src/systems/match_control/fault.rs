@@ -2,15 +2,19 @@
 //! @spec 30902_fault_spec.md
 //!
 //! サーブ時のフォールト判定（サービスボックス外、ダブルフォルト）を行う。
+//! パデルのサーブ合法性（フット・フォルト、バウンド前打球、腰より上の打点、
+//! 着地後のフェンス接触）もここで判定する。
 
 use bevy::prelude::*;
 
 use crate::core::events::{
     DoubleFaultEvent, FaultEvent, FaultReason, GroundBounceEvent, RallyEndEvent, RallyEndReason,
+    ServeLetEvent, ServeNetFaultEvent, ShotEvent, WallMaterial, WallReflectionEvent, WallType,
 };
 use crate::core::CourtSide;
 use crate::resource::config::{GameConfig, ServeSide};
 use crate::resource::{MatchFlowState, PointEndTimer, RallyPhase, RallyState};
+use crate::systems::court_factory::create_court_enclosure;
 
 /// フォールト判定プラグイン
 /// @spec 30902_fault_spec.md
@@ -20,10 +24,13 @@ impl Plugin for FaultJudgmentPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<FaultEvent>()
             .add_message::<DoubleFaultEvent>()
+            .add_message::<ServeLetEvent>()
+            .add_message::<ServeNetFaultEvent>()
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     serve_landing_judgment_system,
+                    serve_fence_judgment_system,
                     fault_processing_system,
                     double_fault_processing_system,
                 )
@@ -58,22 +65,31 @@ impl ServiceBox {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ServeLandingOutcome {
     Fault,
+    NetFault,
     Let,
     Valid,
 }
 
+/// サーブの着地結果を判定
+/// @spec 30902_fault_spec.md#req-30902-001
+/// @spec 30902_fault_spec.md#req-30902-055
+/// @spec 30902_fault_spec.md#req-30902-056
+/// @spec 30902_fault_spec.md#req-30902-057
+///
+/// 連続レットが上限に達している場合、ネットインはインプレー（Valid）とする。
 #[inline]
 fn judge_serve_landing(
     service_box: &ServiceBox,
     bounce_point: Vec3,
     serve_touched_net: bool,
+    lets_exhausted: bool,
 ) -> ServeLandingOutcome {
-    if !service_box.contains(bounce_point.x, bounce_point.z) {
-        ServeLandingOutcome::Fault
-    } else if serve_touched_net {
-        ServeLandingOutcome::Let
-    } else {
-        ServeLandingOutcome::Valid
+    let in_box = service_box.contains(bounce_point.x, bounce_point.z);
+    match (in_box, serve_touched_net) {
+        (false, true) => ServeLandingOutcome::NetFault,
+        (false, false) => ServeLandingOutcome::Fault,
+        (true, true) if !lets_exhausted => ServeLandingOutcome::Let,
+        (true, _) => ServeLandingOutcome::Valid,
     }
 }
 
@@ -123,12 +139,99 @@ pub fn get_service_box(
     }
 }
 
+/// サーバーが立つべき半面のZ符号
+/// @spec 30902_fault_spec.md#req-30902-050
+///
+/// serve_position_system の配置と一致させる（Left: デュース = +Z、Right: デュース = -Z）
+#[inline]
+fn server_half_z_sign(server: CourtSide, serve_side: ServeSide) -> f32 {
+    if (server == CourtSide::Left) == (serve_side == ServeSide::Deuce) {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// サーブ打点の合法性を判定
+/// @spec 30902_fault_spec.md#req-30902-050
+/// @spec 30902_fault_spec.md#req-30902-052
+/// @spec 30902_fault_spec.md#req-30902-053
+///
+/// フット・フォルト → バウンド前打球 → 腰より上の打点 の順に判定し、
+/// 最初に該当したフォールト理由を返す。合法なサーブなら None。
+pub fn judge_serve_contact(
+    server: CourtSide,
+    serve_side: ServeSide,
+    server_pos: Vec3,
+    contact_point: Vec3,
+    toss_bounced: bool,
+    config: &GameConfig,
+) -> Option<FaultReason> {
+    let tolerance = config.serve.foot_fault_tolerance.max(0.0);
+
+    // サービスラインより後ろ（自陣側）に立っているか
+    let own_sign = match server {
+        CourtSide::Left => -1.0,
+        CourtSide::Right => 1.0,
+    };
+    let depth_from_net = (server_pos.x - config.court.net_x) * own_sign;
+    let behind_service_line = depth_from_net >= config.court.service_box_depth - tolerance;
+
+    // センターラインを越えず、サーブサイドの半面に立っているか
+    let in_serve_half = server_pos.z * server_half_z_sign(server, serve_side) >= -tolerance;
+
+    if !behind_service_line || !in_serve_half {
+        return Some(FaultReason::FootFault);
+    }
+    if config.serve.require_bounce_before_hit && !toss_bounced {
+        return Some(FaultReason::NoBounceBeforeHit);
+    }
+    if contact_point.y > config.serve.max_contact_height {
+        return Some(FaultReason::ContactAboveWaist);
+    }
+    None
+}
+
+/// 壁の接触点がフェンス（金網）部分かを判定
+/// @spec 30902_fault_spec.md#req-30902-054
+///
+/// 接触点の壁セグメント（BEH-30503-009）が金網ならフェンスとして扱う。
+/// 材質の判定は壁反射（REQ-30402-102）と共通。
+pub fn is_fence_contact(wall_type: WallType, contact_point: Vec3, config: &GameConfig) -> bool {
+    create_court_enclosure(&config.court).material_at(wall_type, contact_point)
+        == WallMaterial::Mesh
+}
+
+/// サーブのフォールトを発行する
+/// @spec 30902_fault_spec.md#req-30902-001
+///
+/// Faultカウンタの更新は fault_processing_system で行う。
+/// 発行後は追加の判定を防ぐため PointEnded に遷移する
+/// （fault_countをリセットしないようにend_point()は呼ばない）。
+pub fn write_serve_fault(
+    rally_state: &mut RallyState,
+    fault_events: &mut MessageWriter<FaultEvent>,
+    reason: FaultReason,
+) {
+    fault_events.write(FaultEvent {
+        server: rally_state.server,
+        fault_count: rally_state.fault_count + 1,
+        reason,
+    });
+    rally_state.serve_awaiting_return = false;
+    rally_state.phase = RallyPhase::PointEnded;
+}
+
 /// サーブ着地判定システム
 /// @spec 30902_fault_spec.md#req-30902-001
+/// @spec 30902_fault_spec.md#req-30902-055
+/// @spec 30902_fault_spec.md#req-30902-057
 ///
 /// サーブ中（Serving状態）にボールが最初に着地した位置を判定し、
 /// サービスボックス外であればフォールトイベントを発行する。
+/// ネット接触後のサービスボックス内はレット、外はネットフォールトとする。
 /// サービスボックス内であればラリーフェーズに遷移する。
+#[allow(clippy::too_many_arguments)]
 pub fn serve_landing_judgment_system(
     mut commands: Commands,
     mut bounce_events: MessageReader<GroundBounceEvent>,
@@ -136,6 +239,8 @@ pub fn serve_landing_judgment_system(
     mut next_state: ResMut<NextState<crate::resource::MatchFlowState>>,
     config: Res<GameConfig>,
     mut fault_events: MessageWriter<FaultEvent>,
+    mut let_events: MessageWriter<ServeLetEvent>,
+    mut net_fault_events: MessageWriter<ServeNetFaultEvent>,
 ) {
     // サーブ中でなければスキップ
     if rally_state.phase != RallyPhase::Serving {
@@ -148,12 +253,18 @@ pub fn serve_landing_judgment_system(
 
         // サービスボックスを取得
         let service_box = get_service_box(rally_state.server, rally_state.serve_side, &config);
-        let outcome = judge_serve_landing(&service_box, ball_pos, rally_state.serve_touched_net);
+        let lets_exhausted = rally_state.lets_exhausted(config.serve.max_consecutive_lets);
+        let outcome = judge_serve_landing(
+            &service_box,
+            ball_pos,
+            rally_state.serve_touched_net,
+            lets_exhausted,
+        );
 
         // サーブ練習モード: 着地結果に関係なく即座に再サーブ
         if config.serve.practice_infinite_mode {
             match outcome {
-                ServeLandingOutcome::Fault => info!(
+                ServeLandingOutcome::Fault | ServeLandingOutcome::NetFault => info!(
                     "Serve practice: fault landing at ({:.2}, {:.2}) -> re-serve",
                     ball_pos.x, ball_pos.z
                 ),
@@ -183,35 +294,57 @@ pub fn serve_landing_judgment_system(
                     ball_pos.x, ball_pos.z, new_fault_count
                 );
 
-                fault_events.write(FaultEvent {
+                write_serve_fault(
+                    &mut rally_state,
+                    &mut fault_events,
+                    FaultReason::OutOfServiceBox,
+                );
+                return;
+            }
+            // @spec 30902_fault_spec.md#req-30902-057: ネット接触 + サービスボックス外
+            ServeLandingOutcome::NetFault => {
+                let new_fault_count = rally_state.fault_count + 1;
+
+                info!(
+                    "Net fault! Serve touched net and landed at ({:.2}, {:.2}) outside service box. Fault count: {}",
+                    ball_pos.x, ball_pos.z, new_fault_count
+                );
+
+                net_fault_events.write(ServeNetFaultEvent {
                     server: rally_state.server,
+                    bounce_point: ball_pos,
                     fault_count: new_fault_count,
-                    reason: FaultReason::OutOfServiceBox,
                 });
-
-                // フォルト発行後は追加のバウンス判定を防ぐ
-                // (fault_countをリセットしないようにend_point()は呼ばない)
-                rally_state.phase = RallyPhase::PointEnded;
+                write_serve_fault(&mut rally_state, &mut fault_events, FaultReason::NetFault);
                 return;
             }
-            // サービスボックス内 + ネット接触済みはレット（再サーブ）
+            // @spec 30902_fault_spec.md#req-30902-055: サービスボックス内 + ネット接触済みはレット（再サーブ）
             ServeLandingOutcome::Let => {
+                rally_state.record_let();
+
                 info!(
-                    "Let! Serve touched net and landed in service box at ({:.2}, {:.2}). Re-serve.",
-                    ball_pos.x, ball_pos.z
+                    "Let! Serve touched net and landed in service box at ({:.2}, {:.2}). Re-serve. Consecutive lets: {}",
+                    ball_pos.x, ball_pos.z, rally_state.consecutive_lets
                 );
 
-                // 現在のサーブを破棄して同一点を再サーブ
+                let_events.write(ServeLetEvent {
+                    server: rally_state.server,
+                    bounce_point: ball_pos,
+                    consecutive_lets: rally_state.consecutive_lets,
+                });
+
+                // 現在のサーブを破棄して同一点を再サーブ（fault_countは変更しない）
                 commands.entity(event.ball).despawn();
                 rally_state.next_serve();
                 next_state.set(crate::resource::MatchFlowState::Serve);
                 return;
             }
             // サービスボックス内 → 有効なサーブ（ラリー開始）
+            // @spec 30902_fault_spec.md#req-30902-056: 連続レット上限後のネットインもここ
             ServeLandingOutcome::Valid => {
                 info!(
-                    "Valid serve! Ball landed at ({:.2}, {:.2}) inside service box. Starting rally.",
-                    ball_pos.x, ball_pos.z
+                    "Valid serve! Ball landed at ({:.2}, {:.2}) inside service box (net touch: {}). Starting rally.",
+                    ball_pos.x, ball_pos.z, rally_state.serve_touched_net
                 );
 
                 // @spec 30101_flow_spec.md#req-30101-002: ラリーフェーズに遷移
@@ -222,6 +355,58 @@ pub fn serve_landing_judgment_system(
     }
 }
 
+/// サーブ後のフェンス接触判定システム
+/// @spec 30902_fault_spec.md#req-30902-054
+///
+/// サービスボックスに有効に入ったサーブが、レシーバーに打ち返される前に
+/// フェンス（金網）に当たった場合はフォールトとする。
+pub fn serve_fence_judgment_system(
+    mut shot_events: MessageReader<ShotEvent>,
+    mut wall_events: MessageReader<WallReflectionEvent>,
+    mut rally_state: ResMut<RallyState>,
+    config: Res<GameConfig>,
+    mut fault_events: MessageWriter<FaultEvent>,
+) {
+    // レシーバーが打ち返したらサーブの判定は終了
+    let mut returned = false;
+    for event in shot_events.read() {
+        returned |= !event.is_serve;
+    }
+    if returned {
+        rally_state.serve_awaiting_return = false;
+    }
+
+    // イベントは毎フレーム読み切る（古い接触で判定しないため）
+    let mut fence_contact = None;
+    for event in wall_events.read() {
+        if fence_contact.is_none()
+            && is_fence_contact(event.wall_type, event.contact_point, &config)
+        {
+            fence_contact = Some(event.contact_point);
+        }
+    }
+
+    if rally_state.phase != RallyPhase::Rally || !rally_state.serve_awaiting_return {
+        return;
+    }
+    let Some(contact_point) = fence_contact else {
+        return;
+    };
+
+    info!(
+        "Fault! Serve hit the fence at ({:.2}, {:.2}, {:.2}) before the return. Fault count: {}",
+        contact_point.x,
+        contact_point.y,
+        contact_point.z,
+        rally_state.fault_count + 1
+    );
+    write_serve_fault(
+        &mut rally_state,
+        &mut fault_events,
+        FaultReason::FenceAfterBounce,
+    );
+}
+
 /// フォールト処理システム
 /// @spec 30902_fault_spec.md#req-30902-003
 ///
@@ -315,6 +500,7 @@ mod tests {
             physics: crate::resource::config::PhysicsConfig {
                 gravity: -9.8,
                 max_fall_speed: -20.0,
+                ..Default::default()
             },
             court: crate::resource::config::CourtConfig {
                 width: 10.0,
@@ -326,6 +512,7 @@ mod tests {
                 service_box_depth: 1.5,
                 outer_wall_z: 8.0,
                 outer_wall_x: 10.0,
+                ..Default::default()
             },
             player: crate::resource::config::PlayerConfig {
                 move_speed: 5.0,
@@ -343,11 +530,11 @@ mod tests {
                 bounce_factor: 0.8,
                 radius: 0.2,
                 min_bounce_velocity: 1.0,
-                wall_bounce_factor: 0.8,
             },
             collision: crate::resource::config::CollisionConfig {
                 character_radius: 0.5,
                 z_tolerance: 0.3,
+                ..Default::default()
             },
             knockback: crate::resource::config::KnockbackConfig {
                 enabled: true,
@@ -363,12 +550,24 @@ mod tests {
                 jump_shot_angle: 30.0,
                 jump_threshold: 0.5,
                 racket_swing: crate::resource::config::RacketSwingConfig::default(),
+                shot_types: crate::resource::config::ShotTypeConfig::default(),
             },
             scoring: crate::resource::config::ScoringConfig {
                 point_values: vec![0, 15, 30, 40],
                 games_to_win_set: 6,
+                set_win_margin: 2,
                 sets_to_win_match: 1,
+                tiebreak_enabled: true,
+                tiebreak_at_games: None,
+                tiebreak_points: 7,
+                final_set_super_tiebreak: false,
+                super_tiebreak_points: 10,
                 point_end_delay: 1.5,
+                change_ends_enabled: true,
+                tiebreak_change_ends_interval: 6,
+                changeover_break_secs: 3.0,
+                deuce_mode: crate::resource::config::DeuceMode::Advantage,
+                time_limit_secs: None,
             },
             input: crate::resource::config::InputConfig {
                 jump_buffer_time: 0.1,
@@ -376,17 +575,7 @@ mod tests {
                 normalization_threshold: 1.0,
                 input_sensitivity: 1.0,
             },
-            input_keys: crate::resource::config::InputKeysConfig::default(),
-            gamepad_buttons: crate::resource::config::GamepadButtonsConfig::default(),
-            shadow: crate::resource::config::ShadowConfig::default(),
-            shot_attributes: crate::resource::config::ShotAttributesConfig::default(),
-            ai: crate::resource::config::AiConfig::default(),
-            visual_feedback: crate::resource::config::VisualFeedbackConfig::default(),
-            player_visual: crate::resource::config::PlayerVisualConfig::default(),
-            serve: crate::resource::config::ServeConfig::default(),
-            spin_physics: crate::resource::config::SpinPhysicsConfig::default(),
-            trajectory: crate::resource::config::TrajectoryConfig::default(),
-            character: crate::resource::config::CharacterConfig::default(),
+            ..Default::default()
         }
     }
 
@@ -503,26 +692,270 @@ mod tests {
             z_max: 0.0,
         };
 
-        // サービスボックス外はネット接触の有無に関わらずフォールト
+        // サービスボックス外 + ネット接触なしはフォールト
         assert_eq!(
-            judge_serve_landing(&service_box, Vec3::new(2.0, 0.0, -2.5), false),
+            judge_serve_landing(&service_box, Vec3::new(2.0, 0.0, -2.5), false, false),
             ServeLandingOutcome::Fault
         );
+        // サービスボックス外 + ネット接触ありはネットフォールト
         assert_eq!(
-            judge_serve_landing(&service_box, Vec3::new(2.0, 0.0, -2.5), true),
-            ServeLandingOutcome::Fault
+            judge_serve_landing(&service_box, Vec3::new(2.0, 0.0, -2.5), true, false),
+            ServeLandingOutcome::NetFault
         );
 
         // サービスボックス内 + ネット接触ありはレット
         assert_eq!(
-            judge_serve_landing(&service_box, Vec3::new(0.8, 0.0, -2.0), true),
+            judge_serve_landing(&service_box, Vec3::new(0.8, 0.0, -2.0), true, false),
             ServeLandingOutcome::Let
         );
 
         // サービスボックス内 + ネット接触なしは有効サーブ
         assert_eq!(
-            judge_serve_landing(&service_box, Vec3::new(0.8, 0.0, -2.0), false),
+            judge_serve_landing(&service_box, Vec3::new(0.8, 0.0, -2.0), false, false),
             ServeLandingOutcome::Valid
         );
     }
+
+    /// TST-30904-065: 連続レット上限テスト
+    /// @spec 30902_fault_spec.md#req-30902-055
+    /// @spec 30902_fault_spec.md#req-30902-056
+    #[test]
+    fn test_req_30902_056_consecutive_let_cap() {
+        let service_box = ServiceBox {
+            x_min: 0.0,
+            x_max: 1.5,
+            z_min: -5.0,
+            z_max: 0.0,
+        };
+        let in_box = Vec3::new(0.8, 0.0, -2.0);
+        let max_lets = 3;
+        let mut rally_state = RallyState::new(CourtSide::Left);
+        rally_state.record_fault();
+
+        // 上限までのレットは fault_count を変えずに打ち直し
+        for expected in 1..=max_lets {
+            assert!(!rally_state.lets_exhausted(max_lets));
+            assert_eq!(
+                judge_serve_landing(&service_box, in_box, true, false),
+                ServeLandingOutcome::Let
+            );
+            rally_state.record_let();
+            assert_eq!(rally_state.consecutive_lets, expected);
+            assert_eq!(rally_state.fault_count, 1);
+        }
+
+        // 上限到達後のネットインはインプレー
+        assert!(rally_state.lets_exhausted(max_lets));
+        assert_eq!(
+            judge_serve_landing(&service_box, in_box, true, true),
+            ServeLandingOutcome::Valid
+        );
+        // ネット接触 + ボックス外は上限に関わらずネットフォールト
+        assert_eq!(
+            judge_serve_landing(&service_box, Vec3::new(2.0, 0.0, -2.5), true, true),
+            ServeLandingOutcome::NetFault
+        );
+
+        // フォールトでカウンタはリセット、0 は上限なし
+        rally_state.record_fault();
+        assert_eq!(rally_state.consecutive_lets, 0);
+        rally_state.consecutive_lets = 100;
+        assert!(!rally_state.lets_exhausted(0));
+    }
+
+    /// TST-30904-060: フット・フォルト判定テスト
+    /// @spec 30902_fault_spec.md#req-30902-050
+    #[test]
+    fn test_req_30902_050_foot_fault() {
+        let config = test_config();
+        let contact = Vec3::new(-3.0, 0.6, 2.0);
+
+        // サービスラインより後ろ・デュース半面（+Z）は合法
+        let legal_pos = Vec3::new(-3.0, 0.0, 2.0);
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                legal_pos,
+                contact,
+                true,
+                &config
+            ),
+            None
+        );
+
+        // サービスライン（ネットから1.5m）より前に立っている
+        let inside_line = Vec3::new(-1.0, 0.0, 2.0);
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                inside_line,
+                contact,
+                true,
+                &config
+            ),
+            Some(FaultReason::FootFault)
+        );
+
+        // センターラインを越えて逆サイドの半面に立っている
+        let wrong_half = Vec3::new(-3.0, 0.0, -0.5);
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                wrong_half,
+                contact,
+                true,
+                &config
+            ),
+            Some(FaultReason::FootFault)
+        );
+
+        // 2Pのアドサイドは +Z 半面が正しい
+        let p2_ad = Vec3::new(3.0, 0.0, 2.0);
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Right,
+                ServeSide::Ad,
+                p2_ad,
+                contact,
+                true,
+                &config
+            ),
+            None
+        );
+
+        // 許容量の範囲内のはみ出しは合法
+        let mut tolerant = test_config();
+        tolerant.serve.foot_fault_tolerance = 0.6;
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                inside_line,
+                contact,
+                true,
+                &tolerant
+            ),
+            None
+        );
+    }
+
+    /// TST-30904-062: バウンド前打球のフォールト判定テスト
+    /// @spec 30902_fault_spec.md#req-30902-052
+    #[test]
+    fn test_req_30902_052_no_bounce_before_hit() {
+        let mut config = test_config();
+        let server_pos = Vec3::new(-3.0, 0.0, 2.0);
+        let contact = Vec3::new(-3.0, 0.8, 2.0);
+
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                server_pos,
+                contact,
+                false,
+                &config
+            ),
+            Some(FaultReason::NoBounceBeforeHit)
+        );
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                server_pos,
+                contact,
+                true,
+                &config
+            ),
+            None
+        );
+
+        // バウンド必須を無効にすればノーバウンドでも合法
+        config.serve.require_bounce_before_hit = false;
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                server_pos,
+                contact,
+                false,
+                &config
+            ),
+            None
+        );
+    }
+
+    /// TST-30904-063: 腰より上の打点のフォールト判定テスト
+    /// @spec 30902_fault_spec.md#req-30902-053
+    #[test]
+    fn test_req_30902_053_contact_above_waist() {
+        let config = test_config();
+        let server_pos = Vec3::new(-3.0, 0.0, 2.0);
+        let waist = config.serve.max_contact_height;
+
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                server_pos,
+                Vec3::new(-3.0, waist, 2.0),
+                true,
+                &config
+            ),
+            None
+        );
+        assert_eq!(
+            judge_serve_contact(
+                CourtSide::Left,
+                ServeSide::Deuce,
+                server_pos,
+                Vec3::new(-3.0, waist + 0.1, 2.0),
+                true,
+                &config
+            ),
+            Some(FaultReason::ContactAboveWaist)
+        );
+    }
+
+    /// TST-30904-064: サーブ後のフェンス接触判定テスト
+    /// @spec 30902_fault_spec.md#req-30902-054
+    #[test]
+    fn test_req_30902_054_fence_contact() {
+        let config = test_config();
+        // デフォルトの壁セグメント: |X| < 6.0 が金網
+
+        // サイドウォールのネット寄りは金網
+        assert!(is_fence_contact(
+            WallType::RightWall,
+            Vec3::new(3.0, 1.0, 8.0),
+            &config
+        ));
+        assert!(is_fence_contact(
+            WallType::LeftWall,
+            Vec3::new(-5.9, 1.0, -8.0),
+            &config
+        ));
+
+        // サイドウォールのバックウォール寄りはガラス
+        assert!(!is_fence_contact(
+            WallType::RightWall,
+            Vec3::new(7.0, 1.0, 8.0),
+            &config
+        ));
+
+        // バックウォール・天井は金網扱いしない
+        assert!(!is_fence_contact(
+            WallType::BackWallRight,
+            Vec3::new(10.0, 1.0, 0.0),
+            &config
+        ));
+        assert!(!is_fence_contact(
+            WallType::Ceiling,
+            Vec3::new(0.0, 5.0, 0.0),
+            &config
+        ));
+    }
 }
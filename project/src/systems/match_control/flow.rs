@@ -3,7 +3,7 @@
 //! @spec 30102_serve_spec.md
 //!
 //! 試合全体の状態遷移とフロー管理を行う。
-//! MatchStart → Serve → Rally → PointEnd → Serve/MatchEnd
+//! MatchStart → Serve → Rally → PointEnd → (Changeover →) Serve/MatchEnd
 
 use bevy::prelude::*;
 
@@ -11,11 +11,14 @@ use super::{
     serve_hit_input_system, serve_init_system, serve_position_system, serve_toss_input_system,
     serve_toss_physics_system, serve_toss_timeout_system,
 };
-use crate::components::{Ball, LogicalPosition, Player, TossBall};
-use crate::core::{CourtSide, MatchStartEvent, MatchWonEvent, RallyEndEvent, ShotEvent};
-use crate::resource::scoring::{PointEndTimer, ServeState};
+use crate::components::{AiController, Ball, LogicalPosition, Player, TossBall};
+use crate::core::{
+    player_team_side, player_team_slot, ChangeEndsEvent, CourtLane, CourtSide, MatchStartEvent,
+    MatchWonEvent, RallyEndEvent, ShotEvent,
+};
+use crate::resource::scoring::{ChangeoverTimer, PointEndTimer, ServeState};
 use crate::resource::{
-    FixedDeltaTime, GameConfig, GameState, MatchFlowState, MatchScore, RallyState,
+    FixedDeltaTime, GameConfig, GameState, MatchFlowState, MatchScore, RallyState, ScoreLog,
 };
 use crate::systems::GameSystemSet;
 
@@ -28,7 +31,9 @@ impl Plugin for MatchFlowPlugin {
         app.init_state::<MatchFlowState>()
             .init_resource::<ServeState>()
             .init_resource::<PointEndTimer>()
+            .init_resource::<ChangeoverTimer>()
             .add_message::<MatchStartEvent>()
+            .add_message::<ChangeEndsEvent>()
             .add_systems(OnEnter(MatchFlowState::MatchStart), match_start_system)
             .add_systems(
                 OnEnter(MatchFlowState::Serve),
@@ -37,7 +42,7 @@ impl Plugin for MatchFlowPlugin {
             // @spec 30102_serve_spec.md: トス→ヒット方式サーブシステム（Serve状態でのみ動作）
             // GameSystemSet::GameLogic に配置し、入力読み取り後に実行されることを保証
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     serve_toss_input_system,
                     serve_toss_physics_system,
@@ -50,18 +55,33 @@ impl Plugin for MatchFlowPlugin {
                     .in_set(GameSystemSet::GameLogic),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 rally_to_point_end_system
                     .run_if(in_state(MatchFlowState::Rally))
                     .in_set(GameSystemSet::GameLogic),
             )
             .add_systems(OnEnter(MatchFlowState::PointEnd), point_end_enter_system)
             .add_systems(
-                Update,
+                FixedUpdate,
                 point_end_to_next_system
                     .run_if(in_state(MatchFlowState::PointEnd))
                     .in_set(GameSystemSet::GameLogic),
             )
+            // @spec 30101_flow_spec.md#req-30101-051: エンドチェンジ
+            .add_systems(OnEnter(MatchFlowState::Changeover), changeover_enter_system)
+            .add_systems(
+                FixedUpdate,
+                changeover_to_serve_system
+                    .run_if(in_state(MatchFlowState::Changeover))
+                    .in_set(GameSystemSet::GameLogic),
+            )
+            // @spec 30703_set_spec.md#req-30703-058: 時間制マッチ用の試合時間計測
+            .add_systems(
+                FixedUpdate,
+                match_clock_system
+                    .run_if(not(in_state(MatchFlowState::MatchEnd)))
+                    .in_set(GameSystemSet::GameLogic),
+            )
             .add_systems(OnEnter(MatchFlowState::MatchEnd), match_end_system);
     }
 }
@@ -73,23 +93,27 @@ fn match_start_system(
     mut next_state: ResMut<NextState<MatchFlowState>>,
     mut match_score: ResMut<MatchScore>,
     mut rally_state: ResMut<RallyState>,
+    mut score_log: ResMut<ScoreLog>,
     mut match_start_events: MessageWriter<MatchStartEvent>,
-    mut query: Query<(&Player, &mut LogicalPosition)>,
+    mut query: Query<(&mut Player, &mut LogicalPosition, Option<&mut AiController>)>,
     config: Res<GameConfig>,
 ) {
     info!("Match starting...");
 
     // @spec 30101_flow_spec.md#req-30101-001: スコアを初期化する
-    *match_score = MatchScore::new();
+    // @spec 30105_doubles_spec.md#req-30105-002: チーム人数に応じたサーブローテーション
+    *match_score = MatchScore::with_players_per_team(config.team.team_size());
     match_score.game_state = GameState::Playing;
+    // @spec 30704_score_log_spec.md#req-30704-001: ポイント履歴を初期化する
+    *score_log = ScoreLog::new();
 
     // @spec 30101_flow_spec.md#req-30101-001: サーブ権をLeft側に設定する
     match_score.server = CourtSide::Left;
     *rally_state = RallyState::new(CourtSide::Left);
 
-    // @spec 30101_flow_spec.md#req-30101-001: プレイヤーを配置する
-    reset_player_positions(&mut query, &config);
-    for (player, logical_pos) in query.iter() {
+    // @spec 30101_flow_spec.md#req-30101-001: プレイヤーを開始時のサイドに配置する
+    reset_player_sides(&mut query, &config);
+    for (player, logical_pos, _) in query.iter() {
         info!("Player {} positioned at {:?}", player.id, logical_pos.value);
     }
 
@@ -116,8 +140,8 @@ fn serve_to_rally_system(
 ) {
     // @spec 30101_flow_spec.md#req-30101-002: サーブが打たれる（ShotEvent受信）
     for event in shot_events.read() {
-        // サーバーのショットのみを検出（CourtSideで直接比較）
-        if event.court_side == match_score.server {
+        // サーバー本人のショットのみを検出（ダブルスではパートナーを除外）
+        if event.player_id == match_score.server_player_id() {
             // @spec 30902_fault_spec.md: Servingフェーズに遷移（サービスボックス判定待ち）
             rally_state.start_serve();
             info!(
@@ -223,6 +247,13 @@ fn point_end_to_next_system(
         return;
     }
 
+    // @spec 30101_flow_spec.md#req-30101-051: エンドチェンジが予約されていれば休憩へ
+    if match_score.end_change_pending {
+        next_state.set(MatchFlowState::Changeover);
+        info!("Change of ends. State: PointEnd -> Changeover");
+        return;
+    }
+
     // @spec 30101_flow_spec.md#req-30101-004: 試合が終了していない場合
     // @spec 30101_flow_spec.md#req-30101-004: プレイヤーを初期位置に戻す
     reset_player_positions(&mut query, &config);
@@ -239,16 +270,119 @@ fn point_end_to_next_system(
     );
 }
 
+/// エンドチェンジ開始システム
+/// @spec 30101_flow_spec.md#req-30101-051
+///
+/// 両チームのサイドとスコア配列を入れ替え、プレイヤーを新しいサイドの初期位置に配置する。
+/// AI のホームポジションも新しいサイドへミラーする。
+fn changeover_enter_system(
+    mut match_score: ResMut<MatchScore>,
+    mut changeover_timer: ResMut<ChangeoverTimer>,
+    mut change_ends_events: MessageWriter<ChangeEndsEvent>,
+    mut query: Query<(&mut Player, &mut LogicalPosition, Option<&mut AiController>)>,
+    config: Res<GameConfig>,
+) {
+    match_score.change_ends();
+    swap_player_sides(&mut query, &config);
+
+    changeover_timer.remaining = config.scoring.changeover_break_secs;
+
+    change_ends_events.write(ChangeEndsEvent {
+        ends_swapped: match_score.ends_swapped,
+        games_played: match_score.games_played(),
+        in_tiebreak: match_score.is_tiebreak(),
+    });
+
+    info!(
+        "Ends changed (swapped: {}). Break {:.1} secs. Server: {:?}",
+        match_score.ends_swapped, changeover_timer.remaining, match_score.server
+    );
+}
+
+/// 全プレイヤーを反対側のサイドの初期位置へ移す
+/// @spec 30101_flow_spec.md#req-30101-051
+///
+/// AI のホームポジションも新しいサイドへミラーする。
+pub(crate) fn swap_player_sides(
+    query: &mut Query<(&mut Player, &mut LogicalPosition, Option<&mut AiController>)>,
+    config: &GameConfig,
+) {
+    for (mut player, mut logical_pos, ai_controller) in query.iter_mut() {
+        player.court_side = player.court_side.opponent();
+        logical_pos.value = player_position_for_side(player.id, player.court_side, config);
+        if let Some(mut ai) = ai_controller {
+            ai.home_position = logical_pos.value;
+        }
+    }
+}
+
+/// 全プレイヤーをチームの開始サイドの初期位置へ戻す
+/// @spec 30101_flow_spec.md#req-30101-001
+///
+/// 前の試合がエンドチェンジ後のまま終わっていても、開始時のサイドからやり直す。
+/// AI のホームポジションも開始サイドに戻す。
+fn reset_player_sides(
+    query: &mut Query<(&mut Player, &mut LogicalPosition, Option<&mut AiController>)>,
+    config: &GameConfig,
+) {
+    for (mut player, mut logical_pos, ai_controller) in query.iter_mut() {
+        player.court_side = player_team_side(player.id);
+        logical_pos.value = initial_player_position(player.id, config);
+        if let Some(mut ai) = ai_controller {
+            ai.home_position = logical_pos.value;
+        }
+    }
+}
+
+/// エンドチェンジ休憩から次のサーブへの遷移システム
+/// @spec 30101_flow_spec.md#req-30101-053
+fn changeover_to_serve_system(
+    fixed_dt: Res<FixedDeltaTime>,
+    mut changeover_timer: ResMut<ChangeoverTimer>,
+    mut next_state: ResMut<NextState<MatchFlowState>>,
+    match_score: Res<MatchScore>,
+    mut rally_state: ResMut<RallyState>,
+) {
+    changeover_timer.remaining -= fixed_dt.delta_secs();
+    if changeover_timer.remaining > 0.0 {
+        return;
+    }
+
+    rally_state.next_serve();
+    rally_state.server = match_score.server;
+
+    next_state.set(MatchFlowState::Serve);
+    info!(
+        "Changeover ended. Server: {:?}. State: Changeover -> Serve",
+        match_score.server
+    );
+}
+
+/// 試合時間計測システム
+/// @spec 30703_set_spec.md#req-30703-058
+///
+/// ポイント間の待機やエンドチェンジの休憩も試合時間に含める。
+fn match_clock_system(fixed_dt: Res<FixedDeltaTime>, mut match_score: ResMut<MatchScore>) {
+    match_score.elapsed_secs += fixed_dt.delta_secs();
+}
+
 /// 試合終了システム
 /// @spec 30101_flow_spec.md#req-30101-005
+/// @spec 30704_score_log_spec.md#req-30704-003
 fn match_end_system(
     match_score: Res<MatchScore>,
+    score_log: Res<ScoreLog>,
     mut match_end_events: MessageWriter<MatchWonEvent>,
 ) {
     if let GameState::MatchWon(winner) = match_score.game_state {
         // @spec 30101_flow_spec.md#req-30101-005: MatchWonEvent を発行する
         match_end_events.write(MatchWonEvent { winner });
         info!("Match ended! Winner: {:?}", winner);
+        // 結果表示用にポイント履歴を出力
+        info!("Point log ({} points):", score_log.points.len());
+        for line in score_log.recent_display(score_log.points.len()) {
+            info!("  {}", line);
+        }
     }
 }
 
@@ -256,19 +390,36 @@ fn match_end_system(
 /// @spec 30101_flow_spec.md#req-30101-001
 fn reset_player_positions(query: &mut Query<(&Player, &mut LogicalPosition)>, config: &GameConfig) {
     for (player, mut logical_pos) in query.iter_mut() {
-        logical_pos.value = get_initial_position(player.court_side, config);
+        // エンドチェンジ後は現在のサイドに配置する
+        logical_pos.value = player_position_for_side(player.id, player.court_side, config);
     }
 }
 
-/// プレイヤーの初期位置を取得
+/// プレイヤーの初期位置を取得（試合開始時のサイド）
 /// 論理座標系: X=打ち合い方向, Y=高さ, Z=コート幅
 /// @spec 30101_flow_spec.md#req-30101-001
-fn get_initial_position(court_side: CourtSide, config: &GameConfig) -> Vec3 {
+/// @spec 30105_doubles_spec.md#req-30105-004
+pub fn initial_player_position(player_id: u8, config: &GameConfig) -> Vec3 {
+    player_position_for_side(player_id, player_team_side(player_id), config)
+}
+
+/// 指定サイドでのプレイヤーの初期位置を取得
+/// @spec 30101_flow_spec.md#req-30101-001
+/// @spec 30101_flow_spec.md#req-30101-051
+/// @spec 30105_doubles_spec.md#req-30105-004
+pub fn player_position_for_side(player_id: u8, court_side: CourtSide, config: &GameConfig) -> Vec3 {
+    // ダブルスでは担当サイドの中央（コート幅の1/4）に配置
+    let z = if config.team.is_doubles() {
+        let lane = CourtLane::from_slot(player_team_slot(player_id));
+        lane.z_sign(court_side) * config.court.width / 4.0
+    } else {
+        0.0
+    };
     match court_side {
         // @spec 30101_flow_spec.md#req-30101-001: Left側（画面左）
-        CourtSide::Left => Vec3::new(config.player.x_min + 1.0, 0.0, 0.0),
+        CourtSide::Left => Vec3::new(config.player.x_min + 1.0, 0.0, z),
         // @spec 30101_flow_spec.md#req-30101-001: Right側（画面右）
-        CourtSide::Right => Vec3::new(config.player.x_max - 1.0, 0.0, 0.0),
+        CourtSide::Right => Vec3::new(config.player.x_max - 1.0, 0.0, z),
     }
 }
 
@@ -340,6 +491,176 @@ mod tests {
         ));
     }
 
+    /// TST-30104-051: 奇数ゲーム終了後のエンドチェンジ判定
+    /// @spec 30101_flow_spec.md#req-30101-051
+    #[test]
+    fn test_req_30101_051_end_change_after_odd_games() {
+        let mut match_score = MatchScore::new();
+        assert!(!match_score.end_change_due_after_game());
+
+        // 1ゲーム目終了後
+        match_score.win_game(CourtSide::Left);
+        assert_eq!(match_score.games_played(), 1);
+        assert!(match_score.end_change_due_after_game());
+
+        // 2ゲーム目終了後は入れ替えなし、3ゲーム目終了後は入れ替え
+        match_score.win_game(CourtSide::Right);
+        assert!(!match_score.end_change_due_after_game());
+        match_score.win_game(CourtSide::Left);
+        assert!(match_score.end_change_due_after_game());
+
+        // 6-4（10ゲーム）で終わったセットの次は、次セット1ゲーム目終了後に入れ替え
+        let mut match_score = MatchScore::new();
+        match_score.get_score_mut(CourtSide::Left).games = 6;
+        match_score.get_score_mut(CourtSide::Right).games = 4;
+        match_score.win_set(CourtSide::Left);
+        assert!(!match_score.end_change_due_after_game());
+        match_score.win_game(CourtSide::Right);
+        assert!(match_score.end_change_due_after_game());
+    }
+
+    /// TST-30104-052: タイブレーク中は6ポイントごとにエンドチェンジ
+    /// @spec 30101_flow_spec.md#req-30101-052
+    #[test]
+    fn test_req_30101_052_tiebreak_end_change_interval() {
+        let mut match_score = MatchScore::new();
+        match_score.start_tiebreak(crate::resource::TiebreakKind::Standard, 7);
+        assert!(!match_score.end_change_due_in_tiebreak(6));
+
+        for played in 1..=12 {
+            let scorer = if played % 2 == 0 {
+                CourtSide::Left
+            } else {
+                CourtSide::Right
+            };
+            match_score.add_point(scorer);
+            assert_eq!(
+                match_score.end_change_due_in_tiebreak(6),
+                played % 6 == 0,
+                "points played: {}",
+                played
+            );
+        }
+
+        // 通常ゲーム中は判定しない
+        assert!(!MatchScore::new().end_change_due_in_tiebreak(6));
+    }
+
+    /// TST-30104-053: エンドチェンジでスコアとサーブ権がチームに追従する
+    /// @spec 30101_flow_spec.md#req-30101-051
+    #[test]
+    fn test_req_30101_051_change_ends_remaps_score() {
+        let mut match_score = MatchScore::with_players_per_team(2);
+        match_score.win_game(CourtSide::Left);
+        match_score.add_point(CourtSide::Left);
+        match_score.end_change_pending = true;
+
+        // 2ゲーム目: Right開始チーム（P2）がサーブ
+        assert_eq!(match_score.server, CourtSide::Right);
+        let server_id = match_score.server_player_id();
+        let receiver_id = match_score.receiver_player_id(crate::resource::ServeSide::Deuce);
+
+        match_score.change_ends();
+
+        assert!(match_score.ends_swapped);
+        assert!(!match_score.end_change_pending);
+        // スコア配列は現在のサイドへ付け替え
+        assert_eq!(match_score.get_score(CourtSide::Right).games, 1);
+        assert_eq!(match_score.get_point_index(CourtSide::Right), 1);
+        assert_eq!(match_score.get_score(CourtSide::Left).games, 0);
+        // サーバーは同じチーム（現在は Left 側）で、プレイヤーIDも変わらない
+        assert_eq!(match_score.server, CourtSide::Left);
+        assert_eq!(match_score.team_of(CourtSide::Left), CourtSide::Right);
+        assert_eq!(match_score.server_player_id(), server_id);
+        assert_eq!(
+            match_score.receiver_player_id(crate::resource::ServeSide::Deuce),
+            receiver_id
+        );
+
+        // セット記録はチーム順で残る
+        match_score.get_score_mut(CourtSide::Right).games = 6;
+        match_score.win_set(CourtSide::Right);
+        assert_eq!(match_score.set_history[0].games, [6, 0]);
+
+        // 2回入れ替えると元に戻る
+        match_score.change_ends();
+        assert!(!match_score.ends_swapped);
+        assert_eq!(match_score.team_of(CourtSide::Left), CourtSide::Left);
+    }
+
+    /// TST-30104-054: エンドチェンジ後の配置は反対サイドにミラーされる
+    /// @spec 30101_flow_spec.md#req-30101-051
+    #[test]
+    fn test_req_30101_051_position_mirrors_after_change() {
+        let mut config = crate::resource::config::load_game_config("assets/config/game_config.ron")
+            .expect("game_config load failed");
+        config.team.players_per_team = 2;
+
+        for player_id in 1..=4 {
+            let start = initial_player_position(player_id, &config);
+            let swapped = player_position_for_side(
+                player_id,
+                player_team_side(player_id).opponent(),
+                &config,
+            );
+            // ネットを挟んで反対側、担当レーンは向かい合う側で同じ役割（Z符号反転）
+            assert_ne!(start.x.signum(), swapped.x.signum());
+            assert_eq!(start.z, -swapped.z);
+        }
+    }
+
+    /// TST-30104-055: 試合開始時にエンドチェンジ後のサイドを開始サイドへ戻す
+    /// @spec 30101_flow_spec.md#req-30101-001
+    #[test]
+    fn test_req_30101_001_match_start_resets_sides() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let config = GameConfig::default();
+        let mut world = World::new();
+        world.insert_resource(config.clone());
+        world.insert_resource(MatchScore::new());
+        world.insert_resource(RallyState::new(CourtSide::Left));
+        world.insert_resource(ScoreLog::new());
+        world.init_resource::<NextState<MatchFlowState>>();
+        world.init_resource::<Messages<MatchStartEvent>>();
+        // 前の試合でエンドチェンジしたまま（P1 が Right 側）
+        let players: Vec<Entity> = [1u8, 2]
+            .into_iter()
+            .map(|id| {
+                let side = player_team_side(id).opponent();
+                let position = player_position_for_side(id, side, &config);
+                world
+                    .spawn((
+                        Player {
+                            id,
+                            court_side: side,
+                        },
+                        LogicalPosition { value: position },
+                        AiController {
+                            home_position: position,
+                            ..default()
+                        },
+                    ))
+                    .id()
+            })
+            .collect();
+
+        world.run_system_once(match_start_system).unwrap();
+
+        for (id, entity) in [1u8, 2].into_iter().zip(players) {
+            let start = initial_player_position(id, &config);
+            assert_eq!(
+                world.get::<Player>(entity).unwrap().court_side,
+                player_team_side(id)
+            );
+            assert_eq!(world.get::<LogicalPosition>(entity).unwrap().value, start);
+            assert_eq!(
+                world.get::<AiController>(entity).unwrap().home_position,
+                start
+            );
+        }
+    }
+
     /// TST-30104-006: 状態遷移の順序テスト
     /// @spec 30101_flow_spec.md
     #[test]
@@ -1,5 +1,6 @@
 //! スコア表示システム
 //! @spec 30701_point_spec.md#req-30701-004
+//! @spec 30703_set_spec.md#req-30703-054
 
 use bevy::prelude::*;
 
@@ -14,16 +15,25 @@ pub fn score_display_system(
     mut last_display: Local<Option<String>>,
 ) {
     let point_values = &config.scoring.point_values;
-    let p1_point = match_score.get_point_display(CourtSide::Left, point_values);
-    let p2_point = match_score.get_point_display(CourtSide::Right, point_values);
+    // エンドチェンジ後もチーム（開始時のサイド）順で表示
+    let p1 = match_score.side_of_team(CourtSide::Left);
+    let p2 = match_score.side_of_team(CourtSide::Right);
+    let p1_point = match_score.get_point_display(p1, point_values);
+    let p2_point = match_score.get_point_display(p2, point_values);
 
-    let p1_score = match_score.get_score(CourtSide::Left);
-    let p2_score = match_score.get_score(CourtSide::Right);
+    let p1_score = match_score.get_score(p1);
+    let p2_score = match_score.get_score(p2);
 
-    let score_text = format!(
+    let mut score_text = format!(
         "P1: {} (G:{} S:{}) - P2: {} (G:{} S:{})",
         p1_point, p1_score.games, p1_score.sets, p2_point, p2_score.games, p2_score.sets,
     );
+    if match_score.is_tiebreak() {
+        score_text.push_str(" [TB]");
+    }
+    if !match_score.set_history.is_empty() {
+        score_text.push_str(&format!(" Sets: {}", match_score.set_history_display()));
+    }
 
     // 変更があった場合のみ表示
     if last_display.as_ref() != Some(&score_text) {
@@ -4,16 +4,21 @@
 
 use bevy::prelude::*;
 
-use crate::core::{CourtSide, GameWonEvent, MatchWonEvent, PointScoredEvent, SetWonEvent};
-use crate::resource::{GameConfig, GameState, MatchScore, RallyState, ServeSide};
+use crate::core::{
+    CourtSide, GameWonEvent, MatchWonEvent, PointScoredEvent, PointSituation, SetWonEvent,
+};
+use crate::resource::{GameConfig, GameState, MatchScore, RallyState, ServeSide, TiebreakKind};
 
 /// ゲーム勝利時の処理
 /// @spec 30702_game_spec.md#req-30702-002
+/// @spec 30702_game_spec.md#req-30702-050
 /// @spec 30703_set_spec.md#req-30703-002
 /// @spec 30703_set_spec.md#req-30703-003
 /// @spec 30903_serve_authority_spec.md#req-30903-002
+#[allow(clippy::too_many_arguments)]
 pub(super) fn handle_game_win(
     scorer: CourtSide,
+    deciding_situation: PointSituation,
     match_score: &mut MatchScore,
     rally_state: &mut RallyState,
     config: &GameConfig,
@@ -24,11 +29,141 @@ pub(super) fn handle_game_win(
     // ゲーム獲得
     match_score.win_game(scorer);
 
+    write_game_won(scorer, deciding_situation, match_score, game_events);
+
+    // ゲーム終了時はデュースサイドから開始
+    rally_state.serve_side = ServeSide::Deuce;
+
+    let scoring = &config.scoring;
+
+    // セット勝利判定（2ゲーム差）
+    if match_score.check_set_win(scorer, scoring.games_to_win_set, scoring.set_win_margin) {
+        handle_set_win(scorer, match_score, config, set_events, match_events);
+    } else if scoring.tiebreak_enabled
+        && match_score.should_start_tiebreak(scoring.tiebreak_trigger_games())
+    {
+        // 6-6（プロセットは 8-8、Fast4 は 3-3）でタイブレークへ
+        // @spec 30702_game_spec.md#req-30702-050
+        // @spec 30703_set_spec.md#req-30703-057
+        match_score.start_tiebreak(TiebreakKind::Standard, scoring.tiebreak_points as usize);
+        info!(
+            "Tiebreak started! First server: {:?} (first to {})",
+            match_score.server, scoring.tiebreak_points
+        );
+    }
+
+    // @spec 30101_flow_spec.md#req-30101-051: 奇数ゲーム終了後はエンドチェンジ
+    let due = match_score.end_change_due_after_game();
+    schedule_end_change(due, match_score, config);
+}
+
+/// タイブレーク中のポイント処理
+/// @spec 30702_game_spec.md#req-30702-051
+/// @spec 30702_game_spec.md#req-30702-052
+/// @spec 30702_game_spec.md#req-30702-053
+/// @spec 30702_game_spec.md#req-30702-054
+#[allow(clippy::too_many_arguments)]
+pub(super) fn handle_tiebreak_point(
+    scorer: CourtSide,
+    match_score: &mut MatchScore,
+    rally_state: &mut RallyState,
+    config: &GameConfig,
+    point_events: &mut MessageWriter<PointScoredEvent>,
+    game_events: &mut MessageWriter<GameWonEvent>,
+    set_events: &mut MessageWriter<SetWonEvent>,
+    match_events: &mut MessageWriter<MatchWonEvent>,
+) {
+    if match_score.check_tiebreak_win(scorer) {
+        // タイブレーク獲得は1ゲームとして数える（7-6）
+        match_score.get_score_mut(scorer).win_game();
+        write_game_won(scorer, PointSituation::Tiebreak, match_score, game_events);
+        rally_state.serve_side = ServeSide::Deuce;
+        handle_set_win(scorer, match_score, config, set_events, match_events);
+
+        // @spec 30101_flow_spec.md#req-30101-051: タイブレークセット終了も1ゲームとして判定
+        let due = match_score.end_change_due_after_game();
+        schedule_end_change(due, match_score, config);
+        return;
+    }
+
+    // 1-2-2 ローテーションでサーバー更新
+    // @spec 30702_game_spec.md#req-30702-052
+    match_score.update_tiebreak_server();
+
+    // @spec 30101_flow_spec.md#req-30101-052: タイブレーク中は規定ポイントごとにエンドチェンジ
+    let interval = config.scoring.tiebreak_change_ends_interval as usize;
+    let due = match_score.end_change_due_in_tiebreak(interval);
+    schedule_end_change(due, match_score, config);
+
+    let new_index = match_score.get_point_index(scorer);
+    handle_point_scored(
+        scorer,
+        new_index,
+        &config.scoring.point_values,
+        PointSituation::Tiebreak,
+        match_score,
+        rally_state,
+        point_events,
+    );
+}
+
+/// エンドチェンジを予約（次のポイント開始前に MatchFlowState::Changeover で実行）
+/// @spec 30101_flow_spec.md#req-30101-051
+fn schedule_end_change(due: bool, match_score: &mut MatchScore, config: &GameConfig) {
+    if !due || !config.scoring.change_ends_enabled {
+        return;
+    }
+    // マッチ終了時は入れ替え不要
+    if matches!(match_score.game_state, GameState::MatchWon(_)) {
+        return;
+    }
+    match_score.end_change_pending = true;
+    info!(
+        "Change of ends scheduled (games played: {})",
+        match_score.games_played()
+    );
+}
+
+/// 時間制マッチの終了判定（ポイント終了ごとに呼ぶ）
+/// @spec 30703_set_spec.md#req-30703-058
+///
+/// 制限時間を過ぎていればリードしている側の勝利でマッチを終了する。
+/// 同点の場合は決着がつくポイントまで続行する。
+pub(super) fn check_time_limit(
+    match_score: &mut MatchScore,
+    config: &GameConfig,
+    match_events: &mut MessageWriter<MatchWonEvent>,
+) {
+    let Some(limit) = config.scoring.time_limit_secs else {
+        return;
+    };
+    if match_score.game_state != GameState::Playing || match_score.elapsed_secs < limit {
+        return;
+    }
+    if let Some(winner) = match_score.finish_on_time() {
+        info!(
+            "Time limit reached ({:.0}s)! Match won by {:?} ({})",
+            match_score.elapsed_secs,
+            winner,
+            match_score.set_history_display()
+        );
+        match_events.write(MatchWonEvent { winner });
+    }
+}
+
+/// GameWonEvent を発行
+fn write_game_won(
+    scorer: CourtSide,
+    deciding_situation: PointSituation,
+    match_score: &MatchScore,
+    game_events: &mut MessageWriter<GameWonEvent>,
+) {
     let games_won = match_score.get_score(scorer).games;
 
     info!(
-        "Game won by {:?}! Games: P1={}, P2={}",
+        "Game won by {:?} ({:?})! Games: P1={}, P2={}",
         scorer,
+        deciding_situation,
         match_score.get_score(CourtSide::Left).games,
         match_score.get_score(CourtSide::Right).games
     );
@@ -36,22 +171,14 @@ pub(super) fn handle_game_win(
     game_events.write(GameWonEvent {
         winner: scorer,
         games_won,
+        deciding_situation,
     });
-
-    // ゲーム終了時はデュースサイドから開始
-    rally_state.serve_side = ServeSide::Deuce;
-
-    // セット勝利判定
-    if !match_score.check_set_win(scorer, config.scoring.games_to_win_set) {
-        return;
-    }
-
-    handle_set_win(scorer, match_score, config, set_events, match_events);
 }
 
 /// セット勝利時の処理
 /// @spec 30703_set_spec.md#req-30703-002
 /// @spec 30703_set_spec.md#req-30703-003
+/// @spec 30703_set_spec.md#req-30703-055
 fn handle_set_win(
     scorer: CourtSide,
     match_score: &mut MatchScore,
@@ -64,10 +191,11 @@ fn handle_set_win(
     let sets_won = match_score.get_score(scorer).sets;
 
     info!(
-        "Set won by {:?}! Sets: P1={}, P2={}",
+        "Set won by {:?}! Sets: P1={}, P2={} ({})",
         scorer,
         match_score.get_score(CourtSide::Left).sets,
-        match_score.get_score(CourtSide::Right).sets
+        match_score.get_score(CourtSide::Right).sets,
+        match_score.set_history_display()
     );
 
     set_events.write(SetWonEvent {
@@ -75,41 +203,65 @@ fn handle_set_win(
         sets_won,
     });
 
+    let scoring = &config.scoring;
+
     // マッチ勝利判定
-    if match_score.check_match_win(scorer, config.scoring.sets_to_win_match) {
+    if match_score.check_match_win(scorer, scoring.sets_to_win_match) {
         match_score.game_state = GameState::MatchWon(scorer);
         info!("Match won by {:?}!", scorer);
         match_events.write(MatchWonEvent { winner: scorer });
+        return;
+    }
+
+    // セットオール（最終セット）ならスーパータイブレークで代替
+    // @spec 30703_set_spec.md#req-30703-055
+    let deciding_set = scoring.sets_to_win_match.saturating_sub(1);
+    let is_final_set = match_score.scores.iter().all(|s| s.sets == deciding_set);
+    if scoring.final_set_super_tiebreak && is_final_set {
+        match_score.start_tiebreak(TiebreakKind::Super, scoring.super_tiebreak_points as usize);
+        info!(
+            "Super tiebreak started! First server: {:?} (first to {})",
+            match_score.server, scoring.super_tiebreak_points
+        );
     }
 }
 
 /// ポイント獲得（ゲーム未終了）時の処理
 /// @spec 30701_point_spec.md#req-30701-004
+/// @spec 30701_point_spec.md#req-30701-054
 /// @spec 30903_serve_authority_spec.md#req-30903-003
 pub(super) fn handle_point_scored(
     scorer: CourtSide,
     new_index: usize,
     point_values: &[u32],
+    situation: PointSituation,
     match_score: &MatchScore,
     rally_state: &mut RallyState,
     point_events: &mut MessageWriter<PointScoredEvent>,
 ) {
-    let new_point_value = if new_index < point_values.len() {
-        point_values[new_index]
+    // タイブレーク中はポイント数そのもの、デュース圏内では 40 に丸める
+    let new_point_value = if match_score.is_tiebreak() {
+        new_index as u32
     } else {
-        0
+        point_values
+            .get(new_index)
+            .or(point_values.last())
+            .copied()
+            .unwrap_or(0)
     };
 
     info!(
-        "Point scored by {:?}! Score: {} - {}",
+        "Point scored by {:?}! Score: {} - {} ({:?})",
         scorer,
         match_score.get_point_display(CourtSide::Left, point_values),
         match_score.get_point_display(CourtSide::Right, point_values),
+        situation,
     );
 
     point_events.write(PointScoredEvent {
         scorer,
         new_point_value,
+        situation,
     });
 
     // サーブサイドを更新
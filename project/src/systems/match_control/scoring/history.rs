@@ -0,0 +1,75 @@
+//! ポイント履歴処理
+//! @spec 30704_score_log_spec.md
+
+use bevy::prelude::*;
+
+use crate::components::{AiController, LogicalPosition, Player};
+use crate::core::{ShotEvent, UndoPointEvent};
+use crate::resource::scoring::{ServeState, ServeSubPhase};
+use crate::resource::{GameConfig, MatchFlowState, MatchScore, RallyState, ScoreLog};
+use crate::systems::match_control::swap_player_sides;
+
+/// ラリー長計測のためショットを記録
+/// @spec 30704_score_log_spec.md#req-30704-001
+pub fn score_log_shot_system(
+    mut shot_events: MessageReader<ShotEvent>,
+    mut score_log: ResMut<ScoreLog>,
+) {
+    for event in shot_events.read() {
+        score_log.record_shot(event.is_serve);
+    }
+}
+
+/// 直前のポイントを取り消す
+/// @spec 30704_score_log_spec.md#req-30704-002
+///
+/// ポイント間（トス前のサーブ待機中、または試合終了後）のみ受け付ける。
+/// 取り消したポイントの後にエンドチェンジしていれば、プレイヤーも元のサイドへ戻す。
+/// 取り消し後はサーブ待機からやり直す。
+#[allow(clippy::too_many_arguments)]
+pub fn undo_point_system(
+    mut undo_events: MessageReader<UndoPointEvent>,
+    mut score_log: ResMut<ScoreLog>,
+    mut match_score: ResMut<MatchScore>,
+    mut rally_state: ResMut<RallyState>,
+    mut serve_state: ResMut<ServeState>,
+    flow_state: Res<State<MatchFlowState>>,
+    mut next_state: ResMut<NextState<MatchFlowState>>,
+    mut player_query: Query<(&mut Player, &mut LogicalPosition, Option<&mut AiController>)>,
+    config: Res<GameConfig>,
+) {
+    for _ in undo_events.read() {
+        let between_points = match flow_state.get() {
+            MatchFlowState::Serve => serve_state.phase == ServeSubPhase::Waiting,
+            MatchFlowState::MatchEnd => true,
+            _ => false,
+        };
+        if !between_points {
+            warn!("Undo ignored: only available between points");
+            continue;
+        }
+
+        let ends_swapped = match_score.ends_swapped;
+        let Some(record) = score_log.undo_last_point(&mut match_score) else {
+            info!("Undo ignored: no points recorded");
+            continue;
+        };
+        if match_score.ends_swapped != ends_swapped {
+            swap_player_sides(&mut player_query, &config);
+        }
+
+        serve_state.reset_for_new_point();
+        *rally_state = RallyState::new(match_score.server);
+        let server_points = match_score.get_point_index(match_score.server);
+        let receiver_points = match_score.get_point_index(match_score.server.opponent());
+        rally_state.update_serve_side(server_points, receiver_points);
+
+        // サーブ位置・サーバー情報を戻したスコアで初期化し直す
+        next_state.set(MatchFlowState::Serve);
+        info!(
+            "Point #{} undone. Score restored to {}",
+            record.number,
+            record.before.display()
+        );
+    }
+}
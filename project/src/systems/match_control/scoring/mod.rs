@@ -6,6 +6,7 @@
 
 mod display;
 mod game_set;
+mod history;
 mod rally;
 
 #[cfg(test)]
@@ -13,10 +14,13 @@ mod tests;
 
 use bevy::prelude::*;
 
-use crate::core::{GameWonEvent, MatchWonEvent, PointScoredEvent, RallyEndEvent, SetWonEvent};
-use crate::resource::MatchScore;
+use crate::core::{
+    GameWonEvent, MatchWonEvent, PointScoredEvent, RallyEndEvent, SetWonEvent, UndoPointEvent,
+};
+use crate::resource::{MatchScore, ScoreLog};
 
 pub use display::score_display_system;
+pub use history::{score_log_shot_system, undo_point_system};
 pub use rally::{point_scored_system, rally_end_system};
 
 /// スコアリングプラグイン
@@ -26,14 +30,24 @@ pub struct ScoringPlugin;
 impl Plugin for ScoringPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MatchScore>()
+            .init_resource::<ScoreLog>()
             .add_message::<RallyEndEvent>()
             .add_message::<PointScoredEvent>()
             .add_message::<GameWonEvent>()
             .add_message::<SetWonEvent>()
             .add_message::<MatchWonEvent>()
+            .add_message::<UndoPointEvent>()
             .add_systems(
-                Update,
-                (rally_end_system, point_scored_system, score_display_system).chain(),
-            );
+                FixedUpdate,
+                (
+                    score_log_shot_system,
+                    rally_end_system,
+                    point_scored_system,
+                    score_display_system,
+                )
+                    .chain(),
+            )
+            // @spec 30704_score_log_spec.md#req-30704-002: ポイント取り消し
+            .add_systems(FixedUpdate, undo_point_system);
     }
 }
@@ -5,14 +5,17 @@
 use bevy::prelude::*;
 
 use crate::core::{GameWonEvent, MatchWonEvent, PointScoredEvent, RallyEndEvent, SetWonEvent};
-use crate::resource::{GameConfig, GameState, MatchScore, RallyState};
+use crate::resource::{GameConfig, GameState, MatchScore, RallyState, ScoreLog};
 
-use super::game_set::{handle_game_win, handle_point_scored};
+use super::game_set::{
+    check_time_limit, handle_game_win, handle_point_scored, handle_tiebreak_point,
+};
 
 /// ラリー終了イベントを処理してポイントを加算
 /// @spec 30701_point_spec.md#req-30701-002
 /// @spec 30903_serve_authority_spec.md#req-30903-002
 /// @spec 30903_serve_authority_spec.md#req-30903-003
+/// @spec 30704_score_log_spec.md#req-30704-001
 ///
 /// 重複防止:
 /// - 同一ポイント内で既にスコア加算済みならスキップ（フレームをまたいだ重複防止）
@@ -26,6 +29,7 @@ pub fn rally_end_system(
     mut match_events: MessageWriter<MatchWonEvent>,
     mut match_score: ResMut<MatchScore>,
     mut rally_state: ResMut<RallyState>,
+    mut score_log: ResMut<ScoreLog>,
     config: Res<GameConfig>,
 ) {
     for event in rally_events.read() {
@@ -46,6 +50,13 @@ pub fn rally_end_system(
         let scorer = event.winner;
         let point_values = &config.scoring.point_values;
         let win_index = point_values.len();
+        let deuce_mode = config.scoring.deuce_mode;
+
+        // 決着ポイント前の状況（AD・ゴールデンポイントからの勝利表示用）
+        let situation_before = match_score.point_situation(win_index, deuce_mode);
+        // ポイント履歴用のポイント前の状態
+        let score_before = match_score.clone();
+        let serve_side = rally_state.serve_side;
 
         // ポイント加算
         // @spec 30701_point_spec.md#req-30701-002
@@ -54,13 +65,26 @@ pub fn rally_end_system(
         // 重複加算防止フラグを設定
         rally_state.point_scored_this_rally = true;
 
-        let new_index = match_score.get_point_index(scorer);
-
-        // ゲーム勝利判定
-        // @spec 30701_point_spec.md#req-30701-003
-        if new_index >= win_index {
+        // タイブレーク中は専用のポイント処理
+        // @spec 30702_game_spec.md#req-30702-051
+        if match_score.is_tiebreak() {
+            handle_tiebreak_point(
+                scorer,
+                &mut match_score,
+                &mut rally_state,
+                &config,
+                &mut point_events,
+                &mut game_events,
+                &mut set_events,
+                &mut match_events,
+            );
+        } else if match_score.check_game_win(scorer, win_index, deuce_mode) {
+            // ゲーム勝利判定
+            // @spec 30701_point_spec.md#req-30701-003
+            // @spec 30701_point_spec.md#req-30701-052
             handle_game_win(
                 scorer,
+                situation_before,
                 &mut match_score,
                 &mut rally_state,
                 &config,
@@ -69,15 +93,32 @@ pub fn rally_end_system(
                 &mut match_events,
             );
         } else {
+            let new_index = match_score.get_point_index(scorer);
             handle_point_scored(
                 scorer,
                 new_index,
                 point_values,
+                match_score.point_situation(win_index, deuce_mode),
                 &match_score,
                 &mut rally_state,
                 &mut point_events,
             );
         }
+
+        // 時間制マッチは制限時間経過後のポイント終了で決着
+        // @spec 30703_set_spec.md#req-30703-058
+        check_time_limit(&mut match_score, &config, &mut match_events);
+
+        // ポイント履歴に記録
+        // @spec 30704_score_log_spec.md#req-30704-001
+        let record = score_log.record_point(
+            &score_before,
+            &match_score,
+            scorer,
+            serve_side,
+            event.reason,
+        );
+        debug!("Point logged: {}", record.display());
     }
 }
 
@@ -88,8 +129,8 @@ pub fn point_scored_system(mut point_events: MessageReader<PointScoredEvent>) {
         // 現在はログ出力のみ
         // 将来はUI更新などを行う
         debug!(
-            "PointScoredEvent: {:?} scored, new value: {}",
-            event.scorer, event.new_point_value
+            "PointScoredEvent: {:?} scored, new value: {}, situation: {:?}",
+            event.scorer, event.new_point_value, event.situation
         );
     }
 }
@@ -2,9 +2,10 @@
 //! @spec 30701_point_spec.md
 //! @spec 30702_game_spec.md
 //! @spec 30703_set_spec.md
+//! @spec 30704_score_log_spec.md
 
-use crate::core::CourtSide;
-use crate::resource::MatchScore;
+use crate::core::{CourtSide, PointSituation, RallyEndReason};
+use crate::resource::{DeuceMode, MatchScore, ScoreLog, ServeSide, TiebreakKind};
 
 /// TST-30705-001: ポイント初期化テスト
 /// @spec 30701_point_spec.md#req-30701-001
@@ -60,11 +61,11 @@ fn test_game_win_detection() {
     match_score.add_point(CourtSide::Left); // 40
 
     // まだ勝利ではない
-    assert!(!match_score.check_game_win(CourtSide::Left, win_index));
+    assert!(!match_score.check_game_win(CourtSide::Left, win_index, DeuceMode::Advantage));
 
     // 40 -> Game
     match_score.add_point(CourtSide::Left);
-    assert!(match_score.check_game_win(CourtSide::Left, win_index));
+    assert!(match_score.check_game_win(CourtSide::Left, win_index, DeuceMode::Advantage));
 }
 
 /// TST-30705-004: ポイント表示テスト
@@ -112,6 +113,147 @@ fn test_point_reset() {
     assert_eq!(match_score.get_point(CourtSide::Right).index, 0);
 }
 
+/// 両サイドを 40-40 まで進める
+fn advance_to_deuce(match_score: &mut MatchScore) {
+    for _ in 0..3 {
+        match_score.add_point(CourtSide::Left);
+        match_score.add_point(CourtSide::Right);
+    }
+}
+
+/// TST-30705-050: デュース判定テスト
+/// @spec 30701_point_spec.md#req-30701-050
+#[test]
+fn test_deuce_detection() {
+    let mut match_score = MatchScore::new();
+    let win_index = 4;
+
+    match_score.add_point(CourtSide::Left);
+    assert_eq!(
+        match_score.point_situation(win_index, DeuceMode::Advantage),
+        PointSituation::Regular
+    );
+
+    let mut match_score = MatchScore::new();
+    advance_to_deuce(&mut match_score);
+    assert_eq!(
+        match_score.point_situation(win_index, DeuceMode::Advantage),
+        PointSituation::Deuce
+    );
+    assert_eq!(
+        match_score.point_situation(win_index, DeuceMode::GoldenPoint),
+        PointSituation::GoldenPoint
+    );
+}
+
+/// TST-30705-051: アドバンテージ処理テスト
+/// @spec 30701_point_spec.md#req-30701-051
+#[test]
+fn test_advantage_after_deuce() {
+    let mut match_score = MatchScore::new();
+    let win_index = 4;
+    advance_to_deuce(&mut match_score);
+
+    match_score.add_point(CourtSide::Right);
+
+    // 40-40 から1ポイントではゲームにならない
+    assert!(!match_score.check_game_win(CourtSide::Right, win_index, DeuceMode::Advantage));
+    assert_eq!(
+        match_score.point_situation(win_index, DeuceMode::Advantage),
+        PointSituation::Advantage(CourtSide::Right)
+    );
+}
+
+/// TST-30705-052: アドバンテージからのゲーム勝利テスト
+/// @spec 30701_point_spec.md#req-30701-052
+#[test]
+fn test_game_win_from_advantage() {
+    let mut match_score = MatchScore::new();
+    let win_index = 4;
+    advance_to_deuce(&mut match_score);
+
+    match_score.add_point(CourtSide::Left); // AD Left
+    match_score.add_point(CourtSide::Left); // Game
+    assert!(match_score.check_game_win(CourtSide::Left, win_index, DeuceMode::Advantage));
+}
+
+/// TST-30705-053: アドバンテージからデュースへの復帰テスト
+/// @spec 30701_point_spec.md#req-30701-053
+#[test]
+fn test_advantage_back_to_deuce() {
+    let mut match_score = MatchScore::new();
+    let win_index = 4;
+    advance_to_deuce(&mut match_score);
+
+    match_score.add_point(CourtSide::Left); // AD Left
+    match_score.add_point(CourtSide::Right); // Deuce
+    assert!(!match_score.check_game_win(CourtSide::Right, win_index, DeuceMode::Advantage));
+    assert_eq!(
+        match_score.point_situation(win_index, DeuceMode::Advantage),
+        PointSituation::Deuce
+    );
+
+    // 再度アドバンテージ → ゲーム
+    match_score.add_point(CourtSide::Right);
+    match_score.add_point(CourtSide::Right);
+    assert!(match_score.check_game_win(CourtSide::Right, win_index, DeuceMode::Advantage));
+}
+
+/// TST-30705-054: デュース／アドバンテージ表示テスト
+/// @spec 30701_point_spec.md#req-30701-054
+#[test]
+fn test_deuce_and_advantage_display() {
+    let mut match_score = MatchScore::new();
+    let point_values = vec![0, 15, 30, 40];
+    advance_to_deuce(&mut match_score);
+
+    // 40-40
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Left, &point_values),
+        "40"
+    );
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Right, &point_values),
+        "40"
+    );
+
+    // AD-40
+    match_score.add_point(CourtSide::Left);
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Left, &point_values),
+        "AD"
+    );
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Right, &point_values),
+        "40"
+    );
+
+    // デュース復帰後は再び 40-40
+    match_score.add_point(CourtSide::Right);
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Left, &point_values),
+        "40"
+    );
+    assert_eq!(
+        match_score.get_point_display(CourtSide::Right, &point_values),
+        "40"
+    );
+}
+
+/// TST-30705-055: ゴールデンポイントテスト
+/// @spec 30701_point_spec.md#req-30701-055
+#[test]
+fn test_golden_point_decides_game() {
+    let mut match_score = MatchScore::new();
+    let win_index = 4;
+    advance_to_deuce(&mut match_score);
+
+    // 40-40 の次のポイントで即ゲーム
+    match_score.add_point(CourtSide::Right);
+    assert!(match_score.check_game_win(CourtSide::Right, win_index, DeuceMode::GoldenPoint));
+    assert!(!match_score.check_game_win(CourtSide::Left, win_index, DeuceMode::GoldenPoint));
+}
+
 // ========================================
 // 30702: ゲームカウント管理テスト
 // ========================================
@@ -159,11 +301,11 @@ fn test_set_win_at_six_games() {
     for _ in 0..5 {
         match_score.win_game(CourtSide::Left);
     }
-    assert!(!match_score.check_set_win(CourtSide::Left, games_to_win));
+    assert!(!match_score.check_set_win(CourtSide::Left, games_to_win, 2));
 
     // Left側が6ゲーム目を獲得（セット勝利）
     match_score.win_game(CourtSide::Left);
-    assert!(match_score.check_set_win(CourtSide::Left, games_to_win));
+    assert!(match_score.check_set_win(CourtSide::Left, games_to_win, 2));
 }
 
 /// TST-30706-004: ポイントリセット確認（ゲーム獲得後）
@@ -244,6 +386,158 @@ fn test_game_count_reset_after_set_win() {
     assert_eq!(match_score.get_score(CourtSide::Left).sets, 1);
 }
 
+/// 指定ゲーム数まで両者に交互にゲームを獲得させる（例: 6-6）
+fn advance_to_games(match_score: &mut MatchScore, games: u32) {
+    for _ in 0..games {
+        match_score.win_game(CourtSide::Left);
+        match_score.win_game(CourtSide::Right);
+    }
+}
+
+/// TST-30706-055: 2ゲーム差ルール（6-5 ではセット未決着）
+/// @spec 30702_game_spec.md#req-30702-004
+#[test]
+fn test_set_win_requires_two_game_margin() {
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 5);
+
+    // 6-5: まだセット獲得ではない
+    match_score.win_game(CourtSide::Left);
+    assert!(!match_score.check_set_win(CourtSide::Left, 6, 2));
+
+    // 7-5: セット獲得
+    match_score.win_game(CourtSide::Left);
+    assert!(match_score.check_set_win(CourtSide::Left, 6, 2));
+}
+
+/// TST-30706-050: 6-6 でタイブレーク開始
+/// @spec 30702_game_spec.md#req-30702-050
+#[test]
+fn test_tiebreak_starts_at_six_all() {
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 5);
+    match_score.win_game(CourtSide::Left);
+    assert!(!match_score.should_start_tiebreak(6));
+
+    match_score.win_game(CourtSide::Right);
+    assert!(match_score.should_start_tiebreak(6));
+
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+    assert!(match_score.is_tiebreak());
+    assert_eq!(
+        match_score.point_situation(4, DeuceMode::Advantage),
+        PointSituation::Tiebreak
+    );
+}
+
+/// TST-30706-053: タイブレーク 7-5 で勝利
+/// @spec 30702_game_spec.md#req-30702-053
+#[test]
+fn test_tiebreak_win_at_seven() {
+    let mut match_score = MatchScore::new();
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+
+    for _ in 0..5 {
+        match_score.add_point(CourtSide::Left);
+        match_score.add_point(CourtSide::Right);
+    }
+    match_score.add_point(CourtSide::Left);
+    assert!(!match_score.check_tiebreak_win(CourtSide::Left));
+
+    match_score.add_point(CourtSide::Left);
+    assert!(match_score.check_tiebreak_win(CourtSide::Left));
+    assert!(!match_score.check_tiebreak_win(CourtSide::Right));
+}
+
+/// TST-30706-054: タイブレークは 6-6 以降 2 ポイント差で決着
+/// @spec 30702_game_spec.md#req-30702-053
+/// @spec 30702_game_spec.md#req-30702-054
+#[test]
+fn test_tiebreak_win_requires_two_point_margin() {
+    let mut match_score = MatchScore::new();
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+
+    // 6-6
+    for _ in 0..6 {
+        match_score.add_point(CourtSide::Left);
+        match_score.add_point(CourtSide::Right);
+    }
+    // 7-6: まだ決着しない
+    match_score.add_point(CourtSide::Left);
+    assert!(!match_score.check_tiebreak_win(CourtSide::Left));
+
+    // 8-6: 決着
+    match_score.add_point(CourtSide::Left);
+    assert!(match_score.check_tiebreak_win(CourtSide::Left));
+}
+
+/// TST-30706-051: タイブレークのポイント表示は数字そのもの
+/// @spec 30702_game_spec.md#req-30702-051
+#[test]
+fn test_tiebreak_point_display() {
+    let mut match_score = MatchScore::new();
+    let point_values = vec![0, 15, 30, 40];
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+
+    for _ in 0..5 {
+        match_score.add_point(CourtSide::Left);
+    }
+    match_score.add_point(CourtSide::Right);
+
+    assert_eq!(match_score.get_point_display(CourtSide::Left, &point_values), "5");
+    assert_eq!(match_score.get_point_display(CourtSide::Right, &point_values), "1");
+}
+
+/// TST-30706-052: タイブレークのサーブ順（1-2-2 ローテーション）
+/// @spec 30702_game_spec.md#req-30702-052
+#[test]
+fn test_tiebreak_server_rotation() {
+    let mut match_score = MatchScore::new();
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+    let first = match_score.server;
+
+    // ポイント数ごとの期待サーバー: A, B, B, A, A, B, B
+    let expected = [
+        first,
+        first.opponent(),
+        first.opponent(),
+        first,
+        first,
+        first.opponent(),
+        first.opponent(),
+    ];
+    for (played, server) in expected.iter().enumerate() {
+        assert_eq!(
+            match_score.tiebreak_server(),
+            Some(*server),
+            "points played: {}",
+            played
+        );
+        match_score.add_point(CourtSide::Left);
+    }
+}
+
+/// TST-30706-056: タイブレーク後は最初のサーバーの相手が次セット最初のサーバー
+/// @spec 30702_game_spec.md#req-30702-052
+#[test]
+fn test_server_after_tiebreak_set() {
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 6);
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+    let first = match_score.server;
+
+    for _ in 0..7 {
+        match_score.add_point(CourtSide::Left);
+    }
+    match_score.get_score_mut(CourtSide::Left).win_game();
+    match_score.win_set(CourtSide::Left);
+
+    assert!(!match_score.is_tiebreak());
+    assert_eq!(match_score.server, first.opponent());
+    assert_eq!(match_score.get_score(CourtSide::Left).games, 0);
+    assert_eq!(match_score.get_score(CourtSide::Right).games, 0);
+}
+
 // ========================================
 // 30703: セットカウント管理テスト
 // ========================================
@@ -310,3 +604,249 @@ fn test_set_count_display() {
     assert_eq!(match_score.get_score(CourtSide::Left).sets, 1);
     assert_eq!(match_score.get_score(CourtSide::Right).sets, 0);
 }
+
+/// TST-30707-054: セット履歴表示（"Set 1: 6-4, Set 2: 7-6(5)"）
+/// @spec 30703_set_spec.md#req-30703-051
+/// @spec 30703_set_spec.md#req-30703-054
+#[test]
+fn test_set_history_display() {
+    let mut match_score = MatchScore::new();
+
+    // 第1セット 6-4
+    advance_to_games(&mut match_score, 4);
+    match_score.win_game(CourtSide::Left);
+    match_score.win_game(CourtSide::Left);
+    match_score.win_set(CourtSide::Left);
+
+    // 第2セット 7-6(5)
+    advance_to_games(&mut match_score, 6);
+    match_score.start_tiebreak(TiebreakKind::Standard, 7);
+    for _ in 0..5 {
+        match_score.add_point(CourtSide::Left);
+        match_score.add_point(CourtSide::Right);
+    }
+    match_score.add_point(CourtSide::Left);
+    match_score.add_point(CourtSide::Left);
+    match_score.get_score_mut(CourtSide::Left).win_game();
+    match_score.win_set(CourtSide::Left);
+
+    assert_eq!(match_score.set_history.len(), 2);
+    assert_eq!(
+        match_score.set_history_display(),
+        "Set 1: 6-4, Set 2: 7-6(5)"
+    );
+}
+
+/// TST-30707-055: スーパータイブレークは 10 ポイント先取で "[10-8]" と表示
+/// @spec 30703_set_spec.md#req-30703-055
+#[test]
+fn test_super_tiebreak_record() {
+    let mut match_score = MatchScore::new();
+    match_score.start_tiebreak(TiebreakKind::Super, 10);
+
+    for _ in 0..8 {
+        match_score.add_point(CourtSide::Left);
+        match_score.add_point(CourtSide::Right);
+    }
+    match_score.add_point(CourtSide::Right);
+    assert!(!match_score.check_tiebreak_win(CourtSide::Right));
+    match_score.add_point(CourtSide::Right);
+    assert!(match_score.check_tiebreak_win(CourtSide::Right));
+
+    match_score.get_score_mut(CourtSide::Right).win_game();
+    match_score.win_set(CourtSide::Right);
+
+    let record = match_score.set_history.last().unwrap();
+    assert!(record.super_tiebreak);
+    assert_eq!(record.display(), "[8-10]");
+}
+
+/// TST-30707-056: マッチ形式プリセットの選択で ScoringConfig が上書きされる
+/// @spec 30703_set_spec.md#req-30703-056
+#[test]
+fn test_match_format_presets() {
+    let mut config = crate::resource::config::load_game_config("assets/config/game_config.ron")
+        .expect("game_config load failed");
+    for name in ["best_of_3", "best_of_5", "pro_set", "fast4", "timed"] {
+        assert!(config.match_format.presets.contains_key(name), "{}", name);
+    }
+
+    config.select_match_format("best_of_3").unwrap();
+    assert_eq!(config.scoring.sets_to_win_match, 2);
+    assert!(config.scoring.final_set_super_tiebreak);
+
+    config.select_match_format("fast4").unwrap();
+    assert_eq!(config.scoring.games_to_win_set, 4);
+    assert_eq!(config.scoring.tiebreak_trigger_games(), 3);
+    assert_eq!(config.scoring.deuce_mode, DeuceMode::GoldenPoint);
+    assert!(!config.scoring.final_set_super_tiebreak);
+    // ポイント進行値はプリセットの対象外
+    assert_eq!(config.scoring.point_values, vec![0, 15, 30, 40]);
+
+    config.select_match_format("timed").unwrap();
+    assert!(config.scoring.time_limit_secs.is_some());
+
+    assert!(config.select_match_format("unknown").is_err());
+    assert_eq!(config.match_format.selected.as_deref(), Some("timed"));
+}
+
+/// TST-30707-057: プロセットは 8-8、Fast4 は 3-3 でタイブレーク開始
+/// @spec 30703_set_spec.md#req-30703-057
+#[test]
+fn test_tiebreak_trigger_games() {
+    let mut config = crate::resource::config::ScoringConfig::default();
+    assert_eq!(config.tiebreak_trigger_games(), 6);
+
+    // プロセット: 8-8 でタイブレーク（8-7 では開始しない）
+    config.games_to_win_set = 9;
+    config.tiebreak_at_games = Some(8);
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 7);
+    match_score.win_game(CourtSide::Left);
+    assert!(!match_score.should_start_tiebreak(config.tiebreak_trigger_games()));
+    match_score.win_game(CourtSide::Right);
+    assert!(match_score.should_start_tiebreak(config.tiebreak_trigger_games()));
+
+    // Fast4: 3-3 でタイブレーク、5ポイント先取の勝者が 4-3 でセット獲得
+    config.games_to_win_set = 4;
+    config.tiebreak_at_games = Some(3);
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 3);
+    assert!(!match_score.check_set_win(CourtSide::Left, 4, 2));
+    assert!(match_score.should_start_tiebreak(config.tiebreak_trigger_games()));
+
+    match_score.start_tiebreak(TiebreakKind::Standard, 5);
+    for _ in 0..5 {
+        match_score.add_point(CourtSide::Right);
+    }
+    assert!(match_score.check_tiebreak_win(CourtSide::Right));
+    match_score.get_score_mut(CourtSide::Right).win_game();
+    match_score.win_set(CourtSide::Right);
+    assert_eq!(match_score.set_history_display(), "Set 1: 3-4(0)");
+}
+
+/// TST-30707-058: 時間切れ時はリードしている側の勝利、同点なら続行
+/// @spec 30703_set_spec.md#req-30703-058
+#[test]
+fn test_finish_on_time() {
+    let mut match_score = MatchScore::new();
+    advance_to_games(&mut match_score, 2);
+
+    // 2-2, 0-0: 同点なので終了しない
+    assert_eq!(match_score.leading_side(), None);
+    assert_eq!(match_score.finish_on_time(), None);
+    assert_eq!(match_score.game_state, crate::resource::GameState::Playing);
+
+    // ゲーム同数ならポイントで比較
+    match_score.add_point(CourtSide::Right);
+    assert_eq!(match_score.leading_side(), Some(CourtSide::Right));
+
+    // ゲーム数がポイントより優先される
+    match_score.reset_points();
+    match_score.win_game(CourtSide::Left);
+    match_score.add_point(CourtSide::Right);
+    assert_eq!(match_score.finish_on_time(), Some(CourtSide::Left));
+    assert_eq!(
+        match_score.game_state,
+        crate::resource::GameState::MatchWon(CourtSide::Left)
+    );
+    // 途中経過のセットが記録される
+    assert_eq!(match_score.set_history_display(), "Set 1: 3-2");
+    // 総獲得ポイントはチーム順で集計される
+    assert_eq!(match_score.points_won, [0, 2]);
+}
+
+/// TST-30708-001: ポイントごとにサーバー・理由・ラリー長・前後スコアを記録
+/// @spec 30704_score_log_spec.md#req-30704-001
+#[test]
+fn test_score_log_records_point() {
+    let mut match_score = MatchScore::new();
+    let mut score_log = ScoreLog::new();
+
+    // フォルト後のセカンドサーブから3ショット
+    score_log.record_shot(true);
+    score_log.record_shot(true);
+    score_log.record_shot(false);
+    score_log.record_shot(false);
+
+    let before = match_score.clone();
+    match_score.add_point(CourtSide::Right);
+    let record = score_log.record_point(
+        &before,
+        &match_score,
+        CourtSide::Right,
+        ServeSide::Deuce,
+        RallyEndReason::DoubleBounce,
+    );
+
+    assert_eq!(record.number, 1);
+    assert_eq!(record.winner, CourtSide::Right);
+    assert_eq!(record.server, CourtSide::Left);
+    assert_eq!(record.server_player_id, 1);
+    assert_eq!(record.rally_length, 3);
+    assert_eq!(record.before.points, [0, 0]);
+    assert_eq!(record.after.points, [0, 1]);
+    assert_eq!(score_log.shots_this_point, 0);
+}
+
+/// TST-30708-002: 直前のポイントを取り消すとゲーム獲得前のスコアに戻る
+/// @spec 30704_score_log_spec.md#req-30704-002
+#[test]
+fn test_undo_last_point_restores_game() {
+    let mut match_score = MatchScore::new();
+    let mut score_log = ScoreLog::new();
+
+    for _ in 0..3 {
+        match_score.add_point(CourtSide::Left);
+    }
+    let before = match_score.clone();
+    match_score.add_point(CourtSide::Left);
+    match_score.win_game(CourtSide::Left);
+    score_log.record_point(
+        &before,
+        &match_score,
+        CourtSide::Left,
+        ServeSide::Ad,
+        RallyEndReason::Out,
+    );
+    assert_eq!(match_score.get_score(CourtSide::Left).games, 1);
+    assert_eq!(match_score.server, CourtSide::Right);
+
+    let undone = score_log.undo_last_point(&mut match_score).unwrap();
+    assert_eq!(undone.reason, RallyEndReason::Out);
+    assert_eq!(match_score.get_score(CourtSide::Left).games, 0);
+    assert_eq!(match_score.get_point_index(CourtSide::Left), 3);
+    assert_eq!(match_score.server, CourtSide::Left);
+    assert!(score_log.points.is_empty());
+    assert!(score_log.undo_last_point(&mut match_score).is_none());
+}
+
+/// TST-30708-003: エンドチェンジ後の取り消しはサイドもポイント前に戻す
+/// @spec 30704_score_log_spec.md#req-30704-002
+#[test]
+fn test_undo_last_point_after_change_ends() {
+    let mut match_score = MatchScore::new();
+    let mut score_log = ScoreLog::new();
+
+    let before = match_score.clone();
+    match_score.add_point(CourtSide::Left);
+    match_score.win_game(CourtSide::Left);
+    score_log.record_point(
+        &before,
+        &match_score,
+        CourtSide::Left,
+        ServeSide::Deuce,
+        RallyEndReason::NetFault,
+    );
+    match_score.change_ends();
+    match_score.elapsed_secs = 42.0;
+
+    score_log.undo_last_point(&mut match_score).unwrap();
+    assert!(!match_score.ends_swapped);
+    // P1（Left開始チーム）は Left 側に戻る
+    assert_eq!(match_score.get_score(CourtSide::Left).games, 0);
+    assert_eq!(match_score.server, CourtSide::Left);
+    assert_eq!(match_score.server_player_id(), 1);
+    // 試合時間は巻き戻さない
+    assert_eq!(match_score.elapsed_secs, 42.0);
+}
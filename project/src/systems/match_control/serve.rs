@@ -5,26 +5,32 @@
 //! 1回目ボタン: トス開始（ボールを上に投げる）
 //! 2回目ボタン: ヒット（ボールを打つ）
 //! ヒット可能高さ外でボタン押下しても発射されない
-//! タイムアウトまたはボール落下でFault
+//! タイムアウトまたはボール落下でlet（打ち直し）
+//!
+//! パデルルール: トスは一度床でバウンドさせ、腰より下で打つ。
+//! 打点の合法性は judge_serve_contact で判定し、違反はFaultとする。
 
 use bevy::prelude::*;
 
 use crate::components::{
     AiController, Ball, InputState, LogicalPosition, Player, TossBall, TossBallBundle, Velocity,
 };
-use crate::core::{CourtSide, ShotEvent};
+use crate::core::{CourtSide, FaultEvent, ShotEvent, ShotType};
 use crate::resource::config::{ServeConfig, ServeSide};
 use crate::resource::scoring::{MatchFlowState, ServeState, ServeSubPhase};
-use crate::resource::{FixedDeltaTime, GameConfig, MatchScore};
+use crate::resource::{FixedDeltaTime, GameConfig, MatchScore, RallyState};
 use crate::systems::ai::AiServeTimer;
 
+use super::fault::{judge_serve_contact, write_serve_fault};
+
 /// サーバーを検索するヘルパー関数
 /// @spec 30102_serve_spec.md
+/// @spec 30105_doubles_spec.md#req-30105-002
 fn find_server<'a>(
     mut player_query: impl Iterator<Item = (&'a Player, &'a LogicalPosition, &'a InputState)>,
-    server_side: CourtSide,
+    server_id: u8,
 ) -> Option<(&'a Player, &'a LogicalPosition, &'a InputState)> {
-    player_query.find(|(p, _, _)| p.court_side == server_side)
+    player_query.find(|(p, _, _)| p.id == server_id)
 }
 
 #[inline]
@@ -99,14 +105,24 @@ pub fn serve_position_system(
         (CourtSide::Right, ServeSide::Ad) => base_z,   // Right: アド = +Z（対向）
     };
 
+    // ダブルス: サーバーのパートナーはネット前、レシーバーのパートナーは逆サイド
+    // @spec 30105_doubles_spec.md#req-30105-004
+    let server_id = match_score.server_player_id();
+    let receiver_id = match_score.receiver_player_id(serve_side);
+    let net_player_distance = config.team.net_player_distance;
+
     for (player, mut pos, ai_controller) in player_query.iter_mut() {
-        let is_server = player.court_side == match_score.server;
+        let is_server = player.id == server_id;
+        let is_receiver = player.id == receiver_id;
+        let is_server_team = player.court_side == match_score.server;
 
         // サーバーとレシーバーは対角線上（クロス）に配置
-        let target_z = if is_server {
-            serve_z // サーバーはサーブサイドに
-        } else {
-            -serve_z // レシーバーは対角線上（逆サイド）に
+        // パートナーはそれぞれ味方と逆サイドを担当
+        let target_z = match (is_server_team, is_server || is_receiver) {
+            (true, true) => serve_z,   // サーバーはサーブサイドに
+            (false, true) => -serve_z, // レシーバーは対角線上（逆サイド）に
+            (true, false) => -serve_z, // サーバーのパートナーは逆サイドに
+            (false, false) => serve_z, // レシーバーのパートナーは逆サイドに
         };
 
         // X位置: サーバーはベースライン外に配置
@@ -116,13 +132,22 @@ pub fn serve_position_system(
                 CourtSide::Left => config.serve.serve_baseline_x_p1,
                 CourtSide::Right => config.serve.serve_baseline_x_p2,
             };
+        } else if is_server_team {
+            // サーバーのパートナーはネット前で構える
+            pos.value.x = match player.court_side {
+                CourtSide::Left => config.court.net_x - net_player_distance,
+                CourtSide::Right => config.court.net_x + net_player_distance,
+            };
         }
 
         pos.value.z = target_z;
 
-        // AIのホームポジションも更新
+        // AIのホームポジションも更新（ネット前のパートナーはX位置も維持）
         if let Some(mut ai) = ai_controller {
             ai.home_position.z = target_z;
+            if is_server_team && !is_server {
+                ai.home_position.x = pos.value.x;
+            }
         }
     }
 
@@ -160,7 +185,7 @@ pub fn serve_toss_input_system(
 
     // サーバーを特定
     let Some((player, logical_pos, input_state)) =
-        find_server(player_query.iter(), match_score.server)
+        find_server(player_query.iter(), match_score.server_player_id())
     else {
         return;
     };
@@ -222,9 +247,24 @@ pub fn serve_toss_input_system(
     );
 }
 
+/// トスボールの床バウンドを適用
+/// @spec 30902_fault_spec.md#req-30902-052
+///
+/// 床（Y=0）に下向きで到達した場合に反射させ、バウンドしたかを返す。
+#[inline]
+fn apply_toss_floor_bounce(pos: &mut Vec3, vel: &mut Vec3, bounce_factor: f32) -> bool {
+    if pos.y > 0.0 || vel.y >= 0.0 {
+        return false;
+    }
+    pos.y = 0.0;
+    vel.y = -vel.y * bounce_factor;
+    true
+}
+
 /// トス物理システム（重力適用）
 /// @spec 30102_serve_spec.md#req-30102-081
-/// トスボールに重力を適用する
+/// @spec 30902_fault_spec.md#req-30902-052
+/// トスボールに重力を適用し、バウンド必須ルールでは床で跳ね返す
 pub fn serve_toss_physics_system(
     config: Res<GameConfig>,
     fixed_dt: Res<FixedDeltaTime>,
@@ -244,6 +284,13 @@ pub fn serve_toss_physics_system(
 
         // 位置更新
         pos.value += vel.value * delta;
+
+        // 床バウンド（パデル: 一度バウンドさせてから打つ）
+        if config.serve.require_bounce_before_hit
+            && apply_toss_floor_bounce(&mut pos.value, &mut vel.value, config.ball.bounce_factor)
+        {
+            serve_state.toss_bounces += 1;
+        }
     }
 
     // トス時間更新
@@ -253,15 +300,22 @@ pub fn serve_toss_physics_system(
 /// ヒット入力システム（2回目ボタン）
 /// @spec 30102_serve_spec.md#req-30102-082
 /// @spec 30102_serve_spec.md#req-30102-083
+/// @spec 30902_fault_spec.md#req-30902-050
+/// @spec 30902_fault_spec.md#req-30902-052
+/// @spec 30902_fault_spec.md#req-30902-053
 /// Tossing状態でショットボタンを押すとヒットを試行
+/// 打点が非合法ならボールは打ち出さずFaultとする
+#[allow(clippy::too_many_arguments)]
 pub fn serve_hit_input_system(
     mut commands: Commands,
     config: Res<GameConfig>,
     match_score: Res<MatchScore>,
     mut serve_state: ResMut<ServeState>,
+    mut rally_state: ResMut<RallyState>,
     player_query: Query<(&Player, &LogicalPosition, &InputState)>,
     toss_ball_query: Query<(Entity, &LogicalPosition), With<TossBall>>,
     mut shot_event_writer: MessageWriter<ShotEvent>,
+    mut fault_events: MessageWriter<FaultEvent>,
 ) {
     // @spec 30102_serve_spec.md#req-30102-082: Tossing状態でのみヒット可能
     if serve_state.phase != ServeSubPhase::Tossing {
@@ -270,7 +324,7 @@ pub fn serve_hit_input_system(
 
     // サーバーを特定し、ショット入力をチェック
     let Some((player, player_pos, input_state)) =
-        find_server(player_query.iter(), match_score.server)
+        find_server(player_query.iter(), match_score.server_player_id())
     else {
         return;
     };
@@ -295,10 +349,32 @@ pub fn serve_hit_input_system(
         return;
     }
 
-    // @spec 30102_serve_spec.md#req-30102-082: ヒット成功
     let hit_pos = toss_pos.value;
     commands.entity(toss_entity).despawn();
 
+    // @spec 30902_fault_spec.md#req-30902-050: 打点の合法性判定
+    if let Some(reason) = judge_serve_contact(
+        match_score.server,
+        rally_state.serve_side,
+        player_pos.value,
+        hit_pos,
+        serve_state.toss_bounced(),
+        &config,
+    ) {
+        info!(
+            "Serve fault: {:?} (height {:.2}m, bounced={}) by Player{}",
+            reason,
+            hit_pos.y,
+            serve_state.toss_bounced(),
+            player.id
+        );
+        serve_state.on_hit_success();
+        write_serve_fault(&mut rally_state, &mut fault_events, reason);
+        return;
+    }
+
+    // @spec 30102_serve_spec.md#req-30102-082: ヒット成功
+
     // 入力方向を正規化（ゼロベクトルの場合はそのまま）
     let direction = input_state.movement.normalize_or_zero();
 
@@ -318,6 +394,7 @@ pub fn serve_hit_input_system(
         is_serve: true,
         hit_position: Some(hit_pos),
         serve_toss_velocity_y: Some(toss_velocity_y),
+        shot_type: ShotType::Serve,
     });
 
     info!(
@@ -347,11 +424,15 @@ pub fn serve_toss_timeout_system(
     };
 
     // タイムアウトまたは落下判定
+    // バウンド必須ルールでは、バウンド前の落下は打ち直しにしない
     let is_timeout = serve_state.toss_time >= config.serve.toss_timeout;
-    let is_falling_too_low =
-        velocity.value.y < 0.0 && toss_pos.value.y < config.serve.hit_height_min;
+    let can_judge_falling = !config.serve.require_bounce_before_hit || serve_state.toss_bounced();
+    let is_falling_too_low = can_judge_falling
+        && velocity.value.y < 0.0
+        && toss_pos.value.y < config.serve.hit_height_min;
+    let is_bounced_twice = serve_state.toss_bounces >= 2;
 
-    if !is_timeout && !is_falling_too_low {
+    if !is_timeout && !is_falling_too_low && !is_bounced_twice {
         return;
     }
 
@@ -366,6 +447,8 @@ pub fn serve_toss_timeout_system(
 
     let reason = if is_timeout {
         "timeout"
+    } else if is_bounced_twice {
+        "ball bounced twice"
     } else {
         "ball too low"
     };
@@ -448,6 +531,26 @@ mod tests {
         assert!(!can_hit);
     }
 
+    /// TST-30904-062: トスボールの床バウンドテスト
+    /// @spec 30902_fault_spec.md#req-30902-052
+    #[test]
+    fn test_req_30902_052_toss_floor_bounce() {
+        // 落下中に床へ到達すると反射する
+        let mut pos = Vec3::new(-8.5, -0.02, 3.0);
+        let mut vel = Vec3::new(0.0, -4.0, 0.0);
+        assert!(apply_toss_floor_bounce(&mut pos, &mut vel, 0.7));
+        assert_eq!(pos.y, 0.0);
+        assert!((vel.y - 2.8).abs() < 0.001);
+
+        // 上昇中・空中ではバウンドしない
+        let mut pos = Vec3::new(-8.5, 0.5, 3.0);
+        let mut vel = Vec3::new(0.0, -1.0, 0.0);
+        assert!(!apply_toss_floor_bounce(&mut pos, &mut vel, 0.7));
+        let mut pos = Vec3::new(-8.5, 0.0, 3.0);
+        let mut vel = Vec3::new(0.0, 2.8, 0.0);
+        assert!(!apply_toss_floor_bounce(&mut pos, &mut vel, 0.7));
+    }
+
     #[test]
     fn test_toss_velocity_changes_with_hold_time() {
         let serve_config = ServeConfig::default();
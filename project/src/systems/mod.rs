@@ -27,12 +27,15 @@ mod trajectory_calculator;
 mod boundary;
 mod court_factory;
 mod debug_marker;
+mod fixed_tick;
 
 // re-export
 pub use ai::*;
 pub use ball::*;
 pub use boundary::*;
+pub use court_factory::create_court_enclosure;
 pub use debug_marker::*;
+pub use fixed_tick::FixedTickPlugin;
 pub use input::*;
 pub use match_control::*;
 pub use player::*;
@@ -130,7 +130,8 @@ pub fn movement_system(
         }
 
         // @spec 30102_serve_spec.md#req-30102-085: トス中は完全に移動禁止
-        let is_server = player.court_side == match_score.server;
+        // @spec 30105_doubles_spec.md#req-30105-002: ダブルスではサーバー本人のみ
+        let is_server = player.id == match_score.server_player_id();
         if is_serve_state && is_server && serve_state.phase == ServeSubPhase::Tossing {
             velocity.value.x = 0.0;
             velocity.value.z = 0.0;
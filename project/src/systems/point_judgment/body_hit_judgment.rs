@@ -0,0 +1,115 @@
+//! ボディヒット判定システム
+//! @spec 30901_point_judgment_spec.md#req-30901-052
+
+use bevy::prelude::*;
+
+use super::RallyEndJudge;
+use crate::core::events::{BallBodyHitEvent, RallyEndReason};
+use crate::core::CourtSide;
+
+/// ボディヒット判定（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+///
+/// 戻り値: ラリー終了となる場合は (得点者, 終了理由)、インプレー継続なら None
+///
+/// - 打った側のチーム（ダブルスのパートナー）に当たった → BodyHit（相手の得点）
+/// - 受け手の体に当たった → BodyHit（打った側の得点、受け手コートでのバウンドの有無によらない）
+pub fn judge_body_hit(
+    shooter: CourtSide,
+    hit_side: CourtSide,
+) -> Option<(CourtSide, RallyEndReason)> {
+    if hit_side == shooter {
+        return Some((shooter.opponent(), RallyEndReason::BodyHit));
+    }
+    Some((shooter, RallyEndReason::BodyHit))
+}
+
+/// ボディヒット判定システム（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-052
+///
+/// `BallBodyHitEvent`（collision.body_hit_rule = PointLoss の場合のみ発行）を受信し、
+/// 当たったプレイヤーのチームの失点とする。
+pub fn body_hit_judgment_system(
+    mut body_hit_events: MessageReader<BallBodyHitEvent>,
+    mut judge: RallyEndJudge,
+) {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
+        return;
+    }
+
+    for event in body_hit_events.read() {
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        let Some(shooter) = ball.last_shooter.side else {
+            continue;
+        };
+        let Some((winner, reason)) = judge_body_hit(shooter, event.court_side) else {
+            continue;
+        };
+
+        info!(
+            "Body hit! Ball struck player {} at {:?}. {:?} wins the point.",
+            event.player_id, event.hit_point, winner
+        );
+
+        judge.end_rally(
+            ball.entity,
+            winner,
+            reason,
+            &format!(
+                "player={} pos=({:.2},{:.2},{:.2}) shooter={:?}",
+                event.player_id, event.hit_point.x, event.hit_point.y, event.hit_point.z, shooter
+            ),
+        );
+        break;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TST-30901-052: バウンド前に受け手の体に当たったら打った側の得点
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    #[test]
+    fn test_req_30901_052_body_hit_before_bounce() {
+        assert_eq!(
+            judge_body_hit(CourtSide::Left, CourtSide::Right),
+            Some((CourtSide::Left, RallyEndReason::BodyHit))
+        );
+        assert_eq!(
+            judge_body_hit(CourtSide::Right, CourtSide::Left),
+            Some((CourtSide::Right, RallyEndReason::BodyHit))
+        );
+    }
+
+    /// TST-30901-053: 受け手のコートでバウンドした後に受け手に当たっても打った側の得点
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    #[test]
+    fn test_req_30901_052_body_hit_after_bounce() {
+        assert_eq!(
+            judge_body_hit(CourtSide::Left, CourtSide::Right),
+            Some((CourtSide::Left, RallyEndReason::BodyHit))
+        );
+        assert_eq!(
+            judge_body_hit(CourtSide::Right, CourtSide::Left),
+            Some((CourtSide::Right, RallyEndReason::BodyHit))
+        );
+    }
+
+    /// TST-30901-054: 打った側のパートナーに当たったら相手の得点
+    /// @spec 30901_point_judgment_spec.md#req-30901-052
+    #[test]
+    fn test_req_30901_052_body_hit_on_own_team() {
+        assert_eq!(
+            judge_body_hit(CourtSide::Left, CourtSide::Left),
+            Some((CourtSide::Right, RallyEndReason::BodyHit))
+        );
+        assert_eq!(
+            judge_body_hit(CourtSide::Right, CourtSide::Right),
+            Some((CourtSide::Left, RallyEndReason::BodyHit))
+        );
+    }
+}
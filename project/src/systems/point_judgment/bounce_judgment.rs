@@ -3,9 +3,11 @@
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, BounceCount, LastShooter, PointEnded};
-use crate::core::events::{GroundBounceEvent, RallyEndEvent, RallyEndReason};
-use crate::resource::{GameState, MatchScore, RallyPhase, RallyState};
+use super::RallyEndJudge;
+use crate::components::{Ball, BounceCount};
+use crate::core::events::{GroundBounceEvent, RallyEndReason};
+use crate::core::CourtSide;
+use crate::resource::RallyPhase;
 use crate::simulation::DebugLogger;
 
 /// バウンス回数更新システム
@@ -42,134 +44,105 @@ pub fn bounce_count_update_system(
     }
 }
 
+/// ツーバウンド判定
+/// @spec 30901_point_judgment_spec.md#req-30901-002
+///
+/// 戻り値: 同じコート側で2回以上バウンドしていれば (得点者, DoubleBounce)、それ以外は None
+/// （バウンドしたコート側のプレイヤーが失点＝相手側が得点）
+pub fn judge_double_bounce(bounce_count: &BounceCount) -> Option<(CourtSide, RallyEndReason)> {
+    if bounce_count.count < 2 {
+        return None;
+    }
+    bounce_count
+        .last_court_side
+        .map(|court_side| (court_side.opponent(), RallyEndReason::DoubleBounce))
+}
+
 /// ツーバウンド判定システム
 /// @spec 30901_point_judgment_spec.md#req-30901-002
 /// BounceCount >= 2 でラリー終了（該当プレイヤーが失点）
-#[allow(clippy::type_complexity)]
-pub fn double_bounce_judgment_system(
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut BounceCount), (With<Ball>, Without<PointEnded>)>,
-    match_score: Res<MatchScore>,
-    mut rally_state: ResMut<RallyState>,
-    mut debug_logger: Option<ResMut<DebugLogger>>,
-    mut rally_events: MessageWriter<RallyEndEvent>,
-) {
-    // ゲーム進行中でなければスキップ
-    if match_score.game_state != GameState::Playing {
+pub fn double_bounce_judgment_system(mut judge: RallyEndJudge) {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
         return;
     }
 
     // サーブ中はツーバウンド判定を行わない（フォルト判定は別システムで処理）
-    if rally_state.phase == RallyPhase::Serving {
+    if judge.rally_state.phase == RallyPhase::Serving {
         return;
     }
 
-    // 同一フレームで既にイベント発行済みならスキップ
-    if rally_state.rally_end_event_sent_this_frame {
-        return;
+    // @spec 30901_point_judgment_spec.md#req-30901-002
+    // ツーバウンド判定: 同じコート側で2回以上バウンド
+    // event_sent フラグで重複発行を防止
+    let mut double_bounces = Vec::new();
+    for (entity, _, _, mut bounce_count) in judge.balls.iter_mut() {
+        if bounce_count.event_sent {
+            continue;
+        }
+        if let Some((winner, _)) = judge_double_bounce(&bounce_count) {
+            bounce_count.event_sent = true;
+            double_bounces.push((entity, winner.opponent(), winner));
+        }
     }
 
-    for (entity, mut bounce_count) in query.iter_mut() {
-        // @spec 30901_point_judgment_spec.md#req-30901-002
-        // ツーバウンド判定: 同じコート側で2回以上バウンド
-        // event_sent フラグで重複発行を防止
-        if bounce_count.count >= 2 && !bounce_count.event_sent {
-            if let Some(court_side) = bounce_count.last_court_side {
-                // バウンドしたコート側のプレイヤーが失点
-                // つまり、相手側が得点
-                let winner = court_side.opponent();
-
-                // ツーバウンス得点ログ出力
-                if let Some(ref mut logger) = debug_logger {
-                    logger.log_scoring(&format!(
-                        "POINT winner={:?} reason=DoubleBounce court={:?}",
-                        winner, court_side
-                    ));
-                }
-
-                info!(
-                    "Double bounce on {:?} court! {:?} wins the point.",
-                    court_side, winner
-                );
-
-                rally_events.write(RallyEndEvent {
-                    winner,
-                    reason: RallyEndReason::DoubleBounce,
-                });
-
-                // 重複発行防止フラグを設定
-                bounce_count.event_sent = true;
-                rally_state.rally_end_event_sent_this_frame = true;
-
-                // 他のポイント判定システムからの重複発行を防止
-                commands.entity(entity).insert(PointEnded);
-            }
-        }
+    for (entity, court_side, winner) in double_bounces {
+        info!(
+            "Double bounce on {:?} court! {:?} wins the point.",
+            court_side, winner
+        );
+
+        judge.end_rally(
+            entity,
+            winner,
+            RallyEndReason::DoubleBounce,
+            &format!("court={:?}", court_side),
+        );
     }
 }
 
 /// 自コート打球失点判定システム
 /// @spec 30103_point_end_spec.md#req-30103-003
 /// 打った打球が自コートに落ちた場合は失点
-#[allow(clippy::type_complexity)]
 pub fn own_court_hit_judgment_system(
-    mut commands: Commands,
     mut bounce_events: MessageReader<GroundBounceEvent>,
-    mut rally_state: ResMut<RallyState>,
-    match_score: Res<MatchScore>,
-    mut debug_logger: Option<ResMut<DebugLogger>>,
-    query: Query<(Entity, &BounceCount, &LastShooter), (With<Ball>, Without<PointEnded>)>,
-    mut rally_events: MessageWriter<RallyEndEvent>,
+    mut judge: RallyEndJudge,
 ) {
     // ラリー中でなければスキップ
-    if rally_state.phase != RallyPhase::Rally {
+    if judge.rally_state.phase != RallyPhase::Rally {
         return;
     }
 
-    // ゲーム進行中でなければスキップ
-    if match_score.game_state != GameState::Playing {
-        return;
-    }
-
-    // 同一フレームで既にイベント発行済みならスキップ
-    if rally_state.rally_end_event_sent_this_frame {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
         return;
     }
 
     for event in bounce_events.read() {
-        if let Ok((entity, bounce_count, last_shooter)) = query.get(event.ball) {
-            if let Some(shooter) = last_shooter.side {
-                // @spec 30103_point_end_spec.md#req-30103-003
-                // 最初のバウンドで、バウンドしたコート側が打った側と同じ場合
-                // つまり、ネットを超える前に自コートでバウンドした
-                if bounce_count.count == 1 && event.court_side == shooter {
-                    let winner = shooter.opponent();
-
-                    // 自コート打球得点ログ出力
-                    if let Some(ref mut logger) = debug_logger {
-                        logger.log_scoring(&format!(
-                            "POINT winner={:?} reason=OwnCourtHit shooter={:?}",
-                            winner, shooter
-                        ));
-                    }
-
-                    info!(
-                        "Own court hit! {:?} hit ball landed on their own court. {:?} wins.",
-                        shooter, winner
-                    );
-
-                    rally_events.write(RallyEndEvent {
-                        winner,
-                        reason: RallyEndReason::OwnCourtHit,
-                    });
-
-                    // 重複発行防止フラグを設定
-                    rally_state.rally_end_event_sent_this_frame = true;
-
-                    // 他のポイント判定システムからの重複発行を防止
-                    commands.entity(entity).insert(PointEnded);
-                }
-            }
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        let Some(shooter) = ball.last_shooter.side else {
+            continue;
+        };
+
+        // @spec 30103_point_end_spec.md#req-30103-003
+        // 最初のバウンドで、バウンドしたコート側が打った側と同じ場合
+        // つまり、ネットを超える前に自コートでバウンドした
+        if ball.bounce_count.count == 1 && event.court_side == shooter {
+            let winner = shooter.opponent();
+
+            info!(
+                "Own court hit! {:?} hit ball landed on their own court. {:?} wins.",
+                shooter, winner
+            );
+
+            judge.end_rally(
+                ball.entity,
+                winner,
+                RallyEndReason::OwnCourtHit,
+                &format!("shooter={:?}", shooter),
+            );
         }
     }
 }
@@ -177,7 +150,7 @@ pub fn own_court_hit_judgment_system(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::CourtSide;
+    use crate::components::LastShooter;
 
     /// TST-30904-001: ツーバウンド判定テスト
     /// @spec 30901_point_judgment_spec.md#req-30901-002
@@ -242,7 +215,7 @@ mod tests {
         let mut bounce_count = BounceCount::default();
 
         // 1Pがショット
-        last_shooter.record(CourtSide::Left);
+        last_shooter.record(CourtSide::Left, 1);
         assert_eq!(last_shooter.side, Some(CourtSide::Left));
 
         // 1Pコートでバウンド（自コート打球）
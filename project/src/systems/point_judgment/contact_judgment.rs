@@ -0,0 +1,267 @@
+//! 反則接触判定システム（ダブルヒット、オーバーネット、タッチネット）
+//! @spec 30901_point_judgment_spec.md#req-30901-051
+//! @spec 30901_point_judgment_spec.md#req-30901-053
+//! @spec 30901_point_judgment_spec.md#req-30901-054
+
+use bevy::prelude::*;
+
+use super::RallyEndJudge;
+use crate::components::{LogicalPosition, Player};
+use crate::core::court::determine_court_side;
+use crate::core::events::{RacketContactEvent, RallyEndReason};
+use crate::core::CourtSide;
+use crate::resource::config::CourtConfig;
+use crate::resource::GameConfig;
+
+/// ダブルヒット判定
+/// @spec 30901_point_judgment_spec.md#req-30901-053
+///
+/// 戻り値: ラリー終了となる場合は (得点者, 終了理由)、インプレー継続なら None
+///
+/// - 直前にボールに触れたのが同じ側 → DoubleHit（相手の得点）
+/// - 相手が触れた後の接触 → インプレー継続
+pub fn judge_double_hit(
+    last_contact_side: Option<CourtSide>,
+    hitter: CourtSide,
+) -> Option<(CourtSide, RallyEndReason)> {
+    (last_contact_side == Some(hitter)).then_some((hitter.opponent(), RallyEndReason::DoubleHit))
+}
+
+/// オーバーネット判定
+/// @spec 30901_point_judgment_spec.md#req-30901-054
+///
+/// - ラケットの接触点が net_x に対して相手コート側 → ReachOverNet（相手の得点）
+/// - 自コート側での接触 → インプレー継続
+pub fn judge_reach_over_net(
+    hitter: CourtSide,
+    contact_point: Vec3,
+    net_x: f32,
+) -> Option<(CourtSide, RallyEndReason)> {
+    let contact_side = determine_court_side(contact_point.x, net_x);
+    (contact_side != hitter).then_some((hitter.opponent(), RallyEndReason::ReachOverNet))
+}
+
+/// タッチネット判定
+/// @spec 30901_point_judgment_spec.md#req-30901-051
+///
+/// 体の当たり判定（半径 body_radius の円柱、足元から）がネットの範囲
+/// （net_x の面、コート幅の内側、高さ net_height 以下）と重なれば TouchNet（相手の得点）。
+/// 足元がネットより高い（ジャンプ中）場合は触れていないものとする。
+pub fn judge_touch_net(
+    player_side: CourtSide,
+    player_pos: Vec3,
+    body_radius: f32,
+    court: &CourtConfig,
+) -> Option<(CourtSide, RallyEndReason)> {
+    let within_depth = (player_pos.x - court.net_x).abs() < body_radius;
+    let within_width = player_pos.z.abs() < court.width / 2.0 + body_radius;
+    let below_net = player_pos.y < court.net_height;
+    (within_depth && within_width && below_net)
+        .then_some((player_side.opponent(), RallyEndReason::TouchNet))
+}
+
+/// ラケット接触の反則判定システム（ダブルヒット、オーバーネット）
+/// @spec 30901_point_judgment_spec.md#req-30901-053
+/// @spec 30901_point_judgment_spec.md#req-30901-054
+///
+/// `RacketContactEvent` を受信し、接触した側を RallyState.last_contact_side に記録する。
+/// 判定は記録の前に行う（LastShooter はショット処理で既に上書きされているため使わない）。
+pub fn racket_contact_judgment_system(
+    mut contact_events: MessageReader<RacketContactEvent>,
+    config: Res<GameConfig>,
+    mut judge: RallyEndJudge,
+) {
+    for event in contact_events.read() {
+        let previous = judge.rally_state.last_contact_side;
+        judge.rally_state.last_contact_side = Some(event.court_side);
+
+        // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+        // （接触の記録は続ける）
+        if !judge.can_judge() {
+            continue;
+        }
+
+        let Some((winner, reason)) =
+            judge_reach_over_net(event.court_side, event.contact_point, config.court.net_x)
+                .or_else(|| judge_double_hit(previous, event.court_side))
+        else {
+            continue;
+        };
+        let Some(entity) = judge.single_ball() else {
+            continue;
+        };
+
+        info!(
+            "{:?}! Player {} touched the ball at {:?}. {:?} wins the point.",
+            reason, event.player_id, event.contact_point, winner
+        );
+
+        judge.end_rally(
+            entity,
+            winner,
+            reason,
+            &format!(
+                "player={} contact=({:.2},{:.2},{:.2}) previous={:?}",
+                event.player_id,
+                event.contact_point.x,
+                event.contact_point.y,
+                event.contact_point.z,
+                previous
+            ),
+        );
+    }
+}
+
+/// タッチネット判定システム
+/// @spec 30901_point_judgment_spec.md#req-30901-051
+///
+/// 体の当たり判定がネットに重なったプレイヤーの失点とする。
+pub fn touch_net_judgment_system(
+    config: Res<GameConfig>,
+    players: Query<(&Player, &LogicalPosition)>,
+    mut judge: RallyEndJudge,
+) {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
+        return;
+    }
+
+    let Some(entity) = judge.single_ball() else {
+        return;
+    };
+
+    for (player, player_pos) in players.iter() {
+        let Some((winner, reason)) = judge_touch_net(
+            player.court_side,
+            player_pos.value,
+            config.collision.body_radius,
+            &config.court,
+        ) else {
+            continue;
+        };
+
+        info!(
+            "Touch net! Player {} touched the net at {:?}. {:?} wins the point.",
+            player.id, player_pos.value, winner
+        );
+
+        judge.end_rally(
+            entity,
+            winner,
+            reason,
+            &format!(
+                "player={} pos=({:.2},{:.2},{:.2})",
+                player.id, player_pos.value.x, player_pos.value.y, player_pos.value.z
+            ),
+        );
+        break;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::RallyState;
+
+    /// TST-30904-054: 同じ側が続けて触れたらダブルヒット（相手の得点）
+    /// @spec 30901_point_judgment_spec.md#req-30901-053
+    #[test]
+    fn test_req_30901_053_double_hit() {
+        assert_eq!(
+            judge_double_hit(Some(CourtSide::Left), CourtSide::Left),
+            Some((CourtSide::Right, RallyEndReason::DoubleHit))
+        );
+        assert_eq!(
+            judge_double_hit(Some(CourtSide::Right), CourtSide::Right),
+            Some((CourtSide::Left, RallyEndReason::DoubleHit))
+        );
+        // 相手が触れた後・記録なしはインプレー継続
+        assert_eq!(
+            judge_double_hit(Some(CourtSide::Right), CourtSide::Left),
+            None
+        );
+        assert_eq!(judge_double_hit(None, CourtSide::Left), None);
+    }
+
+    /// TST-30904-055: サーブはサーバーの接触として扱い、レシーバー側の返球は反則でない
+    /// @spec 30901_point_judgment_spec.md#req-30901-053
+    #[test]
+    fn test_req_30901_053_serve_counts_as_server_contact() {
+        let mut rally_state = RallyState::new(CourtSide::Right);
+        rally_state.start_serve();
+        assert_eq!(
+            judge_double_hit(rally_state.last_contact_side, CourtSide::Left),
+            None
+        );
+        assert_eq!(
+            judge_double_hit(rally_state.last_contact_side, CourtSide::Right),
+            Some((CourtSide::Left, RallyEndReason::DoubleHit))
+        );
+
+        rally_state.next_serve();
+        assert_eq!(rally_state.last_contact_side, None);
+    }
+
+    /// TST-30904-056: 相手コート側での接触はオーバーネット
+    /// @spec 30901_point_judgment_spec.md#req-30901-054
+    #[test]
+    fn test_req_30901_054_reach_over_net() {
+        let net_x = 0.0;
+        assert_eq!(
+            judge_reach_over_net(CourtSide::Left, Vec3::new(0.2, 1.0, 0.0), net_x),
+            Some((CourtSide::Right, RallyEndReason::ReachOverNet))
+        );
+        assert_eq!(
+            judge_reach_over_net(CourtSide::Right, Vec3::new(-0.2, 1.0, 0.0), net_x),
+            Some((CourtSide::Left, RallyEndReason::ReachOverNet))
+        );
+        // 自コート側での接触はインプレー継続
+        assert_eq!(
+            judge_reach_over_net(CourtSide::Left, Vec3::new(-0.2, 1.0, 0.0), net_x),
+            None
+        );
+        assert_eq!(
+            judge_reach_over_net(CourtSide::Right, Vec3::new(0.2, 1.0, 0.0), net_x),
+            None
+        );
+    }
+
+    /// TST-30904-057: 体がネットに重なったらタッチネット
+    /// @spec 30901_point_judgment_spec.md#req-30901-051
+    #[test]
+    fn test_req_30901_051_touch_net() {
+        let court = CourtConfig::default();
+        let body_radius = 0.3;
+        let at_net = Vec3::new(court.net_x - 0.1, 0.0, 0.0);
+        assert_eq!(
+            judge_touch_net(CourtSide::Left, at_net, body_radius, &court),
+            Some((CourtSide::Right, RallyEndReason::TouchNet))
+        );
+        assert_eq!(
+            judge_touch_net(
+                CourtSide::Right,
+                Vec3::new(court.net_x, 0.0, 0.0),
+                body_radius,
+                &court
+            ),
+            Some((CourtSide::Left, RallyEndReason::TouchNet))
+        );
+
+        // ネットから離れている・ネットより高く跳んでいる・コート幅の外はインプレー継続
+        let away = Vec3::new(court.net_x - 1.0, 0.0, 0.0);
+        assert_eq!(
+            judge_touch_net(CourtSide::Left, away, body_radius, &court),
+            None
+        );
+        let jumping = Vec3::new(court.net_x - 0.1, court.net_height + 0.1, 0.0);
+        assert_eq!(
+            judge_touch_net(CourtSide::Left, jumping, body_radius, &court),
+            None
+        );
+        let outside = Vec3::new(court.net_x - 0.1, 0.0, court.width / 2.0 + 1.0);
+        assert_eq!(
+            judge_touch_net(CourtSide::Left, outside, body_radius, &court),
+            None
+        );
+    }
+}
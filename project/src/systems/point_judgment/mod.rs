@@ -1,26 +1,123 @@
 //! ポイント判定システム
 //! @spec 30901_point_judgment_spec.md
 //!
-//! 得点条件（ツーバウンド、アウト、レット）を判定する。
+//! 得点条件（ツーバウンド、アウト、レット、ボディヒット、反則接触）を判定する。
 
+mod body_hit_judgment;
 mod bounce_judgment;
+mod contact_judgment;
 mod net_judgment;
 mod out_judgment;
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-use crate::components::{Ball, LastShooter};
-use crate::core::events::ShotExecutedEvent;
+use crate::components::{Ball, BounceCount, LastShooter, LogicalPosition, Player, PointEnded};
+use crate::core::events::{RallyEndEvent, RallyEndReason, ShotExecutedEvent};
 use crate::core::CourtSide;
+use crate::resource::{GameState, MatchScore};
+use crate::simulation::DebugLogger;
 
+pub use body_hit_judgment::body_hit_judgment_system;
 pub use bounce_judgment::{
     bounce_count_update_system, double_bounce_judgment_system, own_court_hit_judgment_system,
 };
+pub use contact_judgment::{racket_contact_judgment_system, touch_net_judgment_system};
 pub use net_judgment::{let_judgment_system, net_fault_judgment_system};
-pub use out_judgment::{out_of_bounds_judgment_system, wall_hit_judgment_system};
+pub use out_judgment::{
+    court_exit_judgment_system, out_of_bounds_judgment_system, wall_hit_judgment_system,
+};
 
 use crate::resource::{MatchFlowState, RallyState};
 
+/// 判定対象のボール（ポイント終了済みのボールは除く）
+/// BounceCount はツーバウンド判定の重複発行防止フラグ（event_sent）を更新するため可変
+type JudgedBallQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static LogicalPosition,
+        &'static LastShooter,
+        &'static mut BounceCount,
+    ),
+    (With<Ball>, Without<PointEnded>),
+>;
+
+/// 判定対象のボールの状態
+#[derive(Debug, Clone, Copy)]
+struct JudgedBall {
+    entity: Entity,
+    position: Vec3,
+    last_shooter: LastShooter,
+    bounce_count: BounceCount,
+}
+
+/// ラリー終了判定システムの共通処理
+/// @spec 30901_point_judgment_spec.md
+///
+/// 試合進行中かどうか・同一フレームでの重複発行の確認と、
+/// ラリー終了の確定（得点ログ、RallyEndEvent 発行、PointEnded 付与）をまとめる。
+#[derive(SystemParam)]
+pub struct RallyEndJudge<'w, 's> {
+    commands: Commands<'w, 's>,
+    match_score: Res<'w, MatchScore>,
+    rally_state: ResMut<'w, RallyState>,
+    debug_logger: Option<ResMut<'w, DebugLogger>>,
+    rally_events: MessageWriter<'w, RallyEndEvent>,
+    balls: JudgedBallQuery<'w, 's>,
+}
+
+impl RallyEndJudge<'_, '_> {
+    /// ラリー終了を判定できるか（試合進行中で、このフレームにまだ RallyEndEvent を発行していない）
+    fn can_judge(&self) -> bool {
+        self.match_score.game_state == GameState::Playing
+            && !self.rally_state.rally_end_event_sent_this_frame
+    }
+
+    /// 判定対象のボールの状態を取得
+    fn ball(&self, entity: Entity) -> Option<JudgedBall> {
+        self.balls
+            .get(entity)
+            .ok()
+            .map(
+                |(entity, position, last_shooter, bounce_count)| JudgedBall {
+                    entity,
+                    position: position.value,
+                    last_shooter: *last_shooter,
+                    bounce_count: *bounce_count,
+                },
+            )
+    }
+
+    /// 判定対象のボール（ボールは1つのみ）
+    fn single_ball(&self) -> Option<Entity> {
+        self.balls.single().ok().map(|(entity, ..)| entity)
+    }
+
+    /// ラリー終了を確定する
+    ///
+    /// 得点ログ（`POINT winner=.. reason=.. <detail>`）を出力して RallyEndEvent を発行し、
+    /// 同一フレームの重複発行防止フラグを立て、他の判定システムから外すため PointEnded を付与する。
+    fn end_rally(
+        &mut self,
+        ball: Entity,
+        winner: CourtSide,
+        reason: RallyEndReason,
+        log_detail: &str,
+    ) {
+        if let Some(ref mut logger) = self.debug_logger {
+            logger.log_scoring(&format!(
+                "POINT winner={:?} reason={:?} {}",
+                winner, reason, log_detail
+            ));
+        }
+        self.rally_events.write(RallyEndEvent { winner, reason });
+        self.rally_state.rally_end_event_sent_this_frame = true;
+        self.commands.entity(ball).insert(PointEnded);
+    }
+}
+
 /// ポイント判定プラグイン
 /// @spec 30901_point_judgment_spec.md
 pub struct PointJudgmentPlugin;
@@ -30,7 +127,7 @@ impl Plugin for PointJudgmentPlugin {
         app.init_resource::<RallyState>()
             // 常に動作するシステム（フラグリセット、ショット追跡、バウンスカウント）
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     // 最初にフラグをリセット
                     reset_rally_end_event_flag_system,
@@ -46,13 +143,17 @@ impl Plugin for PointJudgmentPlugin {
             // サーブ中（Serve状態）ではこれらは動作しない
             // （サーブのフォルト判定は FaultJudgmentPlugin が担当）
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     double_bounce_judgment_system,
                     out_of_bounds_judgment_system,
                     wall_hit_judgment_system,
+                    court_exit_judgment_system,
                     net_fault_judgment_system,
                     own_court_hit_judgment_system,
+                    body_hit_judgment_system,
+                    racket_contact_judgment_system,
+                    touch_net_judgment_system,
                 )
                     .chain()
                     .run_if(in_state(MatchFlowState::Rally)),
@@ -72,16 +173,22 @@ fn reset_rally_end_event_flag_system(mut rally_state: ResMut<RallyState>) {
 pub fn update_last_shooter_system(
     mut shot_events: MessageReader<ShotExecutedEvent>,
     mut query: Query<&mut LastShooter, With<Ball>>,
+    players: Query<&Player>,
 ) {
     for event in shot_events.read() {
-        // プレイヤーIDからCourtSideを決定
-        let shooter = match event.player_id {
-            1 => CourtSide::Left,
-            _ => CourtSide::Right,
+        // プレイヤーの現在のCourtSideを使用（エンドチェンジ後はIDと一致しない）
+        // @spec 30105_doubles_spec.md#req-30105-001
+        // @spec 30101_flow_spec.md#req-30101-051
+        let Some(shooter) = players
+            .iter()
+            .find(|player| player.id == event.player_id)
+            .map(|player| player.court_side)
+        else {
+            continue;
         };
 
         for mut last_shooter in query.iter_mut() {
-            last_shooter.record(shooter);
+            last_shooter.record(shooter, event.player_id);
             info!("Ball shot by {:?}", shooter);
         }
     }
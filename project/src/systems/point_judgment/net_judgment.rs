@@ -4,11 +4,11 @@
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, LastShooter, LogicalPosition, PointEnded};
-use crate::core::events::{NetHitEvent, RallyEndEvent, RallyEndReason};
+use super::RallyEndJudge;
+use crate::core::events::{NetContact, NetHitEvent, RallyEndReason};
 use crate::core::CourtSide;
 use crate::resource::config::GameConfig;
-use crate::resource::{GameState, MatchScore, RallyPhase, RallyState};
+use crate::resource::{RallyPhase, RallyState};
 use crate::simulation::DebugLogger;
 
 /// レット判定システム
@@ -51,30 +51,19 @@ pub fn let_judgment_system(
 /// ネット失点判定システム（ラリー中）
 /// @spec 30103_point_end_spec.md#req-30103-002
 /// ラリー中にネットに当たった後、自コートに落ちた場合は失点
-#[allow(clippy::too_many_arguments)]
-#[allow(clippy::type_complexity)]
+/// 白帯を越えた場合（NetContact::CordRollOver）はそのままラリー継続
 pub fn net_fault_judgment_system(
-    mut commands: Commands,
     mut net_events: MessageReader<NetHitEvent>,
-    mut rally_state: ResMut<RallyState>,
     config: Res<GameConfig>,
-    match_score: Res<MatchScore>,
-    mut debug_logger: Option<ResMut<DebugLogger>>,
-    query: Query<(Entity, &LogicalPosition, &LastShooter), (With<Ball>, Without<PointEnded>)>,
-    mut rally_events: MessageWriter<RallyEndEvent>,
+    mut judge: RallyEndJudge,
 ) {
     // ラリー中でなければスキップ（サーブ中はlet_judgment_systemで処理）
-    if rally_state.phase != RallyPhase::Rally {
+    if judge.rally_state.phase != RallyPhase::Rally {
         return;
     }
 
-    // ゲーム進行中でなければスキップ
-    if match_score.game_state != GameState::Playing {
-        return;
-    }
-
-    // 同一フレームで既にイベント発行済みならスキップ
-    if rally_state.rally_end_event_sent_this_frame {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
         return;
     }
 
@@ -82,46 +71,42 @@ pub fn net_fault_judgment_system(
     let net_x = config.court.net_x;
 
     for event in net_events.read() {
-        if let Ok((entity, logical_pos, last_shooter)) = query.get(event.ball) {
-            if let Some(shooter) = last_shooter.side {
-                let ball_x = logical_pos.value.x;
-
-                // @spec 30103_point_end_spec.md#req-30103-002
-                // 打ったボールがネットに当たった時点でショット元のコート側にあれば失点
-                let in_shooter_court = match shooter {
-                    CourtSide::Left => ball_x < net_x, // 1Pが打った → ネット手前（-X側）
-                    CourtSide::Right => ball_x > net_x, // 2Pが打った → ネット手前（+X側）
-                };
-
-                if in_shooter_court {
-                    // ネットに当たって相手コートに届かなかった → 失点
-                    let winner = shooter.opponent();
-
-                    // ネットフォールト得点ログ出力
-                    if let Some(ref mut logger) = debug_logger {
-                        logger.log_scoring(&format!(
-                            "POINT winner={:?} reason=NetFault shooter={:?}",
-                            winner, shooter
-                        ));
-                    }
-
-                    info!(
-                        "Net fault! {:?} hit the net and ball stayed on their side. {:?} wins.",
-                        shooter, winner
-                    );
-
-                    rally_events.write(RallyEndEvent {
-                        winner,
-                        reason: RallyEndReason::NetFault,
-                    });
-
-                    // 重複発行防止フラグを設定
-                    rally_state.rally_end_event_sent_this_frame = true;
-
-                    // 他のポイント判定システムからの重複発行を防止
-                    commands.entity(entity).insert(PointEnded);
-                }
-            }
+        // @spec 30503_boundary_behavior.md#beh-30503-005
+        // 白帯に当たって相手コートへ越えたボールはインプレー
+        if event.contact == NetContact::CordRollOver {
+            continue;
+        }
+
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        let Some(shooter) = ball.last_shooter.side else {
+            continue;
+        };
+        let ball_x = ball.position.x;
+
+        // @spec 30103_point_end_spec.md#req-30103-002
+        // 打ったボールがネットに当たった時点でショット元のコート側にあれば失点
+        let in_shooter_court = match shooter {
+            CourtSide::Left => ball_x < net_x, // 1Pが打った → ネット手前（-X側）
+            CourtSide::Right => ball_x > net_x, // 2Pが打った → ネット手前（+X側）
+        };
+
+        if in_shooter_court {
+            // ネットに当たって相手コートに届かなかった → 失点
+            let winner = shooter.opponent();
+
+            info!(
+                "Net fault! {:?} hit the net and ball stayed on their side. {:?} wins.",
+                shooter, winner
+            );
+
+            judge.end_rally(
+                ball.entity,
+                winner,
+                RallyEndReason::NetFault,
+                &format!("shooter={:?}", shooter),
+            );
         }
     }
 }
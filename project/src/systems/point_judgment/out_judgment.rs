@@ -3,13 +3,57 @@
 
 use bevy::prelude::*;
 
-use crate::components::{Ball, LastShooter, PointEnded};
+use super::RallyEndJudge;
+use crate::components::BounceCount;
 use crate::core::events::{
-    BallOutOfBoundsEvent, RallyEndEvent, RallyEndReason, WallReflectionEvent,
+    BallLeftCourtEvent, BallOutOfBoundsEvent, RallyEndReason, WallReflectionEvent,
 };
 use crate::core::CourtSide;
-use crate::resource::{GameState, MatchScore, RallyState};
-use crate::simulation::DebugLogger;
+use crate::resource::GameConfig;
+
+/// アウト判定
+/// @spec 30901_point_judgment_spec.md#req-30901-001
+/// @spec 30901_point_judgment_spec.md#req-30901-008
+///
+/// 戻り値: ラリー終了となる場合は (得点者, 終了理由)、インプレー継続なら None
+///
+/// - 受け手コートでバウンドする前にコート外へ着地 → Out（打った側の失点）
+/// - 受け手コートでバウンド済み → コート外の着地は2回目のバウンドとして
+///   double_bounce_judgment_system が判定する（ここでは判定しない）
+pub fn judge_out(
+    shooter: CourtSide,
+    bounce_count: &BounceCount,
+) -> Option<(CourtSide, RallyEndReason)> {
+    let receiver = shooter.opponent();
+    let bounced_on_receiver_floor =
+        bounce_count.count >= 1 && bounce_count.last_court_side == Some(receiver);
+    if bounced_on_receiver_floor {
+        return None;
+    }
+    Some((receiver, RallyEndReason::Out))
+}
+
+/// 囲い離脱判定（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-007
+/// @spec 30901_point_judgment_spec.md#req-30901-008
+///
+/// - outside_play が有効で、受け手コートでバウンド後に受け手側から囲いの外へ出た
+///   → インプレー継続（囲いの外でバウンドするまで受け手は打ち返せる）
+/// - それ以外 → judge_wall_hit（壁面なし）と同じく BallThroughFence
+pub fn judge_court_exit(
+    shooter: CourtSide,
+    exit_side: CourtSide,
+    bounce_count: &BounceCount,
+    outside_play: bool,
+) -> Option<(CourtSide, RallyEndReason)> {
+    let receiver = shooter.opponent();
+    let bounced_on_receiver_floor =
+        bounce_count.count >= 1 && bounce_count.last_court_side == Some(receiver);
+    if outside_play && bounced_on_receiver_floor && exit_side == receiver {
+        return None;
+    }
+    judge_wall_hit(shooter, None, bounce_count)
+}
 
 /// アウト判定システム（主要失点条件）
 /// @spec 30901_point_judgment_spec.md#req-30901-001
@@ -17,181 +61,243 @@ use crate::simulation::DebugLogger;
 /// テニスルールに準拠したアウト判定。
 /// ボールがコート境界外に着地した場合、最後に打った側（LastShooter）の失点。
 /// 壁を超えてコート外に着地した場合のフォールバックとして機能。
-#[allow(clippy::type_complexity)]
+/// 受け手コートでバウンド済みのボールはツーバウンド判定に任せる（judge_out）。
 pub fn out_of_bounds_judgment_system(
-    mut commands: Commands,
     mut out_events: MessageReader<BallOutOfBoundsEvent>,
-    match_score: Res<MatchScore>,
-    mut rally_state: ResMut<RallyState>,
-    mut debug_logger: Option<ResMut<DebugLogger>>,
-    query: Query<(Entity, &LastShooter), (With<Ball>, Without<PointEnded>)>,
-    mut rally_events: MessageWriter<RallyEndEvent>,
+    mut judge: RallyEndJudge,
 ) {
-    // ゲーム進行中でなければスキップ
-    if match_score.game_state != GameState::Playing {
-        return;
-    }
-
-    // 同一フレームで既にイベント発行済みならスキップ
-    if rally_state.rally_end_event_sent_this_frame {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
         return;
     }
 
     for event in out_events.read() {
         // @spec 30901_point_judgment_spec.md#req-30901-001
         // LastShooter（最後に打った側）から失点側を決定
-        if let Ok((entity, last_shooter)) = query.get(event.ball) {
-            if let Some(shooter) = last_shooter.side {
-                // 打った側の失点 = 相手の得点
-                let winner = shooter.opponent();
-
-                // アウト得点ログ出力
-                if let Some(ref mut logger) = debug_logger {
-                    logger.log_scoring(&format!(
-                        "POINT winner={:?} reason=Out pos=({:.2},{:.2},{:.2}) shooter={:?}",
-                        winner,
-                        event.final_position.x,
-                        event.final_position.y,
-                        event.final_position.z,
-                        shooter
-                    ));
-                }
-
-                info!(
-                    "Out! Ball landed out of bounds at {:?}. {:?} hit the ball. {:?} wins.",
-                    event.final_position, shooter, winner
-                );
-
-                rally_events.write(RallyEndEvent {
-                    winner,
-                    reason: RallyEndReason::Out,
-                });
-
-                // 重複発行防止フラグを設定
-                rally_state.rally_end_event_sent_this_frame = true;
-
-                // 他のポイント判定システムからの重複発行を防止
-                commands.entity(entity).insert(PointEnded);
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        if let Some(shooter) = ball.last_shooter.side {
+            // 打った側の失点 = 相手の得点
+            let Some((winner, _)) = judge_out(shooter, &ball.bounce_count) else {
+                continue;
+            };
+
+            info!(
+                "Out! Ball landed out of bounds at {:?}. {:?} hit the ball. {:?} wins.",
+                event.final_position, shooter, winner
+            );
+
+            judge.end_rally(
+                ball.entity,
+                winner,
+                RallyEndReason::Out,
+                &format!(
+                    "pos=({:.2},{:.2},{:.2}) shooter={:?}",
+                    event.final_position.x, event.final_position.y, event.final_position.z, shooter
+                ),
+            );
+        } else {
+            // LastShooter が未設定の場合（サーブ前など）
+            // ボール位置から判定（フォールバック）
+            let court_side = if event.final_position.z < 0.0 {
+                CourtSide::Left
             } else {
-                // LastShooter が未設定の場合（サーブ前など）
-                // ボール位置から判定（フォールバック）
-                let court_side = if event.final_position.z < 0.0 {
-                    CourtSide::Left
-                } else {
-                    CourtSide::Right
-                };
-                let winner = court_side.opponent();
+                CourtSide::Right
+            };
+            let winner = court_side.opponent();
 
-                warn!(
-                    "Out with no LastShooter! Ball at {:?}. Defaulting to {:?} side loss.",
-                    event.final_position, court_side
-                );
-
-                rally_events.write(RallyEndEvent {
-                    winner,
-                    reason: RallyEndReason::Out,
-                });
+            warn!(
+                "Out with no LastShooter! Ball at {:?}. Defaulting to {:?} side loss.",
+                event.final_position, court_side
+            );
 
-                // 重複発行防止フラグを設定
-                rally_state.rally_end_event_sent_this_frame = true;
+            judge.end_rally(ball.entity, winner, RallyEndReason::Out, "shooter=None");
+        }
+    }
+}
 
-                // 他のポイント判定システムからの重複発行を防止
-                commands.entity(entity).insert(PointEnded);
-            }
+/// 壁ヒット判定（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-006
+/// @spec 30901_point_judgment_spec.md#req-30901-007
+///
+/// 戻り値: ラリー終了となる場合は (得点者, 終了理由)、インプレー継続なら None
+///
+/// - 受け手側の壁に床より先に当たった → DirectWallHit（打った側の失点）
+/// - 受け手コートでバウンド後に壁に当たった → インプレー
+/// - 自陣の壁（後壁・側壁）に当てて返球 → インプレー
+/// - 囲いの外（天井・ドア・壁の上）へ出た → BallThroughFence
+///   （受け手コートでバウンド済みなら受け手の失点、未バウンドなら打った側の失点）
+pub fn judge_wall_hit(
+    shooter: CourtSide,
+    wall_side: Option<CourtSide>,
+    bounce_count: &BounceCount,
+) -> Option<(CourtSide, RallyEndReason)> {
+    let receiver = shooter.opponent();
+    let bounced_on_receiver_floor =
+        bounce_count.count >= 1 && bounce_count.last_court_side == Some(receiver);
+
+    match wall_side {
+        Some(side) if side == receiver && !bounced_on_receiver_floor => {
+            Some((receiver, RallyEndReason::DirectWallHit))
         }
+        Some(_) => None,
+        None if bounced_on_receiver_floor => Some((shooter, RallyEndReason::BallThroughFence)),
+        None => Some((receiver, RallyEndReason::BallThroughFence)),
     }
 }
 
-/// 壁ヒット判定システム（テニスルール）
-/// @spec 30901_point_judgment_spec.md#REQ-30901-006
+/// 壁ヒット判定システム（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-006
+/// @spec 30901_point_judgment_spec.md#req-30901-007
 ///
-/// ボールが壁（フェンス）に当たった時点でラリー終了（アウト）。
-/// テニスでは壁に当たった時点でインプレーではなくなる。
-/// 最後に打った側（LastShooter）の失点となる。
-#[allow(clippy::type_complexity)]
+/// 床→壁の順序を BounceCount / LastShooter で追跡し、
+/// 受け手側の壁へのダイレクトヒットと囲いの外への飛び出しのみラリー終了とする。
 pub fn wall_hit_judgment_system(
-    mut commands: Commands,
     mut wall_events: MessageReader<WallReflectionEvent>,
-    match_score: Res<MatchScore>,
-    mut rally_state: ResMut<RallyState>,
-    mut debug_logger: Option<ResMut<DebugLogger>>,
-    query: Query<(Entity, &LastShooter), (With<Ball>, Without<PointEnded>)>,
-    mut rally_events: MessageWriter<RallyEndEvent>,
+    config: Res<GameConfig>,
+    mut judge: RallyEndJudge,
 ) {
-    // ゲーム進行中でなければスキップ
-    if match_score.game_state != GameState::Playing {
-        return;
-    }
-
-    // 同一フレームで既にイベント発行済みならスキップ
-    if rally_state.rally_end_event_sent_this_frame {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
         return;
     }
 
     for event in wall_events.read() {
-        // 壁に当たったボールの LastShooter を取得
-        if let Ok((entity, last_shooter)) = query.get(event.ball) {
-            if let Some(shooter) = last_shooter.side {
-                // 壁に当てた側の失点 = 相手の得点
-                let winner = shooter.opponent();
-
-                // 壁ヒット得点ログ出力
-                if let Some(ref mut logger) = debug_logger {
-                    logger.log_scoring(&format!(
-                        "POINT winner={:?} reason=WallHit wall={:?} shooter={:?}",
-                        winner, event.wall_type, shooter
-                    ));
+        // 壁に当たったボールの LastShooter / BounceCount を取得
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        let last_shooter = ball.last_shooter;
+
+        let wall_side = event
+            .wall_type
+            .court_side(event.contact_point, config.court.net_x);
+
+        let (winner, reason) = if let Some(shooter) = last_shooter.side {
+            match judge_wall_hit(shooter, wall_side, &ball.bounce_count) {
+                Some(result) => result,
+                None => {
+                    debug!(
+                        "Wall hit ({:?}) in play. shooter={:?} bounce={:?}",
+                        event.wall_type, shooter, ball.bounce_count.last_court_side
+                    );
+                    continue;
                 }
+            }
+        } else {
+            // LastShooter が未設定の場合（サーブ前など）
+            // 壁に当たった位置から判定
+            let court_side = wall_side.unwrap_or_else(|| {
+                crate::core::determine_court_side(event.contact_point.x, config.court.net_x)
+            });
+
+            warn!(
+                "Wall hit ({:?}) with no LastShooter! Defaulting to {:?} side loss.",
+                event.wall_type, court_side
+            );
+
+            (court_side.opponent(), RallyEndReason::Out)
+        };
 
-                info!(
-                    "Wall hit ({:?})! {:?} hit ball to wall. {:?} wins the point.",
-                    event.wall_type, shooter, winner
-                );
-
-                rally_events.write(RallyEndEvent {
-                    winner,
-                    reason: RallyEndReason::Out,
-                });
+        info!(
+            "Wall hit ({:?})! {:?}. {:?} wins the point.",
+            event.wall_type, reason, winner
+        );
+
+        judge.end_rally(
+            ball.entity,
+            winner,
+            reason,
+            &format!("wall={:?} shooter={:?}", event.wall_type, last_shooter.side),
+        );
+        break;
+    }
+}
 
-                // 重複発行防止フラグを設定
-                rally_state.rally_end_event_sent_this_frame = true;
+/// 囲い離脱判定システム（パデルルール）
+/// @spec 30901_point_judgment_spec.md#req-30901-007
+/// @spec 30901_point_judgment_spec.md#req-30901-008
+/// @spec 30503_boundary_behavior.md#beh-30503-009
+///
+/// ドアや壁の上から囲いの外へ出たボールを BallThroughFence として判定する。
+/// 失点側は天井と同じく judge_wall_hit（壁面なし）で決める。
+/// court.outside_play が有効なら、受け手コートでバウンド後のボールはインプレーのまま（judge_court_exit）。
+pub fn court_exit_judgment_system(
+    mut exit_events: MessageReader<BallLeftCourtEvent>,
+    config: Res<GameConfig>,
+    mut judge: RallyEndJudge,
+) {
+    // ゲーム進行中でなければ、または同一フレームで既にイベント発行済みならスキップ
+    if !judge.can_judge() {
+        return;
+    }
 
-                // 他のポイント判定システムからの重複発行を防止
-                commands.entity(entity).insert(PointEnded);
-            } else {
+    for event in exit_events.read() {
+        let Some(ball) = judge.ball(event.ball) else {
+            continue;
+        };
+        let last_shooter = ball.last_shooter;
+
+        let exit_side = crate::core::determine_court_side(event.exit_point.x, config.court.net_x);
+        let (winner, reason) = match last_shooter.side {
+            Some(shooter) => {
+                match judge_court_exit(
+                    shooter,
+                    exit_side,
+                    &ball.bounce_count,
+                    config.court.outside_play,
+                ) {
+                    Some(result) => result,
+                    None => {
+                        // REQ-30901-008: 囲いの外でのプレー（外でバウンドするまでインプレー）
+                        info!(
+                            "Ball left the court ({:?}) at {:?} after bouncing. Play on outside.",
+                            event.wall_type, event.exit_point
+                        );
+                        continue;
+                    }
+                }
+            }
+            None => {
                 // LastShooter が未設定の場合（サーブ前など）
-                // 壁に当たった位置から判定
-                let court_side = if event.contact_point.z < 0.0 {
-                    CourtSide::Left
-                } else {
-                    CourtSide::Right
-                };
-                let winner = court_side.opponent();
-
+                // 離脱位置から判定
                 warn!(
-                    "Wall hit ({:?}) with no LastShooter! Defaulting to {:?} side loss.",
-                    event.wall_type, court_side
+                    "Ball left the court ({:?}) with no LastShooter! Defaulting to {:?} side loss.",
+                    event.wall_type, exit_side
                 );
 
-                rally_events.write(RallyEndEvent {
-                    winner,
-                    reason: RallyEndReason::Out,
-                });
-
-                // 重複発行防止フラグを設定
-                rally_state.rally_end_event_sent_this_frame = true;
-
-                // 他のポイント判定システムからの重複発行を防止
-                commands.entity(entity).insert(PointEnded);
+                (exit_side.opponent(), RallyEndReason::Out)
             }
-        }
+        };
+
+        info!(
+            "Ball left the court ({:?}) at {:?}! {:?}. {:?} wins the point.",
+            event.wall_type, event.exit_point, reason, winner
+        );
+
+        judge.end_rally(
+            ball.entity,
+            winner,
+            reason,
+            &format!(
+                "exit={:?} pos=({:.2},{:.2},{:.2}) shooter={:?}",
+                event.wall_type,
+                event.exit_point.x,
+                event.exit_point.y,
+                event.exit_point.z,
+                last_shooter.side
+            ),
+        );
+        break;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::events::RallyEndReason;
+    use crate::components::LastShooter;
+    use crate::core::events::WallType;
 
     /// TST-30904-003: アウト判定テスト（X軸=打ち合い方向）
     /// @spec 30901_point_judgment_spec.md#req-30901-001
@@ -227,6 +333,7 @@ mod tests {
             service_box_depth: 1.5,
             outer_wall_z: 8.0,
             outer_wall_x: 10.0,
+            ..Default::default()
         };
 
         let bounds = create_court_bounds(&config);
@@ -240,31 +347,211 @@ mod tests {
         assert_eq!(bounds.back_right, 3.0);
     }
 
-    /// TST-30036-001: 壁ヒット時アウト判定テスト
-    /// 壁に当たった場合、LastShooter の失点となる
+    /// 指定コート側で n 回バウンドした BounceCount を生成
+    fn bounced_on(side: CourtSide, n: u32) -> BounceCount {
+        let mut bounce_count = BounceCount::default();
+        for _ in 0..n {
+            bounce_count.record_bounce(side);
+        }
+        bounce_count
+    }
+
+    /// TST-30036-001: 受け手側の壁へのダイレクトヒットは打った側の失点
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
     #[test]
-    fn test_wall_hit_out_judgment() {
-        let mut last_shooter = LastShooter::default();
+    fn test_req_30901_006_direct_wall_hit() {
+        let shooter = CourtSide::Left;
+        let no_bounce = BounceCount::default();
+
+        let result = judge_wall_hit(shooter, Some(CourtSide::Right), &no_bounce);
+        assert_eq!(
+            result,
+            Some((CourtSide::Right, RallyEndReason::DirectWallHit))
+        );
+    }
 
-        // 1Pがショット
-        last_shooter.record(CourtSide::Left);
-        assert_eq!(last_shooter.side, Some(CourtSide::Left));
+    /// TST-30036-002: 受け手コートでバウンド後の壁ヒットはインプレー
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
+    #[test]
+    fn test_req_30901_006_floor_then_wall_in_play() {
+        let shooter = CourtSide::Left;
+        let bounced = bounced_on(CourtSide::Right, 1);
+
+        assert_eq!(
+            judge_wall_hit(shooter, Some(CourtSide::Right), &bounced),
+            None
+        );
+    }
 
-        // 壁に当たった → 1P失点（2P得点）
-        let winner = last_shooter
-            .side
-            .expect("last_shooter.side should be set after record")
-            .opponent();
-        assert_eq!(winner, CourtSide::Right);
+    /// TST-30036-003: 自陣の壁（後壁・側壁）に当てた返球はインプレー
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
+    #[test]
+    fn test_req_30901_006_own_glass_in_play() {
+        let shooter = CourtSide::Right;
+        let no_bounce = BounceCount::default();
+
+        assert_eq!(
+            judge_wall_hit(shooter, Some(CourtSide::Right), &no_bounce),
+            None
+        );
+    }
+
+    /// TST-30036-004: 壁が属するコート側の判定
+    /// @spec 30901_point_judgment_spec.md#req-30901-006
+    #[test]
+    fn test_req_30901_006_wall_court_side() {
+        let net_x = 0.0;
+        let left_contact = Vec3::new(-3.0, 1.0, -5.0);
+        let right_contact = Vec3::new(3.0, 1.0, 5.0);
+
+        assert_eq!(
+            WallType::BackWallLeft.court_side(left_contact, net_x),
+            Some(CourtSide::Left)
+        );
+        assert_eq!(
+            WallType::BackWallRight.court_side(right_contact, net_x),
+            Some(CourtSide::Right)
+        );
+        assert_eq!(
+            WallType::LeftWall.court_side(left_contact, net_x),
+            Some(CourtSide::Left)
+        );
+        assert_eq!(
+            WallType::RightWall.court_side(right_contact, net_x),
+            Some(CourtSide::Right)
+        );
+        assert_eq!(WallType::Ceiling.court_side(left_contact, net_x), None);
+    }
+
+    /// TST-30036-005: 囲いの外へ出たボールは BallThroughFence
+    /// @spec 30901_point_judgment_spec.md#req-30901-007
+    #[test]
+    fn test_req_30901_007_ball_through_fence() {
+        let shooter = CourtSide::Left;
+
+        // 受け手コートで未バウンド → 打った側の失点
+        let no_bounce = BounceCount::default();
+        assert_eq!(
+            judge_wall_hit(shooter, None, &no_bounce),
+            Some((CourtSide::Right, RallyEndReason::BallThroughFence))
+        );
+
+        // 受け手コートでバウンド後 → 受け手の失点
+        let bounced = bounced_on(CourtSide::Right, 1);
+        assert_eq!(
+            judge_wall_hit(shooter, None, &bounced),
+            Some((CourtSide::Left, RallyEndReason::BallThroughFence))
+        );
+    }
+
+    /// TST-30036-006: ドアから囲いの外へ出たボールは BallThroughFence
+    /// @spec 30901_point_judgment_spec.md#req-30901-007
+    /// @spec 30503_boundary_behavior.md#beh-30503-009
+    #[test]
+    fn test_req_30901_007_exit_through_door() {
+        use crate::resource::CourtConfig;
+
+        let config = CourtConfig::default();
+        let enclosure = crate::systems::court_factory::create_court_enclosure(&config);
+        let net = crate::systems::court_factory::create_net_info(&config);
+
+        // デフォルトの壁セグメント: 右壁の 1 < X < 2 はドア
+        let contact = crate::core::sweep_first_contact(
+            Vec3::new(1.5, 1.0, 7.9),
+            Vec3::new(0.0, 0.0, 20.0),
+            1.0 / 60.0,
+            &net,
+            &enclosure,
+        )
+        .expect("should pass the door");
+        assert_eq!(
+            contact.surface,
+            crate::core::SweptSurface::Opening(WallType::RightWall)
+        );
+
+        // 離脱判定は court_exit_judgment_system と同じく通過点のコート側で行う
+        let shooter = CourtSide::Left;
+        let exit_side = crate::core::determine_court_side(contact.point.x, config.net_x);
+        assert_eq!(exit_side, CourtSide::Right);
+
+        // 受け手コートでバウンド後にドアから出た → 受け手の失点
+        assert_eq!(
+            judge_court_exit(
+                shooter,
+                exit_side,
+                &bounced_on(CourtSide::Right, 1),
+                config.outside_play
+            ),
+            Some((CourtSide::Left, RallyEndReason::BallThroughFence))
+        );
+
+        // 未バウンドでドアから出た → 打った側の失点
+        assert_eq!(
+            judge_court_exit(
+                shooter,
+                exit_side,
+                &BounceCount::default(),
+                config.outside_play
+            ),
+            Some((CourtSide::Right, RallyEndReason::BallThroughFence))
+        );
     }
 
-    /// TST-30036-002: 壁ヒット時のRallyEndReason確認
-    /// Out理由が正しく使われることを確認
+    /// TST-30036-007: 受け手コートでバウンド後に囲いの外へ出たボールは外でもインプレー
+    /// @spec 30901_point_judgment_spec.md#req-30901-008
+    #[test]
+    fn test_req_30901_008_outside_play() {
+        let shooter = CourtSide::Left;
+        let bounced = bounced_on(CourtSide::Right, 1);
+
+        // 受け手側から出た → インプレー継続
+        assert_eq!(
+            judge_court_exit(shooter, CourtSide::Right, &bounced, true),
+            None
+        );
+
+        // outside_play 無効 → 従来どおり受け手の失点
+        assert_eq!(
+            judge_court_exit(shooter, CourtSide::Right, &bounced, false),
+            Some((CourtSide::Left, RallyEndReason::BallThroughFence))
+        );
+
+        // 未バウンドで出た → 打った側の失点
+        assert_eq!(
+            judge_court_exit(shooter, CourtSide::Right, &BounceCount::default(), true),
+            Some((CourtSide::Right, RallyEndReason::BallThroughFence))
+        );
+
+        // 跳ね返って打った側から出た → 受け手の失点（返球できなかった）
+        assert_eq!(
+            judge_court_exit(shooter, CourtSide::Left, &bounced, true),
+            Some((CourtSide::Left, RallyEndReason::BallThroughFence))
+        );
+    }
+
+    /// TST-30036-008: 受け手コートでバウンド後のコート外着地はアウトではなくツーバウンド
+    /// @spec 30901_point_judgment_spec.md#req-30901-001
+    /// @spec 30901_point_judgment_spec.md#req-30901-008
     #[test]
-    fn test_wall_hit_rally_end_reason() {
-        // 壁ヒット時は Out 理由を使用
-        let reason = RallyEndReason::Out;
-        assert_eq!(reason, RallyEndReason::Out);
+    fn test_req_30901_008_landing_outside_after_bounce() {
+        let shooter = CourtSide::Left;
+
+        // 未バウンドでコート外へ着地 → 打った側の失点
+        assert_eq!(
+            judge_out(shooter, &BounceCount::default()),
+            Some((CourtSide::Right, RallyEndReason::Out))
+        );
+
+        // 受け手コートでバウンド済み → アウト判定しない
+        let mut bounced = bounced_on(CourtSide::Right, 1);
+        assert_eq!(judge_out(shooter, &bounced), None);
+
+        // コート外の着地は受け手側の2バウンド目 → ツーバウンドで受け手の失点
+        bounced.record_bounce(CourtSide::Right);
+        assert_eq!(
+            super::super::bounce_judgment::judge_double_bounce(&bounced),
+            Some((CourtSide::Left, RallyEndReason::DoubleBounce))
+        );
     }
 
     /// TST-30037-001: サイドライン外アウト判定テスト
@@ -284,7 +571,7 @@ mod tests {
 
         // LastShooter ベースで失点判定
         let mut last_shooter = LastShooter::default();
-        last_shooter.record(CourtSide::Left);
+        last_shooter.record(CourtSide::Left, 1);
 
         // 1Pがサイドアウト → 1P失点（2P得点）
         let winner = last_shooter
@@ -311,7 +598,7 @@ mod tests {
 
         // LastShooter ベースで失点判定
         let mut last_shooter = LastShooter::default();
-        last_shooter.record(CourtSide::Right);
+        last_shooter.record(CourtSide::Right, 2);
 
         // 2Pがベースラインアウト → 2P失点（1P得点）
         let winner = last_shooter
@@ -263,6 +263,10 @@ pub fn calculate_shot_attributes_detail(
     };
     let spin = (spin_height + spin_timing).clamp(-1.0, 1.0);
 
+    // サイドスピンの計算（打点が高いほど強い）
+    let sidespin =
+        get_spin_height_factor(context.hit_height, &config.sidespin_height_curve).clamp(0.0, 1.0);
+
     // 精度の最終計算
     let accuracy = config.base_accuracy * distance_factors.2;
 
@@ -281,6 +285,7 @@ pub fn calculate_shot_attributes_detail(
             stability,
             angle,
             spin,
+            sidespin,
             accuracy,
         },
     }
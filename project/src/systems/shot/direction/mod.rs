@@ -18,10 +18,11 @@ use crate::components::{
     Velocity,
 };
 use crate::core::events::{ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent};
-use crate::core::CourtSide;
+use crate::core::{CourtSide, ShotType};
 use crate::resource::config::GameConfig;
 use crate::resource::debug::LastShotDebugInfo;
 use crate::resource::scoring::MatchScore;
+use crate::resource::{BallCondition, WindField};
 
 use normal_shot::handle_normal_shot;
 use serve_shot::handle_serve_shot;
@@ -38,6 +39,12 @@ pub(super) struct NormalShotContext {
     pub player_velocity: Vec3,
     pub ball_pos: Vec3,
     pub bounce_state: BounceState,
+    /// ショット種類
+    /// @spec 30607_shot_type_spec.md#req-30607-004
+    pub shot_type: ShotType,
+    /// 打点の高さでの風の加速度
+    /// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+    pub wind_acceleration: Vec3,
 }
 
 /// 通常ショット計算結果
@@ -64,6 +71,8 @@ pub fn shot_direction_system(
     mut commands: Commands,
     config: Res<GameConfig>,
     match_score: Res<MatchScore>,
+    wind: Res<WindField>,
+    condition: Res<BallCondition>,
     mut shot_events: MessageReader<ShotEvent>,
     mut ball_query: Query<
         (
@@ -103,6 +112,8 @@ pub fn shot_direction_system(
         handle_normal_shot(
             event,
             &config,
+            &wind,
+            condition.factors.drag_scale,
             &mut ball_query,
             &player_query,
             &mut shot_executed_writer,
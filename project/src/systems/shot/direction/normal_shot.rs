@@ -11,20 +11,26 @@ use crate::components::{
 use crate::core::events::{ShotAttributesCalculatedEvent, ShotEvent, ShotExecutedEvent};
 use crate::resource::config::GameConfig;
 use crate::resource::debug::LastShotDebugInfo;
+use crate::resource::WindField;
 use crate::systems::shot::attributes::{
     build_shot_context_from_input_state, calculate_shot_attributes_detail,
 };
-use crate::systems::trajectory_calculator::{calculate_trajectory, TrajectoryContext};
+use crate::systems::shot::shot_type::apply_shot_type_params;
+use crate::systems::trajectory_calculator::{
+    calculate_shot_wall_trajectory, calculate_trajectory, TrajectoryContext,
+};
 
 use super::utils::{calculate_stability_power_factor, get_player_info, update_shot_debug_info};
 use super::{NormalShotContext, NormalShotResult};
 
 /// 通常ショット処理
 /// @spec 30602_shot_direction_spec.md#req-30602-032
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn handle_normal_shot(
     event: &ShotEvent,
     config: &GameConfig,
+    wind: &WindField,
+    drag_scale: f32,
     ball_query: &mut Query<
         (
             Entity,
@@ -66,7 +72,7 @@ pub(super) fn handle_normal_shot(
     };
 
     // 最後にショットを打ったプレイヤーを記録
-    last_shooter.record(event.court_side);
+    last_shooter.record(event.court_side, event.player_id);
 
     // ショット計算コンテキストを構築
     let ctx = NormalShotContext {
@@ -79,6 +85,8 @@ pub(super) fn handle_normal_shot(
         player_velocity,
         ball_pos: ball_pos.value,
         bounce_state: *bounce_state,
+        shot_type: event.shot_type,
+        wind_acceleration: wind.acceleration_at(ball_pos.value.y, &config.wind, drag_scale),
     };
 
     // ショット計算を実行
@@ -87,7 +95,7 @@ pub(super) fn handle_normal_shot(
     // 結果をボールに適用
     ball_velocity.value = result.shot_velocity;
     bounce_count.reset();
-    ball_spin.value = result.spin;
+    ball_spin.angular_velocity = result.trajectory_result.spin;
 
     // デバッグ情報を更新
     update_shot_debug_info(
@@ -109,6 +117,7 @@ pub(super) fn handle_normal_shot(
         player_id: ctx.player_id,
         shot_velocity: result.shot_velocity,
         is_jump_shot: result.is_jump_shot,
+        shot_type: ctx.shot_type,
     });
 
     // ショット属性詳細イベント発行（トレース用）
@@ -135,8 +144,9 @@ pub(super) fn handle_normal_shot(
     });
 
     info!(
-        "Player {} shot executed: power={:.1}, angle={:.1}, stability={:.2}, accuracy={:.2}, spin={:.2}, landing=({:.1}, {:.1})",
+        "Player {} shot executed: type={:?}, power={:.1}, angle={:.1}, stability={:.2}, accuracy={:.2}, spin={:.2}, landing=({:.1}, {:.1})",
         ctx.player_id,
+        ctx.shot_type,
         result.effective_power,
         result.trajectory_result.launch_angle,
         result.stability,
@@ -156,6 +166,8 @@ pub(super) fn handle_normal_shot(
 /// @spec 30604_shot_attributes_spec.md#req-30604-069
 /// @spec 30604_shot_attributes_spec.md#req-30604-070
 /// @spec 30605_trajectory_calculation_spec.md - 着地点逆算型弾道システム
+/// @spec 30607_shot_type_spec.md#req-30607-004
+/// @spec 30607_shot_type_spec.md#req-30607-007
 fn calculate_normal_shot(ctx: &NormalShotContext, config: &GameConfig) -> NormalShotResult {
     // ショット属性計算（詳細版）
     let shot_context = build_shot_context_from_input_state(
@@ -173,18 +185,42 @@ fn calculate_normal_shot(ctx: &NormalShotContext, config: &GameConfig) -> Normal
     // 安定性による威力減衰
     let stability_factor =
         calculate_stability_power_factor(shot_attrs.stability, &config.shot_attributes);
-    let effective_power = shot_attrs.power * stability_factor;
+
+    // REQ-30607-004: ショット種類による威力・スピンの補正
+    let (effective_power, spin, sidespin) = apply_shot_type_params(
+        shot_attrs.power * stability_factor,
+        shot_attrs.spin,
+        shot_attrs.sidespin,
+        config.shot.shot_types.params(ctx.shot_type),
+    );
 
     // 弾道計算
     let trajectory_ctx = TrajectoryContext {
         input: ctx.direction,
         court_side: ctx.court_side,
         ball_position: ctx.ball_pos,
-        spin: shot_attrs.spin,
+        spin,
+        sidespin,
         base_speed: effective_power,
         accuracy: shot_attrs.accuracy,
+        wind_acceleration: ctx.wind_acceleration,
+        shot_type: ctx.shot_type,
     };
-    let trajectory_result = calculate_trajectory(&trajectory_ctx, config);
+    // REQ-30607-007: 壁狙いの種類は後壁に当てる弾道を優先し、届かなければ通常の弾道
+    let trajectory_result = calculate_shot_wall_trajectory(&trajectory_ctx, config)
+        .unwrap_or_else(|| calculate_trajectory(&trajectory_ctx, config));
+    if let Some(contact) = &trajectory_result.wall_contact {
+        info!(
+            "wall target: {:?} ({:?}) at {:?} after {:.2}s, incident={:?}, reflected={:?}, spin={:?}",
+            contact.wall,
+            contact.material,
+            contact.position,
+            contact.time,
+            contact.incident_velocity,
+            contact.reflected_velocity,
+            contact.spin
+        );
+    }
     let shot_velocity = trajectory_result.direction * trajectory_result.final_speed;
 
     // ジャンプショット判定
@@ -203,7 +239,7 @@ fn calculate_normal_shot(ctx: &NormalShotContext, config: &GameConfig) -> Normal
         shot_velocity,
         trajectory_result,
         effective_power,
-        spin: shot_attrs.spin,
+        spin,
         accuracy: shot_attrs.accuracy,
         stability: shot_attrs.stability,
         is_jump_shot,
@@ -61,6 +61,7 @@ pub(super) fn handle_serve_shot(
             hit_position,
             shot_velocity,
             event.court_side,
+            event.player_id,
             m,
             mat,
         ));
@@ -69,6 +70,7 @@ pub(super) fn handle_serve_shot(
             hit_position,
             shot_velocity,
             event.court_side,
+            event.player_id,
         ));
     }
 
@@ -77,6 +79,7 @@ pub(super) fn handle_serve_shot(
         player_id: event.player_id,
         shot_velocity,
         is_jump_shot: false, // サーブはジャンプショットではない
+        shot_type: event.shot_type,
     });
 
     info!(
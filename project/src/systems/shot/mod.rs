@@ -4,6 +4,7 @@
 mod attributes;
 mod direction;
 mod racket_contact;
+mod shot_type;
 
 pub use direction::*;
 pub use racket_contact::*;
@@ -7,13 +7,18 @@ use crate::components::{Ball, BounceState, LogicalPosition, Player, ShotState, V
 use crate::core::events::{RacketContactEvent, ShotEvent, SwingIntentEvent};
 use crate::core::CourtSide;
 use crate::resource::config::GameConfig;
-use crate::resource::FixedDeltaTime;
+use crate::resource::{BallPath, BallPrediction, FixedDeltaTime, PathPoint};
+
+use super::shot_type::{classify_shot_type, ShotTypeInput};
 
 /// 入力意図からラケットスイング計画を作成
 /// @spec 30606_racket_contact_spec.md#req-30606-002
+///
+/// 打点は共有の予測軌道（BallPrediction）から求める。
 #[allow(clippy::type_complexity)]
 pub fn plan_racket_swing_system(
     config: Res<GameConfig>,
+    prediction: Res<BallPrediction>,
     mut intents: MessageReader<SwingIntentEvent>,
     ball_query: Query<(&LogicalPosition, &Velocity, &BounceState), With<Ball>>,
     mut player_query: Query<(&Player, &LogicalPosition, &mut ShotState), Without<Ball>>,
@@ -24,8 +29,23 @@ pub fn plan_racket_swing_system(
     let ball_pos = ball_pos.value;
     let ball_vel = ball_vel.value;
     let is_volley = bounce_state.time_since_bounce.is_none();
-    let gravity = config.physics.gravity;
     let swing_cfg = &config.shot.racket_swing;
+    // 予測がない（このティックで出現した）ボールは現在位置に留まるとみなす
+    let stationary;
+    let path = match prediction.path.as_ref() {
+        Some(path) => path,
+        None => {
+            stationary = BallPath {
+                points: vec![PathPoint {
+                    time: 0.0,
+                    position: ball_pos,
+                    velocity: ball_vel,
+                }],
+                contacts: Vec::new(),
+            };
+            &stationary
+        }
+    };
 
     for intent in intents.read() {
         for (player, player_pos, mut shot_state) in player_query.iter_mut() {
@@ -35,9 +55,7 @@ pub fn plan_racket_swing_system(
 
             let (predicted_hit, hit_time) = predict_hit_point(
                 player_pos.value,
-                ball_pos,
-                ball_vel,
-                gravity,
+                path,
                 swing_cfg.min_prediction_time,
                 swing_cfg.max_prediction_time,
                 swing_cfg.prediction_step,
@@ -73,6 +91,7 @@ pub fn plan_racket_swing_system(
                 .clamp(0.01, swing.duration_seconds - 0.01);
             swing.input_direction = intent.direction;
             swing.hold_time_ms = intent.hold_time_ms;
+            swing.modifier = intent.modifier;
             if use_direct_style {
                 // ボレー/スライスは直線寄り（面を直接合わせる）軌道
                 swing.planned_hit_position = planned_hit;
@@ -141,16 +160,17 @@ pub fn plan_racket_swing_system(
 /// ラケット軌道を進め、接触時に ShotEvent を発行する
 /// @spec 30606_racket_contact_spec.md#req-30606-003
 /// @spec 30606_racket_contact_spec.md#req-30606-004
+/// @spec 30607_shot_type_spec.md#req-30607-002 - 接触時にショット種類を選ぶ
 #[allow(clippy::type_complexity)]
 pub fn update_racket_swing_system(
     fixed_dt: Res<FixedDeltaTime>,
     config: Res<GameConfig>,
-    ball_query: Query<(&LogicalPosition, &Velocity), With<Ball>>,
+    ball_query: Query<(&LogicalPosition, &Velocity, &BounceState), With<Ball>>,
     mut player_query: Query<(&Player, &LogicalPosition, &mut ShotState), Without<Ball>>,
     mut shot_writer: MessageWriter<ShotEvent>,
     mut contact_writer: MessageWriter<RacketContactEvent>,
 ) {
-    let Some((ball_pos, ball_vel)) = ball_query.iter().next() else {
+    let Some((ball_pos, ball_vel, bounce_state)) = ball_query.iter().next() else {
         return;
     };
     let ball_pos = ball_pos.value;
@@ -206,6 +226,19 @@ pub fn update_racket_swing_system(
                     racket_velocity,
                 });
 
+                let shot_type = classify_shot_type(
+                    &ShotTypeInput {
+                        direction: swing.input_direction,
+                        modifier: swing.modifier,
+                        hit_height: contact_point.y,
+                        jump_height: player_pos.value.y,
+                        distance_to_net: (player_pos.value.x - config.court.net_x).abs(),
+                        is_volley: bounce_state.time_since_bounce.is_none(),
+                    },
+                    &config.shot.shot_types,
+                    config.shot.jump_threshold,
+                );
+
                 shot_writer.write(ShotEvent {
                     player_id: player.id,
                     court_side: player.court_side,
@@ -214,6 +247,7 @@ pub fn update_racket_swing_system(
                     is_serve: false,
                     hit_position: Some(contact_point),
                     serve_toss_velocity_y: None,
+                    shot_type,
                 });
             }
         }
@@ -231,11 +265,13 @@ fn front_sign(court_side: CourtSide) -> f32 {
     }
 }
 
+/// 予測軌道上で手の届く最初の打点と時刻を求める
+/// @spec 30606_racket_contact_spec.md#req-30606-002
+///
+/// 届く点がなければ届かなさ（距離・高さの超過）と時間が最小の点を返す。
 fn predict_hit_point(
     player_pos: Vec3,
-    ball_pos: Vec3,
-    ball_vel: Vec3,
-    gravity: f32,
+    path: &BallPath,
     min_t: f32,
     max_t: f32,
     step_t: f32,
@@ -246,12 +282,13 @@ fn predict_hit_point(
     let max_t = max_t.max(t + step_t);
     let step_t = step_t.max(1e-3);
 
-    let mut best_pos = predict_ball_position(ball_pos, ball_vel, gravity, t);
+    let predict_ball_position = |t| path.position_at(t).unwrap_or(player_pos);
+    let mut best_pos = predict_ball_position(t);
     let mut best_time = t;
     let mut best_score = f32::MAX;
 
     while t <= max_t {
-        let p = predict_ball_position(ball_pos, ball_vel, gravity, t);
+        let p = predict_ball_position(t);
         let horizontal = Vec2::new(p.x - player_pos.x, p.z - player_pos.z).length();
         let height_diff = (p.y - player_pos.y).abs();
 
@@ -275,10 +312,6 @@ fn predict_hit_point(
     (best_pos, best_time)
 }
 
-fn predict_ball_position(ball_pos: Vec3, ball_vel: Vec3, gravity: f32, t: f32) -> Vec3 {
-    ball_pos + ball_vel * t + Vec3::new(0.0, 0.5 * gravity * t * t, 0.0)
-}
-
 fn clamp_hit_to_reach(
     player_pos: Vec3,
     hit: Vec3,
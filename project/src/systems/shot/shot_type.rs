@@ -0,0 +1,231 @@
+//! ショット種類の選択
+//! @spec 30607_shot_type_spec.md
+
+use bevy::prelude::*;
+
+use crate::core::{ShotModifier, ShotType};
+use crate::resource::config::{ShotTypeConfig, ShotTypeParams};
+
+/// ショット種類の選択に使う接触時の状況
+/// @spec 30607_shot_type_spec.md#req-30607-002
+/// @spec 30607_shot_type_spec.md#req-30607-003
+#[derive(Debug, Clone, Copy)]
+pub struct ShotTypeInput {
+    /// 入力方向（X=深さ: 負でネット側、Y=コース）
+    pub direction: Vec2,
+    /// ショット種類ボタン（ロブ・ドロップショット）
+    pub modifier: ShotModifier,
+    /// 打点の高さ（接触点のY座標）
+    pub hit_height: f32,
+    /// プレイヤーの足元の高さ（ジャンプ中の高さ）
+    pub jump_height: f32,
+    /// プレイヤーとネットの距離（X方向）
+    pub distance_to_net: f32,
+    /// ノーバウンドで打ったか
+    pub is_volley: bool,
+}
+
+/// 接触時の入力・打点の高さ・位置からショット種類を選ぶ
+/// @spec 30607_shot_type_spec.md#req-30607-002
+/// @spec 30607_shot_type_spec.md#req-30607-003
+///
+/// - 打点が overhead_height 以上: ジャンプ中ならスマッシュ、コース入力ありならビボラ、それ以外はバンデハ
+/// - ドロップショットボタン: ネットに近ければドロップショット、後方ならチキータ
+/// - ロブボタン: ロブ
+/// - それ以外: ノーバウンドならボレー、バウンド後ならドライブ（深さは方向入力のまま）
+pub fn classify_shot_type(
+    input: &ShotTypeInput,
+    config: &ShotTypeConfig,
+    jump_threshold: f32,
+) -> ShotType {
+    let threshold = config.input_threshold;
+
+    if input.hit_height >= config.overhead_height {
+        return if input.jump_height > jump_threshold {
+            ShotType::Smash
+        } else if input.direction.y.abs() >= threshold {
+            ShotType::Vibora
+        } else {
+            ShotType::Bandeja
+        };
+    }
+
+    match input.modifier {
+        ShotModifier::DropShot if input.distance_to_net <= config.drop_shot_max_net_distance => {
+            ShotType::DropShot
+        }
+        ShotModifier::DropShot => ShotType::Chiquita,
+        ShotModifier::Lob => ShotType::Lob,
+        ShotModifier::None if input.is_volley => ShotType::Volley,
+        ShotModifier::None => ShotType::Drive,
+    }
+}
+
+/// ショット種類による威力・スピンの補正
+/// @spec 30607_shot_type_spec.md#req-30607-004
+///
+/// 戻り値: (威力, スピン, サイドスピン量)
+pub fn apply_shot_type_params(
+    power: f32,
+    spin: f32,
+    sidespin: f32,
+    params: &ShotTypeParams,
+) -> (f32, f32, f32) {
+    (
+        power * params.power_scale,
+        (spin + params.spin_offset).clamp(-1.0, 1.0),
+        sidespin.max(params.min_sidespin),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JUMP_THRESHOLD: f32 = 0.5;
+
+    fn ground_stroke() -> ShotTypeInput {
+        ShotTypeInput {
+            direction: Vec2::ZERO,
+            modifier: ShotModifier::None,
+            hit_height: 1.0,
+            jump_height: 0.0,
+            distance_to_net: 6.0,
+            is_volley: false,
+        }
+    }
+
+    /// TST-30607-001: 高い打点はジャンプ・コース入力でスマッシュ/ビボラ/バンデハに分かれる
+    /// @spec 30607_shot_type_spec.md#req-30607-002
+    #[test]
+    fn test_req_30607_002_overhead() {
+        let config = ShotTypeConfig::default();
+        let overhead = ShotTypeInput {
+            hit_height: 2.4,
+            is_volley: true,
+            ..ground_stroke()
+        };
+        let classify = |input: ShotTypeInput| classify_shot_type(&input, &config, JUMP_THRESHOLD);
+
+        assert_eq!(classify(overhead), ShotType::Bandeja);
+        assert_eq!(
+            classify(ShotTypeInput {
+                jump_height: 0.8,
+                ..overhead
+            }),
+            ShotType::Smash
+        );
+        assert_eq!(
+            classify(ShotTypeInput {
+                direction: Vec2::new(0.0, -1.0),
+                ..overhead
+            }),
+            ShotType::Vibora
+        );
+        // オーバーヘッドではロブボタンを押してもロブにならない
+        assert_eq!(
+            classify(ShotTypeInput {
+                modifier: ShotModifier::Lob,
+                ..overhead
+            }),
+            ShotType::Bandeja
+        );
+    }
+
+    /// TST-30607-002: 低い打点はショット種類ボタン・位置で選ばれる
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    #[test]
+    fn test_req_30607_003_below_overhead() {
+        let config = ShotTypeConfig::default();
+        let classify = |input: ShotTypeInput| classify_shot_type(&input, &config, JUMP_THRESHOLD);
+
+        assert_eq!(classify(ground_stroke()), ShotType::Drive);
+        assert_eq!(
+            classify(ShotTypeInput {
+                is_volley: true,
+                ..ground_stroke()
+            }),
+            ShotType::Volley
+        );
+        assert_eq!(
+            classify(ShotTypeInput {
+                modifier: ShotModifier::Lob,
+                ..ground_stroke()
+            }),
+            ShotType::Lob
+        );
+        assert_eq!(
+            classify(ShotTypeInput {
+                modifier: ShotModifier::DropShot,
+                distance_to_net: 3.0,
+                ..ground_stroke()
+            }),
+            ShotType::DropShot
+        );
+        assert_eq!(
+            classify(ShotTypeInput {
+                modifier: ShotModifier::DropShot,
+                ..ground_stroke()
+            }),
+            ShotType::Chiquita
+        );
+    }
+
+    /// TST-30607-005: 方向入力だけではロブ・ドロップショットにならない
+    /// @spec 30607_shot_type_spec.md#req-30607-003
+    #[test]
+    fn test_req_30607_003_direction_keeps_drive() {
+        let config = ShotTypeConfig::default();
+        let classify = |input: ShotTypeInput| classify_shot_type(&input, &config, JUMP_THRESHOLD);
+
+        // ネット側への深さ入力（短く狙うドライブ）は深さ入力のままドライブ
+        for distance_to_net in [3.0, 6.0] {
+            assert_eq!(
+                classify(ShotTypeInput {
+                    direction: Vec2::new(-1.0, 0.0),
+                    distance_to_net,
+                    ..ground_stroke()
+                }),
+                ShotType::Drive
+            );
+        }
+        assert_eq!(
+            classify(ShotTypeInput {
+                direction: Vec2::new(-1.0, 0.5),
+                is_volley: true,
+                ..ground_stroke()
+            }),
+            ShotType::Volley
+        );
+    }
+
+    /// TST-30607-004: 種類別パラメータで威力・スピン・サイドスピンを補正する
+    /// @spec 30607_shot_type_spec.md#req-30607-004
+    #[test]
+    fn test_req_30607_004_shot_type_params() {
+        let config = ShotTypeConfig::default();
+
+        // ドライブは補正なし
+        assert_eq!(
+            apply_shot_type_params(15.0, 0.2, 0.1, config.params(ShotType::Drive)),
+            (15.0, 0.2, 0.1)
+        );
+
+        let (power, spin, sidespin) =
+            apply_shot_type_params(15.0, 0.0, 0.0, config.params(ShotType::Smash));
+        assert!(power > 15.0);
+        assert!(spin > 0.0);
+        assert_eq!(sidespin, 0.0);
+
+        // ドロップショットは弱い逆回転、スピンは -1.0 までに収まる
+        let (power, spin, _) =
+            apply_shot_type_params(15.0, -0.8, 0.0, config.params(ShotType::DropShot));
+        assert!(power < 15.0);
+        assert_eq!(spin, -1.0);
+
+        // ビボラはサイドスピン量の下限を持つ
+        let (_, _, sidespin) =
+            apply_shot_type_params(15.0, 0.0, 0.1, config.params(ShotType::Vibora));
+        assert_eq!(sidespin, config.vibora.min_sidespin);
+    }
+}
@@ -1,21 +1,32 @@
 //! メイン弾道計算
 //! @spec 30605_trajectory_calculation_spec.md
 
+use bevy::prelude::*;
+
+use crate::core::spin_from_shot;
 use crate::resource::config::GameConfig;
 
 use super::landing_position::{apply_landing_deviation, calculate_landing_position};
 use super::launch_angle::calculate_launch_angle;
-use super::physics_utils::{calculate_direction_vector, calculate_effective_gravity};
+use super::physics_utils::{
+    calculate_direction_vector, calculate_effective_gravity, calculate_sidespin,
+    calculate_speed_for_target, estimate_sidespin_drift, estimate_wind_drift, CourtSideExt,
+};
 use super::types::{TrajectoryContext, TrajectoryResult};
 
 /// 弾道を計算（メイン関数）
 /// @spec 30605_trajectory_calculation_spec.md
+/// @spec 30607_shot_type_spec.md#req-30607-006
 pub fn calculate_trajectory(ctx: &TrajectoryContext, config: &GameConfig) -> TrajectoryResult {
     let court_config = &config.court;
     let trajectory_config = &config.trajectory;
+    let intent = config.shot.shot_types.params(ctx.shot_type);
 
-    // 1. 着地地点を決定
-    let raw_landing = calculate_landing_position(ctx, court_config, trajectory_config);
+    // 1. 着地地点を決定（ショット種類が深さを決める場合は入力の深さより優先）
+    let mut raw_landing = calculate_landing_position(ctx, court_config, trajectory_config);
+    if let Some(depth) = intent.landing_depth {
+        raw_landing.x = court_config.net_x + depth * ctx.court_side.sign();
+    }
 
     // 2. 精度によるズレを適用
     let landing_with_deviation =
@@ -25,7 +36,7 @@ pub fn calculate_trajectory(ctx: &TrajectoryContext, config: &GameConfig) -> Tra
     let effective_gravity = calculate_effective_gravity(ctx.spin, ctx.ball_position.y, config);
 
     // 4. 発射角度と調整後初速を計算（着地点も調整される可能性あり）
-    let (launch_angle, adjusted_speed, landing_position) = calculate_launch_angle(
+    let (mut launch_angle, mut adjusted_speed, landing_position) = calculate_launch_angle(
         ctx.ball_position,
         landing_with_deviation,
         ctx.base_speed,
@@ -34,6 +45,59 @@ pub fn calculate_trajectory(ctx: &TrajectoryContext, config: &GameConfig) -> Tra
         court_config.net_x,
         court_config.net_height,
     );
+    (launch_angle, adjusted_speed) = raise_to_min_launch_angle(
+        ctx.ball_position,
+        landing_position,
+        launch_angle,
+        adjusted_speed,
+        intent.min_launch_angle,
+        effective_gravity,
+    );
+
+    // 5. サイドスピンの曲がり・風による流れを見込んで狙う地点をずらす
+    let sidespin = calculate_sidespin(ctx.sidespin, ctx.input.y, ctx.court_side);
+    let mut drift = Vec3::ZERO;
+    if sidespin != 0.0 {
+        drift += estimate_sidespin_drift(
+            ctx.ball_position,
+            landing_position,
+            launch_angle,
+            adjusted_speed,
+            sidespin,
+            config,
+        );
+    }
+    // REQ-30605-028: 風の補正（compensate_wind が無効なら風に流される）
+    if trajectory_config.compensate_wind && ctx.wind_acceleration != Vec3::ZERO {
+        drift += estimate_wind_drift(
+            ctx.ball_position,
+            landing_position,
+            launch_angle,
+            adjusted_speed,
+            ctx.wind_acceleration,
+        );
+    }
+    let mut aim_position = landing_position;
+    if drift != Vec3::ZERO {
+        let (aim_angle, aim_speed, aim) = calculate_launch_angle(
+            ctx.ball_position,
+            landing_position - drift,
+            ctx.base_speed,
+            effective_gravity,
+            trajectory_config,
+            court_config.net_x,
+            court_config.net_height,
+        );
+        (launch_angle, adjusted_speed) = raise_to_min_launch_angle(
+            ctx.ball_position,
+            aim,
+            aim_angle,
+            aim_speed,
+            intent.min_launch_angle,
+            effective_gravity,
+        );
+        aim_position = aim;
+    }
 
     // 6. 最終初速（角度計算と一貫性を保つため、speed_factor は適用しない）
     // 注: speed_factor を適用すると、角度計算時の速度と実際の速度が乖離し、
@@ -41,12 +105,46 @@ pub fn calculate_trajectory(ctx: &TrajectoryContext, config: &GameConfig) -> Tra
     let final_speed = adjusted_speed;
 
     // 8. 方向ベクトルを計算
-    let direction = calculate_direction_vector(ctx.ball_position, landing_position, launch_angle);
+    let direction = calculate_direction_vector(ctx.ball_position, aim_position, launch_angle);
+
+    // 9. 角速度ベクトルを計算
+    let spin = spin_from_shot(direction, ctx.spin, sidespin);
 
     TrajectoryResult {
         launch_angle,
         final_speed,
         direction,
         landing_position,
+        spin,
+        wall_contact: None,
+    }
+}
+
+/// ショット種類の最小発射角度まで弾道を上げる（着地点は変えずに初速を合わせる）
+/// @spec 30607_shot_type_spec.md#req-30607-006
+///
+/// 戻り値: (角度, 速度)。最小角度以上、またはその角度で届かない場合はそのまま返す。
+fn raise_to_min_launch_angle(
+    start_pos: Vec3,
+    target_pos: Vec3,
+    launch_angle: f32,
+    speed: f32,
+    min_launch_angle: f32,
+    effective_gravity: f32,
+) -> (f32, f32) {
+    if launch_angle >= min_launch_angle {
+        return (launch_angle, speed);
+    }
+    let distance = Vec2::new(target_pos.x - start_pos.x, target_pos.z - start_pos.z).length();
+    let raised_speed = calculate_speed_for_target(
+        min_launch_angle,
+        distance,
+        effective_gravity,
+        target_pos.y - start_pos.y,
+    );
+    if raised_speed > 0.0 {
+        (min_launch_angle, raised_speed)
+    } else {
+        (launch_angle, speed)
     }
 }
@@ -11,6 +11,7 @@ mod serve_trajectory;
 #[cfg(test)]
 mod tests;
 mod types;
+mod wall_trajectory;
 
 // 型の再エクスポート
 pub use types::{ServeTrajectoryContext, TrajectoryContext, TrajectoryResult};
@@ -19,8 +20,8 @@ pub use types::{ServeTrajectoryContext, TrajectoryContext, TrajectoryResult};
 #[allow(unused_imports)]
 pub use physics_utils::{
     calculate_direction_vector, calculate_effective_gravity, calculate_landing_distance_for_angle,
-    calculate_max_reachable_distance, calculate_speed_factors, calculate_speed_for_target, lerp,
-    CourtSideExt,
+    calculate_max_reachable_distance, calculate_sidespin, calculate_speed_factors,
+    calculate_speed_for_target, estimate_sidespin_drift, estimate_wind_drift, lerp, CourtSideExt,
 };
 
 // 着地位置計算の再エクスポート（将来の拡張用に保持）
@@ -39,3 +40,6 @@ pub use serve_trajectory::{calculate_serve_landing_position, calculate_serve_tra
 
 // メイン軌道計算の再エクスポート
 pub use main_trajectory::calculate_trajectory;
+
+// 壁狙い軌道計算の再エクスポート
+pub use wall_trajectory::calculate_shot_wall_trajectory;
@@ -3,7 +3,7 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
+use crate::core::{magnus_side_acceleration, wind_drift, CourtSide};
 use crate::resource::config::{GameConfig, TrajectoryConfig};
 
 /// CourtSide の符号を取得（計算用ヘルパー）
@@ -42,6 +42,72 @@ pub fn calculate_effective_gravity(spin: f32, initial_height: f32, config: &Game
     gravity * (1.0 + avg_spin * spin_config.gravity_spin_factor)
 }
 
+/// サイドスピン（角速度Y成分）を計算
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-027
+///
+/// コース入力（input.y）の方向へ曲がる向きにする。コース入力がない場合は 0.0。
+pub fn calculate_sidespin(amount: f32, input_y: f32, court_side: CourtSide) -> f32 {
+    if amount <= 0.0 || input_y.abs() < 0.01 {
+        return 0.0;
+    }
+    // 進行方向 +X（Left側）では反時計回り（ω_y > 0）が -Z へ曲がる
+    -input_y.signum() * court_side.sign() * amount
+}
+
+/// サイドスピンによる着地点の横ズレを推定
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-027
+///
+/// 飛行中の横方向加速度を一定とみなした近似: drift = 0.5 × a × t²
+/// （スピンの時間減衰は飛行時間の中間値で近似）
+pub fn estimate_sidespin_drift(
+    start_pos: Vec3,
+    target_pos: Vec3,
+    launch_angle: f32,
+    speed: f32,
+    sidespin: f32,
+    config: &GameConfig,
+) -> Vec3 {
+    let horizontal = Vec3::new(target_pos.x - start_pos.x, 0.0, target_pos.z - start_pos.z);
+    let distance = horizontal.length();
+    let horizontal_speed = speed * launch_angle.to_radians().cos();
+    if distance < 0.001 || horizontal_speed < 0.001 {
+        return Vec3::ZERO;
+    }
+
+    let spin_config = &config.spin_physics;
+    let flight_time = distance / horizontal_speed;
+    let avg_sidespin = sidespin * (1.0 - spin_config.spin_decay_rate * flight_time / 2.0).max(0.0);
+    let horizontal_velocity = horizontal / distance * horizontal_speed;
+    let accel = magnus_side_acceleration(
+        Vec3::Y * avg_sidespin,
+        horizontal_velocity,
+        spin_config.magnus_side_factor,
+    );
+
+    accel * 0.5 * flight_time * flight_time
+}
+
+/// 風による着地点のズレを推定
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+///
+/// 飛行中の風加速度を一定とみなした近似: drift = 0.5 × a × t²
+pub fn estimate_wind_drift(
+    start_pos: Vec3,
+    target_pos: Vec3,
+    launch_angle: f32,
+    speed: f32,
+    wind_acceleration: Vec3,
+) -> Vec3 {
+    let horizontal = Vec3::new(target_pos.x - start_pos.x, 0.0, target_pos.z - start_pos.z);
+    let distance = horizontal.length();
+    let horizontal_speed = speed * launch_angle.to_radians().cos();
+    if distance < 0.001 || horizontal_speed < 0.001 {
+        return Vec3::ZERO;
+    }
+
+    wind_drift(wind_acceleration, distance / horizontal_speed)
+}
+
 /// 方向ベクトルを計算
 /// @spec 30605_trajectory_calculation_spec.md#req-30605-023
 pub fn calculate_direction_vector(start_pos: Vec3, target_pos: Vec3, launch_angle: f32) -> Vec3 {
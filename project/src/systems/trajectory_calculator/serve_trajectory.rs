@@ -140,5 +140,8 @@ pub fn calculate_serve_trajectory(
         final_speed: adjusted_speed,
         direction,
         landing_position: adjusted_landing,
+        // サーブはフラット（スピンなし）
+        spin: Vec3::ZERO,
+        wall_contact: None,
     }
 }
@@ -3,15 +3,24 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
+use crate::core::{
+    magnus_side_acceleration, topspin_component, CourtSide, ShotType, WallMaterial, WallType,
+};
 use crate::resource::config::*;
+use crate::systems::ball::ground_bounce_velocity;
 use crate::systems::match_control::get_service_box;
 
 use super::landing_position::{apply_landing_deviation, calculate_landing_position};
 use super::launch_angle::calculate_launch_angle;
-use super::physics_utils::{calculate_effective_gravity, calculate_speed_factors};
+use super::main_trajectory::calculate_trajectory;
+use super::physics_utils::{
+    calculate_effective_gravity, calculate_sidespin, calculate_speed_factors,
+};
 use super::serve_trajectory::{calculate_serve_landing_position, calculate_serve_trajectory};
-use super::types::{ServeTrajectoryContext, TrajectoryContext};
+use super::types::{
+    ServeTrajectoryContext, TrajectoryContext, TrajectoryResult, WallTarget, WallTrajectoryContext,
+};
+use super::wall_trajectory::{calculate_shot_wall_trajectory, calculate_wall_trajectory};
 
 fn make_test_config() -> GameConfig {
     // テスト用の最小限の設定
@@ -19,6 +28,7 @@ fn make_test_config() -> GameConfig {
         physics: PhysicsConfig {
             gravity: -9.8,
             max_fall_speed: -20.0,
+            ..Default::default()
         },
         court: CourtConfig {
             width: 12.0,
@@ -30,6 +40,7 @@ fn make_test_config() -> GameConfig {
             service_box_depth: 5.0,
             outer_wall_z: 10.0,
             outer_wall_x: 12.0,
+            ..Default::default()
         },
         player: PlayerConfig {
             move_speed: 5.0,
@@ -47,11 +58,11 @@ fn make_test_config() -> GameConfig {
             bounce_factor: 0.8,
             radius: 0.2,
             min_bounce_velocity: 1.0,
-            wall_bounce_factor: 0.8,
         },
         collision: CollisionConfig {
             character_radius: 0.5,
             z_tolerance: 0.3,
+            ..Default::default()
         },
         knockback: KnockbackConfig {
             enabled: true,
@@ -67,12 +78,24 @@ fn make_test_config() -> GameConfig {
             jump_shot_angle: 30.0,
             jump_threshold: 0.5,
             racket_swing: RacketSwingConfig::default(),
+            shot_types: ShotTypeConfig::default(),
         },
         scoring: ScoringConfig {
             point_values: vec![0, 15, 30, 40],
             games_to_win_set: 6,
+            set_win_margin: 2,
             sets_to_win_match: 1,
+            tiebreak_enabled: true,
+            tiebreak_at_games: None,
+            tiebreak_points: 7,
+            final_set_super_tiebreak: false,
+            super_tiebreak_points: 10,
             point_end_delay: 1.5,
+            change_ends_enabled: true,
+            tiebreak_change_ends_interval: 6,
+            changeover_break_secs: 3.0,
+            deuce_mode: DeuceMode::Advantage,
+            time_limit_secs: None,
         },
         input: InputConfig {
             jump_buffer_time: 0.1,
@@ -80,17 +103,7 @@ fn make_test_config() -> GameConfig {
             normalization_threshold: 1.0,
             input_sensitivity: 1.0,
         },
-        input_keys: InputKeysConfig::default(),
-        gamepad_buttons: GamepadButtonsConfig::default(),
-        shadow: ShadowConfig::default(),
-        shot_attributes: ShotAttributesConfig::default(),
-        ai: AiConfig::default(),
-        visual_feedback: VisualFeedbackConfig::default(),
-        player_visual: PlayerVisualConfig::default(),
-        serve: ServeConfig::default(),
-        spin_physics: SpinPhysicsConfig::default(),
-        trajectory: TrajectoryConfig::default(),
-        character: CharacterConfig::default(),
+        ..Default::default()
     }
 }
 
@@ -104,8 +117,11 @@ fn test_neutral_landing_position() {
         court_side: CourtSide::Left,
         ball_position: Vec3::new(-5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
 
     let landing = calculate_landing_position(&ctx, &config.court, &config.trajectory);
@@ -134,8 +150,11 @@ fn test_forward_landing_position() {
         court_side: CourtSide::Left,
         ball_position: Vec3::new(-5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
 
     let landing = calculate_landing_position(&ctx, &config.court, &config.trajectory);
@@ -154,8 +173,11 @@ fn test_backward_landing_position() {
         court_side: CourtSide::Left,
         ball_position: Vec3::new(-5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
 
     let landing = calculate_landing_position(&ctx, &config.court, &config.trajectory);
@@ -180,8 +202,11 @@ fn test_side_landing_position() {
         court_side: CourtSide::Left,
         ball_position: Vec3::new(-5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
     let landing_right = calculate_landing_position(&ctx_right, &config.court, &config.trajectory);
 
@@ -198,8 +223,11 @@ fn test_side_landing_position() {
         court_side: CourtSide::Left,
         ball_position: Vec3::new(-5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
     let landing_left = calculate_landing_position(&ctx_left, &config.court, &config.trajectory);
 
@@ -318,8 +346,11 @@ fn test_right_side_landing_position() {
         court_side: CourtSide::Right,
         ball_position: Vec3::new(5.0, 1.0, 0.0),
         spin: 0.0,
+        sidespin: 0.0,
         base_speed: 15.0,
         accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
     };
 
     let landing = calculate_landing_position(&ctx, &config.court, &config.trajectory);
@@ -583,3 +614,368 @@ fn test_net_clearance_with_far_target() {
         );
     }
 }
+
+/// TST-30605-060: サイドスピンはコース入力の方向へ曲がる向きになる
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-027
+#[test]
+fn test_sidespin_curves_toward_course() {
+    // Left側（+X方向）で +Z を狙う → 時計回り（ω_y < 0）で +Z へ曲がる
+    assert!((calculate_sidespin(0.6, 0.5, CourtSide::Left) - (-0.6)).abs() < 1e-6);
+    // Right側（-X方向）で +Z を狙う → 反時計回り（ω_y > 0）で +Z へ曲がる
+    assert!((calculate_sidespin(0.6, 0.5, CourtSide::Right) - 0.6).abs() < 1e-6);
+    // コース入力なし・サイドスピンなしでは 0
+    assert_eq!(calculate_sidespin(0.6, 0.0, CourtSide::Left), 0.0);
+    assert_eq!(calculate_sidespin(0.0, 1.0, CourtSide::Left), 0.0);
+}
+
+/// TST-30605-061: サイドスピンの曲がりを見込んでも着地予定地点に落ちる
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-027
+#[test]
+fn test_sidespin_shot_lands_near_target() {
+    let config = make_test_config();
+    let ctx = TrajectoryContext {
+        input: Vec2::new(0.0, 0.5),
+        court_side: CourtSide::Left,
+        // ネット通過のための角度補正が入らない打点の高さ
+        ball_position: Vec3::new(-5.0, 1.5, 0.0),
+        spin: 0.0,
+        sidespin: 0.6,
+        base_speed: 18.0,
+        accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type: ShotType::Drive,
+    };
+
+    let result = calculate_trajectory(&ctx, &config);
+
+    // 角速度はサイドスピンのみ
+    assert!((result.spin.y - (-0.6)).abs() < 1e-5);
+    // 曲がる分だけ内側（-Z側）へ打ち出す
+    let straight = result.landing_position - ctx.ball_position;
+    assert!(result.direction.z / result.direction.x < straight.z / straight.x);
+
+    // 重力・マグヌス効果・スピン減衰で飛行をシミュレート
+    let dt = 1.0 / 60.0;
+    let mut pos = ctx.ball_position;
+    let mut vel = result.direction * result.final_speed;
+    let mut spin = result.spin;
+    while pos.y > 0.0 {
+        vel.y += config.physics.gravity * dt;
+        vel += magnus_side_acceleration(spin, vel, config.spin_physics.magnus_side_factor) * dt;
+        spin *= 1.0 - config.spin_physics.spin_decay_rate * dt;
+        pos += vel * dt;
+    }
+
+    assert!(
+        (pos.z - result.landing_position.z).abs() < 0.15,
+        "Landing z {:.3} should be near target {:.3}",
+        pos.z,
+        result.landing_position.z
+    );
+}
+
+/// TST-30605-062: 風を見込んで狙うと横風の中でも着地予定地点付近に落ちる
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+#[test]
+fn test_wind_compensated_shot_lands_near_target() {
+    let mut config = make_test_config();
+    let ctx = TrajectoryContext {
+        input: Vec2::new(0.0, 0.0),
+        court_side: CourtSide::Left,
+        // ネット通過のための角度補正が入らない打点の高さ
+        ball_position: Vec3::new(-5.0, 1.5, 0.0),
+        spin: 0.0,
+        sidespin: 0.0,
+        base_speed: 18.0,
+        accuracy: 1.0,
+        wind_acceleration: Vec3::new(0.0, 0.0, 3.0),
+        shot_type: ShotType::Drive,
+    };
+
+    // 重力と一定の風加速度で飛行をシミュレート
+    let fly = |config: &GameConfig| {
+        let result = calculate_trajectory(&ctx, config);
+        let dt = 1.0 / 60.0;
+        let mut pos = ctx.ball_position;
+        let mut vel = result.direction * result.final_speed;
+        while pos.y > 0.0 {
+            vel.y += config.physics.gravity * dt;
+            vel += ctx.wind_acceleration * dt;
+            pos += vel * dt;
+        }
+        (pos, result.landing_position)
+    };
+
+    let (pos, target) = fly(&config);
+    assert!(
+        (pos.z - target.z).abs() < 0.15,
+        "Landing z {:.3} should be near target {:.3}",
+        pos.z,
+        target.z
+    );
+
+    // 補正しない場合は風下（+Z）へ流される
+    config.trajectory.compensate_wind = false;
+    let (pos, target) = fly(&config);
+    assert!(pos.z - target.z > 0.3);
+}
+
+/// TST-30607-003: ロブは高い弾道で深く、ドロップショットはネット際に落ちる
+/// @spec 30607_shot_type_spec.md#req-30607-006
+#[test]
+fn test_shot_type_trajectory_intent() {
+    let config = make_test_config();
+    let ctx = |shot_type| TrajectoryContext {
+        input: Vec2::ZERO,
+        court_side: CourtSide::Left,
+        ball_position: Vec3::new(-5.0, 1.0, 0.0),
+        spin: 0.0,
+        sidespin: 0.0,
+        base_speed: 15.0,
+        accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type,
+    };
+    let types = &config.shot.shot_types;
+
+    let drive = calculate_trajectory(&ctx(ShotType::Drive), &config);
+    let lob = calculate_trajectory(&ctx(ShotType::Lob), &config);
+    assert!(
+        lob.launch_angle >= types.lob.min_launch_angle - 0.01,
+        "Lob angle {:.1} should be at least {:.1}",
+        lob.launch_angle,
+        types.lob.min_launch_angle
+    );
+    assert!(lob.launch_angle > drive.launch_angle);
+    assert!((lob.landing_position.x - types.lob.landing_depth.unwrap()).abs() < 0.01);
+
+    // 角度を上げても着地予定地点に落ちる
+    let dt = 1.0 / 240.0;
+    let mut pos = ctx(ShotType::Lob).ball_position;
+    let mut vel = lob.direction * lob.final_speed;
+    while pos.y > 0.0 {
+        vel.y += config.physics.gravity * dt;
+        pos += vel * dt;
+    }
+    assert!(
+        (pos.x - lob.landing_position.x).abs() < 0.2,
+        "Lob landed at x={:.2}, target {:.2}",
+        pos.x,
+        lob.landing_position.x
+    );
+
+    let drop_shot = calculate_trajectory(&ctx(ShotType::DropShot), &config);
+    assert!((drop_shot.landing_position.x - types.drop_shot.landing_depth.unwrap()).abs() < 0.01);
+}
+
+/// 壁狙いの弾道をボール物理（スピンによる重力変動・スピン減衰・床バウンド）で飛ばし、
+/// 壁の平面に達した位置と床バウンド回数を返す
+fn fly_to_wall(
+    ctx: &WallTrajectoryContext,
+    result: &TrajectoryResult,
+    config: &GameConfig,
+) -> (Vec3, usize) {
+    let dt = 1.0 / 240.0;
+    let normal = ctx.target.wall.normal();
+    let wall_point = result.wall_contact.unwrap().position;
+    let mut pos = ctx.ball_position;
+    let mut vel = result.direction * result.final_speed;
+    let mut spin = result.spin;
+    let mut bounces = 0;
+    while (pos - wall_point).dot(normal) > 0.0 && bounces < 2 {
+        let topspin = topspin_component(spin, vel);
+        vel.y +=
+            config.physics.gravity * (1.0 + topspin * config.spin_physics.gravity_spin_factor) * dt;
+        spin *= 1.0 - config.spin_physics.spin_decay_rate * dt;
+        pos += vel * dt;
+        if pos.y <= 0.0 && vel.y < 0.0 {
+            pos.y = 0.0;
+            vel = ground_bounce_velocity(config, vel, spin, 1.0);
+            bounces += 1;
+        }
+    }
+    (pos, bounces)
+}
+
+fn back_glass_context(spin: f32, height: f32) -> WallTrajectoryContext {
+    WallTrajectoryContext {
+        court_side: CourtSide::Left,
+        ball_position: Vec3::new(-5.0, 1.0, 0.0),
+        spin,
+        base_speed: 18.0,
+        min_launch_angle: -90.0,
+        target: WallTarget {
+            wall: WallType::BackWallRight,
+            along: 0.0,
+            height,
+        },
+    }
+}
+
+/// TST-30605-070: 相手コートでバウンドした後、後壁の狙った高さに当たる
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[test]
+fn test_wall_trajectory_hits_back_glass_at_target_height() {
+    let config = make_test_config();
+    let low = back_glass_context(0.0, 1.5);
+    let high = back_glass_context(0.0, 2.5);
+
+    let low_result = calculate_wall_trajectory(&low, &config).expect("back glass reachable");
+    let high_result = calculate_wall_trajectory(&high, &config).expect("back glass reachable");
+
+    for (ctx, result) in [(&low, &low_result), (&high, &high_result)] {
+        let contact = result.wall_contact.unwrap();
+        assert_eq!(contact.wall, WallType::BackWallRight);
+        assert_eq!(contact.material, WallMaterial::Glass);
+        assert!((contact.position.x - config.court.outer_wall_x).abs() < 1e-4);
+        assert!((contact.position.y - ctx.target.height).abs() < 0.01);
+        // 床バウンドは相手コート内
+        let bounce = result.landing_position;
+        assert!(bounce.x > config.court.net_x && bounce.x < config.court.depth / 2.0);
+        assert!(result.final_speed <= ctx.base_speed + 0.01);
+
+        // 1回バウンドして狙った高さで壁に当たる
+        let (pos, bounces) = fly_to_wall(ctx, result, &config);
+        assert_eq!(bounces, 1);
+        assert!(
+            (pos.y - ctx.target.height).abs() < 0.1,
+            "Wall contact height {:.3} should be near target {:.3}",
+            pos.y,
+            ctx.target.height
+        );
+    }
+    // 高く当てるほど高い弾道
+    assert!(high_result.launch_angle > low_result.launch_angle);
+
+    // 跳ね返りはガラスの反発係数で弱まる（フラットなので摩擦以外の変化なし）
+    let contact = low_result.wall_contact.unwrap();
+    let restitution = config.wall_material.glass.restitution;
+    assert!(
+        (contact.reflected_velocity.x + contact.incident_velocity.x * restitution).abs() < 1e-4
+    );
+    assert!(contact.reflected_velocity.x < 0.0);
+}
+
+/// TST-30605-071: トップスピン・スライスのバウンド変化を見込んで狙った高さに当たる
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[test]
+fn test_wall_trajectory_accounts_for_spin() {
+    let config = make_test_config();
+
+    for spin in [0.6, -0.5] {
+        let ctx = back_glass_context(spin, 1.5);
+        let result = calculate_wall_trajectory(&ctx, &config).expect("back glass reachable");
+        assert!(result.spin.length() > 0.0);
+
+        let (pos, bounces) = fly_to_wall(&ctx, &result, &config);
+        assert_eq!(bounces, 1);
+        assert!(
+            (pos.y - ctx.target.height).abs() < 0.15,
+            "spin {:.1}: wall contact height {:.3} should be near target {:.3}",
+            spin,
+            pos.y,
+            ctx.target.height
+        );
+    }
+}
+
+/// TST-30605-072: 左右壁も狙え、相手側の壁以外・壁のない位置は狙えない
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[test]
+fn test_wall_trajectory_side_wall_and_invalid_targets() {
+    let config = make_test_config();
+    let side = WallTrajectoryContext {
+        court_side: CourtSide::Left,
+        ball_position: Vec3::new(-6.0, 1.0, -4.0),
+        spin: 0.0,
+        base_speed: 20.0,
+        min_launch_angle: -90.0,
+        target: WallTarget {
+            wall: WallType::RightWall,
+            along: 9.0,
+            height: 1.0,
+        },
+    };
+    let result = calculate_wall_trajectory(&side, &config).expect("side glass reachable");
+    let contact = result.wall_contact.unwrap();
+    assert!((contact.position.z - config.court.outer_wall_z).abs() < 1e-4);
+    assert!((contact.position.x - 9.0).abs() < 0.01);
+    assert!(result.landing_position.z.abs() < config.court.width / 2.0);
+    let (pos, bounces) = fly_to_wall(&side, &result, &config);
+    assert_eq!(bounces, 1);
+    assert!((pos.y - 1.0).abs() < 0.1);
+
+    let with_target = |wall, along, height| WallTrajectoryContext {
+        target: WallTarget {
+            wall,
+            along,
+            height,
+        },
+        ..back_glass_context(0.0, 1.5)
+    };
+    // 自コート側の後壁
+    assert!(
+        calculate_wall_trajectory(&with_target(WallType::BackWallLeft, 0.0, 1.5), &config)
+            .is_none()
+    );
+    // 壁より高い位置・開口部
+    assert!(
+        calculate_wall_trajectory(&with_target(WallType::BackWallRight, 0.0, 5.0), &config)
+            .is_none()
+    );
+    assert!(
+        calculate_wall_trajectory(&with_target(WallType::BackWallRight, 9.0, 1.5), &config)
+            .is_none()
+    );
+    // 天井
+    assert!(
+        calculate_wall_trajectory(&with_target(WallType::Ceiling, 0.0, 1.5), &config).is_none()
+    );
+}
+
+/// TST-30607-006: ロブは最小発射角度以上の弾道で後壁の狙った高さに当て、壁狙いのない種類は対象外
+/// @spec 30607_shot_type_spec.md#req-30607-007
+#[test]
+fn test_req_30607_007_lob_targets_back_glass() {
+    let config = make_test_config();
+    let types = &config.shot.shot_types;
+    let ctx = |shot_type| TrajectoryContext {
+        input: Vec2::new(0.0, 0.5),
+        court_side: CourtSide::Left,
+        ball_position: Vec3::new(-5.0, 1.0, 0.0),
+        spin: 0.2,
+        sidespin: 0.0,
+        base_speed: 18.0,
+        accuracy: 1.0,
+        wind_acceleration: Vec3::ZERO,
+        shot_type,
+    };
+
+    let lob = calculate_shot_wall_trajectory(&ctx(ShotType::Lob), &config)
+        .expect("lob reaches the back glass");
+    let contact = lob.wall_contact.unwrap();
+    assert_eq!(contact.wall, WallType::BackWallRight);
+    assert!((contact.position.y - types.lob.wall_target_height.unwrap()).abs() < 0.01);
+    assert!(lob.launch_angle >= types.lob.min_launch_angle - 0.01);
+    // コース入力の延長で後壁に当たる
+    let course = calculate_landing_position(&ctx(ShotType::Lob), &config.court, &config.trajectory);
+    assert!((contact.position.z - course.z).abs() < 0.01);
+
+    // 最小発射角度を外すと、より低い弾道で当てる
+    let unconstrained = WallTrajectoryContext {
+        court_side: CourtSide::Left,
+        ball_position: ctx(ShotType::Lob).ball_position,
+        spin: 0.2,
+        base_speed: 18.0,
+        min_launch_angle: -90.0,
+        target: WallTarget {
+            wall: WallType::BackWallRight,
+            along: course.z,
+            height: types.lob.wall_target_height.unwrap(),
+        },
+    };
+    let low = calculate_wall_trajectory(&unconstrained, &config).expect("back glass reachable");
+    assert!(low.launch_angle <= lob.launch_angle);
+
+    assert!(calculate_shot_wall_trajectory(&ctx(ShotType::Drive), &config).is_none());
+}
@@ -3,7 +3,7 @@
 
 use bevy::prelude::*;
 
-use crate::core::CourtSide;
+use crate::core::{CourtSide, ShotType, WallMaterial, WallType};
 use crate::resource::config::ServeSide;
 
 /// 弾道計算結果
@@ -18,6 +18,32 @@ pub struct TrajectoryResult {
     pub direction: Vec3,
     /// 着地予定地点
     pub landing_position: Vec3,
+    /// ボールに与える角速度ベクトル（トップスピン・サイドスピン）
+    /// @spec 30605_trajectory_calculation_spec.md#req-30605-027
+    pub spin: Vec3,
+    /// 壁接触の予測（壁狙いの弾道のみ。通常の弾道は None）
+    /// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+    pub wall_contact: Option<WallContactPrediction>,
+}
+
+/// 壁接触の予測
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[derive(Debug, Clone, Copy)]
+pub struct WallContactPrediction {
+    /// 接触する壁
+    pub wall: WallType,
+    /// 接触点の材質
+    pub material: WallMaterial,
+    /// 接触点
+    pub position: Vec3,
+    /// 打球から壁に当たるまでの時間（秒）
+    pub time: f32,
+    /// 壁に当たる直前の速度
+    pub incident_velocity: Vec3,
+    /// 壁で跳ね返った後の速度（材質の反発係数・摩擦とスピンを反映）
+    pub reflected_velocity: Vec3,
+    /// 壁で跳ね返った後の角速度
+    pub spin: Vec3,
 }
 
 /// 弾道計算コンテキスト
@@ -32,10 +58,18 @@ pub struct TrajectoryContext {
     pub ball_position: Vec3,
     /// スピン値（-1.0〜+1.0）
     pub spin: f32,
+    /// サイドスピン量（0.0〜1.0、コース入力の方向へ曲げる）
+    pub sidespin: f32,
     /// 基準初速（ショット属性から）
     pub base_speed: f32,
     /// 精度（ショット属性から）
     pub accuracy: f32,
+    /// 打点の高さでボールが受ける風の加速度（無風なら Vec3::ZERO）
+    /// @spec 30605_trajectory_calculation_spec.md#req-30605-028
+    pub wind_acceleration: Vec3,
+    /// ショット種類（着地深さ・最小発射角度の弾道意図を決める）
+    /// @spec 30607_shot_type_spec.md#req-30607-006
+    pub shot_type: ShotType,
 }
 
 /// サーブ用弾道計算コンテキスト
@@ -54,3 +88,34 @@ pub struct ServeTrajectoryContext {
     /// サーブトスの上向き初速度
     pub toss_velocity_y: f32,
 }
+
+/// 壁狙いの目標（相手コートで床にバウンドした後、壁の指定位置・高さに当てる）
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[derive(Debug, Clone, Copy)]
+pub struct WallTarget {
+    /// 狙う壁（相手側の後壁、または左右壁）
+    pub wall: WallType,
+    /// 壁面に沿った位置（左右壁: X、後壁: Z）
+    pub along: f32,
+    /// 壁に当てる高さ（m）
+    pub height: f32,
+}
+
+/// 壁狙い用弾道計算コンテキスト
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+#[derive(Debug, Clone)]
+pub struct WallTrajectoryContext {
+    /// 打つ側のコートサイド
+    pub court_side: CourtSide,
+    /// ボールの現在位置
+    pub ball_position: Vec3,
+    /// スピン値（-1.0〜+1.0）
+    pub spin: f32,
+    /// 基準初速（これを超える初速では打たない）
+    pub base_speed: f32,
+    /// 最小発射角度（度）。これより低い弾道は採らない
+    /// @spec 30607_shot_type_spec.md#req-30607-007
+    pub min_launch_angle: f32,
+    /// 狙う壁の位置
+    pub target: WallTarget,
+}
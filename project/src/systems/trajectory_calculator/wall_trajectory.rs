@@ -0,0 +1,340 @@
+//! 壁狙いの弾道計算
+//! @spec 30605_trajectory_calculation_spec.md#req-30605-060
+//!
+//! 「相手コートで床にバウンドした後、壁の指定位置・高さに当たる」弾道を逆算する。
+//! 床バウンド（スピン込み）と壁での跳ね返り（材質の反発係数・摩擦・スピン）は
+//! ボール物理と同じ計算で予測する。
+
+use bevy::prelude::*;
+
+use crate::core::{spin_from_shot, topspin_component, CourtSide, WallType};
+use crate::resource::config::GameConfig;
+use crate::systems::ball::{ground_bounce_velocity, wall_contact_response};
+use crate::systems::court_factory::create_court_enclosure;
+
+use super::landing_position::calculate_landing_position;
+use super::launch_angle::calculate_launch_angle;
+use super::physics_utils::{
+    calculate_direction_vector, calculate_effective_gravity, calculate_speed_for_target, lerp,
+    CourtSideExt,
+};
+use super::types::{
+    TrajectoryContext, TrajectoryResult, WallContactPrediction, WallTarget, WallTrajectoryContext,
+};
+
+/// バウンド地点の候補数（打点から壁の狙い位置までの水平線上）
+const BOUNCE_SAMPLES: usize = 32;
+/// 発射角度の候補数（バウンド地点ごと）
+const ANGLE_SAMPLES: usize = 32;
+/// 発射角度の二分探索の反復回数
+const BISECTION_STEPS: usize = 24;
+
+/// 床バウンドを経て壁に当たるまでの飛行（1候補分）
+struct WallFlight {
+    launch_angle: f32,
+    speed: f32,
+    direction: Vec3,
+    launch_spin: Vec3,
+    bounce_position: Vec3,
+    contact_position: Vec3,
+    time: f32,
+    incident_velocity: Vec3,
+    incident_spin: Vec3,
+}
+
+/// ショット種類の壁狙いの弾道を計算
+/// @spec 30607_shot_type_spec.md#req-30607-007
+///
+/// ショット種類に `wall_target_height` があれば、入力で決まるコース（REQ-30605-012）の
+/// 延長で相手側の後壁をその高さで狙う。発射角度は種類の `min_launch_angle` 以上に限る。
+///
+/// 戻り値: 壁狙いのない種類、または届かない場合は None（通常の弾道を使う）。
+pub fn calculate_shot_wall_trajectory(
+    ctx: &TrajectoryContext,
+    config: &GameConfig,
+) -> Option<TrajectoryResult> {
+    let intent = config.shot.shot_types.params(ctx.shot_type);
+    let height = intent.wall_target_height?;
+    let course = calculate_landing_position(ctx, &config.court, &config.trajectory);
+    let wall = match ctx.court_side {
+        CourtSide::Left => WallType::BackWallRight,
+        CourtSide::Right => WallType::BackWallLeft,
+    };
+
+    calculate_wall_trajectory(
+        &WallTrajectoryContext {
+            court_side: ctx.court_side,
+            ball_position: ctx.ball_position,
+            spin: ctx.spin,
+            base_speed: ctx.base_speed,
+            min_launch_angle: intent.min_launch_angle,
+            target: WallTarget {
+                wall,
+                along: course.z,
+                height,
+            },
+        },
+        config,
+    )
+}
+
+/// 壁狙いの弾道を計算
+/// @spec 30605_trajectory_calculation_spec.md#req-30605-060
+///
+/// 打点から狙う壁の位置へ向かう水平線上の、相手コート内の各地点を床バウンドの候補とし、
+/// 基準初速以下で壁の狙う高さに当たる発射角度を求める。候補のうち最も低い（速い）弾道を採用する。
+///
+/// 戻り値: 着地予定地点は床バウンド地点、`wall_contact` に壁接触の予測を持つ。
+/// 相手側の壁でない・狙う位置に壁がない（開口部・壁より高い）・どの候補でも届かない場合は None。
+pub fn calculate_wall_trajectory(
+    ctx: &WallTrajectoryContext,
+    config: &GameConfig,
+) -> Option<TrajectoryResult> {
+    let target = &ctx.target;
+
+    // 1. 狙う壁面上の点と材質
+    let target_point = wall_target_point(target, ctx.court_side, config)?;
+    let enclosure = create_court_enclosure(&config.court);
+    let material = enclosure.segment_at(target.wall, target_point)?.material;
+
+    // 2. 有効重力を計算
+    let effective_gravity = calculate_effective_gravity(ctx.spin, ctx.ball_position.y, config);
+
+    // 3. バウンド地点ごとに発射角度を求め、最も低い弾道を採用
+    let mut best: Option<WallFlight> = None;
+    for i in 0..=BOUNCE_SAMPLES {
+        let t = i as f32 / BOUNCE_SAMPLES as f32;
+        let bounce_position = Vec3::new(
+            lerp(ctx.ball_position.x, target_point.x, t),
+            0.0,
+            lerp(ctx.ball_position.z, target_point.z, t),
+        );
+        if !is_in_opponent_court(bounce_position, ctx.court_side, config) {
+            continue;
+        }
+        let Some(flight) = solve_wall_flight(
+            ctx,
+            bounce_position,
+            target_point,
+            effective_gravity,
+            config,
+        ) else {
+            continue;
+        };
+        if best
+            .as_ref()
+            .is_none_or(|best| flight.launch_angle < best.launch_angle)
+        {
+            best = Some(flight);
+        }
+    }
+    let flight = best?;
+
+    // 4. 壁での跳ね返り（予測なので金網の偏向はなし）
+    let response = wall_contact_response(
+        config,
+        target.wall,
+        material,
+        flight.incident_velocity,
+        flight.incident_spin,
+        1.0,
+        Vec2::ZERO,
+    );
+
+    Some(TrajectoryResult {
+        launch_angle: flight.launch_angle,
+        final_speed: flight.speed,
+        direction: flight.direction,
+        landing_position: flight.bounce_position,
+        spin: flight.launch_spin,
+        wall_contact: Some(WallContactPrediction {
+            wall: target.wall,
+            material,
+            position: flight.contact_position,
+            time: flight.time,
+            incident_velocity: flight.incident_velocity,
+            reflected_velocity: response.velocity,
+            spin: response.spin,
+        }),
+    })
+}
+
+/// 狙う壁面上の点（相手コート側の壁のみ）
+fn wall_target_point(
+    target: &WallTarget,
+    court_side: CourtSide,
+    config: &GameConfig,
+) -> Option<Vec3> {
+    let court = &config.court;
+    let point = match target.wall {
+        WallType::BackWallLeft => Vec3::new(-court.outer_wall_x, target.height, target.along),
+        WallType::BackWallRight => Vec3::new(court.outer_wall_x, target.height, target.along),
+        WallType::LeftWall => Vec3::new(target.along, target.height, -court.outer_wall_z),
+        WallType::RightWall => Vec3::new(target.along, target.height, court.outer_wall_z),
+        WallType::Ceiling => return None,
+    };
+    let opponent_side = (point.x - court.net_x) * court_side.sign() > 0.0;
+    (opponent_side && target.height > 0.0).then_some(point)
+}
+
+/// 床バウンド地点が相手コート内（マージン込み）か
+fn is_in_opponent_court(position: Vec3, court_side: CourtSide, config: &GameConfig) -> bool {
+    let court = &config.court;
+    let margin = config.trajectory.landing_margin;
+    let depth = (position.x - court.net_x) * court_side.sign();
+    depth >= margin
+        && position.x.abs() <= court.depth / 2.0 - margin
+        && position.z.abs() <= court.width / 2.0 - margin
+}
+
+/// 床バウンド地点を固定して、壁の狙う高さに当たる最も低い発射角度を求める
+///
+/// 発射角度の範囲は基準初速で届く低い解（ネット通過を考慮、最小発射角度以上）から高い解（最大発射角度まで）。
+/// この範囲では初速が基準初速以下になる。範囲を走査し、壁での高さが狙いを横切る区間を二分探索する。
+fn solve_wall_flight(
+    ctx: &WallTrajectoryContext,
+    bounce_position: Vec3,
+    target_point: Vec3,
+    effective_gravity: f32,
+    config: &GameConfig,
+) -> Option<WallFlight> {
+    let start = ctx.ball_position;
+    let (low_angle, _, reachable) = calculate_launch_angle(
+        start,
+        bounce_position,
+        ctx.base_speed,
+        effective_gravity,
+        &config.trajectory,
+        config.court.net_x,
+        config.court.net_height,
+    );
+    // 基準初速では届かない（着地点が短縮された）
+    if reachable.distance(bounce_position) > 0.01 {
+        return None;
+    }
+
+    let distance = Vec2::new(bounce_position.x - start.x, bounce_position.z - start.z).length();
+    let v2 = ctx.base_speed * ctx.base_speed;
+    let g = effective_gravity;
+    let discriminant = v2 * v2 - g * (g * distance * distance - 2.0 * start.y * v2);
+    let high_angle = ((v2 + discriminant.max(0.0).sqrt()) / (g * distance))
+        .atan()
+        .to_degrees()
+        .min(config.trajectory.max_launch_angle)
+        .max(low_angle);
+    let low_angle = low_angle.max(ctx.min_launch_angle);
+    if low_angle > high_angle {
+        return None;
+    }
+
+    let fly = |angle: f32| {
+        simulate_wall_flight(
+            ctx,
+            bounce_position,
+            target_point,
+            angle,
+            effective_gravity,
+            config,
+        )
+    };
+    let height_error =
+        |angle: f32| fly(angle).map(|flight| flight.contact_position.y - target_point.y);
+
+    let mut previous = height_error(low_angle).map(|error| (low_angle, error));
+    for k in 1..=ANGLE_SAMPLES {
+        let angle = lerp(low_angle, high_angle, k as f32 / ANGLE_SAMPLES as f32);
+        let Some(error) = height_error(angle) else {
+            previous = None;
+            continue;
+        };
+        if let Some((previous_angle, previous_error)) = previous {
+            if (previous_error <= 0.0) != (error <= 0.0) {
+                // 壁での高さが狙いを横切る区間を二分探索
+                let (mut low, mut high, mut low_error) = (previous_angle, angle, previous_error);
+                for _ in 0..BISECTION_STEPS {
+                    let mid = (low + high) / 2.0;
+                    let Some(mid_error) = height_error(mid) else {
+                        break;
+                    };
+                    if (mid_error <= 0.0) == (low_error <= 0.0) {
+                        low = mid;
+                        low_error = mid_error;
+                    } else {
+                        high = mid;
+                    }
+                }
+                return fly((low + high) / 2.0);
+            }
+        }
+        previous = Some((angle, error));
+    }
+    None
+}
+
+/// 発射角度を与えて、床バウンドから壁接触までを予測する
+///
+/// - 床まで: 有効重力の放物線（着地点を通る初速を逆算）
+/// - 床バウンド: ボール物理と同じバウンド計算（スピン込み）
+/// - 壁まで: バウンド後のトップスピン成分による重力変動を含む放物線
+/// - スピンは飛行時間に応じて減衰させる
+fn simulate_wall_flight(
+    ctx: &WallTrajectoryContext,
+    bounce_position: Vec3,
+    target_point: Vec3,
+    launch_angle: f32,
+    effective_gravity: f32,
+    config: &GameConfig,
+) -> Option<WallFlight> {
+    let start = ctx.ball_position;
+    let distance = Vec2::new(bounce_position.x - start.x, bounce_position.z - start.z).length();
+    let speed = calculate_speed_for_target(
+        launch_angle,
+        distance,
+        effective_gravity,
+        bounce_position.y - start.y,
+    );
+    if speed <= 0.0 {
+        return None;
+    }
+    let spin_physics = &config.spin_physics;
+    let direction = calculate_direction_vector(start, bounce_position, launch_angle);
+    let launch_spin = spin_from_shot(direction, ctx.spin, 0.0);
+
+    // 床まで
+    let launch_velocity = direction * speed;
+    let flight_time = distance / Vec2::new(launch_velocity.x, launch_velocity.z).length();
+    let incoming = Vec3::new(
+        launch_velocity.x,
+        launch_velocity.y - effective_gravity * flight_time,
+        launch_velocity.z,
+    );
+    let bounce_spin = launch_spin * (-spin_physics.spin_decay_rate * flight_time).exp();
+    let rebound = ground_bounce_velocity(config, incoming, bounce_spin, 1.0);
+
+    // 壁まで
+    let normal = ctx.target.wall.normal();
+    let approach_speed = -rebound.dot(normal);
+    if approach_speed <= 0.0 {
+        return None;
+    }
+    let wall_time = (bounce_position - target_point).dot(normal) / approach_speed;
+    let gravity = config.physics.gravity.abs()
+        * (1.0 + topspin_component(bounce_spin, rebound) * spin_physics.gravity_spin_factor);
+    let height = rebound.y * wall_time - 0.5 * gravity * wall_time * wall_time;
+
+    Some(WallFlight {
+        launch_angle,
+        speed,
+        direction,
+        launch_spin,
+        bounce_position,
+        contact_position: Vec3::new(
+            bounce_position.x + rebound.x * wall_time,
+            height,
+            bounce_position.z + rebound.z * wall_time,
+        ),
+        time: flight_time + wall_time,
+        incident_velocity: Vec3::new(rebound.x, rebound.y - gravity * wall_time, rebound.z),
+        incident_spin: bounce_spin * (-spin_physics.spin_decay_rate * wall_time).exp(),
+    })
+}